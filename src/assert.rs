@@ -1,6 +1,7 @@
 //! Assertions for array
 
 use ndarray::*;
+use num_traits::{Float, Zero};
 use std::fmt::Debug;
 
 use super::norm::*;
@@ -85,6 +86,81 @@ where
     }
 }
 
+/// check two arrays are close in L2 norm relative to `truth`,
+/// and report the worst element and the norms on failure
+pub fn rclose_l2<A, S1, S2, D>(test: &ArrayBase<S1, D>, truth: &ArrayBase<S2, D>, rtol: A::Real)
+where
+    A: Scalar + Lapack,
+    S1: Data<Elem = A>,
+    S2: Data<Elem = A>,
+    D: Dimension,
+    D::Pattern: PartialEq + Debug,
+{
+    assert_eq!(test.dim(), truth.dim());
+    let diff = test - truth;
+    let dev = diff.norm_l2() / truth.norm_l2();
+    if !(dev <= rtol) {
+        report_discrepancy(test, truth, &diff);
+        panic!("Too large deviation in relative L2-norm: {} > {}", dev, rtol);
+    }
+}
+
+/// check two arrays are close elementwise in the absolute tolerance,
+/// and report the worst element and the norms on failure
+pub fn aclose_max<A, S1, S2, D>(test: &ArrayBase<S1, D>, truth: &ArrayBase<S2, D>, atol: A::Real)
+where
+    A: Scalar + Lapack,
+    S1: Data<Elem = A>,
+    S2: Data<Elem = A>,
+    D: Dimension,
+    D::Pattern: PartialEq + Debug,
+{
+    assert_eq!(test.dim(), truth.dim());
+    let diff = test - truth;
+    // NaN is propagated unlike `norm_max`
+    let dev = diff
+        .iter()
+        .map(|x| x.abs())
+        .fold(A::Real::zero(), |m, d| if d > m || Float::is_nan(d) { d } else { m });
+    if !(dev <= atol) {
+        report_discrepancy(test, truth, &diff);
+        panic!("Too large deviation in maximum norm: {} > {}", dev, atol);
+    }
+}
+
+/// Print the element of the largest deviation `|test - truth|` and the L2 norms
+fn report_discrepancy<A, S1, S2, D>(test: &ArrayBase<S1, D>, truth: &ArrayBase<S2, D>, diff: &Array<A, D>)
+where
+    A: Scalar + Lapack,
+    S1: Data<Elem = A>,
+    S2: Data<Elem = A>,
+    D: Dimension,
+    D::Pattern: Debug,
+{
+    eprintln!("==== Assetion Failed ====");
+    // NaN is regarded as the worst deviation
+    let mut worst: Option<(D::Pattern, A, A, A::Real)> = None;
+    for ((idx, d), (&t, &e)) in diff.indexed_iter().zip(test.iter().zip(truth.iter())) {
+        let d = d.abs();
+        let is_worse = match worst.as_ref() {
+            None => true,
+            Some(&(_, _, _, w)) => d > w || (Float::is_nan(d) && !Float::is_nan(w)),
+        };
+        if is_worse {
+            worst = Some((idx, t, e, d));
+        }
+    }
+    if let Some((idx, t, e, d)) = worst {
+        eprintln!("Worst deviation at {:?}:", idx);
+        eprintln!("  Expected = {}", e);
+        eprintln!("  Actual   = {}", t);
+        eprintln!("  |Actual - Expected| = {}", d);
+    }
+    eprintln!("|Actual|   = {}", test.norm_l2());
+    eprintln!("|Expected| = {}", truth.norm_l2());
+    eprintln!("|Actual - Expected| = {}", diff.norm_l2());
+}
+
 macro_rules! generate_assert {
     ($assert:ident, $close:path) => {
         #[macro_export]
@@ -105,3 +181,5 @@ generate_assert!(assert_aclose, aclose);
 generate_assert!(assert_close_max, close_max);
 generate_assert!(assert_close_l1, close_l1);
 generate_assert!(assert_close_l2, close_l2);
+generate_assert!(assert_rclose_l2, rclose_l2);
+generate_assert!(assert_aclose_max, aclose_max);
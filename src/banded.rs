@@ -0,0 +1,462 @@
+//! Band matrices
+//!
+//! [BandedMatrix](struct.BandedMatrix.html) stores an `n x n` matrix with `kl` sub-diagonals and `ku` super-diagonals
+//! in the LAPACK band storage, which takes `O((kl + ku) n)` memory instead of `O(n^2)`.
+//! Linear systems are solved by the banded LU factorization (`*gbtrf`/`*gbtrs`) in `O(kl (kl + ku) n)` time,
+//! e.g. for the discretized differential operators in one dimension.
+//! Hermitian positive definite band matrices, such as the finite difference approximations of elliptic operators
+//! and the normal equations of smoothing splines, are solved by the banded Cholesky factorization
+//! (`*pbtrf`/`*pbtrs`) through [factorizec](struct.BandedMatrix.html#method.factorizec).
+//!
+//! # Example
+//!
+//! ```
+//! #[macro_use]
+//! extern crate ndarray;
+//! extern crate ndarray_linalg;
+//!
+//! use ndarray::prelude::*;
+//! use ndarray_linalg::*;
+//! # fn main() {
+//!
+//! // 1-D Laplacian with the Dirichlet boundary condition
+//! let n = 5;
+//! let mut a = BandedMatrix::zeros(n, 1, 1);
+//! for i in 0..n {
+//!     *a.get_mut(i, i).unwrap() = 2.0;
+//!     if i > 0 {
+//!         *a.get_mut(i, i - 1).unwrap() = -1.0;
+//!         *a.get_mut(i - 1, i).unwrap() = -1.0;
+//!     }
+//! }
+//! let f = a.factorize().unwrap();
+//! let x = f.solve(&Array1::ones(n)).unwrap();
+//! assert!(a.dot(&x).all_close(&Array1::ones(n), 1e-12));
+//! # }
+//! ```
+
+use ndarray::*;
+use num_traits::Float;
+
+use crate::cholesky::*;
+use crate::error::*;
+use crate::layout::*;
+use crate::norm::*;
+use crate::operator::{AdjointOperator, LinearOperator};
+use crate::opnorm::*;
+use crate::solve::*;
+use crate::types::*;
+
+/// Square band matrix in the LAPACK band storage
+#[derive(Debug, Clone)]
+pub struct BandedMatrix<A> {
+    n: usize,
+    kl: usize,
+    ku: usize,
+    /// Column-major `(2 kl + ku + 1) x n` array, where `A[(i, j)]` is stored at `ab[(kl + ku + i - j, j)]`.
+    /// The leading `kl` rows are the workspace for the fill-in of the LU factorization.
+    ab: Array2<A>,
+}
+
+impl<A: Scalar> BandedMatrix<A> {
+    /// Zero matrix of size `n x n` with `kl` sub-diagonals and `ku` super-diagonals
+    pub fn zeros(n: usize, kl: usize, ku: usize) -> Self {
+        BandedMatrix {
+            n,
+            kl,
+            ku,
+            ab: Array2::zeros((2 * kl + ku + 1, n).f()),
+        }
+    }
+
+    /// Copy the band of a dense square matrix, where the elements outside of the band are ignored
+    pub fn from_dense<S>(a: &ArrayBase<S, Ix2>, kl: usize, ku: usize) -> Result<Self>
+    where
+        S: Data<Elem = A>,
+    {
+        let (n, _) = a.square_layout()?.size();
+        let mut b = Self::zeros(n as usize, kl, ku);
+        for j in 0..b.n {
+            for i in b.band_rows(j) {
+                b.ab[(kl + ku + i - j, j)] = a[(i, j)];
+            }
+        }
+        Ok(b)
+    }
+
+    /// Convert to a dense matrix
+    pub fn to_dense(&self) -> Array2<A> {
+        let mut a = Array2::zeros((self.n, self.n));
+        for j in 0..self.n {
+            for i in self.band_rows(j) {
+                a[(i, j)] = self.ab[(self.kl + self.ku + i - j, j)];
+            }
+        }
+        a
+    }
+
+    /// Size `n` of the matrix
+    pub fn size(&self) -> usize {
+        self.n
+    }
+
+    /// Number of sub-diagonals
+    pub fn kl(&self) -> usize {
+        self.kl
+    }
+
+    /// Number of super-diagonals
+    pub fn ku(&self) -> usize {
+        self.ku
+    }
+
+    /// Reference to `A[(i, j)]`, or `None` if it is out of the band
+    pub fn get(&self, i: usize, j: usize) -> Option<&A> {
+        if self.in_band(i, j) {
+            Some(&self.ab[(self.kl + self.ku + i - j, j)])
+        } else {
+            None
+        }
+    }
+
+    /// Mutable reference to `A[(i, j)]`, or `None` if it is out of the band
+    pub fn get_mut(&mut self, i: usize, j: usize) -> Option<&mut A> {
+        if self.in_band(i, j) {
+            Some(&mut self.ab[(self.kl + self.ku + i - j, j)])
+        } else {
+            None
+        }
+    }
+
+    /// Matrix-vector product `A x`
+    pub fn dot<S>(&self, x: &ArrayBase<S, Ix1>) -> Array1<A>
+    where
+        S: Data<Elem = A>,
+    {
+        assert_eq!(x.len(), self.n);
+        let mut y = Array1::zeros(self.n);
+        for j in 0..self.n {
+            for i in self.band_rows(j) {
+                y[i] = y[i] + self.ab[(self.kl + self.ku + i - j, j)] * x[j];
+            }
+        }
+        y
+    }
+
+    fn in_band(&self, i: usize, j: usize) -> bool {
+        i < self.n && j < self.n && i <= j + self.kl && j <= i + self.ku
+    }
+
+    /// Rows of the non-zero elements in the `j`-th column
+    fn band_rows(&self, j: usize) -> ::std::ops::Range<usize> {
+        let start = if j > self.ku { j - self.ku } else { 0 };
+        let end = ::std::cmp::min(self.n, j + self.kl + 1);
+        start..end
+    }
+}
+
+impl<A: Scalar + Lapack> BandedMatrix<A> {
+    /// Compute the LU factorization `A = P L U` with partial pivoting
+    pub fn factorize(&self) -> Result<BandedLUFactorized<A>> {
+        self.clone().factorize_into()
+    }
+
+    /// Compute the LU factorization `A = P L U` with partial pivoting, consuming the matrix
+    pub fn factorize_into(mut self) -> Result<BandedLUFactorized<A>> {
+        let ipiv = unsafe {
+            A::lu_banded(
+                self.n as i32,
+                self.kl as i32,
+                self.ku as i32,
+                self.ab.as_slice_memory_order_mut().unwrap(),
+            )?
+        };
+        Ok(BandedLUFactorized { lu: self, ipiv })
+    }
+}
+
+/// LU factorization of a band matrix, whose `U` has `kl + ku` super-diagonals due to the pivoting
+#[derive(Debug, Clone)]
+pub struct BandedLUFactorized<A> {
+    lu: BandedMatrix<A>,
+    ipiv: Pivot,
+}
+
+impl<A: Scalar + Lapack> BandedLUFactorized<A> {
+    fn solve_with<'a, S>(&self, t: Transpose, rhs: &'a mut ArrayBase<S, Ix1>) -> Result<&'a mut ArrayBase<S, Ix1>>
+    where
+        S: DataMut<Elem = A>,
+    {
+        if rhs.len() != self.lu.n {
+            return Err(ShapeError::from_kind(ErrorKind::IncompatibleShape).into());
+        }
+        unsafe {
+            A::solve_banded(
+                self.lu.n as i32,
+                self.lu.kl as i32,
+                self.lu.ku as i32,
+                t,
+                self.lu.ab.as_slice_memory_order().unwrap(),
+                &self.ipiv,
+                rhs.as_slice_mut().unwrap(),
+            )?
+        };
+        Ok(rhs)
+    }
+}
+
+impl<A: Scalar + Lapack> Solve<A> for BandedLUFactorized<A> {
+    fn solve_inplace<'a, S>(&self, rhs: &'a mut ArrayBase<S, Ix1>) -> Result<&'a mut ArrayBase<S, Ix1>>
+    where
+        S: DataMut<Elem = A>,
+    {
+        self.solve_with(Transpose::No, rhs)
+    }
+    fn solve_t_inplace<'a, S>(&self, rhs: &'a mut ArrayBase<S, Ix1>) -> Result<&'a mut ArrayBase<S, Ix1>>
+    where
+        S: DataMut<Elem = A>,
+    {
+        self.solve_with(Transpose::Transpose, rhs)
+    }
+    fn solve_h_inplace<'a, S>(&self, rhs: &'a mut ArrayBase<S, Ix1>) -> Result<&'a mut ArrayBase<S, Ix1>>
+    where
+        S: DataMut<Elem = A>,
+    {
+        self.solve_with(Transpose::Hermite, rhs)
+    }
+}
+
+/// The factorization acts as the inverse `A^{-1}`, e.g. as a preconditioner
+///
+/// Panics if `A` is singular.
+impl<A: Scalar + Lapack> LinearOperator for BandedLUFactorized<A> {
+    type Elem = A;
+
+    fn apply<S>(&self, a: &ArrayBase<S, Ix1>) -> Array1<A>
+    where
+        S: Data<Elem = A>,
+    {
+        self.solve(a).expect("LU factors must be non-singular")
+    }
+}
+
+impl<A: Scalar + Lapack> AdjointOperator for BandedLUFactorized<A> {
+    fn shape(&self) -> (usize, usize) {
+        (self.lu.n, self.lu.n)
+    }
+
+    fn apply_adjoint<S>(&self, a: &ArrayBase<S, Ix1>) -> Array1<A>
+    where
+        S: Data<Elem = A>,
+    {
+        self.solve_h(a).expect("LU factors must be non-singular")
+    }
+}
+
+impl<A: Scalar + Lapack> Solve<A> for BandedMatrix<A> {
+    fn solve_inplace<'a, S>(&self, rhs: &'a mut ArrayBase<S, Ix1>) -> Result<&'a mut ArrayBase<S, Ix1>>
+    where
+        S: DataMut<Elem = A>,
+    {
+        self.factorize()?.solve_inplace(rhs)
+    }
+    fn solve_t_inplace<'a, S>(&self, rhs: &'a mut ArrayBase<S, Ix1>) -> Result<&'a mut ArrayBase<S, Ix1>>
+    where
+        S: DataMut<Elem = A>,
+    {
+        self.factorize()?.solve_t_inplace(rhs)
+    }
+    fn solve_h_inplace<'a, S>(&self, rhs: &'a mut ArrayBase<S, Ix1>) -> Result<&'a mut ArrayBase<S, Ix1>>
+    where
+        S: DataMut<Elem = A>,
+    {
+        self.factorize()?.solve_h_inplace(rhs)
+    }
+}
+
+impl<A: Scalar> LinearOperator for BandedMatrix<A> {
+    type Elem = A;
+
+    fn apply<S>(&self, a: &ArrayBase<S, Ix1>) -> Array1<S::Elem>
+    where
+        S: Data<Elem = A>,
+    {
+        self.dot(a)
+    }
+}
+
+impl<A: Scalar> AdjointOperator for BandedMatrix<A> {
+    fn shape(&self) -> (usize, usize) {
+        (self.n, self.n)
+    }
+
+    fn apply_adjoint<S>(&self, a: &ArrayBase<S, Ix1>) -> Array1<A>
+    where
+        S: Data<Elem = A>,
+    {
+        assert_eq!(a.len(), self.n);
+        Array1::from_shape_fn(self.n, |j| {
+            self.band_rows(j).fold(A::zero(), |acc, i| {
+                acc + self.ab[(self.kl + self.ku + i - j, j)].conj() * a[i]
+            })
+        })
+    }
+}
+
+/// The 1-, infinity and Frobenius norms are computed from the band storage in `O((kl + ku) n)` time,
+/// while the 2-norm needs the singular values of the dense copy.
+impl<A: Scalar + Lapack> OperationNorm for BandedMatrix<A> {
+    type Output = A::Real;
+
+    fn opnorm(&self, t: NormType) -> Result<Self::Output> {
+        // Rows of the band in the storage, outside of which in the matrix are zeros
+        let band = self.ab.slice(s![self.kl.., ..]);
+        Ok(match t {
+            NormType::One => band
+                .gencolumns()
+                .into_iter()
+                .map(|col| col.iter().map(|x| x.abs()).sum::<A::Real>())
+                .fold(A::Real::zero(), Float::max),
+            NormType::Infinity => {
+                let mut sums = vec![A::Real::zero(); self.n];
+                for j in 0..self.n {
+                    for i in self.band_rows(j) {
+                        sums[i] = sums[i] + self.ab[(self.kl + self.ku + i - j, j)].abs();
+                    }
+                }
+                sums.into_iter().fold(A::Real::zero(), Float::max)
+            }
+            NormType::Frobenius => band.norm_l2(),
+        })
+    }
+
+    fn opnorm_two(&self) -> Result<Self::Output> {
+        self.to_dense().opnorm_two()
+    }
+}
+
+impl<A: Scalar + Lapack> BandedMatrix<A> {
+    /// Compute the Cholesky factorization of a Hermitian (or real symmetric) positive definite band matrix
+    ///
+    /// If `uplo` is `UPLO::Upper`, `A = U^H U` is computed from the `ku` super-diagonals,
+    /// otherwise `A = L L^H` from the `kl` sub-diagonals, and the other triangular part is not referenced.
+    pub fn factorizec(&self, uplo: UPLO) -> Result<BandedCholeskyFactorized<A>> {
+        let (kd, rows) = match uplo {
+            UPLO::Upper => (self.ku, self.kl..self.kl + self.ku + 1),
+            UPLO::Lower => (self.kl, self.kl + self.ku..2 * self.kl + self.ku + 1),
+        };
+        let mut ab = Array2::zeros((kd + 1, self.n).f());
+        ab.assign(&self.ab.slice(s![rows, ..]));
+        let anorm = self.hermitian_opnorm_one(uplo);
+        unsafe { A::cholesky_banded(uplo, self.n as i32, kd as i32, ab.as_slice_memory_order_mut().unwrap())? };
+        Ok(BandedCholeskyFactorized {
+            n: self.n,
+            kd,
+            uplo,
+            ab,
+            anorm,
+        })
+    }
+
+    /// 1-norm of the Hermitian matrix given by the triangular part `uplo`
+    fn hermitian_opnorm_one(&self, uplo: UPLO) -> A::Real {
+        let mut sums = vec![A::Real::zero(); self.n];
+        for j in 0..self.n {
+            for i in self.band_rows(j) {
+                let in_triangle = match uplo {
+                    UPLO::Upper => i <= j,
+                    UPLO::Lower => i >= j,
+                };
+                if in_triangle {
+                    let x = self.ab[(self.kl + self.ku + i - j, j)].abs();
+                    sums[j] = sums[j] + x;
+                    if i != j {
+                        sums[i] = sums[i] + x;
+                    }
+                }
+            }
+        }
+        sums.into_iter().fold(A::Real::zero(), Float::max)
+    }
+}
+
+/// Cholesky factorization of a Hermitian (or real symmetric) positive definite band matrix
+#[derive(Debug, Clone)]
+pub struct BandedCholeskyFactorized<A: Scalar> {
+    n: usize,
+    kd: usize,
+    uplo: UPLO,
+    /// Column-major `(kd + 1) x n` band storage of the factor `U` or `L`
+    ab: Array2<A>,
+    /// 1-norm of the original matrix
+    anorm: A::Real,
+}
+
+impl<A: Scalar + Lapack> SolveC<A> for BandedCholeskyFactorized<A> {
+    fn solvec_inplace<'a, S>(&self, b: &'a mut ArrayBase<S, Ix1>) -> Result<&'a mut ArrayBase<S, Ix1>>
+    where
+        S: DataMut<Elem = A>,
+    {
+        if b.len() != self.n {
+            return Err(ShapeError::from_kind(ErrorKind::IncompatibleShape).into());
+        }
+        unsafe {
+            A::solve_cholesky_banded(
+                self.uplo,
+                self.n as i32,
+                self.kd as i32,
+                self.ab.as_slice_memory_order().unwrap(),
+                b.as_slice_mut().unwrap(),
+            )?
+        };
+        Ok(b)
+    }
+}
+
+impl<A: Scalar + Lapack> ReciprocalConditionNum<A> for BandedCholeskyFactorized<A> {
+    fn rcond(&self) -> Result<A::Real> {
+        unsafe {
+            A::rcond_cholesky_banded(
+                self.uplo,
+                self.n as i32,
+                self.kd as i32,
+                self.ab.as_slice_memory_order().unwrap(),
+                self.anorm,
+            )
+        }
+    }
+}
+
+/// The factorization acts as the inverse `A^{-1}`, which is Hermitian
+impl<A: Scalar + Lapack> LinearOperator for BandedCholeskyFactorized<A> {
+    type Elem = A;
+
+    fn apply<S>(&self, a: &ArrayBase<S, Ix1>) -> Array1<A>
+    where
+        S: Data<Elem = A>,
+    {
+        self.solvec(a).expect("Cholesky factor must be non-singular")
+    }
+}
+
+impl<A: Scalar + Lapack> AdjointOperator for BandedCholeskyFactorized<A> {
+    fn shape(&self) -> (usize, usize) {
+        (self.n, self.n)
+    }
+
+    fn apply_adjoint<S>(&self, a: &ArrayBase<S, Ix1>) -> Array1<A>
+    where
+        S: Data<Elem = A>,
+    {
+        self.apply(a)
+    }
+}
+
+impl<A: Scalar + Lapack> SolveC<A> for BandedMatrix<A> {
+    fn solvec_inplace<'a, S>(&self, b: &'a mut ArrayBase<S, Ix1>) -> Result<&'a mut ArrayBase<S, Ix1>>
+    where
+        S: DataMut<Elem = A>,
+    {
+        self.factorizec(UPLO::Upper)?.solvec_inplace(b)
+    }
+}
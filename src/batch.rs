@@ -0,0 +1,105 @@
+//! Helpers for stacks of matrices, i.e. `Ix3` arrays whose leading axis is the batch axis
+//!
+//! The decompositions of a stack, e.g. [Cholesky](../cholesky/trait.Cholesky.html),
+//! [SolveStack](../solve/trait.SolveStack.html), [Eigh](../eigh/trait.Eigh.html),
+//! [SVD](../svd/trait.SVD.html) and [QR](../qr/trait.QR.html) of `Ix3` arrays,
+//! process the matrices in parallel on the Rayon global thread pool if the `parallel` feature is enabled.
+//!
+//! Each matrix is decomposed by a LAPACK routine, which may be parallelized again by a threaded BLAS,
+//! e.g. OpenBLAS or Intel MKL, so that both together can oversubscribe the cores.
+//! [set_batch_threads](fn.set_batch_threads.html) limits the number of the matrices processed at once,
+//! which should be about the number of cores divided by the number of the BLAS threads
+//! set by [set_num_threads](../threads/fn.set_num_threads.html).
+
+use ndarray::*;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use crate::error::*;
+
+static BATCH_THREADS: AtomicUsize = AtomicUsize::new(0);
+
+/// Limit the number of matrices in a stack processed at once, where `0` (default) means no limit
+///
+/// This has no effect without the `parallel` feature.
+pub fn set_batch_threads(n: usize) {
+    BATCH_THREADS.store(n, Ordering::Relaxed);
+}
+
+/// Current limit set by [set_batch_threads](fn.set_batch_threads.html)
+pub fn batch_threads() -> usize {
+    BATCH_THREADS.load(Ordering::Relaxed)
+}
+
+/// Apply `f` to each item and collect the results in order
+///
+/// With the `parallel` feature, the items are distributed over the Rayon global thread pool,
+/// split into at most `batch_threads()` chunks if it is set.
+fn map_items<I, T, F>(items: Vec<I>, f: F) -> Result<Vec<T>>
+where
+    I: Send + Sync,
+    T: Send,
+    F: Fn(&I) -> Result<T> + Send + Sync,
+{
+    #[cfg(feature = "parallel")]
+    {
+        use rayon::prelude::*;
+        match batch_threads() {
+            0 => items.par_iter().map(f).collect(),
+            threads => {
+                let chunk = std::cmp::max(1, (items.len() + threads - 1) / threads);
+                let res: Result<Vec<Vec<T>>> = items.par_chunks(chunk).map(|c| c.iter().map(&f).collect()).collect();
+                Ok(res?.into_iter().flatten().collect())
+            }
+        }
+    }
+    #[cfg(not(feature = "parallel"))]
+    {
+        items.iter().map(f).collect()
+    }
+}
+
+/// Apply `f` to each matrix in the stack and collect the results in order
+pub(crate) fn map_stack<A, S, T, F>(a: &ArrayBase<S, Ix3>, f: F) -> Result<Vec<T>>
+where
+    A: Send + Sync,
+    S: Data<Elem = A>,
+    T: Send,
+    F: Fn(ArrayView2<A>) -> Result<T> + Send + Sync,
+{
+    map_items(a.outer_iter().collect(), |a| f(a.view()))
+}
+
+/// Apply `f` to each pair of a matrix in the stack `a` and the vector in the corresponding row of `b`
+pub(crate) fn map_stack_with<A, Sa, Sb, T, F>(a: &ArrayBase<Sa, Ix3>, b: &ArrayBase<Sb, Ix2>, f: F) -> Result<Vec<T>>
+where
+    A: Send + Sync,
+    Sa: Data<Elem = A>,
+    Sb: Data<Elem = A>,
+    T: Send,
+    F: Fn(ArrayView2<A>, ArrayView1<A>) -> Result<T> + Send + Sync,
+{
+    if a.len_of(Axis(0)) != b.rows() {
+        return Err(ShapeError::from_kind(ErrorKind::IncompatibleShape).into());
+    }
+    let items: Vec<_> = a.outer_iter().zip(b.outer_iter()).collect();
+    map_items(items, |(a, b)| f(a.view(), b.view()))
+}
+
+/// Stack matrices of shape `dim` into an `Ix3` array
+pub(crate) fn stack_matrices<A: Copy>(dim: (usize, usize), mats: &[Array2<A>]) -> Array3<A> {
+    // every element is assigned below
+    let mut stack = unsafe { Array3::uninitialized((mats.len(), dim.0, dim.1)) };
+    for (mut dst, src) in stack.outer_iter_mut().zip(mats.iter()) {
+        dst.assign(src);
+    }
+    stack
+}
+
+/// Stack vectors of length `len` into an `Ix2` array
+pub(crate) fn stack_vectors<A: Copy>(len: usize, vecs: &[Array1<A>]) -> Array2<A> {
+    let mut stack = unsafe { Array2::uninitialized((vecs.len(), len)) };
+    for (mut dst, src) in stack.outer_iter_mut().zip(vecs.iter()) {
+        dst.assign(src);
+    }
+    stack
+}
@@ -0,0 +1,306 @@
+//! Block-diagonal matrices
+//!
+//! [BlockDiag](struct.BlockDiag.html) stores a matrix `diag(A_1, ..., A_k)` by its square diagonal blocks.
+//! Linear systems, determinants, inverses, and eigenvalue decompositions are computed block by block,
+//! which costs `O(sum_i n_i^3)` instead of `O((sum_i n_i)^3)` for the dense matrix.
+//! The eigenvalues are returned in the order of the blocks (not sorted as a whole),
+//! and the eigenvectors as a block-diagonal matrix.
+//!
+//! # Example
+//!
+//! ```
+//! #[macro_use]
+//! extern crate ndarray;
+//! extern crate ndarray_linalg;
+//!
+//! use ndarray::prelude::*;
+//! use ndarray_linalg::*;
+//! # fn main() {
+//!
+//! let mut a = BlockDiag::new();
+//! a.push(array![[2., 1.], [1., 3.]]).unwrap();
+//! a.push(array![[4.]]).unwrap();
+//! assert_eq!(a.size(), 3);
+//! assert!((a.det().unwrap() - 20.).abs() < 1e-12);
+//! let x = a.solve(&array![3., 4., 8.]).unwrap();
+//! assert!(x.all_close(&array![1., 1., 2.], 1e-12));
+//! # }
+//! ```
+
+use ndarray::*;
+use num_traits::Zero;
+
+use crate::eig::*;
+use crate::eigh::*;
+use crate::error::*;
+use crate::layout::*;
+use crate::operator::{AdjointOperator, LinearOperator};
+use crate::solve::*;
+use crate::types::*;
+
+/// Block-diagonal matrix with square diagonal blocks
+#[derive(Debug, Clone)]
+pub struct BlockDiag<A> {
+    blocks: Vec<Array2<A>>,
+}
+
+impl<A: Scalar> BlockDiag<A> {
+    /// Empty matrix, to which blocks are appended by [push](#method.push)
+    pub fn new() -> Self {
+        BlockDiag { blocks: Vec::new() }
+    }
+
+    /// Create from the diagonal blocks, which must be square
+    pub fn from_blocks(blocks: Vec<Array2<A>>) -> Result<Self> {
+        for block in &blocks {
+            block.ensure_square()?;
+        }
+        Ok(BlockDiag { blocks })
+    }
+
+    /// Append a square block to the lower right corner
+    pub fn push(&mut self, block: Array2<A>) -> Result<()> {
+        block.ensure_square()?;
+        self.blocks.push(block);
+        Ok(())
+    }
+
+    /// Diagonal blocks
+    pub fn blocks(&self) -> &[Array2<A>] {
+        &self.blocks
+    }
+
+    /// Size of the whole matrix, i.e. the sum of the sizes of the blocks
+    pub fn size(&self) -> usize {
+        self.blocks.iter().map(|b| b.rows()).sum()
+    }
+
+    /// Convert to a dense matrix
+    pub fn to_dense(&self) -> Array2<A> {
+        let n = self.size();
+        let mut a = Array2::zeros((n, n));
+        let mut start = 0;
+        for block in &self.blocks {
+            let end = start + block.rows();
+            a.slice_mut(s![start..end, start..end]).assign(block);
+            start = end;
+        }
+        a
+    }
+
+    /// Matrix-vector product computed block by block
+    pub fn dot<S>(&self, x: &ArrayBase<S, Ix1>) -> Array1<A>
+    where
+        S: Data<Elem = A>,
+    {
+        assert_eq!(x.len(), self.size());
+        let mut y = Array1::zeros(x.len());
+        let mut start = 0;
+        for block in &self.blocks {
+            let end = start + block.rows();
+            y.slice_mut(s![start..end]).assign(&block.dot(&x.slice(s![start..end])));
+            start = end;
+        }
+        y
+    }
+}
+
+impl<A: Scalar> Default for BlockDiag<A> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<A: Scalar + Lapack> BlockDiag<A> {
+    /// Compute the LU factorization of each block
+    pub fn factorize(&self) -> Result<BlockDiagLUFactorized<A>> {
+        let factors = self.blocks.iter().map(|b| b.factorize()).collect::<Result<_>>()?;
+        Ok(BlockDiagLUFactorized { factors })
+    }
+}
+
+/// LU factorizations of the blocks of a block-diagonal matrix
+pub struct BlockDiagLUFactorized<A> {
+    factors: Vec<LUFactorized<OwnedRepr<A>>>,
+}
+
+impl<A: Scalar + Lapack> BlockDiagLUFactorized<A> {
+    /// Solve each segment of `rhs` by `solve` with the factorization of the corresponding block
+    fn solve_blockwise<'a, S, F>(&self, rhs: &'a mut ArrayBase<S, Ix1>, solve: F) -> Result<&'a mut ArrayBase<S, Ix1>>
+    where
+        S: DataMut<Elem = A>,
+        F: Fn(&LUFactorized<OwnedRepr<A>>, ArrayView1<A>) -> Result<Array1<A>>,
+    {
+        let n: usize = self.factors.iter().map(|f| f.a.rows()).sum();
+        if rhs.len() != n {
+            return Err(ShapeError::from_kind(ErrorKind::IncompatibleShape).into());
+        }
+        let mut start = 0;
+        for f in &self.factors {
+            let end = start + f.a.rows();
+            let x = solve(f, rhs.slice(s![start..end]))?;
+            rhs.slice_mut(s![start..end]).assign(&x);
+            start = end;
+        }
+        Ok(rhs)
+    }
+}
+
+impl<A: Scalar + Lapack> Solve<A> for BlockDiagLUFactorized<A> {
+    fn solve_inplace<'a, S>(&self, rhs: &'a mut ArrayBase<S, Ix1>) -> Result<&'a mut ArrayBase<S, Ix1>>
+    where
+        S: DataMut<Elem = A>,
+    {
+        self.solve_blockwise(rhs, |f, b| f.solve(&b))
+    }
+    fn solve_t_inplace<'a, S>(&self, rhs: &'a mut ArrayBase<S, Ix1>) -> Result<&'a mut ArrayBase<S, Ix1>>
+    where
+        S: DataMut<Elem = A>,
+    {
+        self.solve_blockwise(rhs, |f, b| f.solve_t(&b))
+    }
+    fn solve_h_inplace<'a, S>(&self, rhs: &'a mut ArrayBase<S, Ix1>) -> Result<&'a mut ArrayBase<S, Ix1>>
+    where
+        S: DataMut<Elem = A>,
+    {
+        self.solve_blockwise(rhs, |f, b| f.solve_h(&b))
+    }
+}
+
+impl<A: Scalar + Lapack> Solve<A> for BlockDiag<A> {
+    fn solve_inplace<'a, S>(&self, rhs: &'a mut ArrayBase<S, Ix1>) -> Result<&'a mut ArrayBase<S, Ix1>>
+    where
+        S: DataMut<Elem = A>,
+    {
+        self.factorize()?.solve_inplace(rhs)
+    }
+    fn solve_t_inplace<'a, S>(&self, rhs: &'a mut ArrayBase<S, Ix1>) -> Result<&'a mut ArrayBase<S, Ix1>>
+    where
+        S: DataMut<Elem = A>,
+    {
+        self.factorize()?.solve_t_inplace(rhs)
+    }
+    fn solve_h_inplace<'a, S>(&self, rhs: &'a mut ArrayBase<S, Ix1>) -> Result<&'a mut ArrayBase<S, Ix1>>
+    where
+        S: DataMut<Elem = A>,
+    {
+        self.factorize()?.solve_h_inplace(rhs)
+    }
+}
+
+/// Multiply the signs and add the logarithms of the determinants of the blocks
+fn sln_det_blockwise<A, I>(dets: I) -> Result<(A, A::Real)>
+where
+    A: Scalar,
+    I: Iterator<Item = Result<(A, A::Real)>>,
+{
+    let mut sign = A::one();
+    let mut ln_det = A::Real::zero();
+    for det in dets {
+        let (s, l) = det?;
+        sign = sign * s;
+        ln_det = ln_det + l;
+    }
+    Ok((sign, ln_det))
+}
+
+impl<A: Scalar + Lapack> Determinant<A> for BlockDiagLUFactorized<A> {
+    fn sln_det(&self) -> Result<(A, A::Real)> {
+        sln_det_blockwise(self.factors.iter().map(|f| f.sln_det()))
+    }
+}
+
+impl<A: Scalar + Lapack> Determinant<A> for BlockDiag<A> {
+    fn sln_det(&self) -> Result<(A, A::Real)> {
+        // Singular blocks are handled by the dense determinant
+        sln_det_blockwise(self.blocks.iter().map(|b| b.sln_det()))
+    }
+}
+
+impl<A: Scalar + Lapack> Inverse for BlockDiagLUFactorized<A> {
+    type Output = BlockDiag<A>;
+
+    fn inv(&self) -> Result<BlockDiag<A>> {
+        let blocks = self.factors.iter().map(|f| f.inv()).collect::<Result<_>>()?;
+        Ok(BlockDiag { blocks })
+    }
+}
+
+impl<A: Scalar + Lapack> Inverse for BlockDiag<A> {
+    type Output = BlockDiag<A>;
+
+    fn inv(&self) -> Result<BlockDiag<A>> {
+        self.factorize()?.inv()
+    }
+}
+
+impl<A> Eig for BlockDiag<A>
+where
+    A: Scalar + Lapack,
+{
+    type EigVal = Array1<A::Complex>;
+    type EigVec = BlockDiag<A::Complex>;
+
+    fn eig(&self) -> Result<(Self::EigVal, Self::EigVec)> {
+        let mut values = Vec::with_capacity(self.size());
+        let mut vectors = Vec::with_capacity(self.blocks.len());
+        for block in &self.blocks {
+            let (e, v) = block.eig()?;
+            values.extend(e.iter().cloned());
+            vectors.push(v);
+        }
+        Ok((Array1::from_vec(values), BlockDiag { blocks: vectors }))
+    }
+}
+
+impl<A> Eigh for BlockDiag<A>
+where
+    A: Scalar + Lapack,
+{
+    type EigVal = Array1<A::Real>;
+    type EigVec = BlockDiag<A>;
+
+    fn eigh(&self, uplo: UPLO) -> Result<(Self::EigVal, Self::EigVec)> {
+        let mut values = Vec::with_capacity(self.size());
+        let mut vectors = Vec::with_capacity(self.blocks.len());
+        for block in &self.blocks {
+            let (e, v) = block.eigh(uplo)?;
+            values.extend(e.iter().cloned());
+            vectors.push(v);
+        }
+        Ok((Array1::from_vec(values), BlockDiag { blocks: vectors }))
+    }
+}
+
+impl<A: Scalar> LinearOperator for BlockDiag<A> {
+    type Elem = A;
+
+    fn apply<S>(&self, a: &ArrayBase<S, Ix1>) -> Array1<S::Elem>
+    where
+        S: Data<Elem = A>,
+    {
+        self.dot(a)
+    }
+}
+
+impl<A: Scalar> AdjointOperator for BlockDiag<A> {
+    fn shape(&self) -> (usize, usize) {
+        (self.size(), self.size())
+    }
+
+    fn apply_adjoint<S>(&self, a: &ArrayBase<S, Ix1>) -> Array1<A>
+    where
+        S: Data<Elem = A>,
+    {
+        assert_eq!(a.len(), self.size());
+        let mut y = Array1::zeros(a.len());
+        let mut start = 0;
+        for block in &self.blocks {
+            let end = start + block.rows();
+            y.slice_mut(s![start..end])
+                .assign(&block.apply_adjoint(&a.slice(s![start..end])));
+            start = end;
+        }
+        y
+    }
+}
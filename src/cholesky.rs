@@ -42,13 +42,19 @@
 //! assert!(x.all_close(&array![-2., 1., 0.], 1e-9));
 //! # }
 //! ```
+//!
+//! [Cholesky](trait.Cholesky.html) is also implemented for stacks of matrices (`Ix3` arrays whose leading axis
+//! is the batch axis), which are decomposed in parallel if the `parallel` feature is enabled.
 
 use ndarray::*;
 use num_traits::Float;
 
+use crate::batch::*;
 use crate::convert::*;
 use crate::error::*;
+use crate::lapack::Cholesky_;
 use crate::layout::*;
+use crate::operator::{AdjointOperator, LinearOperator};
 use crate::triangular::IntoTriangular;
 use crate::types::*;
 
@@ -66,7 +72,7 @@ pub struct CholeskyFactorized<S: Data> {
 
 impl<A, S> CholeskyFactorized<S>
 where
-    A: Scalar + Lapack,
+    A: Scalar + Cholesky_,
     S: DataMut<Elem = A>,
 {
     /// Returns `L` from the Cholesky decomposition `A = L * L^H`.
@@ -96,7 +102,7 @@ where
 
 impl<A, S> DeterminantC for CholeskyFactorized<S>
 where
-    A: Scalar + Lapack,
+    A: Scalar + Cholesky_,
     S: Data<Elem = A>,
 {
     type Output = <A as Scalar>::Real;
@@ -116,7 +122,7 @@ where
 
 impl<A, S> DeterminantCInto for CholeskyFactorized<S>
 where
-    A: Scalar + Lapack,
+    A: Scalar + Cholesky_,
     S: Data<Elem = A>,
 {
     type Output = <A as Scalar>::Real;
@@ -132,7 +138,7 @@ where
 
 impl<A, S> InverseC for CholeskyFactorized<S>
 where
-    A: Scalar + Lapack,
+    A: Scalar + Cholesky_,
     S: Data<Elem = A>,
 {
     type Output = Array2<A>;
@@ -148,7 +154,7 @@ where
 
 impl<A, S> InverseCInto for CholeskyFactorized<S>
 where
-    A: Scalar + Lapack,
+    A: Scalar + Cholesky_,
     S: DataMut<Elem = A>,
 {
     type Output = ArrayBase<S, Ix2>;
@@ -163,7 +169,7 @@ where
 
 impl<A, S> SolveC<A> for CholeskyFactorized<S>
 where
-    A: Scalar + Lapack,
+    A: Cholesky_,
     S: Data<Elem = A>,
 {
     fn solvec_inplace<'a, Sb>(&self, b: &'a mut ArrayBase<Sb, Ix1>) -> Result<&'a mut ArrayBase<Sb, Ix1>>
@@ -182,6 +188,39 @@ where
     }
 }
 
+/// The factorization acts as the inverse `A^{-1}`, which is Hermitian
+impl<A, S> LinearOperator for CholeskyFactorized<S>
+where
+    A: Scalar + Cholesky_,
+    S: Data<Elem = A>,
+{
+    type Elem = A;
+
+    fn apply<Sb>(&self, a: &ArrayBase<Sb, Ix1>) -> Array1<A>
+    where
+        Sb: Data<Elem = A>,
+    {
+        self.solvec(a).expect("Cholesky factor must be non-singular")
+    }
+}
+
+impl<A, S> AdjointOperator for CholeskyFactorized<S>
+where
+    A: Scalar + Cholesky_,
+    S: Data<Elem = A>,
+{
+    fn shape(&self) -> (usize, usize) {
+        self.factor.dim()
+    }
+
+    fn apply_adjoint<Sb>(&self, a: &ArrayBase<Sb, Ix1>) -> Array1<A>
+    where
+        Sb: Data<Elem = A>,
+    {
+        self.apply(a)
+    }
+}
+
 /// Cholesky decomposition of Hermitian (or real symmetric) positive definite matrix reference
 pub trait Cholesky {
     type Output;
@@ -226,7 +265,7 @@ pub trait CholeskyInplace {
 
 impl<A, S> Cholesky for ArrayBase<S, Ix2>
 where
-    A: Scalar + Lapack,
+    A: Cholesky_,
     S: Data<Elem = A>,
 {
     type Output = Array2<A>;
@@ -237,9 +276,23 @@ where
     }
 }
 
+impl<A, S> Cholesky for ArrayBase<S, Ix3>
+where
+    A: Cholesky_ + Send + Sync,
+    S: Data<Elem = A>,
+{
+    type Output = Array3<A>;
+
+    fn cholesky(&self, uplo: UPLO) -> Result<Array3<A>> {
+        let (_, n, _) = self.dim();
+        let res = map_stack(self, |a| a.cholesky(uplo))?;
+        Ok(stack_matrices((n, n), &res))
+    }
+}
+
 impl<A, S> CholeskyInto for ArrayBase<S, Ix2>
 where
-    A: Scalar + Lapack,
+    A: Cholesky_,
     S: DataMut<Elem = A>,
 {
     type Output = Self;
@@ -252,7 +305,7 @@ where
 
 impl<A, S> CholeskyInplace for ArrayBase<S, Ix2>
 where
-    A: Scalar + Lapack,
+    A: Cholesky_,
     S: DataMut<Elem = A>,
 {
     fn cholesky_inplace(&mut self, uplo: UPLO) -> Result<&mut Self> {
@@ -289,7 +342,7 @@ pub trait FactorizeCInto<S: Data> {
 
 impl<A, S> FactorizeCInto<S> for ArrayBase<S, Ix2>
 where
-    A: Scalar + Lapack,
+    A: Cholesky_,
     S: DataMut<Elem = A>,
 {
     fn factorizec_into(self, uplo: UPLO) -> Result<CholeskyFactorized<S>> {
@@ -302,7 +355,7 @@ where
 
 impl<A, Si> FactorizeC<OwnedRepr<A>> for ArrayBase<Si, Ix2>
 where
-    A: Scalar + Lapack,
+    A: Cholesky_,
     Si: Data<Elem = A>,
 {
     fn factorizec(&self, uplo: UPLO) -> Result<CholeskyFactorized<OwnedRepr<A>>> {
@@ -315,7 +368,7 @@ where
 
 /// Solve systems of linear equations with Hermitian (or real symmetric)
 /// positive definite coefficient matrices
-pub trait SolveC<A: Scalar> {
+pub trait SolveC<A: LinalgScalar> {
     /// Solves a system of linear equations `A * x = b` with Hermitian (or real
     /// symmetric) positive definite matrix `A`, where `A` is `self`, `b` is
     /// the argument, and `x` is the successful result.
@@ -343,7 +396,7 @@ pub trait SolveC<A: Scalar> {
 
 impl<A, S> SolveC<A> for ArrayBase<S, Ix2>
 where
-    A: Scalar + Lapack,
+    A: Cholesky_,
     S: Data<Elem = A>,
 {
     fn solvec_inplace<'a, Sb>(&self, b: &'a mut ArrayBase<Sb, Ix1>) -> Result<&'a mut ArrayBase<Sb, Ix1>>
@@ -372,7 +425,7 @@ pub trait InverseCInto {
 
 impl<A, S> InverseC for ArrayBase<S, Ix2>
 where
-    A: Scalar + Lapack,
+    A: Scalar + Cholesky_,
     S: Data<Elem = A>,
 {
     type Output = Array2<A>;
@@ -384,7 +437,7 @@ where
 
 impl<A, S> InverseCInto for ArrayBase<S, Ix2>
 where
-    A: Scalar + Lapack,
+    A: Scalar + Cholesky_,
     S: DataMut<Elem = A>,
 {
     type Output = Self;
@@ -430,7 +483,7 @@ pub trait DeterminantCInto {
 
 impl<A, S> DeterminantC for ArrayBase<S, Ix2>
 where
-    A: Scalar + Lapack,
+    A: Scalar + Cholesky_,
     S: Data<Elem = A>,
 {
     type Output = Result<<A as Scalar>::Real>;
@@ -446,7 +499,7 @@ where
 
 impl<A, S> DeterminantCInto for ArrayBase<S, Ix2>
 where
-    A: Scalar + Lapack,
+    A: Scalar + Cholesky_,
     S: DataMut<Elem = A>,
 {
     type Output = Result<<A as Scalar>::Real>;
@@ -0,0 +1,171 @@
+//! Circulant matrices
+//!
+//! [Circulant](struct.Circulant.html) stores an `n x n` matrix `C[(i, j)] = c_{(i - j) mod n}` by its first column `c`.
+//! `C x` is the cyclic convolution of `c` and `x`, and `C` is diagonalized by the discrete Fourier transform,
+//! `C = F^{-1} diag(λ) F` with the eigenvalues `λ = F c`.
+//! Products, linear systems, the eigenvalues, and the determinant are computed by the [FFT](../fft/index.html)
+//! in `O(n log n)` time, which gives the convolution and deconvolution with periodic boundaries.
+//!
+//! # Example
+//!
+//! ```
+//! #[macro_use]
+//! extern crate ndarray;
+//! extern crate ndarray_linalg;
+//!
+//! use ndarray::prelude::*;
+//! use ndarray_linalg::*;
+//! # fn main() {
+//!
+//! // Periodic blur kernel
+//! let c = Circulant::new(array![0.5, 0.25, 0., 0., 0.25]);
+//! let x = array![1., 0., 0., 2., 0.];
+//! let b = c.dot(&x);
+//! assert!(b.all_close(&array![0.5, 0.25, 0.5, 1., 0.75], 1e-12));
+//! assert!(c.solve(&b).unwrap().all_close(&x, 1e-12));
+//! # }
+//! ```
+
+use ndarray::*;
+use num_traits::{One, Zero};
+
+use crate::error::*;
+use crate::fft::*;
+use crate::operator::{AdjointOperator, LinearOperator};
+use crate::solve::*;
+use crate::types::*;
+
+/// Circulant matrix given by its first column
+///
+/// Solving fails with `LinalgError::Lapack { return_code: k }` if the `k`-th eigenvalue is exactly zero.
+#[derive(Debug, Clone)]
+pub struct Circulant<A: Scalar> {
+    c: Array1<A>,
+    /// Eigenvalues `λ_k = sum_j c_j exp(-2 pi i j k / n)`
+    eigs: Array1<A::Complex>,
+}
+
+impl<A: Fourier> Circulant<A> {
+    /// Create from the first column
+    pub fn new(c: Array1<A>) -> Self {
+        let eigs = A::fft(&c);
+        Circulant { c, eigs }
+    }
+
+    /// Convert to a dense matrix
+    pub fn to_dense(&self) -> Array2<A> {
+        Array2::from_shape_fn((self.size(), self.size()), |(i, j)| self.get(i, j))
+    }
+
+    /// `C[(i, j)]`
+    pub fn get(&self, i: usize, j: usize) -> A {
+        let n = self.size();
+        assert!(i < n && j < n);
+        self.c[(i + n - j) % n]
+    }
+
+    /// Size `n` of the matrix
+    pub fn size(&self) -> usize {
+        self.c.len()
+    }
+
+    /// First column
+    pub fn column(&self) -> ArrayView1<A> {
+        self.c.view()
+    }
+
+    /// Eigenvalues `λ_k = sum_j c_j exp(-2 pi i j k / n)`, whose eigenvectors are `exp(2 pi i j k / n)`
+    pub fn eigenvalues(&self) -> ArrayView1<A::Complex> {
+        self.eigs.view()
+    }
+
+    /// Determinant as the product of the eigenvalues
+    ///
+    /// This is complex even for real matrices, whose imaginary part is only the rounding error.
+    pub fn det(&self) -> A::Complex {
+        self.eigs.iter().fold(A::Complex::one(), |acc, &l| acc * l)
+    }
+
+    /// Matrix-vector product `C x`, i.e. the cyclic convolution of `c` and `x`
+    pub fn dot<S>(&self, x: &ArrayBase<S, Ix1>) -> Array1<A>
+    where
+        S: Data<Elem = A>,
+    {
+        assert_eq!(x.len(), self.size());
+        let y = A::fft(x) * &self.eigs;
+        A::ifft(&y)
+    }
+
+    /// Solve by dividing the DFT of `rhs` by the eigenvalues `eigs`
+    fn solve_with<'a, S>(
+        &self,
+        eigs: &Array1<A::Complex>,
+        rhs: &'a mut ArrayBase<S, Ix1>,
+    ) -> Result<&'a mut ArrayBase<S, Ix1>>
+    where
+        S: DataMut<Elem = A>,
+    {
+        if rhs.len() != self.size() {
+            return Err(ShapeError::from_kind(ErrorKind::IncompatibleShape).into());
+        }
+        if let Some(k) = eigs.iter().position(|l| l.is_zero()) {
+            return Err(LinalgError::Lapack {
+                return_code: k as i32 + 1,
+            });
+        }
+        let y = A::fft(&*rhs) / eigs;
+        rhs.assign(&A::ifft(&y));
+        Ok(rhs)
+    }
+}
+
+impl<A: Fourier> Solve<A> for Circulant<A> {
+    fn solve_inplace<'a, S>(&self, rhs: &'a mut ArrayBase<S, Ix1>) -> Result<&'a mut ArrayBase<S, Ix1>>
+    where
+        S: DataMut<Elem = A>,
+    {
+        self.solve_with(&self.eigs, rhs)
+    }
+    fn solve_t_inplace<'a, S>(&self, rhs: &'a mut ArrayBase<S, Ix1>) -> Result<&'a mut ArrayBase<S, Ix1>>
+    where
+        S: DataMut<Elem = A>,
+    {
+        // `C^T` is circulant with the eigenvalues `λ_{-k mod n}`
+        let n = self.size();
+        let eigs = Array1::from_shape_fn(n, |k| self.eigs[(n - k) % n]);
+        self.solve_with(&eigs, rhs)
+    }
+    fn solve_h_inplace<'a, S>(&self, rhs: &'a mut ArrayBase<S, Ix1>) -> Result<&'a mut ArrayBase<S, Ix1>>
+    where
+        S: DataMut<Elem = A>,
+    {
+        let eigs = self.eigs.mapv(|l| l.conj());
+        self.solve_with(&eigs, rhs)
+    }
+}
+
+impl<A: Fourier> LinearOperator for Circulant<A> {
+    type Elem = A;
+
+    fn apply<S>(&self, a: &ArrayBase<S, Ix1>) -> Array1<S::Elem>
+    where
+        S: Data<Elem = A>,
+    {
+        self.dot(a)
+    }
+}
+
+impl<A: Fourier> AdjointOperator for Circulant<A> {
+    fn shape(&self) -> (usize, usize) {
+        (self.size(), self.size())
+    }
+
+    fn apply_adjoint<S>(&self, a: &ArrayBase<S, Ix1>) -> Array1<A>
+    where
+        S: Data<Elem = A>,
+    {
+        assert_eq!(a.len(), self.size());
+        let y = A::fft(a) * &self.eigs.mapv(|l| l.conj());
+        A::ifft(&y)
+    }
+}
@@ -0,0 +1,89 @@
+//! Companion matrices and roots of polynomials
+//!
+//! The roots of `p(x) = a_0 x^n + a_1 x^{n-1} + ... + a_n` are the eigenvalues of
+//! its [companion](fn.companion.html) matrix.
+//! [poly_roots](fn.poly_roots.html) computes them by the QR algorithm on the balanced companion matrix,
+//! which is backward stable with respect to the matrix, as `roots` of MATLAB and NumPy.
+//!
+//! - A. Edelman and H. Murakami, Polynomial roots from companion matrix eigenvalues, Math. Comp. 64 (1995)
+//!
+//! # Example
+//!
+//! ```
+//! #[macro_use]
+//! extern crate ndarray;
+//! extern crate ndarray_linalg;
+//!
+//! use ndarray::prelude::*;
+//! use ndarray_linalg::*;
+//! # fn main() {
+//!
+//! // x^2 - 3x + 2 = (x - 1)(x - 2)
+//! let c = companion(&array![1., -3., 2.]).unwrap();
+//! assert_eq!(c, array![[3., -2.], [1., 0.]]);
+//! let mut roots: Vec<f64> = poly_roots(&array![1., -3., 2.]).unwrap().iter().map(|z| z.re).collect();
+//! roots.sort_by(|a, b| a.partial_cmp(b).unwrap());
+//! assert!((roots[0] - 1.).abs() < 1e-12 && (roots[1] - 2.).abs() < 1e-12);
+//! # }
+//! ```
+
+use ndarray::*;
+use num_traits::Zero;
+
+use crate::eig::*;
+use crate::error::*;
+use crate::types::*;
+
+/// Companion matrix of `p(x) = a_0 x^n + a_1 x^{n-1} + ... + a_n` given by the coefficients `a_0, ..., a_n`
+///
+/// The first row is `-a_1 / a_0, ..., -a_n / a_0` and the subdiagonal is one,
+/// where leading zero coefficients are skipped to determine the degree `n`.
+/// Fails if the degree is less than one.
+pub fn companion<A, S>(coeffs: &ArrayBase<S, Ix1>) -> Result<Array2<A>>
+where
+    A: Scalar,
+    S: Data<Elem = A>,
+{
+    let first = coeffs
+        .iter()
+        .position(|a| !a.is_zero())
+        .ok_or_else(|| ShapeError::from_kind(ErrorKind::IncompatibleShape))?;
+    let p = coeffs.slice(s![first..]);
+    let n = p.len() - 1;
+    if n == 0 {
+        return Err(ShapeError::from_kind(ErrorKind::IncompatibleShape).into());
+    }
+    let mut c = Array2::zeros((n, n));
+    for j in 0..n {
+        c[(0, j)] = A::zero() - p[j + 1] / p[0];
+    }
+    for i in 1..n {
+        c[(i, i - 1)] = A::one();
+    }
+    Ok(c)
+}
+
+/// Roots of `p(x) = a_0 x^n + a_1 x^{n-1} + ... + a_n` given by the coefficients `a_0, ..., a_n`
+///
+/// The roots are the eigenvalues of the balanced companion matrix, in no particular order.
+/// Leading zero coefficients lower the degree, and trailing zero coefficients give roots at exactly zero.
+/// A constant polynomial has no roots, and fails if it is zero.
+pub fn poly_roots<A, S>(coeffs: &ArrayBase<S, Ix1>) -> Result<Array1<A::Complex>>
+where
+    A: Scalar + Lapack,
+    S: Data<Elem = A>,
+{
+    let first = coeffs
+        .iter()
+        .position(|a| !a.is_zero())
+        .ok_or_else(|| ShapeError::from_kind(ErrorKind::IncompatibleShape))?;
+    let last = (0..coeffs.len()).rev().find(|&i| !coeffs[i].is_zero()).unwrap();
+    let p = coeffs.slice(s![first..last + 1]);
+    let mut roots = if p.len() > 1 {
+        companion(&p)?.eigvals()?.to_vec()
+    } else {
+        Vec::new()
+    };
+    roots.extend((last + 1..coeffs.len()).map(|_| A::Complex::zero()));
+    Ok(Array1::from_vec(roots))
+}
@@ -0,0 +1,109 @@
+//! Condition numbers
+//!
+//! The condition number `κ(A) = |A| |A^{-1}|` bounds the relative error of the solution of `A x = b`
+//! by `κ(A)` times the relative perturbation of `A` or `b`.
+//! [Cond](trait.Cond.html) computes it in the norm specified by [CondNorm](enum.CondNorm.html):
+//!
+//! - `One` and `Infinity` are *estimated* from the LU factorization by LAPACK `*gecon`
+//!   in `O(n^2)` operations after the factorization
+//! - `Two` is computed exactly from the singular values as `σ_max / σ_min`,
+//!   which is also defined for rectangular matrices
+//! - `Frobenius` is computed exactly from the inverse
+//!
+//! All of them are infinite for singular matrices.
+//!
+//! # Example
+//!
+//! ```
+//! use ndarray::*;
+//! use ndarray_linalg::*;
+//!
+//! let a = arr2(&[[1.0, 0.0], [0.0, 1e-3]]);
+//! assert!((a.cond(CondNorm::Two).unwrap() - 1e3).abs() < 1e-9);
+//! assert!((a.cond(CondNorm::One).unwrap() - 1e3).abs() < 1e-9);
+//! ```
+
+use ndarray::*;
+use num_traits::{Float, Zero};
+
+use crate::error::*;
+use crate::layout::*;
+use crate::opnorm::*;
+use crate::solve::*;
+use crate::svd::*;
+use crate::types::*;
+
+/// Norm in which the condition number is measured
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CondNorm {
+    /// Maximum column sum
+    One,
+    /// Largest singular value
+    Two,
+    /// Maximum row sum
+    Infinity,
+    /// Square root of the sum of squares
+    Frobenius,
+}
+
+/// Condition number of a matrix reference
+pub trait Cond {
+    type Output;
+
+    /// Condition number `|A| |A^{-1}|` in the norm `norm`
+    ///
+    /// Fails with `NotSquare` unless `norm` is `Two`.
+    fn cond(&self, norm: CondNorm) -> Result<Self::Output>;
+}
+
+impl<A, S> Cond for ArrayBase<S, Ix2>
+where
+    A: Scalar + Lapack,
+    S: Data<Elem = A>,
+{
+    type Output = A::Real;
+
+    fn cond(&self, norm: CondNorm) -> Result<A::Real> {
+        match norm {
+            CondNorm::One => cond_one(self),
+            // `κ_∞(A) = κ_1(A^T)`
+            CondNorm::Infinity => cond_one(&self.t()),
+            CondNorm::Two => {
+                if self.is_empty() {
+                    return Ok(A::Real::zero());
+                }
+                let (_, s, _) = self.svd(false, false)?;
+                Ok(s[0] / s[s.len() - 1])
+            }
+            CondNorm::Frobenius => {
+                self.ensure_square()?;
+                match self.inv() {
+                    Ok(inv) => Ok(self.opnorm_fro()? * inv.opnorm_fro()?),
+                    Err(LinalgError::Lapack { return_code }) if return_code > 0 => Ok(A::Real::infinity()),
+                    Err(err) => Err(err),
+                }
+            }
+        }
+    }
+}
+
+/// Estimate `κ_1(A)` by `*gecon`, which needs the 1-norm of `A` itself besides the LU factors
+fn cond_one<A, S>(a: &ArrayBase<S, Ix2>) -> Result<A::Real>
+where
+    A: Scalar + Lapack,
+    S: Data<Elem = A>,
+{
+    a.ensure_square()?;
+    let anorm = a.opnorm_one()?;
+    let f = match a.factorize() {
+        Ok(f) => f,
+        Err(LinalgError::Lapack { return_code }) if return_code > 0 => return Ok(A::Real::infinity()),
+        Err(err) => return Err(err),
+    };
+    let rcond = unsafe { A::rcond(f.a.layout()?, f.a.as_allocated()?, anorm)? };
+    Ok(if rcond.is_zero() {
+        A::Real::infinity()
+    } else {
+        Float::recip(rcond)
+    })
+}
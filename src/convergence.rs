@@ -0,0 +1,222 @@
+//! Convergence criteria for iterative algorithms
+//!
+//! Iterative solvers and eigensolvers share the [StoppingCriterion](enum.StoppingCriterion.html)
+//! to decide when an iterate is accurate enough, and record the measured error of each iterate
+//! into an [IterationReport](struct.IterationReport.html) so that convergence histories of
+//! different methods can be compared.
+//!
+//! For a linear system `A x = b` the error is measured from the residual `r = b - A x`.
+//! For an eigenproblem `A x = λ x`, use `r = A x - λ x` and `b = λ x`;
+//! then the normwise backward error becomes the usual `|r| / ((|A| + |λ|) |x|)`.
+//!
+//! # Example
+//!
+//! ```
+//! #[macro_use]
+//! extern crate ndarray;
+//! extern crate ndarray_linalg;
+//!
+//! use ndarray::prelude::*;
+//! use ndarray_linalg::*;
+//! # fn main() {
+//!
+//! let b = array![1.0, 0.0];
+//! let x = array![0.9, 0.0];
+//! let r = array![0.1, 0.0]; // b - A x with A = I
+//!
+//! let mut report = IterationReport::new(StoppingCriterion::RelativeResidual(1e-3));
+//! let err = report.criterion.error(&Residual::new(r.view(), b.view(), x.view()));
+//! assert!(!report.record(err));
+//! assert_eq!(report.history, vec![0.1]);
+//! # }
+//! ```
+
+use ndarray::*;
+use num_traits::Float;
+
+use crate::norm::Norm;
+use crate::types::*;
+
+/// Stopping criterion of iterative algorithms
+///
+/// `|.|` denotes the Euclidean norm for vectors and the norm given by
+/// [Residual::a_norm](struct.Residual.html#structfield.a_norm) for the operator.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum StoppingCriterion<R> {
+    /// `|r| <= tol`
+    Absolute(R),
+    /// `|r| <= tol |b|`
+    RelativeResidual(R),
+    /// Normwise backward error `|r| <= tol (|A| |x| + |b|)`
+    BackwardError(R),
+    /// Component-wise backward error `max_i |r_i| / (|A| |x| + |b|)_i <= tol`
+    ComponentWise(R),
+    /// `|r| <= max(rtol |b|, atol)`, the relative residual with an absolute floor
+    ///
+    /// The error is measured relative to `max(|b|, atol / rtol)`, and compared with `rtol`
+    /// (or the absolute residual compared with `atol` if `rtol` is zero).
+    Mixed { rtol: R, atol: R },
+}
+
+impl<R: Float> Default for StoppingCriterion<R> {
+    /// Relative residual with the square root of the machine epsilon
+    fn default() -> Self {
+        StoppingCriterion::RelativeResidual(R::epsilon().sqrt())
+    }
+}
+
+impl<R: Float> StoppingCriterion<R> {
+    /// Tolerance of the criterion
+    pub fn tolerance(&self) -> R {
+        match *self {
+            StoppingCriterion::Absolute(tol)
+            | StoppingCriterion::RelativeResidual(tol)
+            | StoppingCriterion::BackwardError(tol)
+            | StoppingCriterion::ComponentWise(tol) => tol,
+            StoppingCriterion::Mixed { rtol, atol } => {
+                if rtol.is_zero() {
+                    atol
+                } else {
+                    rtol
+                }
+            }
+        }
+    }
+
+    /// Measure the error of an iterate in the sense of this criterion
+    ///
+    /// If `|b|` vanishes, normwise criteria fall back to the absolute residual.
+    /// Missing `a_norm` and `abs_ax` are regarded as zero.
+    pub fn error<A>(&self, res: &Residual<A>) -> R
+    where
+        A: Scalar<Real = R> + Lapack,
+    {
+        match *self {
+            StoppingCriterion::Absolute(_)
+            | StoppingCriterion::RelativeResidual(_)
+            | StoppingCriterion::BackwardError(_)
+            | StoppingCriterion::Mixed { .. } => {
+                self.error_from_norms(res.r.norm_l2(), res.b.norm_l2(), res.x.norm_l2(), res.a_norm)
+            }
+            StoppingCriterion::ComponentWise(_) => res
+                .r
+                .iter()
+                .zip(res.b.iter())
+                .enumerate()
+                .map(|(i, (r, b))| {
+                    let ax = res.abs_ax.as_ref().map(|v| v[i]).unwrap_or_else(R::zero);
+                    scale_componentwise(r.abs(), ax + b.abs())
+                })
+                .fold(R::zero(), R::max),
+        }
+    }
+
+    /// Measure the error only from the norms `|r|`, `|b|` and `|x|`
+    ///
+    /// This is for methods which estimate the residual norm without forming the residual,
+    /// e.g. Ritz pairs in Krylov eigensolvers.
+    /// Since no component is available, `ComponentWise` is evaluated as `BackwardError`.
+    pub fn error_from_norms(&self, r: R, b: R, x: R, a_norm: Option<R>) -> R {
+        match *self {
+            StoppingCriterion::Absolute(_) => r,
+            StoppingCriterion::RelativeResidual(_) => scale_normwise(r, b),
+            StoppingCriterion::Mixed { rtol, atol } => {
+                if rtol.is_zero() {
+                    r
+                } else {
+                    scale_normwise(r, b.max(atol / rtol))
+                }
+            }
+            StoppingCriterion::BackwardError(_) | StoppingCriterion::ComponentWise(_) => {
+                let a = a_norm.unwrap_or_else(R::zero);
+                scale_normwise(r, a * x + b)
+            }
+        }
+    }
+
+    /// Check whether the measured error satisfies the criterion
+    pub fn is_converged(&self, error: R) -> bool {
+        error <= self.tolerance()
+    }
+}
+
+fn scale_normwise<R: Float>(r: R, scale: R) -> R {
+    if scale.is_zero() {
+        r
+    } else {
+        r / scale
+    }
+}
+
+fn scale_componentwise<R: Float>(r: R, scale: R) -> R {
+    if r.is_zero() {
+        R::zero()
+    } else {
+        r / scale
+    }
+}
+
+/// Quantities of an iterate needed to evaluate a [StoppingCriterion](enum.StoppingCriterion.html)
+pub struct Residual<'a, A: Scalar> {
+    /// Residual vector, e.g. `b - A x`, or `A x - λ x` for eigenproblems
+    pub r: ArrayView1<'a, A>,
+    /// Right-hand side `b`, or `λ x` for eigenproblems
+    pub b: ArrayView1<'a, A>,
+    /// Current iterate `x`
+    pub x: ArrayView1<'a, A>,
+    /// Estimate of the operator norm `|A|` used by `BackwardError`
+    pub a_norm: Option<A::Real>,
+    /// Element-wise product `|A| |x|` used by `ComponentWise`
+    pub abs_ax: Option<ArrayView1<'a, A::Real>>,
+}
+
+impl<'a, A: Scalar> Residual<'a, A> {
+    /// Residual without any information of the operator
+    pub fn new(r: ArrayView1<'a, A>, b: ArrayView1<'a, A>, x: ArrayView1<'a, A>) -> Self {
+        Residual {
+            r,
+            b,
+            x,
+            a_norm: None,
+            abs_ax: None,
+        }
+    }
+}
+
+/// Convergence history of an iterative algorithm
+#[derive(Debug, Clone, PartialEq)]
+pub struct IterationReport<R> {
+    /// Criterion used to decide the convergence
+    pub criterion: StoppingCriterion<R>,
+    /// Whether the last recorded iterate satisfies the criterion
+    pub converged: bool,
+    /// Errors measured by the criterion for each recorded iterate
+    pub history: Vec<R>,
+}
+
+impl<R: Float> IterationReport<R> {
+    pub fn new(criterion: StoppingCriterion<R>) -> Self {
+        IterationReport {
+            criterion,
+            converged: false,
+            history: Vec::new(),
+        }
+    }
+
+    /// Record the error of a new iterate, and return whether it has converged
+    pub fn record(&mut self, error: R) -> bool {
+        self.history.push(error);
+        self.converged = self.criterion.is_converged(error);
+        self.converged
+    }
+
+    /// Number of recorded iterates
+    pub fn iterations(&self) -> usize {
+        self.history.len()
+    }
+
+    /// Error of the last recorded iterate
+    pub fn last_error(&self) -> Option<R> {
+        self.history.last().cloned()
+    }
+}
@@ -58,7 +58,7 @@ where
     b
 }
 
-fn clone_with_layout<A, Si, So>(l: MatrixLayout, a: &ArrayBase<Si, Ix2>) -> ArrayBase<So, Ix2>
+pub(crate) fn clone_with_layout<A, Si, So>(l: MatrixLayout, a: &ArrayBase<Si, Ix2>) -> ArrayBase<So, Ix2>
 where
     A: Copy,
     Si: Data<Elem = A>,
@@ -107,7 +107,7 @@ where
 /// ***Panics*** if `a` is not square.
 pub(crate) fn triangular_fill_hermitian<A, S>(a: &mut ArrayBase<S, Ix2>, uplo: UPLO)
 where
-    A: Scalar + Lapack,
+    A: Scalar,
     S: DataMut<Elem = A>,
 {
     assert!(a.is_square());
@@ -0,0 +1,216 @@
+//! Sparse matrices in the compressed sparse row (CSR) format
+//!
+//! [CsrMatrix](struct.CsrMatrix.html) stores only the non-zero elements of an `m x n` matrix row by row,
+//! where the elements of the `i`-th row are `data[indptr[i]..indptr[i + 1]]` in the columns
+//! `indices[indptr[i]..indptr[i + 1]]`.
+//! This is the format used by most sparse matrix libraries, e.g. `scipy.sparse.csr_matrix`,
+//! so that matrices assembled elsewhere can be passed as they are.
+//! It is a [LinearOperator](../operator/trait.LinearOperator.html) for the iterative solvers,
+//! and the [incomplete LU factorization](../krylov/preconditioner/struct.IncompleteLU.html)
+//! gives a preconditioner on its non-zero pattern.
+//!
+//! # Example
+//!
+//! ```
+//! use ndarray::*;
+//! use ndarray_linalg::*;
+//!
+//! // [[2, 0, 1],
+//! //  [0, 3, 0]]
+//! let a = CsrMatrix::new((2, 3), vec![0, 2, 3], vec![0, 2, 1], vec![2.0, 1.0, 3.0]).unwrap();
+//! assert_eq!(a.nnz(), 3);
+//! assert_eq!(a.to_dense(), arr2(&[[2.0, 0.0, 1.0], [0.0, 3.0, 0.0]]));
+//! assert_eq!(a.dot(&arr1(&[1.0, 1.0, 1.0])), arr1(&[3.0, 3.0]));
+//! ```
+
+use ndarray::*;
+
+use crate::banded::*;
+use crate::error::*;
+use crate::operator::{AdjointOperator, LinearOperator};
+use crate::types::*;
+
+/// Sparse matrix in the compressed sparse row (CSR) format
+#[derive(Debug, Clone, PartialEq)]
+pub struct CsrMatrix<A> {
+    rows: usize,
+    cols: usize,
+    indptr: Vec<usize>,
+    indices: Vec<usize>,
+    data: Vec<A>,
+}
+
+impl<A: Scalar> CsrMatrix<A> {
+    /// Create from the CSR arrays of an `m x n` matrix
+    ///
+    /// The column indices in each row must be strictly increasing.
+    /// Fails with `IncompatibleShape` if the lengths of the arrays are inconsistent or `indptr[0] != 0`,
+    /// and with `OutOfBounds` if `indptr` is decreasing, or the indices are not sorted or exceed the shape.
+    pub fn new(shape: (usize, usize), indptr: Vec<usize>, indices: Vec<usize>, data: Vec<A>) -> Result<Self> {
+        let (rows, cols) = shape;
+        if indptr.len() != rows + 1 || indices.len() != data.len() || indptr[0] != 0 || indptr[rows] != data.len() {
+            return Err(ShapeError::from_kind(ErrorKind::IncompatibleShape).into());
+        }
+        // every row has to be checked before slicing, since a later row may be out of order
+        if indptr.windows(2).any(|w| w[0] > w[1] || w[1] > indices.len()) {
+            return Err(ShapeError::from_kind(ErrorKind::OutOfBounds).into());
+        }
+        for i in 0..rows {
+            let row = &indices[indptr[i]..indptr[i + 1]];
+            if row.iter().any(|&j| j >= cols) || row.windows(2).any(|w| w[0] >= w[1]) {
+                return Err(ShapeError::from_kind(ErrorKind::OutOfBounds).into());
+            }
+        }
+        Ok(Self::from_raw_parts(shape, indptr, indices, data))
+    }
+
+    /// Create from CSR arrays known to be valid
+    pub(crate) fn from_raw_parts(shape: (usize, usize), indptr: Vec<usize>, indices: Vec<usize>, data: Vec<A>) -> Self {
+        CsrMatrix {
+            rows: shape.0,
+            cols: shape.1,
+            indptr,
+            indices,
+            data,
+        }
+    }
+
+    /// Copy the non-zero elements of a dense matrix
+    pub fn from_dense<S>(a: &ArrayBase<S, Ix2>) -> Self
+    where
+        S: Data<Elem = A>,
+    {
+        let mut indptr = vec![0];
+        let mut indices = Vec::new();
+        let mut data = Vec::new();
+        for row in a.genrows() {
+            for (j, &x) in row.iter().enumerate() {
+                if !x.is_zero() {
+                    indices.push(j);
+                    data.push(x);
+                }
+            }
+            indptr.push(data.len());
+        }
+        Self::from_raw_parts(a.dim(), indptr, indices, data)
+    }
+
+    /// Copy the non-zero elements of a band matrix
+    pub fn from_banded(a: &BandedMatrix<A>) -> Self {
+        let n = a.size();
+        let mut indptr = vec![0];
+        let mut indices = Vec::new();
+        let mut data = Vec::new();
+        for i in 0..n {
+            let start = if i > a.kl() { i - a.kl() } else { 0 };
+            let end = ::std::cmp::min(n, i + a.ku() + 1);
+            for j in start..end {
+                let x = *a.get(i, j).unwrap();
+                if !x.is_zero() {
+                    indices.push(j);
+                    data.push(x);
+                }
+            }
+            indptr.push(data.len());
+        }
+        Self::from_raw_parts((n, n), indptr, indices, data)
+    }
+
+    /// Convert to a dense matrix
+    pub fn to_dense(&self) -> Array2<A> {
+        let mut a = Array2::zeros((self.rows, self.cols));
+        for i in 0..self.rows {
+            for k in self.row_range(i) {
+                a[(i, self.indices[k])] = self.data[k];
+            }
+        }
+        a
+    }
+
+    /// Shape `(m, n)` of the matrix
+    pub fn shape(&self) -> (usize, usize) {
+        (self.rows, self.cols)
+    }
+
+    /// Number of the stored elements
+    pub fn nnz(&self) -> usize {
+        self.data.len()
+    }
+
+    /// Offsets of the rows in `indices` and `data`
+    pub fn indptr(&self) -> &[usize] {
+        &self.indptr
+    }
+
+    /// Column indices of the stored elements
+    pub fn indices(&self) -> &[usize] {
+        &self.indices
+    }
+
+    /// Values of the stored elements
+    pub fn data(&self) -> &[A] {
+        &self.data
+    }
+
+    /// Reference to `A[(i, j)]`, or `None` if it is not stored
+    pub fn get(&self, i: usize, j: usize) -> Option<&A> {
+        if i >= self.rows {
+            return None;
+        }
+        let range = self.row_range(i);
+        let start = range.start;
+        self.indices[range]
+            .binary_search(&j)
+            .ok()
+            .map(|k| &self.data[start + k])
+    }
+
+    /// Matrix-vector product `A x`
+    pub fn dot<S>(&self, x: &ArrayBase<S, Ix1>) -> Array1<A>
+    where
+        S: Data<Elem = A>,
+    {
+        assert_eq!(x.len(), self.cols);
+        Array1::from_shape_fn(self.rows, |i| {
+            self.row_range(i)
+                .fold(A::zero(), |acc, k| acc + self.data[k] * x[self.indices[k]])
+        })
+    }
+
+    /// Range of the `i`-th row in `indices` and `data`
+    pub(crate) fn row_range(&self, i: usize) -> ::std::ops::Range<usize> {
+        self.indptr[i]..self.indptr[i + 1]
+    }
+}
+
+impl<A: Scalar> LinearOperator for CsrMatrix<A> {
+    type Elem = A;
+
+    fn apply<S>(&self, a: &ArrayBase<S, Ix1>) -> Array1<A>
+    where
+        S: Data<Elem = A>,
+    {
+        self.dot(a)
+    }
+}
+
+impl<A: Scalar> AdjointOperator for CsrMatrix<A> {
+    fn shape(&self) -> (usize, usize) {
+        (self.rows, self.cols)
+    }
+
+    fn apply_adjoint<S>(&self, a: &ArrayBase<S, Ix1>) -> Array1<A>
+    where
+        S: Data<Elem = A>,
+    {
+        assert_eq!(a.len(), self.rows);
+        let mut y = Array1::zeros(self.cols);
+        for i in 0..self.rows {
+            for k in self.row_range(i) {
+                let j = self.indices[k];
+                y[j] = y[j] + self.data[k].conj() * a[i];
+            }
+        }
+        y
+    }
+}
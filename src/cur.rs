@@ -0,0 +1,133 @@
+//! CUR decomposition
+//!
+//! The CUR decomposition approximates an `m x n` matrix `A` by its actual columns `C = A[:, J]`
+//! and rows `R = A[I, :]` as `A ≈ C U R`, which is easier to interpret than the singular vectors
+//! in the data analysis, e.g. in terms of the selected samples and features.
+//!
+//! The columns and rows are selected by the (rank-`k`) leverage scores computed from the SVD `A = W Σ V^H`,
+//!
+//! ```text
+//! π_j = |V_k[j, :]|^2 / k,   ρ_i = |W_k[i, :]|^2 / k,
+//! ```
+//!
+//! which measure the influence of the `j`-th column and the `i`-th row on the best rank-`k` approximation.
+//! The largest ones are taken deterministically, which is exact for matrices of rank `k` and
+//! has a relative error bound when the scores decay fast.
+//! The middle matrix `U = C^+ A R^+` is the optimal one for the selected columns and rows.
+//!
+//! - M. W. Mahoney and P. Drineas, CUR matrix decompositions for improved data analysis, PNAS 106 (2009)
+//! - D. Papailiopoulos, A. Kyrillidis and C. Boutsidis, Provable deterministic leverage score sampling, KDD (2014)
+//!
+//! # Example
+//!
+//! ```
+//! use ndarray::*;
+//! use ndarray_linalg::*;
+//!
+//! // Rank 2 matrix
+//! let x: Array2<f64> = random((20, 2));
+//! let y: Array2<f64> = random((2, 15));
+//! let a = x.dot(&y);
+//! let cur = a.cur(2, 3, 3).unwrap();
+//! assert_eq!(cur.c, a.select(Axis(1), &cur.columns));
+//! assert_eq!(cur.r, a.select(Axis(0), &cur.rows));
+//! assert_close_l2!(&cur.to_dense(), &a, 1e-8);
+//! ```
+
+use ndarray::*;
+use std::cmp::Ordering;
+
+use crate::error::*;
+use crate::least_squares::*;
+use crate::svd::*;
+use crate::types::*;
+
+/// CUR decomposition `A ≈ C U R`
+#[derive(Debug, Clone, PartialEq)]
+pub struct CURDecomposition<A> {
+    /// Indices of the selected columns in the ascending order
+    pub columns: Vec<usize>,
+    /// Indices of the selected rows in the ascending order
+    pub rows: Vec<usize>,
+    /// Selected columns `C = A[:, columns]`
+    pub c: Array2<A>,
+    /// Middle matrix `U = C^+ A R^+`
+    pub u: Array2<A>,
+    /// Selected rows `R = A[rows, :]`
+    pub r: Array2<A>,
+}
+
+impl<A: Scalar> CURDecomposition<A> {
+    /// Approximation `C U R`
+    pub fn to_dense(&self) -> Array2<A> {
+        self.c.dot(&self.u).dot(&self.r)
+    }
+}
+
+/// CUR decomposition of a matrix reference
+pub trait CUR {
+    type Elem: Scalar;
+
+    /// Select `n_cols` columns and `n_rows` rows by the rank-`k` leverage scores
+    ///
+    /// Fails with `OutOfBounds` if `k` is zero or exceeds `min(m, n)`,
+    /// or the numbers of columns or rows are less than `k` or exceed the size of the matrix.
+    fn cur(&self, k: usize, n_cols: usize, n_rows: usize) -> Result<CURDecomposition<Self::Elem>>;
+
+    /// Rank-`k` leverage scores of the columns and the rows, each summing to one
+    fn leverage_scores(
+        &self,
+        k: usize,
+    ) -> Result<(
+        Array1<<Self::Elem as Scalar>::Real>,
+        Array1<<Self::Elem as Scalar>::Real>,
+    )>;
+}
+
+impl<A, S> CUR for ArrayBase<S, Ix2>
+where
+    A: Scalar + Lapack,
+    S: Data<Elem = A>,
+{
+    type Elem = A;
+
+    fn cur(&self, k: usize, n_cols: usize, n_rows: usize) -> Result<CURDecomposition<A>> {
+        let (m, n) = self.dim();
+        if n_cols < k || n_cols > n || n_rows < k || n_rows > m {
+            return Err(ShapeError::from_kind(ErrorKind::OutOfBounds).into());
+        }
+        let (column_scores, row_scores) = self.leverage_scores(k)?;
+        let columns = largest(&column_scores, n_cols);
+        let rows = largest(&row_scores, n_rows);
+        let c = self.select(Axis(1), &columns);
+        let r = self.select(Axis(0), &rows);
+        // `U = C^+ A R^+` from `C X = A` and `R^H U^H = X^H`
+        let x = c.least_squares(self, None)?.solution;
+        let rh = r.t().mapv(|x| x.conj());
+        let xh = x.t().mapv(|x| x.conj());
+        let u = rh.least_squares(&xh, None)?.solution.t().mapv(|x| x.conj());
+        Ok(CURDecomposition { columns, rows, c, u, r })
+    }
+
+    fn leverage_scores(&self, k: usize) -> Result<(Array1<A::Real>, Array1<A::Real>)> {
+        let (m, n) = self.dim();
+        if k == 0 || k > ::std::cmp::min(m, n) {
+            return Err(ShapeError::from_kind(ErrorKind::OutOfBounds).into());
+        }
+        let (u, _, vt) = self.svd(true, true)?;
+        let (u, vt) = (u.unwrap(), vt.unwrap());
+        let k_real = A::real(k);
+        let columns = Array1::from_shape_fn(n, |j| (0..k).map(|i| vt[(i, j)].square()).sum::<A::Real>() / k_real);
+        let rows = Array1::from_shape_fn(m, |i| (0..k).map(|j| u[(i, j)].square()).sum::<A::Real>() / k_real);
+        Ok((columns, rows))
+    }
+}
+
+/// Indices of the `count` largest scores in the ascending order of the indices
+fn largest<R: PartialOrd>(scores: &Array1<R>, count: usize) -> Vec<usize> {
+    let mut indices: Vec<usize> = (0..scores.len()).collect();
+    indices.sort_by(|&i, &j| scores[j].partial_cmp(&scores[i]).unwrap_or(Ordering::Equal));
+    indices.truncate(count);
+    indices.sort();
+    indices
+}
@@ -46,3 +46,20 @@ where
         }
     }
 }
+
+impl<A, Sa> AdjointOperator for Diagonal<Sa>
+where
+    A: Scalar,
+    Sa: Data<Elem = A>,
+{
+    fn shape(&self) -> (usize, usize) {
+        (self.diag.len(), self.diag.len())
+    }
+
+    fn apply_adjoint<S>(&self, a: &ArrayBase<S, Ix1>) -> Array1<A>
+    where
+        S: Data<Elem = A>,
+    {
+        Array1::from_shape_fn(a.len(), |i| self.diag[i].conj() * a[i])
+    }
+}
@@ -0,0 +1,333 @@
+//! Extended precision by double-double arithmetic
+//!
+//! [DoubleDouble](struct.DoubleDouble.html) represents a number as the unevaluated sum of two `f64`,
+//! which gives about 32 significant digits with the error-free transformations of the IEEE arithmetic.
+//! LAPACK has no such precision, and [ExtendedPrecision](trait.ExtendedPrecision.html) provides
+//! the linear solver, the Cholesky and QR decompositions in pure Rust with the `_dd` suffix.
+//! They are implemented for matrices of `f64`, which are computed in double-double
+//! and whose results are rounded to `f64`, and also for those of `DoubleDouble`.
+//!
+//! These are much slower than LAPACK, and meant for small and pathologically ill-conditioned systems,
+//! where `f64` cannot deliver any correct digits:
+//!
+//! ```
+//! use ndarray::*;
+//! use ndarray_linalg::*;
+//!
+//! // Pascal matrix, whose condition number is about 1e18
+//! let n = 18;
+//! let a: Array2<f64> = gallery::pascal(n);
+//! let b = a.dot(&Array1::ones(n));
+//! let x = a.solve_dd(&b).unwrap();
+//! assert_close_max!(&x, &Array1::ones(n), 1e-12);
+//! ```
+//!
+//! - Y. Hida, X. S. Li and D. H. Bailey, Algorithms for quad-double precision floating point arithmetic,
+//!   ARITH-15 (2001)
+
+use ndarray::*;
+use std::ops::{Add, Div, Mul, Neg, Sub};
+
+use crate::error::*;
+use crate::layout::*;
+use crate::UPLO;
+
+/// Number `hi + lo` with `|lo| <= ulp(hi) / 2`
+#[derive(Debug, Clone, Copy, Default, PartialEq, PartialOrd)]
+pub struct DoubleDouble {
+    hi: f64,
+    lo: f64,
+}
+
+/// `(s, e)` with `s = fl(a + b)` and `s + e = a + b` exactly
+fn two_sum(a: f64, b: f64) -> (f64, f64) {
+    let s = a + b;
+    let bb = s - a;
+    (s, (a - (s - bb)) + (b - bb))
+}
+
+/// `two_sum` for `|a| >= |b|`
+fn quick_two_sum(a: f64, b: f64) -> (f64, f64) {
+    let s = a + b;
+    (s, b - (s - a))
+}
+
+/// `(p, e)` with `p = fl(a b)` and `p + e = a b` exactly
+fn two_prod(a: f64, b: f64) -> (f64, f64) {
+    let p = a * b;
+    (p, a.mul_add(b, -p))
+}
+
+impl DoubleDouble {
+    fn normalized((hi, lo): (f64, f64)) -> Self {
+        let (hi, lo) = quick_two_sum(hi, lo);
+        DoubleDouble { hi, lo }
+    }
+
+    /// Leading part, which is the nearest `f64`
+    pub fn hi(self) -> f64 {
+        self.hi
+    }
+
+    /// Trailing part
+    pub fn lo(self) -> f64 {
+        self.lo
+    }
+
+    /// Round to `f64`
+    pub fn to_f64(self) -> f64 {
+        self.hi
+    }
+
+    pub fn zero() -> Self {
+        Self::default()
+    }
+
+    pub fn one() -> Self {
+        Self::from(1.0)
+    }
+
+    pub fn abs(self) -> Self {
+        if self.hi < 0.0 {
+            -self
+        } else {
+            self
+        }
+    }
+
+    pub fn sqrt(self) -> Self {
+        if self.hi <= 0.0 {
+            // sqrt(0) = 0, and NaN for negative numbers
+            return Self::from(self.hi.sqrt());
+        }
+        // One Newton step from the `f64` approximation doubles the number of the correct digits
+        let x = 1.0 / self.hi.sqrt();
+        let ax = self.hi * x;
+        let (p, e) = two_prod(ax, ax);
+        let r = (self - DoubleDouble { hi: p, lo: e }).hi;
+        Self::normalized(two_sum(ax, r * (x * 0.5)))
+    }
+}
+
+impl From<f64> for DoubleDouble {
+    fn from(x: f64) -> Self {
+        DoubleDouble { hi: x, lo: 0.0 }
+    }
+}
+
+impl Neg for DoubleDouble {
+    type Output = Self;
+    fn neg(self) -> Self {
+        DoubleDouble {
+            hi: -self.hi,
+            lo: -self.lo,
+        }
+    }
+}
+
+impl Add for DoubleDouble {
+    type Output = Self;
+    fn add(self, rhs: Self) -> Self {
+        let (s1, s2) = two_sum(self.hi, rhs.hi);
+        let (t1, t2) = two_sum(self.lo, rhs.lo);
+        let (s1, s2) = quick_two_sum(s1, s2 + t1);
+        Self::normalized((s1, s2 + t2))
+    }
+}
+
+impl Sub for DoubleDouble {
+    type Output = Self;
+    fn sub(self, rhs: Self) -> Self {
+        self + (-rhs)
+    }
+}
+
+impl Mul for DoubleDouble {
+    type Output = Self;
+    fn mul(self, rhs: Self) -> Self {
+        let (p1, p2) = two_prod(self.hi, rhs.hi);
+        Self::normalized((p1, p2 + (self.hi * rhs.lo + self.lo * rhs.hi)))
+    }
+}
+
+impl Div for DoubleDouble {
+    type Output = Self;
+    fn div(self, rhs: Self) -> Self {
+        // Long division by three `f64` quotients
+        let q1 = self.hi / rhs.hi;
+        let r = self - rhs * Self::from(q1);
+        let q2 = r.hi / rhs.hi;
+        let r = r - rhs * Self::from(q2);
+        let q3 = r.hi / rhs.hi;
+        Self::normalized(quick_two_sum(q1, q2)) + Self::from(q3)
+    }
+}
+
+/// Linear algebra in double-double precision
+pub trait ExtendedPrecision {
+    type Elem;
+
+    /// Solve `A x = b` by the LU factorization with partial pivoting
+    ///
+    /// Fails with `Lapack { return_code: k }` if the `k`-th pivot is exactly zero.
+    fn solve_dd<S>(&self, b: &ArrayBase<S, Ix1>) -> Result<Array1<Self::Elem>>
+    where
+        S: Data<Elem = Self::Elem>;
+
+    /// Cholesky factor `U` of `A = U^T U`, or `L` of `A = L L^T`, of a real symmetric positive definite matrix
+    ///
+    /// Only the triangular part specified by `uplo` is read.
+    /// Fails with `Lapack { return_code: k }` if the leading minor of order `k` is not positive definite.
+    fn cholesky_dd(&self, uplo: UPLO) -> Result<Array2<Self::Elem>>;
+
+    /// QR decomposition `A = Q R` by Householder reflections,
+    /// where `Q` is `m x k` and `R` is `k x n` for `k = min(m, n)`
+    fn qr_dd(&self) -> Result<(Array2<Self::Elem>, Array2<Self::Elem>)>;
+}
+
+/// Elements of the matrices computed in double-double precision
+pub trait DoubleDoubleElem: Copy {
+    fn to_dd(self) -> DoubleDouble;
+    fn from_dd(x: DoubleDouble) -> Self;
+}
+
+impl DoubleDoubleElem for f64 {
+    fn to_dd(self) -> DoubleDouble {
+        DoubleDouble::from(self)
+    }
+    fn from_dd(x: DoubleDouble) -> Self {
+        x.to_f64()
+    }
+}
+
+impl DoubleDoubleElem for DoubleDouble {
+    fn to_dd(self) -> DoubleDouble {
+        self
+    }
+    fn from_dd(x: DoubleDouble) -> Self {
+        x
+    }
+}
+
+impl<A, S> ExtendedPrecision for ArrayBase<S, Ix2>
+where
+    A: DoubleDoubleElem,
+    S: Data<Elem = A>,
+{
+    type Elem = A;
+
+    fn solve_dd<Sb>(&self, b: &ArrayBase<Sb, Ix1>) -> Result<Array1<A>>
+    where
+        Sb: Data<Elem = A>,
+    {
+        self.ensure_square()?;
+        if b.len() != self.rows() {
+            return Err(ShapeError::from_kind(ErrorKind::IncompatibleShape).into());
+        }
+        let x = solve(self.mapv(A::to_dd), b.mapv(A::to_dd))?;
+        Ok(x.mapv(A::from_dd))
+    }
+
+    fn cholesky_dd(&self, uplo: UPLO) -> Result<Array2<A>> {
+        self.ensure_square()?;
+        Ok(cholesky(self.mapv(A::to_dd), uplo)?.mapv(A::from_dd))
+    }
+
+    fn qr_dd(&self) -> Result<(Array2<A>, Array2<A>)> {
+        let (q, r) = qr(self.mapv(A::to_dd));
+        Ok((q.mapv(A::from_dd), r.mapv(A::from_dd)))
+    }
+}
+
+fn solve(mut a: Array2<DoubleDouble>, mut x: Array1<DoubleDouble>) -> Result<Array1<DoubleDouble>> {
+    let n = x.len();
+    for k in 0..n {
+        let p = (k..n).fold(k, |p, i| if a[(i, k)].abs() > a[(p, k)].abs() { i } else { p });
+        if a[(p, k)] == DoubleDouble::zero() {
+            return Err(LinalgError::Lapack {
+                return_code: k as i32 + 1,
+            });
+        }
+        if p != k {
+            for j in k..n {
+                a.swap((k, j), (p, j));
+            }
+            x.swap(k, p);
+        }
+        for i in (k + 1)..n {
+            let l = a[(i, k)] / a[(k, k)];
+            for j in (k + 1)..n {
+                a[(i, j)] = a[(i, j)] - l * a[(k, j)];
+            }
+            x[i] = x[i] - l * x[k];
+        }
+    }
+    for k in (0..n).rev() {
+        let s = ((k + 1)..n).fold(x[k], |s, j| s - a[(k, j)] * x[j]);
+        x[k] = s / a[(k, k)];
+    }
+    Ok(x)
+}
+
+fn cholesky(a: Array2<DoubleDouble>, uplo: UPLO) -> Result<Array2<DoubleDouble>> {
+    let n = a.rows();
+    // Lower triangular part of `A`
+    let lower = |i: usize, j: usize| match uplo {
+        UPLO::Lower => a[(i, j)],
+        UPLO::Upper => a[(j, i)],
+    };
+    let mut l = Array2::from_elem((n, n), DoubleDouble::zero());
+    for j in 0..n {
+        let d = (0..j).fold(lower(j, j), |s, k| s - l[(j, k)] * l[(j, k)]);
+        if !(d > DoubleDouble::zero()) {
+            return Err(LinalgError::Lapack {
+                return_code: j as i32 + 1,
+            });
+        }
+        l[(j, j)] = d.sqrt();
+        for i in (j + 1)..n {
+            let s = (0..j).fold(lower(i, j), |s, k| s - l[(i, k)] * l[(j, k)]);
+            l[(i, j)] = s / l[(j, j)];
+        }
+    }
+    Ok(match uplo {
+        UPLO::Lower => l,
+        UPLO::Upper => l.reversed_axes(),
+    })
+}
+
+fn qr(mut r: Array2<DoubleDouble>) -> (Array2<DoubleDouble>, Array2<DoubleDouble>) {
+    let (m, n) = r.dim();
+    let k = ::std::cmp::min(m, n);
+    let zero = DoubleDouble::zero();
+    let two = DoubleDouble::from(2.0);
+    let mut q = Array2::from_shape_fn((m, m), |(i, j)| if i == j { DoubleDouble::one() } else { zero });
+    for j in 0..k {
+        // Reflector `H = I - 2 v v^T / (v^T v)` with `H x = alpha e_1` for `x = r[j.., j]`
+        let norm = (j..m).fold(zero, |s, i| s + r[(i, j)] * r[(i, j)]).sqrt();
+        if norm == zero {
+            continue;
+        }
+        let alpha = if r[(j, j)] > zero { -norm } else { norm };
+        let mut v: Vec<DoubleDouble> = (j..m).map(|i| r[(i, j)]).collect();
+        v[0] = v[0] - alpha;
+        let vv = v.iter().fold(zero, |s, &x| s + x * x);
+        for c in j..n {
+            let f = two * (j..m).fold(zero, |s, i| s + v[i - j] * r[(i, c)]) / vv;
+            for i in j..m {
+                r[(i, c)] = r[(i, c)] - f * v[i - j];
+            }
+        }
+        for row in 0..m {
+            let f = two * (j..m).fold(zero, |s, i| s + q[(row, i)] * v[i - j]) / vv;
+            for i in j..m {
+                q[(row, i)] = q[(row, i)] - f * v[i - j];
+            }
+        }
+        r[(j, j)] = alpha;
+        for i in (j + 1)..m {
+            r[(i, j)] = zero;
+        }
+    }
+    (q.slice(s![.., ..k]).to_owned(), r.slice(s![..k, ..]).to_owned())
+}
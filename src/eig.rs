@@ -0,0 +1,346 @@
+//! Eigenvalue decomposition for general matrices
+//!
+//! The eigenvalues and eigenvectors of a real matrix are complex in general,
+//! and thus they are returned as `A::Complex` for both real and complex matrices.
+//!
+//! Right eigenvectors `v_j` satisfy `A v_j = λ_j v_j`,
+//! and left eigenvectors `u_j` satisfy `u_j^H A = λ_j u_j^H`.
+//! Both are stored as the columns of the returned matrix and normalized to have unit Euclidean norm.
+//!
+//! The generalized eigenvalue problem `A x = λ B x` is solved by
+//! [EigGeneralized](trait.EigGeneralized.html) using `*ggev`.
+//! Its eigenvalues are returned as pairs `(α, β)` with `λ = α / β`
+//! since `β` vanishes for the infinite eigenvalues of a singular `B`.
+//!
+//! Balancing
+//! ----------
+//! By default, the matrix is balanced by permutation and diagonal scaling before computing eigenvalues
+//! as `*geev` does. [EigWith](trait.EigWith.html) selects the balancing explicitly,
+//! and [Balancing](trait.Balancing.html) exposes the balancing itself (`*gebal`/`*gebak`).
+//!
+//! Ordering
+//! ---------
+//! LAPACK returns the eigenvalues in an order depending on the driver.
+//! [EigSorted](trait.EigSorted.html) and [EighSorted](../eigh/trait.EighSorted.html) sort them by
+//! [EigOrder](enum.EigOrder.html), and the eigenvectors are permuted consistently.
+
+use ndarray::*;
+use std::cmp::Ordering;
+
+use crate::convert::*;
+use crate::error::*;
+use crate::layout::*;
+use crate::types::*;
+
+pub use crate::lapack::Balance;
+
+/// Eigenvalue decomposition of general matrix reference
+pub trait Eig {
+    type EigVal;
+    type EigVec;
+    /// Calculate eigenvalues with the right eigenvectors
+    fn eig(&self) -> Result<(Self::EigVal, Self::EigVec)>;
+}
+
+/// Eigenvalue decomposition of general matrix reference with left and/or right eigenvectors
+pub trait EigFull {
+    type EigVal;
+    type EigVec;
+    /// Calculate eigenvalues `λ`, the left eigenvectors `U` (if `calc_vl`)
+    /// and the right eigenvectors `V` (if `calc_vr`)
+    fn eig_full(
+        &self,
+        calc_vl: bool,
+        calc_vr: bool,
+    ) -> Result<(Self::EigVal, Option<Self::EigVec>, Option<Self::EigVec>)>;
+}
+
+/// Generalized eigenvalue decomposition `A x = λ B x` of general matrices
+pub trait EigGeneralized<Rhs> {
+    type EigVal;
+    type EigVec;
+    /// Calculate generalized eigenvalues `λ_j = α_j / β_j` as `(α, β)`,
+    /// the left eigenvectors `u_j^H A = λ_j u_j^H B` (if `calc_vl`)
+    /// and the right eigenvectors `A v_j = λ_j B v_j` (if `calc_vr`)
+    ///
+    /// Each eigenvector is normalized so that its largest component has `|re| + |im| = 1`.
+    fn eig_generalized(
+        &self,
+        b: &Rhs,
+        calc_vl: bool,
+        calc_vr: bool,
+    ) -> Result<(Self::EigVal, Self::EigVal, Option<Self::EigVec>, Option<Self::EigVec>)>;
+}
+
+/// Eigenvalue decomposition of general matrix reference with a specified balancing
+pub trait EigWith {
+    type EigVal;
+    type EigVec;
+    /// Same as [EigFull::eig_full](trait.EigFull.html#tymethod.eig_full) except for the balancing
+    fn eig_with(
+        &self,
+        balance: Balance,
+        calc_vl: bool,
+        calc_vr: bool,
+    ) -> Result<(Self::EigVal, Option<Self::EigVec>, Option<Self::EigVec>)>;
+}
+
+/// Calculate eigenvalues without eigenvectors
+pub trait EigVals {
+    type EigVal;
+    fn eigvals(&self) -> Result<Self::EigVal>;
+}
+
+impl<A, S> EigFull for ArrayBase<S, Ix2>
+where
+    A: Scalar + Lapack,
+    S: Data<Elem = A>,
+{
+    type EigVal = Array1<A::Complex>;
+    type EigVec = Array2<A::Complex>;
+
+    fn eig_full(
+        &self,
+        calc_vl: bool,
+        calc_vr: bool,
+    ) -> Result<(Self::EigVal, Option<Self::EigVec>, Option<Self::EigVec>)> {
+        self.eig_with(Balance::default(), calc_vl, calc_vr)
+    }
+}
+
+impl<A, S> EigWith for ArrayBase<S, Ix2>
+where
+    A: Scalar + Lapack,
+    S: Data<Elem = A>,
+{
+    type EigVal = Array1<A::Complex>;
+    type EigVec = Array2<A::Complex>;
+
+    fn eig_with(
+        &self,
+        balance: Balance,
+        calc_vl: bool,
+        calc_vr: bool,
+    ) -> Result<(Self::EigVal, Option<Self::EigVec>, Option<Self::EigVec>)> {
+        self.ensure_square()?;
+        // Copied in Fortran layout, which LAPACKE would otherwise copy once more with the eigenvectors
+        let n = self.rows() as i32;
+        let l = MatrixLayout::F((n, n));
+        let mut a: Array2<A> = clone_with_layout(l, self);
+        let out = unsafe { A::eig(l, balance, calc_vl, calc_vr, a.as_allocated_mut()?)? };
+        let vl = match out.vl {
+            Some(vl) => Some(into_matrix(l, vl)?),
+            None => None,
+        };
+        let vr = match out.vr {
+            Some(vr) => Some(into_matrix(l, vr)?),
+            None => None,
+        };
+        Ok((ArrayBase::from_vec(out.eigs), vl, vr))
+    }
+}
+
+impl<A, S> Eig for ArrayBase<S, Ix2>
+where
+    A: Scalar + Lapack,
+    S: Data<Elem = A>,
+{
+    type EigVal = Array1<A::Complex>;
+    type EigVec = Array2<A::Complex>;
+
+    fn eig(&self) -> Result<(Self::EigVal, Self::EigVec)> {
+        let (e, _, vr) = self.eig_full(false, true)?;
+        Ok((e, vr.unwrap()))
+    }
+}
+
+/// Eigenvalue decomposition of general matrix reference with sorted eigenvalues
+pub trait EigSorted {
+    type EigVal;
+    type EigVec;
+    /// Calculate eigenvalues sorted by `order` with the corresponding right eigenvectors
+    fn eig_sorted(&self, order: EigOrder) -> Result<(Self::EigVal, Self::EigVec)>;
+}
+
+impl<A, S> EigSorted for ArrayBase<S, Ix2>
+where
+    A: Scalar + Lapack,
+    S: Data<Elem = A>,
+{
+    type EigVal = Array1<A::Complex>;
+    type EigVec = Array2<A::Complex>;
+
+    fn eig_sorted(&self, order: EigOrder) -> Result<(Self::EigVal, Self::EigVec)> {
+        let (e, v) = self.eig()?;
+        Ok(sort_eigenpairs(e, v, order))
+    }
+}
+
+impl<A, S> EigVals for ArrayBase<S, Ix2>
+where
+    A: Scalar + Lapack,
+    S: Data<Elem = A>,
+{
+    type EigVal = Array1<A::Complex>;
+
+    fn eigvals(&self) -> Result<Self::EigVal> {
+        let (e, _, _) = self.eig_full(false, false)?;
+        Ok(e)
+    }
+}
+
+/// Ordering of eigenvalues
+///
+/// Complex eigenvalues are compared by their real parts and then by their imaginary parts,
+/// and those of the same magnitude are ordered in the same way.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EigOrder {
+    /// Ascending order `λ_0 <= λ_1 <= ...`
+    Ascending,
+    /// Descending order `λ_0 >= λ_1 >= ...`
+    Descending,
+    /// Ascending order of magnitude `|λ_0| <= |λ_1| <= ...`
+    MagnitudeAscending,
+    /// Descending order of magnitude `|λ_0| >= |λ_1| >= ...`
+    MagnitudeDescending,
+}
+
+impl EigOrder {
+    /// Indices which sort `values` in this order
+    pub fn sort_indices<T: Scalar>(self, values: &[T]) -> Vec<usize> {
+        let cmp = |x: &T, y: &T| {
+            let by_value = x
+                .re()
+                .partial_cmp(&y.re())
+                .unwrap_or(Ordering::Equal)
+                .then(x.im().partial_cmp(&y.im()).unwrap_or(Ordering::Equal));
+            let by_abs = x.abs().partial_cmp(&y.abs()).unwrap_or(Ordering::Equal);
+            match self {
+                EigOrder::Ascending => by_value,
+                EigOrder::Descending => by_value.reverse(),
+                EigOrder::MagnitudeAscending => by_abs.then(by_value),
+                EigOrder::MagnitudeDescending => by_abs.then(by_value).reverse(),
+            }
+        };
+        let mut idx: Vec<usize> = (0..values.len()).collect();
+        idx.sort_by(|&i, &j| cmp(&values[i], &values[j]));
+        idx
+    }
+}
+
+/// Sort eigenvalues by `order`, and permute the columns of the eigenvectors consistently
+pub fn sort_eigenpairs<T, A>(values: Array1<T>, vectors: Array2<A>, order: EigOrder) -> (Array1<T>, Array2<A>)
+where
+    T: Scalar,
+    A: Copy,
+{
+    let idx = order.sort_indices(values.as_slice().unwrap());
+    (values.select(Axis(0), &idx), vectors.select(Axis(1), &idx))
+}
+
+impl<A, Sa, Sb> EigGeneralized<ArrayBase<Sb, Ix2>> for ArrayBase<Sa, Ix2>
+where
+    A: Scalar + Lapack,
+    Sa: Data<Elem = A>,
+    Sb: Data<Elem = A>,
+{
+    type EigVal = Array1<A::Complex>;
+    type EigVec = Array2<A::Complex>;
+
+    fn eig_generalized(
+        &self,
+        b: &ArrayBase<Sb, Ix2>,
+        calc_vl: bool,
+        calc_vr: bool,
+    ) -> Result<(Self::EigVal, Self::EigVal, Option<Self::EigVec>, Option<Self::EigVec>)> {
+        if self.dim() != b.dim() {
+            return Err(ShapeError::from_kind(ErrorKind::IncompatibleShape).into());
+        }
+        // `*ggev` requires `A` and `B` in the same layout
+        let mut a: Array2<A> = replicate(self);
+        let mut b: Array2<A> = replicate(b);
+        let l = a.square_layout()?;
+        let out = unsafe { A::eig_generalized(l, calc_vl, calc_vr, a.as_allocated_mut()?, b.as_allocated_mut()?)? };
+        let vl = match out.vl {
+            Some(vl) => Some(into_matrix(l, vl)?),
+            None => None,
+        };
+        let vr = match out.vr {
+            Some(vr) => Some(into_matrix(l, vr)?),
+            None => None,
+        };
+        Ok((ArrayBase::from_vec(out.alpha), ArrayBase::from_vec(out.beta), vl, vr))
+    }
+}
+
+/// Balanced matrix `A' = D^{-1} P^T A P D` with a permutation `P` and a diagonal scaling `D`
+#[derive(Debug, Clone)]
+pub struct Balanced<A: Scalar> {
+    /// Balanced matrix `A'`
+    pub matrix: Array2<A>,
+    /// Balancing used to compute `A'`
+    pub job: Balance,
+    /// `A'[(i, j)] = 0` if `i > j` and `j < ilo` or `i >= ihi`,
+    /// i.e. the eigenvalues outside `ilo..ihi` are isolated by the permutation
+    pub ilo: usize,
+    /// See `ilo`
+    pub ihi: usize,
+    /// Permutation and scaling factors in the format of `*gebal`
+    pub scale: Array1<A::Real>,
+}
+
+impl<A> Balanced<A>
+where
+    A: Scalar + Lapack,
+{
+    /// Transform the eigenvectors of `A'` into those of `A`
+    ///
+    /// The columns of `v` are the left eigenvectors if `left` is true, and the right ones otherwise.
+    pub fn back_transform<S>(&self, v: &mut ArrayBase<S, Ix2>, left: bool) -> Result<()>
+    where
+        S: DataMut<Elem = A>,
+    {
+        if v.rows() != self.scale.len() {
+            return Err(ShapeError::from_kind(ErrorKind::IncompatibleShape).into());
+        }
+        let l = v.layout()?;
+        unsafe {
+            A::balance_back(
+                l,
+                self.job,
+                left,
+                self.ilo as i32 + 1,
+                self.ihi as i32,
+                self.scale.as_slice().unwrap(),
+                v.as_allocated_mut()?,
+            )
+        }
+    }
+}
+
+/// Balance a general matrix to improve the accuracy of its eigenvalues
+pub trait Balancing {
+    type Output;
+    fn balance(&self, job: Balance) -> Result<Self::Output>;
+}
+
+impl<A, S> Balancing for ArrayBase<S, Ix2>
+where
+    A: Scalar + Lapack,
+    S: Data<Elem = A>,
+{
+    type Output = Balanced<A>;
+
+    fn balance(&self, job: Balance) -> Result<Self::Output> {
+        let mut a = self.to_owned();
+        let l = a.square_layout()?;
+        let (ilo, ihi, scale) = unsafe { A::balance(l, job, a.as_allocated_mut()?)? };
+        Ok(Balanced {
+            matrix: a,
+            job,
+            ilo: ilo as usize - 1,
+            ihi: ihi as usize,
+            scale: ArrayBase::from_vec(scale),
+        })
+    }
+}
@@ -1,14 +1,23 @@
 //! Eigenvalue decomposition for Hermite matrices
+//!
+//! [Eigh](trait.Eigh.html), [EighInto](trait.EighInto.html) and [EigValsh](trait.EigValsh.html) are also
+//! implemented for stacks of matrices (`Ix3` arrays whose leading axis is the batch axis),
+//! which are decomposed in parallel if the `parallel` feature is enabled.
 
 use ndarray::*;
+use num_traits::Float;
 
-use crate::diagonal::*;
+use crate::batch::*;
+use crate::convert::*;
+use crate::eig::{sort_eigenpairs, EigOrder};
 use crate::error::*;
+use crate::generate::conjugate;
 use crate::layout::*;
-use crate::operator::LinearOperator;
 use crate::types::*;
 use crate::UPLO;
 
+pub use crate::lapack::{EigRange, EighDriver, EighGeneralizedType};
+
 /// Eigenvalue decomposition of Hermite matrix reference
 pub trait Eigh {
     type EigVal;
@@ -17,8 +26,16 @@ pub trait Eigh {
 }
 
 /// Eigenvalue decomposition of mutable reference of Hermite matrix
+///
+/// The eigenvectors overwrite the input matrix without allocating another `n x n` matrix,
+/// which is how LAPACK works and halves the peak memory compared to [Eigh](trait.Eigh.html).
 pub trait EighInplace {
     type EigVal;
+    /// Calculate eigenvalues in ascending order, and store the eigenvectors as the columns of `self`
+    ///
+    /// Only the triangular part of `self` specified by `uplo` is read.
+    /// A matrix in C layout is decomposed through its transposed view to avoid a copy,
+    /// so that `self` is left in Fortran layout.
     fn eigh_inplace(&mut self, uplo: UPLO) -> Result<(Self::EigVal, &mut Self)>;
 }
 
@@ -55,6 +72,60 @@ where
     }
 }
 
+impl<A, S> Eigh for ArrayBase<S, Ix3>
+where
+    A: Scalar + Lapack + Send + Sync,
+    A::Real: Send + Sync,
+    S: Data<Elem = A>,
+{
+    type EigVal = Array2<A::Real>;
+    type EigVec = Array3<A>;
+
+    fn eigh(&self, uplo: UPLO) -> Result<(Self::EigVal, Self::EigVec)> {
+        let (_, n, _) = self.dim();
+        let res = map_stack(self, |a| a.eigh(uplo))?;
+        let (es, vs): (Vec<_>, Vec<_>) = res.into_iter().unzip();
+        Ok((stack_vectors(n, &es), stack_matrices((n, n), &vs)))
+    }
+}
+
+impl<A, S> EighInto for ArrayBase<S, Ix3>
+where
+    A: Scalar + Lapack + Send + Sync,
+    A::Real: Send + Sync,
+    S: DataMut<Elem = A>,
+{
+    type EigVal = Array2<A::Real>;
+
+    fn eigh_into(mut self, uplo: UPLO) -> Result<(Self::EigVal, Self)> {
+        let (e, v) = self.eigh(uplo)?;
+        self.assign(&v);
+        Ok((e, self))
+    }
+}
+
+/// Eigenvalue decomposition of Hermite matrix reference with sorted eigenvalues
+pub trait EighSorted {
+    type EigVal;
+    type EigVec;
+    /// Calculate eigenvalues sorted by `order` with the corresponding eigenvectors
+    fn eigh_sorted(&self, uplo: UPLO, order: EigOrder) -> Result<(Self::EigVal, Self::EigVec)>;
+}
+
+impl<A, S> EighSorted for ArrayBase<S, Ix2>
+where
+    A: Scalar + Lapack,
+    S: Data<Elem = A>,
+{
+    type EigVal = Array1<A::Real>;
+    type EigVec = Array2<A>;
+
+    fn eigh_sorted(&self, uplo: UPLO, order: EigOrder) -> Result<(Self::EigVal, Self::EigVec)> {
+        let (e, v) = self.eigh(uplo)?;
+        Ok(sort_eigenpairs(e, v, order))
+    }
+}
+
 impl<A, S> EighInplace for ArrayBase<S, Ix2>
 where
     A: Scalar + Lapack,
@@ -65,15 +136,264 @@ where
     fn eigh_inplace(&mut self, uplo: UPLO) -> Result<(Self::EigVal, &mut Self)> {
         let layout = self.square_layout()?;
         // XXX Force layout to be Fortran (see #146)
-        match layout {
-            MatrixLayout::C(_) => self.swap_axes(0, 1),
-            MatrixLayout::F(_) => {}
-        }
+        //
+        // The transposed view of a Hermite matrix is its complex conjugate,
+        // whose `uplo` part is stored in the opposite triangle and whose eigenvectors are conjugated.
+        let transposed = match layout {
+            MatrixLayout::C(_) => {
+                self.swap_axes(0, 1);
+                true
+            }
+            MatrixLayout::F(_) => false,
+        };
+        let uplo = match (transposed, uplo) {
+            (true, UPLO::Upper) => UPLO::Lower,
+            (true, UPLO::Lower) => UPLO::Upper,
+            (false, uplo) => uplo,
+        };
         let s = unsafe { A::eigh(true, self.square_layout()?, uplo, self.as_allocated_mut()?)? };
+        if transposed {
+            self.mapv_inplace(|x| x.conj());
+        }
         Ok((ArrayBase::from_vec(s), self))
     }
 }
 
+/// Eigenvalue decomposition of Hermite matrix reference using a specified LAPACK driver
+pub trait EighWith {
+    type EigVal;
+    type EigVec;
+    fn eigh_with(&self, uplo: UPLO, driver: EighDriver) -> Result<(Self::EigVal, Self::EigVec)>;
+}
+
+/// Calculate eigenvalues without eigenvectors using a specified LAPACK driver
+pub trait EigValshWith {
+    type EigVal;
+    fn eigvalsh_with(&self, uplo: UPLO, driver: EighDriver) -> Result<Self::EigVal>;
+}
+
+impl<A, S> EighWith for ArrayBase<S, Ix2>
+where
+    A: Scalar + Lapack,
+    S: Data<Elem = A>,
+{
+    type EigVal = Array1<A::Real>;
+    type EigVec = Array2<A>;
+
+    fn eigh_with(&self, uplo: UPLO, driver: EighDriver) -> Result<(Self::EigVal, Self::EigVec)> {
+        let (n, _) = self.square_layout()?.size();
+        // Copied in Fortran layout (see #146)
+        let l = MatrixLayout::F((n, n));
+        let mut a: Array2<A> = clone_with_layout(l, self);
+        let s = unsafe { A::eigh_driver(true, l, uplo, driver, a.as_allocated_mut()?)? };
+        Ok((ArrayBase::from_vec(s), a))
+    }
+}
+
+impl<A, S> EigValshWith for ArrayBase<S, Ix2>
+where
+    A: Scalar + Lapack,
+    S: Data<Elem = A>,
+{
+    type EigVal = Array1<A::Real>;
+
+    fn eigvalsh_with(&self, uplo: UPLO, driver: EighDriver) -> Result<Self::EigVal> {
+        let mut a = self.to_owned();
+        let l = a.square_layout()?;
+        let s = unsafe { A::eigh_driver(false, l, uplo, driver, a.as_allocated_mut()?)? };
+        Ok(ArrayBase::from_vec(s))
+    }
+}
+
+/// Generalized eigenvalue decomposition of Hermite matrix reference with a positive definite matrix `B`
+pub trait EighGeneralized<Rhs> {
+    type EigVal;
+    type EigVec;
+    /// Solve the generalized eigenproblem of `itype` where `A` is `self` and `B` is the argument
+    ///
+    /// The eigenvectors are back-transformed to those of the original problem.
+    fn eigh_generalized(&self, b: &Rhs, itype: EighGeneralizedType, uplo: UPLO)
+        -> Result<(Self::EigVal, Self::EigVec)>;
+}
+
+impl<A, Sa, Sb> EighGeneralized<ArrayBase<Sb, Ix2>> for ArrayBase<Sa, Ix2>
+where
+    A: Scalar + Lapack,
+    Sa: Data<Elem = A>,
+    Sb: Data<Elem = A>,
+{
+    type EigVal = Array1<A::Real>;
+    type EigVec = Array2<A>;
+
+    fn eigh_generalized(
+        &self,
+        b: &ArrayBase<Sb, Ix2>,
+        itype: EighGeneralizedType,
+        uplo: UPLO,
+    ) -> Result<(Self::EigVal, Self::EigVec)> {
+        let (n, _) = self.square_layout()?.size();
+        if b.dim() != self.dim() {
+            return Err(ShapeError::from_kind(ErrorKind::IncompatibleShape).into());
+        }
+        // Both matrices are copied in Fortran layout (see #146)
+        let l = MatrixLayout::F((n, n));
+        let mut a: Array2<A> = clone_with_layout(l, self);
+        let mut b: Array2<A> = clone_with_layout(l, b);
+        let s = unsafe { A::eigh_generalized(true, l, itype, uplo, a.as_allocated_mut()?, b.as_allocated_mut()?)? };
+        Ok((ArrayBase::from_vec(s), a))
+    }
+}
+
+/// Eigenvalue decomposition of a part of the spectrum of Hermite matrix reference
+pub trait EighRange {
+    type EigVal;
+    type EigVec;
+    type Real;
+    /// Calculate the eigenvalues in `range` and the corresponding eigenvectors
+    fn eigh_range(&self, uplo: UPLO, range: EigRange<Self::Real>) -> Result<(Self::EigVal, Self::EigVec)>;
+}
+
+/// Calculate a part of the eigenvalues without eigenvectors
+pub trait EigValshRange {
+    type EigVal;
+    type Real;
+    fn eigvalsh_range(&self, uplo: UPLO, range: EigRange<Self::Real>) -> Result<Self::EigVal>;
+}
+
+impl<A, S> EighRange for ArrayBase<S, Ix2>
+where
+    A: Scalar + Lapack,
+    S: Data<Elem = A>,
+{
+    type EigVal = Array1<A::Real>;
+    type EigVec = Array2<A>;
+    type Real = A::Real;
+
+    fn eigh_range(&self, uplo: UPLO, range: EigRange<A::Real>) -> Result<(Self::EigVal, Self::EigVec)> {
+        let (e, v) = eigh_range(self, true, uplo, range)?;
+        Ok((e, v.unwrap()))
+    }
+}
+
+impl<A, S> EigValshRange for ArrayBase<S, Ix2>
+where
+    A: Scalar + Lapack,
+    S: Data<Elem = A>,
+{
+    type EigVal = Array1<A::Real>;
+    type Real = A::Real;
+
+    fn eigvalsh_range(&self, uplo: UPLO, range: EigRange<A::Real>) -> Result<Self::EigVal> {
+        let (e, _) = eigh_range(self, false, uplo, range)?;
+        Ok(e)
+    }
+}
+
+fn eigh_range<A, S>(
+    a: &ArrayBase<S, Ix2>,
+    calc_v: bool,
+    uplo: UPLO,
+    range: EigRange<A::Real>,
+) -> Result<(Array1<A::Real>, Option<Array2<A>>)>
+where
+    A: Scalar + Lapack,
+    S: Data<Elem = A>,
+{
+    let (n, _) = a.square_layout()?.size();
+    if let EigRange::Index(start, end) = range {
+        if start > end || end > n as usize {
+            return Err(ShapeError::from_kind(ErrorKind::OutOfBounds).into());
+        }
+        if start == end {
+            let v = if calc_v {
+                Some(Array2::zeros((n as usize, 0)))
+            } else {
+                None
+            };
+            return Ok((Array1::zeros(0), v));
+        }
+    }
+    // Copied in Fortran layout (see #146)
+    let l = MatrixLayout::F((n, n));
+    let mut a: Array2<A> = clone_with_layout(l, a);
+    let (e, z) = unsafe { A::eigh_range(calc_v, l, uplo, range, a.as_allocated_mut()?)? };
+    let v = if calc_v {
+        let z: Array2<A> = into_matrix(l, z)?;
+        Some(z.slice(s![.., ..e.len()]).to_owned())
+    } else {
+        None
+    };
+    Ok((ArrayBase::from_vec(e), v))
+}
+
+/// Eigenvalue decomposition of a real symmetric tridiagonal matrix using `*stev`
+///
+/// The matrix is given by its diagonal `d` and off-diagonal `e` with `e.len() == d.len() - 1`,
+/// and the dense matrix is never formed.
+pub fn eigh_tridiagonal<A, Sd, Se>(d: &ArrayBase<Sd, Ix1>, e: &ArrayBase<Se, Ix1>) -> Result<(Array1<A>, Array2<A>)>
+where
+    A: Scalar<Real = A> + Lapack,
+    Sd: Data<Elem = A>,
+    Se: Data<Elem = A>,
+{
+    let n = check_tridiagonal(d, e)?;
+    let (w, z) = unsafe { A::eigh_tridiagonal(true, &d.to_vec(), &e.to_vec())? };
+    Ok((ArrayBase::from_vec(w), Array::from_shape_vec((n, n).f(), z)?))
+}
+
+/// Calculate eigenvalues of a real symmetric tridiagonal matrix without eigenvectors
+///
+/// See [eigh_tridiagonal](fn.eigh_tridiagonal.html) for the arguments.
+pub fn eigvalsh_tridiagonal<A, Sd, Se>(d: &ArrayBase<Sd, Ix1>, e: &ArrayBase<Se, Ix1>) -> Result<Array1<A>>
+where
+    A: Scalar<Real = A> + Lapack,
+    Sd: Data<Elem = A>,
+    Se: Data<Elem = A>,
+{
+    check_tridiagonal(d, e)?;
+    let (w, _) = unsafe { A::eigh_tridiagonal(false, &d.to_vec(), &e.to_vec())? };
+    Ok(ArrayBase::from_vec(w))
+}
+
+/// Eigenvalue decomposition of a part of the spectrum of a real symmetric tridiagonal matrix using `*stemr` (MRRR)
+///
+/// See [eigh_tridiagonal](fn.eigh_tridiagonal.html) for the arguments.
+pub fn eigh_tridiagonal_range<A, Sd, Se>(
+    d: &ArrayBase<Sd, Ix1>,
+    e: &ArrayBase<Se, Ix1>,
+    range: EigRange<A>,
+) -> Result<(Array1<A>, Array2<A>)>
+where
+    A: Scalar<Real = A> + Lapack,
+    Sd: Data<Elem = A>,
+    Se: Data<Elem = A>,
+{
+    let n = check_tridiagonal(d, e)?;
+    if let EigRange::Index(start, end) = range {
+        if start > end || end > n {
+            return Err(ShapeError::from_kind(ErrorKind::OutOfBounds).into());
+        }
+        if start == end {
+            return Ok((Array1::zeros(0), Array2::zeros((n, 0))));
+        }
+    }
+    let (w, z) = unsafe { A::eigh_tridiagonal_range(true, range, &d.to_vec(), &e.to_vec())? };
+    let m = w.len();
+    Ok((ArrayBase::from_vec(w), Array::from_shape_vec((n, m).f(), z)?))
+}
+
+fn check_tridiagonal<A, Sd, Se>(d: &ArrayBase<Sd, Ix1>, e: &ArrayBase<Se, Ix1>) -> Result<usize>
+where
+    Sd: Data<Elem = A>,
+    Se: Data<Elem = A>,
+{
+    let n = d.len();
+    if e.len() + 1 != n && !(n == 0 && e.len() == 0) {
+        return Err(ShapeError::from_kind(ErrorKind::IncompatibleShape).into());
+    }
+    Ok(n)
+}
+
 /// Calculate eigenvalues without eigenvectors
 pub trait EigValsh {
     type EigVal;
@@ -117,6 +437,21 @@ where
     }
 }
 
+impl<A, S> EigValsh for ArrayBase<S, Ix3>
+where
+    A: Scalar + Lapack + Send + Sync,
+    A::Real: Send + Sync,
+    S: Data<Elem = A>,
+{
+    type EigVal = Array2<A::Real>;
+
+    fn eigvalsh(&self, uplo: UPLO) -> Result<Self::EigVal> {
+        let (_, n, _) = self.dim();
+        let es = map_stack(self, |a| a.eigvalsh(uplo))?;
+        Ok(stack_vectors(n, &es))
+    }
+}
+
 impl<A, S> EigValshInplace for ArrayBase<S, Ix2>
 where
     A: Scalar + Lapack,
@@ -125,12 +460,125 @@ where
     type EigVal = Array1<A::Real>;
 
     fn eigvalsh_inplace(&mut self, uplo: UPLO) -> Result<Self::EigVal> {
-        let s = unsafe { A::eigh(true, self.square_layout()?, uplo, self.as_allocated_mut()?)? };
+        // JOBZ = 'N': eigenvectors are neither computed nor stored
+        let s = unsafe { A::eigh(false, self.square_layout()?, uplo, self.as_allocated_mut()?)? };
         Ok(ArrayBase::from_vec(s))
     }
 }
 
+/// Apply a function to a Hermite matrix through its spectrum, i.e. `f(A) = V f(Λ) V^H` using `eigh`
+///
+/// This gives e.g. the matrix exponential, logarithm or inverse of a Hermite matrix
+/// by passing `exp`, `ln` or `recip` as `f`.
+pub trait EighMap {
+    type Output;
+    type Real;
+    fn eigh_map<F>(&self, uplo: UPLO, f: F) -> Result<Self::Output>
+    where
+        F: Fn(Self::Real) -> Self::Real;
+}
+
+/// Apply a function to a Hermite matrix through its spectrum, i.e. `f(A) = V f(Λ) V^H` using `eigh`
+pub trait EighMapInto {
+    type Output;
+    type Real;
+    fn eigh_map_into<F>(self, uplo: UPLO, f: F) -> Result<Self::Output>
+    where
+        F: Fn(Self::Real) -> Self::Real;
+}
+
+impl<A, S> EighMap for ArrayBase<S, Ix2>
+where
+    A: Scalar + Lapack,
+    S: Data<Elem = A>,
+{
+    type Output = Array2<A>;
+    type Real = A::Real;
+
+    fn eigh_map<F>(&self, uplo: UPLO, f: F) -> Result<Self::Output>
+    where
+        F: Fn(A::Real) -> A::Real,
+    {
+        let a = self.to_owned();
+        a.eigh_map_into(uplo, f)
+    }
+}
+
+impl<A, S> EighMapInto for ArrayBase<S, Ix2>
+where
+    A: Scalar + Lapack,
+    S: DataMut<Elem = A>,
+{
+    type Output = Array2<A>;
+    type Real = A::Real;
+
+    fn eigh_map_into<F>(self, uplo: UPLO, f: F) -> Result<Self::Output>
+    where
+        F: Fn(A::Real) -> A::Real,
+    {
+        let (e, v) = self.eigh_into(uplo)?;
+        Ok(reconstruct(&e.mapv(f), &v.to_owned()))
+    }
+}
+
+/// Square root and inverse square root of Hermite positive semi-definite matrix using `eigh`
+///
+/// Eigenvalues not larger than `cutoff` (including negative ones due to rounding) are regarded as zero.
+/// If `cutoff` is `None`, `n * eps * max(|λ|)` is used.
+pub trait HermitianSqrt {
+    type Output;
+    type Real;
+    /// Square root `A^{1/2}`
+    fn sqrt_h(&self, uplo: UPLO, cutoff: Option<Self::Real>) -> Result<Self::Output>;
+    /// Inverse square root `A^{-1/2}`, which becomes the pseudo-inverse if some eigenvalues are cut off
+    fn inv_sqrt_h(&self, uplo: UPLO, cutoff: Option<Self::Real>) -> Result<Self::Output>;
+}
+
+impl<A, S> HermitianSqrt for ArrayBase<S, Ix2>
+where
+    A: Scalar + Lapack,
+    S: Data<Elem = A>,
+{
+    type Output = Array2<A>;
+    type Real = A::Real;
+
+    fn sqrt_h(&self, uplo: UPLO, cutoff: Option<A::Real>) -> Result<Self::Output> {
+        let (e, v) = self.eigh(uplo)?;
+        let cutoff = cutoff.unwrap_or_else(|| default_cutoff(&e));
+        let zero = A::Real::zero();
+        Ok(reconstruct(&e.mapv(|e| if e > cutoff { e.sqrt() } else { zero }), &v))
+    }
+
+    fn inv_sqrt_h(&self, uplo: UPLO, cutoff: Option<A::Real>) -> Result<Self::Output> {
+        let (e, v) = self.eigh(uplo)?;
+        let cutoff = cutoff.unwrap_or_else(|| default_cutoff(&e));
+        let zero = A::Real::zero();
+        Ok(reconstruct(
+            &e.mapv(|e| if e > cutoff { e.sqrt().recip() } else { zero }),
+            &v,
+        ))
+    }
+}
+
+fn default_cutoff<R: Float>(e: &Array1<R>) -> R {
+    let max = e.iter().fold(R::zero(), |m, &e| m.max(e.abs()));
+    R::from(e.len()).unwrap() * R::epsilon() * max
+}
+
+/// `V diag(d) V^H`
+fn reconstruct<A: Scalar>(d: &Array1<A::Real>, v: &Array2<A>) -> Array2<A> {
+    let mut vd = v.clone();
+    for (mut col, &d) in vd.axis_iter_mut(Axis(1)).zip(d.iter()) {
+        col.mapv_inplace(|x| x.mul_real(d));
+    }
+    let vh: Array2<A> = conjugate(v);
+    vd.dot(&vh)
+}
+
 /// Calculate symmetric square-root matrix using `eigh`
+///
+/// This is `V Λ^{1/2} V^H` as [HermitianSqrt::sqrt_h](trait.HermitianSqrt.html#tymethod.sqrt_h),
+/// but without cutting off the eigenvalues, so that a negative one gives NaN.
 pub trait SymmetricSqrt {
     type Output;
     fn ssqrt(&self, uplo: UPLO) -> Result<Self::Output>;
@@ -164,8 +612,6 @@ where
 
     fn ssqrt_into(self, uplo: UPLO) -> Result<Self::Output> {
         let (e, v) = self.eigh_into(uplo)?;
-        let e_sqrt = Array1::from_iter(e.iter().map(|r| Scalar::from_real(r.sqrt())));
-        let ev = e_sqrt.into_diagonal().apply2(&v.t());
-        Ok(v.apply2(&ev))
+        Ok(reconstruct(&e.mapv(|e| e.sqrt()), &v.to_owned()))
     }
 }
@@ -0,0 +1,395 @@
+//! Matrix exponential
+//!
+//! [Expm](trait.Expm.html) computes `exp(A)` of a general square matrix by the scaling and squaring method
+//! with Padé approximants: the matrix is scaled as `A / 2^s` so that its 1-norm is small enough for a Padé approximant of degree `m`
+//! to be accurate to the working precision, and the approximant is squared `s` times.
+//! The degree `m` and the scaling `s` are chosen from the 1-norm of `A` to minimize the cost.
+//!
+//! - N. J. Higham, The scaling and squaring method for the matrix exponential revisited,
+//!   SIAM J. Matrix Anal. Appl. 26 (2005)
+//!
+//! [expm_multiply](fn.expm_multiply.html) computes the action `exp(tA) B` on a few vectors
+//! without forming `exp(tA)`, e.g. for time stepping of large linear ODEs.
+//! The sensitivity of `exp(A)` is given by the Fréchet derivative [expm_frechet](fn.expm_frechet.html)
+//! and the condition number [expm_cond](fn.expm_cond.html).
+//!
+//! # Example
+//!
+//! ```
+//! #[macro_use]
+//! extern crate ndarray;
+//! extern crate ndarray_linalg;
+//!
+//! use ndarray::prelude::*;
+//! use ndarray_linalg::*;
+//! # fn main() {
+//!
+//! // Rotation generator
+//! let t = 0.5_f64;
+//! let a: Array2<f64> = array![[0., -t], [t, 0.]];
+//! let r = a.expm().unwrap();
+//! assert!(r.all_close(&array![[t.cos(), -t.sin()], [t.sin(), t.cos()]], 1e-12));
+//! # }
+//! ```
+
+use ndarray::*;
+use num_traits::{Float, ToPrimitive};
+
+use crate::error::*;
+use crate::layout::*;
+use crate::opnorm::*;
+use crate::solve::*;
+use crate::svd::*;
+use crate::types::*;
+
+/// Matrix exponential
+pub trait Expm {
+    type Output;
+    /// Compute `exp(A)` by the scaling and squaring method
+    ///
+    /// A matrix with an infinite entry gives a matrix filled with NaN.
+    fn expm(&self) -> Result<Self::Output>;
+}
+
+impl<A, S> Expm for ArrayBase<S, Ix2>
+where
+    A: Scalar + Lapack,
+    S: Data<Elem = A>,
+{
+    type Output = Array2<A>;
+
+    fn expm(&self) -> Result<Array2<A>> {
+        self.square_layout()?;
+        let a = self.to_owned();
+        let norm = a.opnorm_one()?;
+        let thetas = pade_thetas::<A::Real>();
+        for &(m, theta) in thetas {
+            if norm <= A::real(theta) {
+                return pade(&a, m);
+            }
+        }
+        let (m, theta) = thetas[thetas.len() - 1];
+        let (a, s) = match scale(&a, norm, A::real(theta)) {
+            Some(scaled) => scaled,
+            // No scaling brings an infinite norm down to `theta`
+            None => return Ok(Array2::from_elem(a.dim(), A::from_real(A::Real::nan()))),
+        };
+        let mut x = pade(&a, m)?;
+        for _ in 0..s {
+            x = x.dot(&x);
+        }
+        Ok(x)
+    }
+}
+
+/// Degrees of the Padé approximants and the maximal 1-norm for each of them,
+/// for which the backward error of the approximant is below the unit roundoff (Higham 2005, Table 2.3)
+fn pade_thetas<R: Float>() -> &'static [(usize, f64)] {
+    if R::epsilon() > R::from(1e-10).unwrap() {
+        &[
+            (3, 4.258730016922831e-1),
+            (5, 1.880152677804762),
+            (7, 3.925724783138660),
+        ]
+    } else {
+        &[
+            (3, 1.495585217958292e-2),
+            (5, 2.539398330063230e-1),
+            (7, 9.504178996162932e-1),
+            (9, 2.097847961257068),
+            (13, 5.371920351148152),
+        ]
+    }
+}
+
+/// Coefficients of the numerator of the `[m/m]` Padé approximant to `exp(x)`
+fn pade_coefficients(m: usize) -> &'static [f64] {
+    match m {
+        3 => &[120., 60., 12., 1.],
+        5 => &[30240., 15120., 3360., 420., 30., 1.],
+        7 => &[17297280., 8648640., 1995840., 277200., 25200., 1512., 56., 1.],
+        9 => &[
+            17643225600.,
+            8821612800.,
+            2075673600.,
+            302702400.,
+            30270240.,
+            2162160.,
+            110880.,
+            3960.,
+            90.,
+            1.,
+        ],
+        13 => &[
+            64764752532480000.,
+            32382376266240000.,
+            7771770303897600.,
+            1187353796428800.,
+            129060195264000.,
+            10559470521600.,
+            670442572800.,
+            33522128640.,
+            1323241920.,
+            40840800.,
+            960960.,
+            16380.,
+            182.,
+            1.,
+        ],
+        _ => unreachable!("No Padé approximant of degree {}", m),
+    }
+}
+
+/// Scale `a` by `2^-s` with the smallest `s >= 0` such that the 1-norm becomes at most `theta`,
+/// or `None` if the 1-norm is infinite
+fn scale<A: Scalar>(a: &Array2<A>, norm: A::Real, theta: A::Real) -> Option<(Array2<A>, i32)> {
+    let s = Float::max((norm / theta).log2().ceil(), A::Real::zero()).to_i32()?;
+    let factor = A::real(2.0).powi(s);
+    Some((a.mapv(|x| x.div_real(factor)), s))
+}
+
+/// `sum_k c_k X_k`
+fn linear_combination<A: Scalar>(terms: &[(f64, &Array2<A>)]) -> Array2<A> {
+    let mut y = Array2::zeros(terms[0].1.dim());
+    for &(c, x) in terms {
+        y.scaled_add(A::from_real(A::real(c)), x);
+    }
+    y
+}
+
+/// The odd and even parts `(U, V)` of the numerator of the `[m/m]` Padé approximant,
+/// so that the approximant is `(V - U)^{-1} (V + U)`
+fn pade_uv<A: Scalar>(a: &Array2<A>, m: usize) -> (Array2<A>, Array2<A>) {
+    let b = pade_coefficients(m);
+    let ident = Array2::eye(a.rows());
+    let a2 = a.dot(a);
+    if m == 13 {
+        let a4 = a2.dot(&a2);
+        let a6 = a4.dot(&a2);
+        let u = linear_combination(&[(b[13], &a6), (b[11], &a4), (b[9], &a2)]);
+        let u = a6.dot(&u) + linear_combination(&[(b[7], &a6), (b[5], &a4), (b[3], &a2), (b[1], &ident)]);
+        let v = linear_combination(&[(b[12], &a6), (b[10], &a4), (b[8], &a2)]);
+        let v = a6.dot(&v) + linear_combination(&[(b[6], &a6), (b[4], &a4), (b[2], &a2), (b[0], &ident)]);
+        return (a.dot(&u), v);
+    }
+    // Even powers `I, A^2, A^4, ...`
+    let mut powers = vec![ident];
+    for k in 1..=m / 2 {
+        let p = powers[k - 1].dot(&a2);
+        powers.push(p);
+    }
+    let u: Vec<_> = powers.iter().enumerate().map(|(k, p)| (b[2 * k + 1], p)).collect();
+    let v: Vec<_> = powers.iter().enumerate().map(|(k, p)| (b[2 * k], p)).collect();
+    (a.dot(&linear_combination(&u)), linear_combination(&v))
+}
+
+/// The `[m/m]` Padé approximant to `exp(A)`
+fn pade<A: Scalar + Lapack>(a: &Array2<A>, m: usize) -> Result<Array2<A>> {
+    let (u, v) = pade_uv(a, m);
+    let f = (&v - &u).factorize_into()?;
+    solve_columns(&f, &(&v + &u))
+}
+
+/// Compute the action `exp(tA) B` of the matrix exponential without forming `exp(tA)`
+///
+/// `b` is a vector (`Ix1`) or a matrix (`Ix2`) whose columns are propagated simultaneously.
+/// The truncated Taylor series of degree `m` is applied `s` times as `exp(tA) B = (exp(tA / s))^s B`,
+/// where `m` and `s` are chosen from the 1-norm of `A` to minimize the number of products `A B`,
+/// and each series is terminated early when it converges.
+/// `A` is shifted by `μ = trace(A) / n` before the propagation to reduce its norm.
+///
+/// This only requires products of `A` with blocks of vectors,
+/// and is much cheaper than [expm](trait.Expm.html) for large `A` and few vectors.
+/// As there, an infinite `|tA|_1` gives NaN.
+///
+/// - A. H. Al-Mohy and N. J. Higham, Computing the action of the matrix exponential,
+///   with an application to exponential integrators, SIAM J. Sci. Comput. 33 (2011)
+pub fn expm_multiply<A, Sa, Sb, D>(a: &ArrayBase<Sa, Ix2>, b: &ArrayBase<Sb, D>, t: A::Real) -> Result<Array<A, D>>
+where
+    A: Scalar + Lapack,
+    Sa: Data<Elem = A>,
+    Sb: Data<Elem = A>,
+    D: Dimension,
+{
+    let (n, _) = a.square_layout()?.size();
+    let b = b.view().into_dyn();
+    let vector = match b.ndim() {
+        1 => true,
+        2 => false,
+        _ => return Err(ShapeError::from_kind(ErrorKind::IncompatibleShape).into()),
+    };
+    let b: ArrayView2<A> = if vector {
+        b.insert_axis(Axis(1)).into_dimensionality()?
+    } else {
+        b.into_dimensionality()?
+    };
+    if b.rows() != n as usize {
+        return Err(ShapeError::from_kind(ErrorKind::IncompatibleShape).into());
+    }
+
+    let mut a = a.to_owned();
+    let mu = a.diag().iter().fold(A::zero(), |acc, &x| acc + x).div_real(A::real(n));
+    for d in a.diag_mut() {
+        *d = *d - mu;
+    }
+    let (m, s) = match taylor_parameters(t.abs() * a.opnorm_one()?) {
+        Some(parameters) => parameters,
+        None => return restore_shape(Array2::from_elem(b.dim(), A::from_real(A::Real::nan())), vector),
+    };
+    let tol = A::Real::epsilon() / A::real(2.0);
+    let eta = mu.mul_real(t / A::real(s)).exp();
+
+    let mut f = b.to_owned();
+    let mut b = f.clone();
+    for _ in 0..s {
+        let mut c1 = norm_inf(&b);
+        for j in 1..=m {
+            b = a.dot(&b);
+            let coef = t / A::real(s * j);
+            b.mapv_inplace(|x| x.mul_real(coef));
+            let c2 = norm_inf(&b);
+            f.scaled_add(A::one(), &b);
+            if c1 + c2 <= tol * norm_inf(&f) {
+                break;
+            }
+            c1 = c2;
+        }
+        f.mapv_inplace(|x| x * eta);
+        b.assign(&f);
+    }
+    restore_shape(f, vector)
+}
+
+/// Convert the propagated block back to the dimension of `B`
+fn restore_shape<A, D: Dimension>(f: Array2<A>, vector: bool) -> Result<Array<A, D>> {
+    let f = if vector {
+        f.index_axis_move(Axis(1), 0).into_dyn()
+    } else {
+        f.into_dyn()
+    };
+    Ok(f.into_dimensionality()?)
+}
+
+/// Maximal `|tA|_1` for which the truncated Taylor series of degree `m` is accurate
+/// to the unit roundoff of double precision (Al-Mohy and Higham 2011, Table 3.1)
+const TAYLOR_THETAS: [(usize, f64); 35] = [
+    (1, 2.29e-16),
+    (2, 2.58e-8),
+    (3, 1.39e-5),
+    (4, 3.40e-4),
+    (5, 2.40e-3),
+    (6, 9.07e-3),
+    (7, 2.38e-2),
+    (8, 5.00e-2),
+    (9, 8.96e-2),
+    (10, 1.44e-1),
+    (11, 2.14e-1),
+    (12, 3.00e-1),
+    (13, 4.00e-1),
+    (14, 5.14e-1),
+    (15, 6.41e-1),
+    (16, 7.81e-1),
+    (17, 9.31e-1),
+    (18, 1.09),
+    (19, 1.26),
+    (20, 1.44),
+    (21, 1.62),
+    (22, 1.82),
+    (23, 2.01),
+    (24, 2.22),
+    (25, 2.43),
+    (26, 2.64),
+    (27, 2.86),
+    (28, 3.08),
+    (29, 3.31),
+    (30, 3.54),
+    (35, 4.7),
+    (40, 6.0),
+    (45, 7.2),
+    (50, 8.5),
+    (55, 9.9),
+];
+
+/// The degree `m` and the number of steps `s` of the truncated Taylor series minimizing the cost `m s`,
+/// or `None` if `|tA|_1` is not finite
+fn taylor_parameters<R: Float>(norm: R) -> Option<(usize, usize)> {
+    if norm.is_zero() {
+        return Some((0, 1));
+    }
+    if !norm.is_finite() {
+        return None;
+    }
+    let norm = norm.to_f64()?;
+    TAYLOR_THETAS
+        .iter()
+        .map(|&(m, theta)| (m, (norm / theta).ceil() as usize))
+        .min_by_key(|&(m, s)| m * s)
+}
+
+/// Maximal absolute row sum
+fn norm_inf<A: Scalar>(b: &Array2<A>) -> A::Real {
+    b.outer_iter()
+        .map(|row| row.iter().fold(A::Real::zero(), |acc, x| acc + x.abs()))
+        .fold(A::Real::zero(), Float::max)
+}
+
+/// Compute `exp(A)` and the Fréchet derivative `L(A, E)` of the matrix exponential in the direction `E`
+///
+/// `L(A, E)` is the linear term of `exp(A + E) - exp(A)` in `E`, i.e. `d/dt exp(A + tE)` at `t = 0`.
+/// Both are read off from the exponential of the block triangular matrix
+///
+/// ```text
+/// exp([A E]) = [exp(A) L(A, E)]
+///     [0 A]    [0      exp(A) ]
+/// ```
+///
+/// which is computed by [expm](trait.Expm.html) of size `2n`.
+///
+/// - N. J. Higham, Functions of Matrices: Theory and Computation, SIAM (2008), Theorem 3.6
+pub fn expm_frechet<A, Sa, Se>(a: &ArrayBase<Sa, Ix2>, e: &ArrayBase<Se, Ix2>) -> Result<(Array2<A>, Array2<A>)>
+where
+    A: Scalar + Lapack,
+    Sa: Data<Elem = A>,
+    Se: Data<Elem = A>,
+{
+    let (n, _) = a.square_layout()?.size();
+    let n = n as usize;
+    if e.dim() != (n, n) {
+        return Err(ShapeError::from_kind(ErrorKind::IncompatibleShape).into());
+    }
+    let mut block = Array2::zeros((2 * n, 2 * n));
+    block.slice_mut(s![..n, ..n]).assign(a);
+    block.slice_mut(s![n.., n..]).assign(a);
+    block.slice_mut(s![..n, n..]).assign(e);
+    let x = block.expm()?;
+    Ok((x.slice(s![..n, ..n]).to_owned(), x.slice(s![..n, n..]).to_owned()))
+}
+
+/// Relative condition number of the matrix exponential in the Frobenius norm
+///
+/// `κ(A) = |L(A)| |A|_F / |exp(A)|_F`, where `|L(A)| = max_{|E|_F = 1} |L(A, E)|_F` is the 2-norm
+/// of the `n^2 x n^2` Kronecker form of the Fréchet derivative.
+/// It is formed exactly from `L(A, E)` for all `n^2` unit matrices `E` by [expm_frechet](fn.expm_frechet.html),
+/// which costs `O(n^5)` and is intended for small matrices.
+pub fn expm_cond<A, S>(a: &ArrayBase<S, Ix2>) -> Result<A::Real>
+where
+    A: Scalar + Lapack,
+    S: Data<Elem = A>,
+{
+    let (n, _) = a.square_layout()?.size();
+    let n = n as usize;
+    let mut kron = Array2::zeros((n * n, n * n));
+    for j in 0..n {
+        for i in 0..n {
+            let mut e = Array2::zeros((n, n));
+            e[(i, j)] = A::one();
+            let (_, l) = expm_frechet(a, &e)?;
+            // Columns of `kron` are `vec(L(A, E_ij))` stacked column-wise
+            kron.column_mut(j * n + i)
+                .assign(&Array::from_iter(l.t().iter().cloned()));
+        }
+    }
+    let (_, sigma, _) = kron.svd(false, false)?;
+    let norm_l = sigma.fold(A::Real::zero(), |acc, &s| Float::max(acc, s));
+    Ok(norm_l * a.opnorm_fro()? / a.expm()?.opnorm_fro()?)
+}
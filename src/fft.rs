@@ -0,0 +1,187 @@
+//! Discrete Fourier transform
+//!
+//! [Fourier](trait.Fourier.html) computes the DFT `X_k = sum_j x_j exp(-2 pi i j k / n)` of a vector
+//! of any length in `O(n log n)` time.
+//! Lengths of powers of two are transformed by the radix-2 Cooley-Tukey algorithm,
+//! and the other lengths by Bluestein's algorithm, which rewrites the DFT as a convolution of a power-of-two length.
+//!
+//! # Example
+//!
+//! ```
+//! #[macro_use]
+//! extern crate ndarray;
+//! extern crate ndarray_linalg;
+//!
+//! use ndarray::prelude::*;
+//! use ndarray_linalg::*;
+//! # fn main() {
+//!
+//! let x = array![1., 2., 3.];
+//! let f = f64::fft(&x);
+//! assert!((f[0] - c64::new(6., 0.)).norm() < 1e-12);
+//! assert!(f64::ifft(&f).all_close(&x, 1e-12));
+//! # }
+//! ```
+
+use ndarray::*;
+use num_complex::Complex;
+use num_traits::{Float, Zero};
+use std::f64::consts::PI;
+
+use crate::types::*;
+
+/// Discrete Fourier transform of vectors
+pub trait Fourier: Scalar {
+    /// DFT `X_k = sum_j x_j exp(-2 pi i j k / n)`
+    fn fft<S>(x: &ArrayBase<S, Ix1>) -> Array1<Self::Complex>
+    where
+        S: Data<Elem = Self>;
+
+    /// Inverse DFT `x_j = sum_k X_k exp(2 pi i j k / n) / n`
+    ///
+    /// For real types, the imaginary part of the result is discarded.
+    fn ifft<S>(x: &ArrayBase<S, Ix1>) -> Array1<Self>
+    where
+        S: Data<Elem = Self::Complex>;
+}
+
+macro_rules! impl_fourier_real {
+    ($scalar:ty) => {
+        impl Fourier for $scalar {
+            fn fft<S>(x: &ArrayBase<S, Ix1>) -> Array1<Complex<$scalar>>
+            where
+                S: Data<Elem = Self>,
+            {
+                let mut z: Vec<_> = x.iter().map(|&re| Complex::new(re, 0.0)).collect();
+                transform(&mut z, false);
+                Array1::from_vec(z)
+            }
+
+            fn ifft<S>(x: &ArrayBase<S, Ix1>) -> Array1<$scalar>
+            where
+                S: Data<Elem = Self::Complex>,
+            {
+                let mut z = x.to_vec();
+                transform(&mut z, true);
+                z.iter().map(|z| z.re).collect()
+            }
+        }
+    };
+}
+
+macro_rules! impl_fourier_complex {
+    ($scalar:ty) => {
+        impl Fourier for $scalar {
+            fn fft<S>(x: &ArrayBase<S, Ix1>) -> Array1<$scalar>
+            where
+                S: Data<Elem = Self>,
+            {
+                let mut z = x.to_vec();
+                transform(&mut z, false);
+                Array1::from_vec(z)
+            }
+
+            fn ifft<S>(x: &ArrayBase<S, Ix1>) -> Array1<$scalar>
+            where
+                S: Data<Elem = Self::Complex>,
+            {
+                let mut z = x.to_vec();
+                transform(&mut z, true);
+                Array1::from_vec(z)
+            }
+        }
+    };
+}
+
+impl_fourier_real!(f32);
+impl_fourier_real!(f64);
+impl_fourier_complex!(c32);
+impl_fourier_complex!(c64);
+
+/// DFT (or inverse DFT scaled by `1/n`) in place
+fn transform<T: Float>(x: &mut [Complex<T>], inverse: bool) {
+    let n = x.len();
+    if n <= 1 {
+        return;
+    }
+    if n.is_power_of_two() {
+        radix2(x, inverse);
+    } else {
+        bluestein(x, inverse);
+    }
+    if inverse {
+        let n = T::from(n).unwrap();
+        for z in x.iter_mut() {
+            *z = *z / n;
+        }
+    }
+}
+
+/// `exp(-2 pi i k / n)`, or its conjugate for the inverse transform
+fn twiddle<T: Float>(k: usize, n: usize, inverse: bool) -> Complex<T> {
+    let sign = if inverse { 1.0 } else { -1.0 };
+    let theta = T::from(sign * 2.0 * PI * k as f64 / n as f64).unwrap();
+    Complex::new(theta.cos(), theta.sin())
+}
+
+/// Unscaled transform of a power-of-two length by the iterative Cooley-Tukey algorithm
+fn radix2<T: Float>(x: &mut [Complex<T>], inverse: bool) {
+    let n = x.len();
+    // Bit-reversal permutation
+    let mut j = 0;
+    for i in 1..n {
+        let mut bit = n >> 1;
+        while j & bit != 0 {
+            j ^= bit;
+            bit >>= 1;
+        }
+        j |= bit;
+        if i < j {
+            x.swap(i, j);
+        }
+    }
+    let mut len = 2;
+    while len <= n {
+        let half = len / 2;
+        let w: Vec<Complex<T>> = (0..half).map(|k| twiddle(k, len, inverse)).collect();
+        for start in (0..n).step_by(len) {
+            for k in 0..half {
+                let u = x[start + k];
+                let v = x[start + k + half] * w[k];
+                x[start + k] = u + v;
+                x[start + k + half] = u - v;
+            }
+        }
+        len <<= 1;
+    }
+}
+
+/// Unscaled transform of an arbitrary length by Bluestein's algorithm
+///
+/// By `jk = (j^2 + k^2 - (k - j)^2) / 2`, the DFT is the convolution of `x_j w_j` and `conj(w_j)`
+/// multiplied by `w_k`, where `w_k = exp(-pi i k^2 / n)` is the chirp.
+fn bluestein<T: Float>(x: &mut [Complex<T>], inverse: bool) {
+    let n = x.len();
+    let m = (2 * n - 1).next_power_of_two();
+    // `k^2` is reduced modulo `2n` to keep the angle accurate
+    let chirp: Vec<Complex<T>> = (0..n).map(|k| twiddle(k * k % (2 * n), 2 * n, inverse)).collect();
+    let mut a = vec![Complex::zero(); m];
+    let mut b = vec![Complex::zero(); m];
+    for k in 0..n {
+        a[k] = x[k] * chirp[k];
+        b[k] = chirp[k].conj();
+        if k > 0 {
+            b[m - k] = chirp[k].conj();
+        }
+    }
+    radix2(&mut a, false);
+    radix2(&mut b, false);
+    for (a, b) in a.iter_mut().zip(b.iter()) {
+        *a = *a * *b;
+    }
+    radix2(&mut a, true);
+    let m = T::from(m).unwrap();
+    for k in 0..n {
+        x[k] = a[k] * chirp[k] / m;
+    }
+}
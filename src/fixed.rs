@@ -0,0 +1,234 @@
+//! Fixed-size matrices on the stack
+//!
+//! [FixedLinalg](trait.FixedLinalg.html) is implemented for the arrays `[[A; N]; N]` of a constant size `N`,
+//! e.g. the `4x4` transforms or the `6x6` spatial inertia of the rigid-body dynamics.
+//! The LU and Cholesky decompositions are computed in pure Rust without any heap allocation,
+//! and the loops over `N` are unrolled by the compiler for a small `N`.
+//! The rows are the outer index as for [arr2](https://docs.rs/ndarray/0.12/ndarray/fn.arr2.html):
+//!
+//! ```
+//! use ndarray::*;
+//! use ndarray_linalg::*;
+//!
+//! let a = [[4.0, 1.0, 0.0], [1.0, 4.0, 1.0], [0.0, 1.0, 4.0]];
+//! let x = a.solve_fixed(&[5.0, 6.0, 5.0]).unwrap();
+//! assert_close_l2!(&arr1(&x), &arr1(&[1.0, 1.0, 1.0]), 1e-12);
+//! let l = a.cholesky_fixed(UPLO::Lower).unwrap();
+//! assert_close_l2!(&from_fixed(&l).dot(&from_fixed(&l).t()), &arr2(&a), 1e-12);
+//! ```
+//!
+//! As the LAPACK routines, a zero pivot of the LU decomposition and a non-positive pivot of the Cholesky decomposition
+//! are reported by `LinalgError::Lapack` with the one-based index of the pivot.
+
+use ndarray::*;
+use num_traits::{Float, One, Zero};
+use std::cmp::Ordering;
+
+use crate::error::*;
+use crate::types::*;
+use crate::UPLO;
+
+/// Decompositions and solvers of `N x N` matrices stored in `[[A; N]; N]`
+pub trait FixedLinalg<A: Scalar, const N: usize>: Sized {
+    /// Determinant by the LU decomposition, which is zero for a singular matrix
+    fn det_fixed(&self) -> A;
+    /// Inverse matrix by the LU decomposition
+    fn inv_fixed(&self) -> Result<Self>;
+    /// Solve `A x = b` by the LU decomposition with partial pivoting
+    fn solve_fixed(&self, b: &[A; N]) -> Result<[A; N]>;
+    /// Cholesky factor `L` of `A = L L^H` for `UPLO::Lower`, or `U` of `A = U^H U` for `UPLO::Upper`,
+    /// reading only the triangular part specified by `uplo`
+    fn cholesky_fixed(&self, uplo: UPLO) -> Result<Self>;
+    /// Solve `A x = b` for a Hermitian (or real symmetric) positive definite matrix by the Cholesky decomposition
+    fn solvec_fixed(&self, b: &[A; N]) -> Result<[A; N]>;
+}
+
+/// LU decomposition `P A = L U` stored as `*getrf` in a single matrix,
+/// where the `i`-th row of `P A` is the `perm[i]`-th row of `A`
+struct FixedLU<A, const N: usize> {
+    lu: [[A; N]; N],
+    perm: [usize; N],
+    odd: bool,
+}
+
+fn lu<A: Scalar, const N: usize>(a: &[[A; N]; N]) -> Result<FixedLU<A, N>> {
+    let mut lu = *a;
+    let mut perm = [0; N];
+    for (i, p) in perm.iter_mut().enumerate() {
+        *p = i;
+    }
+    let mut odd = false;
+    for k in 0..N {
+        let p = (k..N).fold(k, |p, i| if lu[i][k].abs() > lu[p][k].abs() { i } else { p });
+        if lu[p][k].is_zero() {
+            return Err(LinalgError::Lapack {
+                return_code: k as i32 + 1,
+            });
+        }
+        if p != k {
+            lu.swap(p, k);
+            perm.swap(p, k);
+            odd = !odd;
+        }
+        let (top, bottom) = lu.split_at_mut(k + 1);
+        let pivot = &top[k];
+        for row in bottom.iter_mut() {
+            let l = row[k] / pivot[k];
+            row[k] = l;
+            for (x, &u) in row[k + 1..].iter_mut().zip(&pivot[k + 1..]) {
+                *x = *x - l * u;
+            }
+        }
+    }
+    Ok(FixedLU { lu, perm, odd })
+}
+
+impl<A: Scalar, const N: usize> FixedLU<A, N> {
+    fn solve(&self, b: &[A; N]) -> [A; N] {
+        let mut x = [A::zero(); N];
+        for (x, &p) in x.iter_mut().zip(&self.perm) {
+            *x = b[p];
+        }
+        // L y = P b, where the diagonal of L is one
+        for (i, row) in self.lu.iter().enumerate().skip(1) {
+            let (done, rest) = x.split_at_mut(i);
+            rest[0] = row[..i].iter().zip(done.iter()).fold(rest[0], |s, (&l, &y)| s - l * y);
+        }
+        // U x = y
+        for (i, row) in self.lu.iter().enumerate().rev() {
+            let (head, tail) = x.split_at_mut(i + 1);
+            head[i] = row[i + 1..]
+                .iter()
+                .zip(tail.iter())
+                .fold(head[i], |s, (&u, &y)| s - u * y)
+                / row[i];
+        }
+        x
+    }
+
+    fn det(&self) -> A {
+        let d = self.lu.iter().enumerate().fold(A::one(), |d, (i, row)| d * row[i]);
+        if self.odd {
+            -d
+        } else {
+            d
+        }
+    }
+}
+
+/// Lower triangular `L` of `A = L L^H`
+fn cholesky_lower<A: Scalar, const N: usize>(a: &[[A; N]; N], uplo: UPLO) -> Result<[[A; N]; N]> {
+    // element `(i, j)` of `A` for `j <= i`
+    let lower = |i: usize, j: usize| match uplo {
+        UPLO::Lower => a[i][j],
+        UPLO::Upper => a[j][i].conj(),
+    };
+    let mut l = [[A::zero(); N]; N];
+    for i in 0..N {
+        let (done, rest) = l.split_at_mut(i);
+        let row = &mut rest[0];
+        for (j, lj) in done.iter().enumerate() {
+            let s = row[..j]
+                .iter()
+                .zip(&lj[..j])
+                .fold(lower(i, j), |s, (&x, &y)| s - x * y.conj());
+            row[j] = s / lj[j];
+        }
+        let d = row[..i].iter().fold(lower(i, i).re(), |d, x| d - x.square());
+        if d.partial_cmp(&A::Real::zero()) != Some(Ordering::Greater) {
+            return Err(LinalgError::Lapack {
+                return_code: i as i32 + 1,
+            });
+        }
+        row[i] = A::from_real(Float::sqrt(d));
+    }
+    Ok(l)
+}
+
+/// Conjugate transpose
+fn adjoint<A: Scalar, const N: usize>(a: &[[A; N]; N]) -> [[A; N]; N] {
+    let mut t = [[A::zero(); N]; N];
+    for (i, row) in a.iter().enumerate() {
+        for (dst, x) in t.iter_mut().zip(row.iter()) {
+            dst[i] = x.conj();
+        }
+    }
+    t
+}
+
+impl<A: Scalar, const N: usize> FixedLinalg<A, N> for [[A; N]; N] {
+    fn det_fixed(&self) -> A {
+        match lu(self) {
+            Ok(f) => f.det(),
+            Err(_) => A::zero(),
+        }
+    }
+
+    fn inv_fixed(&self) -> Result<Self> {
+        let f = lu(self)?;
+        let mut inv = [[A::zero(); N]; N];
+        for j in 0..N {
+            let mut e = [A::zero(); N];
+            e[j] = A::one();
+            for (row, &x) in inv.iter_mut().zip(f.solve(&e).iter()) {
+                row[j] = x;
+            }
+        }
+        Ok(inv)
+    }
+
+    fn solve_fixed(&self, b: &[A; N]) -> Result<[A; N]> {
+        Ok(lu(self)?.solve(b))
+    }
+
+    fn cholesky_fixed(&self, uplo: UPLO) -> Result<Self> {
+        let l = cholesky_lower(self, uplo)?;
+        Ok(match uplo {
+            UPLO::Lower => l,
+            UPLO::Upper => adjoint(&l),
+        })
+    }
+
+    fn solvec_fixed(&self, b: &[A; N]) -> Result<[A; N]> {
+        let l = cholesky_lower(self, UPLO::Lower)?;
+        let mut x = *b;
+        // L y = b
+        for (i, row) in l.iter().enumerate() {
+            let (done, rest) = x.split_at_mut(i);
+            rest[0] = row[..i].iter().zip(done.iter()).fold(rest[0], |s, (&x, &y)| s - x * y) / row[i];
+        }
+        // L^H x = y
+        for (i, row) in adjoint(&l).iter().enumerate().rev() {
+            let (head, tail) = x.split_at_mut(i + 1);
+            head[i] = row[i + 1..]
+                .iter()
+                .zip(tail.iter())
+                .fold(head[i], |s, (&u, &y)| s - u * y)
+                / row[i];
+        }
+        Ok(x)
+    }
+}
+
+/// Copy an `N x N` matrix into `[[A; N]; N]`, where the other shapes are rejected by `IncompatibleShape`
+pub fn to_fixed<A, S, const N: usize>(a: &ArrayBase<S, Ix2>) -> Result<[[A; N]; N]>
+where
+    A: Scalar,
+    S: Data<Elem = A>,
+{
+    if a.dim() != (N, N) {
+        return Err(ShapeError::from_kind(ErrorKind::IncompatibleShape).into());
+    }
+    let mut m = [[A::zero(); N]; N];
+    for (dst, src) in m.iter_mut().zip(a.outer_iter()) {
+        for (d, &s) in dst.iter_mut().zip(src.iter()) {
+            *d = s;
+        }
+    }
+    Ok(m)
+}
+
+/// Copy `[[A; N]; N]` into an `N x N` array
+pub fn from_fixed<A: Scalar, const N: usize>(a: &[[A; N]; N]) -> Array2<A> {
+    Array2::from_shape_fn((N, N), |(i, j)| a[i][j])
+}
@@ -0,0 +1,194 @@
+//! General matrix functions by the Schur-Parlett algorithm
+//!
+//! [Funm](trait.Funm.html) evaluates `f(A)` for a scalar function `f` analytic on the spectrum of `A`.
+//! `A = Q T Q^H` is reduced to the complex Schur form, and its eigenvalues are grouped into clusters
+//! whose members are within the distance `δ = 0.1` of each other.
+//! The Schur form is reordered so that each cluster forms a diagonal block of `T`.
+//! `f` of each diagonal block is evaluated by the Taylor series about the mean of its eigenvalues,
+//! and the off-diagonal blocks are obtained from `f(T) T = T f(T)` by solving Sylvester equations.
+//! Since the eigenvalues of different blocks are separated, the Sylvester equations are well-conditioned.
+//!
+//! - P. I. Davies and N. J. Higham, A Schur-Parlett algorithm for computing matrix functions,
+//!   SIAM J. Matrix Anal. Appl. 25 (2003)
+//!
+//! # Example
+//!
+//! ```
+//! #[macro_use]
+//! extern crate ndarray;
+//! extern crate ndarray_linalg;
+//!
+//! use ndarray::prelude::*;
+//! use ndarray_linalg::*;
+//! # fn main() {
+//!
+//! // All derivatives of exp are exp
+//! let a: Array2<f64> = array![[1., 2.], [0., -1.]];
+//! let f = a.funm(|z: c64, _| z.exp()).unwrap().mapv(|x| x.re);
+//! assert!(f.all_close(&a.expm().unwrap(), 1e-12));
+//! # }
+//! ```
+
+use ndarray::*;
+use num_traits::Float;
+
+use crate::error::*;
+use crate::generate::conjugate;
+use crate::layout::*;
+use crate::norm::Norm;
+use crate::schur::*;
+use crate::types::*;
+
+/// Maximal distance between the eigenvalues of a cluster
+const CLUSTER_DELTA: f64 = 0.1;
+
+/// Maximal number of the terms of the Taylor series for each diagonal block
+const MAX_TAYLOR_TERMS: usize = 250;
+
+/// Function of a general square matrix
+pub trait Funm<A: Scalar> {
+    type Output;
+    /// Compute `f(A)` by the Schur-Parlett algorithm
+    ///
+    /// `f(z, k)` must return the `k`-th derivative of `f` at `z`.
+    /// Derivatives of higher orders are needed for larger clusters of close eigenvalues;
+    /// if all eigenvalues are separated by more than `0.1`, only `k = 0` is used.
+    /// The result is complex since `f` is evaluated at the complex eigenvalues in general.
+    fn funm<F>(&self, f: F) -> Result<Self::Output>
+    where
+        F: Fn(A::Complex, usize) -> A::Complex;
+}
+
+impl<A, S> Funm<A> for ArrayBase<S, Ix2>
+where
+    A: Scalar + Lapack,
+    A::Complex: Lapack,
+    S: Data<Elem = A>,
+{
+    type Output = Array2<A::Complex>;
+
+    fn funm<F>(&self, f: F) -> Result<Self::Output>
+    where
+        F: Fn(A::Complex, usize) -> A::Complex,
+    {
+        self.square_layout()?;
+        let a: Array2<A::Complex> = self.mapv(|x| x.as_c());
+        let (mut q, mut t) = a.schur()?;
+        let blocks = reorder_clusters(&mut q, &mut t)?;
+        let ft = schur_parlett(&t, &blocks, &f);
+        let qh: Array2<A::Complex> = conjugate(&q);
+        Ok(q.dot(&ft).dot(&qh))
+    }
+}
+
+/// Group the eigenvalues into clusters, and reorder the Schur form so that each cluster is contiguous
+///
+/// Returns the ranges of the diagonal blocks.
+fn reorder_clusters<A: Scalar + Lapack>(q: &mut Array2<A>, t: &mut Array2<A>) -> Result<Vec<(usize, usize)>> {
+    let n = t.rows();
+    let delta = A::real(CLUSTER_DELTA);
+    let mut labels: Vec<usize> = (0..n).collect();
+    for i in 0..n {
+        for j in i + 1..n {
+            if (t[(i, i)] - t[(j, j)]).abs() <= delta && labels[i] != labels[j] {
+                let (old, new) = (labels[j], labels[i]);
+                for l in labels.iter_mut().filter(|l| **l == old) {
+                    *l = new;
+                }
+            }
+        }
+    }
+    let mut clusters: Vec<usize> = Vec::new();
+    for &l in &labels {
+        if !clusters.contains(&l) {
+            clusters.push(l);
+        }
+    }
+
+    // Each reordering is a stable partition into the selected and the others
+    let mut blocks = Vec::with_capacity(clusters.len());
+    let mut start = 0;
+    for (k, &c) in clusters.iter().enumerate() {
+        let size = labels.iter().filter(|&&l| l == c).count();
+        if k + 1 < clusters.len() {
+            let select: Vec<bool> = labels.iter().map(|l| clusters[..=k].contains(l)).collect();
+            reorder_schur(q, t, &select)?;
+            let (mut head, tail): (Vec<usize>, Vec<usize>) = labels.iter().partition(|l| clusters[..=k].contains(l));
+            head.extend(tail);
+            labels = head;
+        }
+        blocks.push((start, start + size));
+        start += size;
+    }
+    Ok(blocks)
+}
+
+/// `f(T)` of the upper triangular `T` partitioned into the diagonal blocks
+fn schur_parlett<A, F>(t: &Array2<A>, blocks: &[(usize, usize)], f: &F) -> Array2<A>
+where
+    A: Scalar,
+    F: Fn(A, usize) -> A,
+{
+    let n = t.rows();
+    let mut ft = Array2::zeros((n, n));
+    for (j, &(cj, dj)) in blocks.iter().enumerate() {
+        let fjj = taylor_block(&t.slice(s![cj..dj, cj..dj]), f);
+        ft.slice_mut(s![cj..dj, cj..dj]).assign(&fjj);
+        for i in (0..j).rev() {
+            let (ci, di) = blocks[i];
+            let tij = t.slice(s![ci..di, cj..dj]);
+            let mut c = ft.slice(s![ci..di, ci..di]).dot(&tij) - tij.dot(&ft.slice(s![cj..dj, cj..dj]));
+            for &(ck, dk) in &blocks[i + 1..j] {
+                c = c + ft.slice(s![ci..di, ck..dk]).dot(&t.slice(s![ck..dk, cj..dj]))
+                    - t.slice(s![ci..di, ck..dk]).dot(&ft.slice(s![ck..dk, cj..dj]));
+            }
+            let fij = solve_triangular_sylvester(&t.slice(s![ci..di, ci..di]), &t.slice(s![cj..dj, cj..dj]), c);
+            ft.slice_mut(s![ci..di, cj..dj]).assign(&fij);
+        }
+    }
+    ft
+}
+
+/// `f(T)` of a diagonal block by the Taylor series about the mean `σ` of its eigenvalues
+fn taylor_block<A, F>(t: &ArrayView2<A>, f: &F) -> Array2<A>
+where
+    A: Scalar,
+    F: Fn(A, usize) -> A,
+{
+    let m = t.rows();
+    let sigma = t.diag().iter().fold(A::zero(), |acc, &x| acc + x).div_real(A::real(m));
+    let mut shifted = t.to_owned();
+    for d in shifted.diag_mut() {
+        *d = *d - sigma;
+    }
+    // `power` holds `(T - σI)^k / k!`
+    let mut power: Array2<A> = Array2::eye(m);
+    let f0 = f(sigma, 0);
+    let mut ft = power.mapv(|x| x * f0);
+    for k in 1..MAX_TAYLOR_TERMS {
+        power = power.dot(&shifted).mapv(|x| x.div_real(A::real(k)));
+        let fk = f(sigma, k);
+        let term = power.mapv(|x| x * fk);
+        ft.scaled_add(A::one(), &term);
+        if k >= m && term.norm_l2() <= A::Real::epsilon() * ft.norm_l2() {
+            break;
+        }
+    }
+    ft
+}
+
+/// Solve `A X - X B = C` for upper triangular `A` and `B` by substitution
+fn solve_triangular_sylvester<A: Scalar>(a: &ArrayView2<A>, b: &ArrayView2<A>, mut c: Array2<A>) -> Array2<A> {
+    let (p, q) = c.dim();
+    for j in 0..q {
+        for k in 0..j {
+            let xk = c.column(k).to_owned();
+            c.column_mut(j).scaled_add(b[(k, j)], &xk);
+        }
+        for i in (0..p).rev() {
+            let s = (i + 1..p).fold(A::zero(), |acc, l| acc + a[(i, l)] * c[(l, j)]);
+            c[(i, j)] = (c[(i, j)] - s) / (a[(i, i)] - b[(j, j)]);
+        }
+    }
+    c
+}
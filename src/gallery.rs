@@ -0,0 +1,103 @@
+//! Classic test matrices
+//!
+//! These are the matrices of MATLAB `gallery` (and `hilb`, `pascal`, `wilkinson`) with known properties,
+//! which are hard for some algorithms, e.g. ill-conditioned or with close eigenvalues,
+//! and so are used to test them:
+//!
+//! - [hilbert](fn.hilbert.html): Hilbert matrix, notoriously ill-conditioned and positive definite
+//! - [pascal](fn.pascal.html): Pascal matrix of binomial coefficients, positive definite with determinant 1
+//! - [wilkinson](fn.wilkinson.html): Wilkinson's symmetric tridiagonal matrix with pairs of nearly equal eigenvalues
+//! - [frank](fn.frank.html): Frank matrix, upper Hessenberg with ill-conditioned eigenvalues
+//! - [lehmer](fn.lehmer.html): Lehmer matrix, positive definite with tridiagonal inverse
+//! - [kahan](fn.kahan.html): Kahan matrix, upper triangular on which QR with column pivoting fails to reveal the rank
+//!
+//! - N. J. Higham, Accuracy and Stability of Numerical Algorithms, 2nd ed., SIAM (2002), Chapter 28
+//!
+//! ```
+//! use ndarray::*;
+//! use ndarray_linalg::*;
+//!
+//! let h: Array2<f64> = gallery::hilbert(3);
+//! assert_eq!(h[(1, 2)], 1.0 / 4.0);
+//! let p: Array2<f64> = gallery::pascal(4);
+//! assert_eq!(p.row(3), arr1(&[1.0, 4.0, 10.0, 20.0]));
+//! ```
+
+use ndarray::*;
+use num_traits::Float;
+
+use crate::types::*;
+
+/// Hilbert matrix `H[(i, j)] = 1 / (i + j + 1)`
+pub fn hilbert<A: Scalar>(n: usize) -> Array2<A> {
+    Array2::from_shape_fn((n, n), |(i, j)| A::from_real(Float::recip(A::real(i + j + 1))))
+}
+
+/// Pascal matrix `P[(i, j)] = (i + j)! / (i! j!)`
+///
+/// The elements are exact in `f64` up to `n = 30`.
+pub fn pascal<A: Scalar>(n: usize) -> Array2<A> {
+    let mut p = Array2::<A>::ones((n, n));
+    for i in 1..n {
+        for j in 1..n {
+            p[(i, j)] = p[(i - 1, j)] + p[(i, j - 1)];
+        }
+    }
+    p
+}
+
+/// Wilkinson matrix `W_n^+`, the symmetric tridiagonal matrix with the diagonal `|(n - 1) / 2 - i|` and ones
+///
+/// For odd `n`, the largest eigenvalues are in pairs agreeing in many digits, e.g. 15 digits for `n = 21`.
+pub fn wilkinson<A: Scalar>(n: usize) -> Array2<A> {
+    let m = A::real(n.saturating_sub(1)) / A::real(2);
+    Array2::from_shape_fn((n, n), |(i, j)| {
+        if i == j {
+            A::from_real(Float::abs(m - A::real(i)))
+        } else if i == j + 1 || j == i + 1 {
+            A::one()
+        } else {
+            A::zero()
+        }
+    })
+}
+
+/// Frank matrix, the upper Hessenberg matrix `F[(i, j)] = n - max(i, j)` for `j + 1 >= i`
+///
+/// Its determinant is 1, and the small eigenvalues are ill-conditioned.
+pub fn frank<A: Scalar>(n: usize) -> Array2<A> {
+    Array2::from_shape_fn((n, n), |(i, j)| {
+        if j + 1 >= i {
+            A::from_real(A::real(n - ::std::cmp::max(i, j)))
+        } else {
+            A::zero()
+        }
+    })
+}
+
+/// Lehmer matrix `L[(i, j)] = (min(i, j) + 1) / (max(i, j) + 1)`
+pub fn lehmer<A: Scalar>(n: usize) -> Array2<A> {
+    Array2::from_shape_fn((n, n), |(i, j)| {
+        let (lo, hi) = if i < j { (i, j) } else { (j, i) };
+        A::from_real(A::real(lo + 1) / A::real(hi + 1))
+    })
+}
+
+/// Kahan matrix `diag(1, s, ..., s^{n-1}) (I - c U)` for `s = sin(theta)`, `c = cos(theta)`,
+/// and the strictly upper triangular matrix `U` of ones
+///
+/// QR with column pivoting does not pivot it (up to the rounding errors) although the last diagonal element
+/// is much larger than the smallest singular value, e.g. for `theta = 1.2` as MATLAB.
+pub fn kahan<A: Scalar>(n: usize, theta: A::Real) -> Array2<A> {
+    let (s, c) = (Float::sin(theta), Float::cos(theta));
+    Array2::from_shape_fn((n, n), |(i, j)| {
+        let si = Float::powi(s, i as i32);
+        if i == j {
+            A::from_real(si)
+        } else if i < j {
+            A::from_real(-c * si)
+        } else {
+            A::zero()
+        }
+    })
+}
@@ -1,12 +1,45 @@
 //! Generator functions for matrices
+//!
+//! The random generators use the thread-local RNG of the `rand` crate,
+//! and each of them has a `*_using` variant taking any `Rng` for reproducible results,
+//! e.g. of [seeded_rng](fn.seeded_rng.html):
+//!
+//! ```
+//! use ndarray::*;
+//! use ndarray_linalg::*;
+//!
+//! let a: Array2<f64> = random_using((3, 3), &mut seeded_rng(42));
+//! let b: Array2<f64> = random_using((3, 3), &mut seeded_rng(42));
+//! assert_eq!(a, b);
+//! ```
+//!
+//! The elements are drawn by `Scalar::rand` by default, and [random_with](fn.random_with.html)
+//! and [random_hpd_with](fn.random_hpd_with.html) take any distribution of `rand`,
+//! e.g. [Normal](struct.Normal.html) or [Uniform](struct.Uniform.html) for both real and complex numbers:
+//!
+//! ```
+//! use ndarray::*;
+//! use ndarray_linalg::*;
+//!
+//! let a: Array2<c64> = random_with((3, 3), &Normal::new(c64::new(1.0, 0.0), 0.1));
+//! let b: Array1<f64> = random_with(5, &Uniform::new(-1.0, 1.0));
+//! assert!(b.iter().all(|&x| x >= -1.0 && x < 1.0));
+//! ```
 
 use ndarray::*;
+use num_traits::{Float, One, Zero};
+use rand::distributions::{Distribution, Gamma, Standard, StandardNormal};
 use rand::prelude::*;
 
+use super::banded::BandedMatrix;
 use super::convert::*;
 use super::error::*;
 use super::qr::*;
+use super::randomized::gaussian;
+use super::triangular::IntoTriangular;
+use super::tridiagonal::Tridiagonal;
 use super::types::*;
+use super::UPLO;
 
 /// Hermite conjugate matrix
 pub fn conjugate<A, Si, So>(a: &ArrayBase<Si, Ix2>) -> ArrayBase<So, Ix2>
@@ -22,6 +55,11 @@ where
     a
 }
 
+/// RNG initialized by `seed`, which generates the same sequence for the same seed
+pub fn seeded_rng(seed: u64) -> StdRng {
+    StdRng::seed_from_u64(seed)
+}
+
 /// Generate random array
 pub fn random<A, S, Sh, D>(sh: Sh) -> ArrayBase<S, D>
 where
@@ -30,22 +68,297 @@ where
     D: Dimension,
     Sh: ShapeBuilder<Dim = D>,
 {
-    let mut rng = thread_rng();
-    ArrayBase::from_shape_fn(sh, |_| A::rand(&mut rng))
+    random_using(sh, &mut thread_rng())
+}
+
+/// Generate random array using `rng`
+pub fn random_using<A, S, Sh, D, R>(sh: Sh, rng: &mut R) -> ArrayBase<S, D>
+where
+    A: Scalar,
+    S: DataOwned<Elem = A>,
+    D: Dimension,
+    Sh: ShapeBuilder<Dim = D>,
+    R: Rng,
+{
+    ArrayBase::from_shape_fn(sh, |_| A::rand(&mut *rng))
+}
+
+/// Generate random array whose elements are drawn from `dist`
+pub fn random_with<A, S, Sh, D, Ds>(sh: Sh, dist: &Ds) -> ArrayBase<S, D>
+where
+    A: Scalar,
+    S: DataOwned<Elem = A>,
+    D: Dimension,
+    Sh: ShapeBuilder<Dim = D>,
+    Ds: Distribution<A>,
+{
+    random_with_using(sh, dist, &mut thread_rng())
+}
+
+/// Generate random array whose elements are drawn from `dist` using `rng`
+pub fn random_with_using<A, S, Sh, D, Ds, R>(sh: Sh, dist: &Ds, rng: &mut R) -> ArrayBase<S, D>
+where
+    A: Scalar,
+    S: DataOwned<Elem = A>,
+    D: Dimension,
+    Sh: ShapeBuilder<Dim = D>,
+    Ds: Distribution<A>,
+    R: Rng,
+{
+    ArrayBase::from_shape_fn(sh, |_| dist.sample(rng))
 }
 
-/// Generate random unitary matrix using QR decomposition
+/// Normal distribution with the mean `mean` and the standard deviation `std`
 ///
-/// Be sure that this it **NOT** a uniform distribution. Use it only for test purpose.
+/// The real and imaginary parts of complex numbers are independent with the variance `std^2 / 2`,
+/// so that `E[|x - mean|^2] = std^2` in both cases.
+#[derive(Debug, Clone, Copy)]
+pub struct Normal<A: Scalar> {
+    mean: A,
+    std: A::Real,
+}
+
+impl<A: Scalar> Normal<A> {
+    pub fn new(mean: A, std: A::Real) -> Self {
+        Normal { mean, std }
+    }
+
+    /// Normal distribution with zero mean and unit variance
+    pub fn standard() -> Self {
+        Self::new(A::zero(), A::Real::one())
+    }
+}
+
+impl<A: Scalar> Distribution<A> for Normal<A> {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> A {
+        let mut normal = || A::real(Distribution::<f64>::sample(&StandardNormal, &mut *rng));
+        match imaginary_unit::<A>() {
+            Some(i) => {
+                let std = self.std * Float::sqrt(A::real(0.5));
+                self.mean + A::from_real(normal() * std) + i.mul_real(normal() * std)
+            }
+            None => self.mean + A::from_real(normal() * self.std),
+        }
+    }
+}
+
+/// Uniform distribution on `[low, high)`, which is that of the real and imaginary parts of complex numbers
+#[derive(Debug, Clone, Copy)]
+pub struct Uniform<A: Scalar> {
+    low: A::Real,
+    high: A::Real,
+}
+
+impl<A: Scalar> Uniform<A> {
+    /// **Panics** unless `low < high`.
+    pub fn new(low: A::Real, high: A::Real) -> Self {
+        assert!(low < high, "Uniform::new requires low < high");
+        Uniform { low, high }
+    }
+}
+
+impl<A: Scalar> Distribution<A> for Uniform<A> {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> A {
+        let mut uniform = || {
+            let u: f64 = Standard.sample(&mut *rng);
+            self.low + (self.high - self.low) * A::real(u)
+        };
+        match imaginary_unit::<A>() {
+            Some(i) => A::from_real(uniform()) + i.mul_real(uniform()),
+            None => A::from_real(uniform()),
+        }
+    }
+}
+
+/// Imaginary unit of complex numbers, or `None` for real numbers
+fn imaginary_unit<A: Scalar>() -> Option<A> {
+    // `sqrt(-1)` is NaN without imaginary part for real numbers
+    let i = A::from_real(-A::Real::one()).sqrt();
+    if i.im().is_zero() {
+        None
+    } else {
+        Some(i)
+    }
+}
+
+/// Generate random unitary matrix distributed uniformly in the Haar measure
+///
+/// This is the QR decomposition `G = Q R` of a Ginibre matrix `G`, i.e. of independent standard normal elements,
+/// where the phases of the columns of `Q` are fixed so that the diagonal of `R` is positive.
+///
+/// - F. Mezzadri, How to generate random matrices from the classical compact groups, Notices AMS 54 (2007)
 pub fn random_unitary<A>(n: usize) -> Array2<A>
 where
     A: Scalar + Lapack,
 {
-    let a: Array2<A> = random((n, n));
-    let (q, _r) = a.qr_into().unwrap();
+    random_unitary_using(n, &mut thread_rng())
+}
+
+/// Generate random unitary matrix distributed uniformly in the Haar measure using `rng`
+pub fn random_unitary_using<A, R>(n: usize, rng: &mut R) -> Array2<A>
+where
+    A: Scalar + Lapack,
+    R: Rng,
+{
+    let g: Array2<A> = gaussian((n, n), rng);
+    let (mut q, r) = g.qr_into().unwrap();
+    for (j, mut col) in q.axis_iter_mut(Axis(1)).enumerate() {
+        let d = r[(j, j)];
+        if !d.is_zero() {
+            let phase = d.div_real(d.abs());
+            col.mapv_inplace(|x| x * phase);
+        }
+    }
     q
 }
 
+/// Generate random real orthogonal matrix distributed uniformly in the Haar measure
+///
+/// See [random_unitary](fn.random_unitary.html) for the algorithm.
+pub fn random_orthogonal<A>(n: usize) -> Array2<A>
+where
+    A: Scalar<Real = A> + Lapack,
+{
+    random_unitary(n)
+}
+
+/// Generate random real orthogonal matrix distributed uniformly in the Haar measure using `rng`
+pub fn random_orthogonal_using<A, R>(n: usize, rng: &mut R) -> Array2<A>
+where
+    A: Scalar<Real = A> + Lapack,
+    R: Rng,
+{
+    random_unitary_using(n, rng)
+}
+
+/// Generate random square matrix `U diag(s) V^H` whose singular values are `s`
+///
+/// `U` and `V` are independent Haar-distributed unitary matrices.
+///
+/// **Panics** if an element of `s` is negative.
+pub fn random_with_singular_values<A>(s: &[A::Real]) -> Array2<A>
+where
+    A: Scalar + Lapack,
+{
+    random_with_singular_values_using(s, &mut thread_rng())
+}
+
+/// Generate random square matrix whose singular values are `s` using `rng`
+pub fn random_with_singular_values_using<A, R>(s: &[A::Real], rng: &mut R) -> Array2<A>
+where
+    A: Scalar + Lapack,
+    R: Rng,
+{
+    let n = s.len();
+    with_singular_values(n, n, s, rng)
+}
+
+/// Generate random `m x n` matrix whose condition number in the 2-norm is `kappa`
+///
+/// The singular values are distributed geometrically from `1` to `1 / kappa`
+/// as the mode 3 of LAPACK `*latms`, and the singular vectors are Haar-distributed.
+///
+/// **Panics** if `kappa < 1`.
+pub fn random_with_cond<A>(m: usize, n: usize, kappa: A::Real) -> Array2<A>
+where
+    A: Scalar + Lapack,
+{
+    random_with_cond_using(m, n, kappa, &mut thread_rng())
+}
+
+/// Generate random `m x n` matrix whose condition number in the 2-norm is `kappa` using `rng`
+pub fn random_with_cond_using<A, R>(m: usize, n: usize, kappa: A::Real, rng: &mut R) -> Array2<A>
+where
+    A: Scalar + Lapack,
+    R: Rng,
+{
+    assert!(kappa >= A::Real::one(), "Condition number must be at least 1");
+    let k = ::std::cmp::min(m, n);
+    let s: Vec<A::Real> = (0..k)
+        .map(|i| {
+            if k == 1 {
+                A::Real::one()
+            } else {
+                Float::powf(kappa, -A::real(i) / A::real(k - 1))
+            }
+        })
+        .collect();
+    with_singular_values(m, n, &s, rng)
+}
+
+/// `U_k diag(s) V_k^H` for the leading `k = min(m, n)` columns of Haar-distributed `U` and `V`
+fn with_singular_values<A, R>(m: usize, n: usize, s: &[A::Real], rng: &mut R) -> Array2<A>
+where
+    A: Scalar + Lapack,
+    R: Rng,
+{
+    assert!(
+        s.iter().all(|&x| x >= A::Real::zero()),
+        "Singular values must be non-negative"
+    );
+    let k = s.len();
+    let u: Array2<A> = random_unitary_using(m, rng);
+    let v: Array2<A> = random_unitary_using(n, rng);
+    let mut us = u.slice(s![.., ..k]).to_owned();
+    for (mut col, &x) in us.axis_iter_mut(Axis(1)).zip(s) {
+        col.mapv_inplace(|y| y.mul_real(x));
+    }
+    let vh: Array2<A> = conjugate(&v.slice(s![.., ..k]));
+    us.dot(&vh)
+}
+
+/// Generate random correlation matrix, i.e. real symmetric positive semi-definite matrix with unit diagonal
+///
+/// The matrix is distributed uniformly over the correlation matrices by the onion method,
+/// which extends the Cholesky factor of a `k x k` correlation matrix by a row on a random point in the unit ball.
+///
+/// - D. Lewandowski, D. Kurowicka and H. Joe, Generating random correlation matrices based on vines
+///   and extended onion method, J. Multivariate Anal. 100 (2009)
+pub fn random_correlation<A>(n: usize) -> Array2<A>
+where
+    A: Scalar,
+{
+    random_correlation_using(n, &mut thread_rng())
+}
+
+/// Generate random correlation matrix using `rng`
+pub fn random_correlation_using<A, R>(n: usize, rng: &mut R) -> Array2<A>
+where
+    A: Scalar,
+    R: Rng,
+{
+    // Lower triangular Cholesky factor, whose rows are unit vectors
+    let mut l = Array2::<f64>::zeros((n, n));
+    if n > 0 {
+        l[(0, 0)] = 1.0;
+    }
+    if n > 1 {
+        let mut beta = 1.0 + (n - 2) as f64 / 2.0;
+        let r = 2.0 * beta_sample(beta, beta, rng) - 1.0;
+        l[(1, 0)] = r;
+        l[(1, 1)] = (1.0 - r * r).sqrt();
+        for k in 2..n {
+            beta -= 0.5;
+            let y = beta_sample(k as f64 / 2.0, beta, rng);
+            // Uniform direction on the unit sphere in `k` dimensions
+            let u: Array1<f64> = Array1::from_shape_fn(k, |_| rng.sample(StandardNormal));
+            let w = u.mapv(|x| x * y.sqrt()) / u.dot(&u).sqrt();
+            l.slice_mut(s![k, ..k]).assign(&w);
+            l[(k, k)] = (1.0 - y).sqrt();
+        }
+    }
+    let mut c = l.dot(&l.t());
+    c.diag_mut().fill(1.0);
+    c.mapv(|x| A::from_real(A::real(x)))
+}
+
+/// Sample of the beta distribution `Beta(a, b)` from two gamma distributions
+fn beta_sample<R: Rng>(a: f64, b: f64, rng: &mut R) -> f64 {
+    let x = rng.sample(Gamma::new(a, 1.0));
+    let y = rng.sample(Gamma::new(b, 1.0));
+    x / (x + y)
+}
+
 /// Generate random regular matrix
 ///
 /// Be sure that this it **NOT** a uniform distribution. Use it only for test purpose.
@@ -53,7 +366,18 @@ pub fn random_regular<A>(n: usize) -> Array2<A>
 where
     A: Scalar + Lapack,
 {
-    let a: Array2<A> = random((n, n));
+    random_regular_using(n, &mut thread_rng())
+}
+
+/// Generate random regular matrix using `rng`
+///
+/// Be sure that this it **NOT** a uniform distribution. Use it only for test purpose.
+pub fn random_regular_using<A, R>(n: usize, rng: &mut R) -> Array2<A>
+where
+    A: Scalar + Lapack,
+    R: Rng,
+{
+    let a: Array2<A> = random_using((n, n), rng);
     let (q, mut r) = a.qr_into().unwrap();
     for i in 0..n {
         r[(i, i)] = A::one() + A::from_real(r[(i, i)].abs());
@@ -61,13 +385,117 @@ where
     q.dot(&r)
 }
 
+/// Random band matrix with `kl` sub-diagonals and `ku` super-diagonals
+pub fn random_banded<A>(n: usize, kl: usize, ku: usize) -> BandedMatrix<A>
+where
+    A: Scalar,
+{
+    random_banded_using(n, kl, ku, &mut thread_rng())
+}
+
+/// Random band matrix with `kl` sub-diagonals and `ku` super-diagonals using `rng`
+pub fn random_banded_using<A, R>(n: usize, kl: usize, ku: usize, rng: &mut R) -> BandedMatrix<A>
+where
+    A: Scalar,
+    R: Rng,
+{
+    let mut a = BandedMatrix::zeros(n, kl, ku);
+    for j in 0..n {
+        let start = if j > ku { j - ku } else { 0 };
+        for i in start..::std::cmp::min(n, j + kl + 1) {
+            *a.get_mut(i, j).unwrap() = A::rand(&mut *rng);
+        }
+    }
+    a
+}
+
+/// Random tridiagonal matrix
+pub fn random_tridiagonal<A>(n: usize) -> Tridiagonal<A>
+where
+    A: Scalar,
+{
+    random_tridiagonal_using(n, &mut thread_rng())
+}
+
+/// Random tridiagonal matrix using `rng`
+pub fn random_tridiagonal_using<A, R>(n: usize, rng: &mut R) -> Tridiagonal<A>
+where
+    A: Scalar,
+    R: Rng,
+{
+    let m = n.saturating_sub(1);
+    let dl = random_using(m, rng);
+    let d = random_using(n, rng);
+    let du = random_using(m, rng);
+    Tridiagonal::new(dl, d, du).unwrap()
+}
+
+/// Random upper or lower triangular matrix
+///
+/// The absolute values of the diagonal elements are larger than 1 (thus non-singular)
+pub fn random_triangular<A>(n: usize, uplo: UPLO) -> Array2<A>
+where
+    A: Scalar,
+{
+    random_triangular_using(n, uplo, &mut thread_rng())
+}
+
+/// Random upper or lower triangular matrix using `rng`
+pub fn random_triangular_using<A, R>(n: usize, uplo: UPLO, rng: &mut R) -> Array2<A>
+where
+    A: Scalar,
+    R: Rng,
+{
+    let a: Array2<A> = random_using((n, n), rng);
+    let mut a = a.into_triangular(uplo);
+    for i in 0..n {
+        a[(i, i)] = A::one() + A::from_real(a[(i, i)].abs());
+    }
+    a
+}
+
+/// Random strictly diagonally dominant matrix
+///
+/// The diagonal element of each row is larger than the sum of the absolute values of the others by 1,
+/// so that the matrix is non-singular, and the LU factorization needs no pivoting.
+pub fn random_diagonally_dominant<A>(n: usize) -> Array2<A>
+where
+    A: Scalar,
+{
+    random_diagonally_dominant_using(n, &mut thread_rng())
+}
+
+/// Random strictly diagonally dominant matrix using `rng`
+pub fn random_diagonally_dominant_using<A, R>(n: usize, rng: &mut R) -> Array2<A>
+where
+    A: Scalar,
+    R: Rng,
+{
+    let mut a: Array2<A> = random_using((n, n), rng);
+    for (i, mut row) in a.outer_iter_mut().enumerate() {
+        let off_diag = row.iter().map(|x| x.abs()).sum::<A::Real>() - row[i].abs();
+        row[i] = A::from_real(off_diag + A::Real::one());
+    }
+    a
+}
+
 /// Random Hermite matrix
 pub fn random_hermite<A, S>(n: usize) -> ArrayBase<S, Ix2>
 where
     A: Scalar,
     S: DataOwned<Elem = A> + DataMut,
 {
-    let mut a: ArrayBase<S, Ix2> = random((n, n));
+    random_hermite_using(n, &mut thread_rng())
+}
+
+/// Random Hermite matrix using `rng`
+pub fn random_hermite_using<A, S, R>(n: usize, rng: &mut R) -> ArrayBase<S, Ix2>
+where
+    A: Scalar,
+    S: DataOwned<Elem = A> + DataMut,
+    R: Rng,
+{
+    let mut a: ArrayBase<S, Ix2> = random_using((n, n), rng);
     for i in 0..n {
         a[(i, i)] = a[(i, i)] + a[(i, i)].conj();
         for j in (i + 1)..n {
@@ -77,6 +505,39 @@ where
     a
 }
 
+/// Random Hermite matrix `Q diag(eigs) Q^H` whose eigenvalues are `eigs`
+///
+/// `Q` is a Haar-distributed unitary matrix, and the result is exactly Hermitian.
+pub fn random_hermitian_with_eigs<A>(eigs: &[A::Real]) -> Array2<A>
+where
+    A: Scalar + Lapack,
+{
+    random_hermitian_with_eigs_using(eigs, &mut thread_rng())
+}
+
+/// Random Hermite matrix whose eigenvalues are `eigs` using `rng`
+pub fn random_hermitian_with_eigs_using<A, R>(eigs: &[A::Real], rng: &mut R) -> Array2<A>
+where
+    A: Scalar + Lapack,
+    R: Rng,
+{
+    let n = eigs.len();
+    let q: Array2<A> = random_unitary_using(n, rng);
+    let mut ql = q.clone();
+    for (mut col, &e) in ql.axis_iter_mut(Axis(1)).zip(eigs) {
+        col.mapv_inplace(|x| x.mul_real(e));
+    }
+    let qh: Array2<A> = conjugate(&q);
+    let mut a = ql.dot(&qh);
+    for i in 0..n {
+        a[(i, i)] = A::from_real(a[(i, i)].re());
+        for j in (i + 1)..n {
+            a[(j, i)] = a[(i, j)].conj();
+        }
+    }
+    a
+}
+
 /// Random Hermite Positive-definite matrix
 ///
 /// - Eigenvalue of matrix must be larger than 1 (thus non-singular)
@@ -86,9 +547,53 @@ where
     A: Scalar,
     S: DataOwned<Elem = A> + DataMut,
 {
-    let a: Array2<A> = random((n, n));
-    let ah: Array2<A> = conjugate(&a);
-    ArrayBase::eye(n) + &ah.dot(&a)
+    random_hpd_using(n, &mut thread_rng())
+}
+
+/// Random Hermite Positive-definite matrix using `rng`
+///
+/// - Eigenvalue of matrix must be larger than 1 (thus non-singular)
+///
+pub fn random_hpd_using<A, S, R>(n: usize, rng: &mut R) -> ArrayBase<S, Ix2>
+where
+    A: Scalar,
+    S: DataOwned<Elem = A> + DataMut,
+    R: Rng,
+{
+    let a: Array2<A> = random_using((n, n), rng);
+    hpd_from(&a)
+}
+
+/// Random Hermite Positive-definite matrix `I + A^H A` for `A` whose elements are drawn from `dist`
+pub fn random_hpd_with<A, S, Ds>(n: usize, dist: &Ds) -> ArrayBase<S, Ix2>
+where
+    A: Scalar,
+    S: DataOwned<Elem = A> + DataMut,
+    Ds: Distribution<A>,
+{
+    random_hpd_with_using(n, dist, &mut thread_rng())
+}
+
+/// Random Hermite Positive-definite matrix `I + A^H A` for `A` whose elements are drawn from `dist` using `rng`
+pub fn random_hpd_with_using<A, S, Ds, R>(n: usize, dist: &Ds, rng: &mut R) -> ArrayBase<S, Ix2>
+where
+    A: Scalar,
+    S: DataOwned<Elem = A> + DataMut,
+    Ds: Distribution<A>,
+    R: Rng,
+{
+    let a: Array2<A> = random_with_using((n, n), dist, rng);
+    hpd_from(&a)
+}
+
+/// `I + A^H A`, whose eigenvalues are larger than 1
+fn hpd_from<A, S>(a: &Array2<A>) -> ArrayBase<S, Ix2>
+where
+    A: Scalar,
+    S: DataOwned<Elem = A> + DataMut,
+{
+    let ah: Array2<A> = conjugate(a);
+    ArrayBase::eye(a.rows()) + &ah.dot(a)
 }
 
 /// construct matrix from diag
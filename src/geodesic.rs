@@ -0,0 +1,94 @@
+//! Geometric mean and geodesics of Hermitian positive definite matrices
+//!
+//! With the affine-invariant Riemannian metric on Hermitian (or real symmetric) positive definite matrices,
+//! the geodesic from `A` to `B` is
+//!
+//! ```text
+//! A #_t B = A^{1/2} (A^{-1/2} B A^{-1/2})^t A^{1/2}
+//! ```
+//!
+//! and the geometric mean `A # B` is its midpoint `t = 1/2`.
+//! They are used for interpolation and averaging of covariance matrices and diffusion tensors,
+//! since they stay positive definite and are invariant under congruence `A -> X A X^H`.
+//!
+//! Instead of the square roots, the Cholesky factorization `A = L L^H` is used as
+//! `A #_t B = L (L^{-1} B L^{-H})^t L^H`, where the power of the Hermitian matrix is computed by its eigendecomposition.
+//!
+//! - R. Bhatia, Positive Definite Matrices, Princeton University Press (2007), Chapter 4 and 6
+//!
+//! # Example
+//!
+//! ```
+//! #[macro_use]
+//! extern crate ndarray;
+//! extern crate ndarray_linalg;
+//!
+//! use ndarray::prelude::*;
+//! use ndarray_linalg::*;
+//! # fn main() {
+//!
+//! let a: Array2<f64> = array![[1., 0.], [0., 4.]];
+//! let b: Array2<f64> = array![[4., 0.], [0., 1.]];
+//! let g = spd_geometric_mean(&a, &b).unwrap();
+//! assert!(g.all_close(&array![[2., 0.], [0., 2.]], 1e-12));
+//! # }
+//! ```
+
+use ndarray::*;
+use num_traits::Float;
+
+use crate::cholesky::*;
+use crate::eigh::*;
+use crate::error::*;
+use crate::generate::conjugate;
+use crate::layout::*;
+use crate::triangular::*;
+use crate::types::*;
+use crate::UPLO;
+
+/// Compute the geometric mean `A # B` of Hermitian positive definite matrices
+///
+/// This is the unique Hermitian positive definite solution `G` of `G A^{-1} G = B`.
+/// Only the lower triangular parts of `a` and `b` are used.
+pub fn spd_geometric_mean<A, Sa, Sb>(a: &ArrayBase<Sa, Ix2>, b: &ArrayBase<Sb, Ix2>) -> Result<Array2<A>>
+where
+    A: Scalar + Lapack,
+    Sa: Data<Elem = A>,
+    Sb: Data<Elem = A>,
+{
+    spd_geodesic(a, b, A::real(0.5))
+}
+
+/// Compute the point `A #_t B` on the geodesic between Hermitian positive definite matrices
+///
+/// `t = 0` gives `A` and `t = 1` gives `B`. `t` outside of `[0, 1]` extrapolates the geodesic.
+/// Only the lower triangular parts of `a` and `b` are used.
+pub fn spd_geodesic<A, Sa, Sb>(a: &ArrayBase<Sa, Ix2>, b: &ArrayBase<Sb, Ix2>, t: A::Real) -> Result<Array2<A>>
+where
+    A: Scalar + Lapack,
+    Sa: Data<Elem = A>,
+    Sb: Data<Elem = A>,
+{
+    a.square_layout()?;
+    if a.dim() != b.dim() {
+        return Err(ShapeError::from_kind(ErrorKind::IncompatibleShape).into());
+    }
+    let l = a.cholesky(UPLO::Lower)?;
+    let mut b = b.to_owned();
+    let n = b.rows();
+    // Fill the upper triangular part from the lower one
+    for i in 0..n {
+        for j in i + 1..n {
+            b[(i, j)] = b[(j, i)].conj();
+        }
+    }
+    // C = L^{-1} B L^{-H} = L^{-1} (L^{-1} B)^H since B is Hermitian
+    let x = l.solve_triangular(UPLO::Lower, Diag::NonUnit, &b)?;
+    let xh: Array2<A> = conjugate(&x);
+    let c = l.solve_triangular(UPLO::Lower, Diag::NonUnit, &xh)?;
+    let (e, v) = c.eigh(UPLO::Lower)?;
+    let w = l.dot(&v);
+    let wh: Array2<A> = conjugate(&w);
+    let wt = Array2::from_shape_fn(w.dim(), |(i, j)| w[(i, j)].mul_real(Float::powf(e[j], t)));
+    Ok(wt.dot(&wh))
+}
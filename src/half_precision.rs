@@ -0,0 +1,154 @@
+//! Half-precision matrices
+//!
+//! With the `half` feature, the high-level traits [Inverse](../solve/trait.Inverse.html),
+//! [Cholesky](../cholesky/trait.Cholesky.html), [Eigh](../eigh/trait.Eigh.html), [SVD](../svd/trait.SVD.html),
+//! [Norm](../norm/trait.Norm.html) and [OperationNorm](../opnorm/trait.OperationNorm.html)
+//! are implemented for the arrays and views of `half::f16` and `half::bf16`.
+//! LAPACK has no half-precision routines, so that the matrix is promoted to `f32`,
+//! computed in single precision, and the results are demoted to the element type.
+//! The accuracy is thus that of the half-precision storage,
+//! and the results out of its range (e.g. the inverse of a nearly singular matrix) become infinite.
+//!
+//! ```ignore
+//! use half::f16;
+//! use ndarray::*;
+//! use ndarray_linalg::*;
+//!
+//! let a = arr2(&[[2.0, 1.0], [1.0, 3.0]]).mapv(f16::from_f32);
+//! let (e, _) = a.eigh(UPLO::Lower).unwrap();
+//! assert_eq!(e, arr1(&[1.381966, 3.618034]).mapv(f16::from_f32));
+//! ```
+
+use half::{bf16, f16};
+use ndarray::*;
+
+use crate::cholesky::Cholesky;
+use crate::eigh::Eigh;
+use crate::error::*;
+use crate::norm::Norm;
+use crate::opnorm::{NormType, OperationNorm};
+use crate::solve::Inverse;
+use crate::svd::SVD;
+use crate::UPLO;
+
+/// Half-precision floating point numbers which are computed in `f32`
+pub trait HalfFloat: Copy {
+    fn to_f32(self) -> f32;
+    fn from_f32(x: f32) -> Self;
+}
+
+impl HalfFloat for f16 {
+    fn to_f32(self) -> f32 {
+        f16::to_f32(self)
+    }
+    fn from_f32(x: f32) -> Self {
+        f16::from_f32(x)
+    }
+}
+
+impl HalfFloat for bf16 {
+    fn to_f32(self) -> f32 {
+        bf16::to_f32(self)
+    }
+    fn from_f32(x: f32) -> Self {
+        bf16::from_f32(x)
+    }
+}
+
+/// Convert a half-precision array to `f32`, which is exact
+pub fn promote<H, S, D>(a: &ArrayBase<S, D>) -> Array<f32, D>
+where
+    H: HalfFloat,
+    S: Data<Elem = H>,
+    D: Dimension,
+{
+    a.mapv(H::to_f32)
+}
+
+/// Round an `f32` array to the nearest half-precision numbers
+pub fn demote<H, S, D>(a: &ArrayBase<S, D>) -> Array<H, D>
+where
+    H: HalfFloat,
+    S: Data<Elem = f32>,
+    D: Dimension,
+{
+    a.mapv(H::from_f32)
+}
+
+// The traits are implemented for the concrete arrays and views,
+// since the implementations for `ArrayBase<S, _>` with `S: Data<Elem = f16>` would conflict with the generic ones.
+macro_rules! impl_half {
+    ([$($gen:tt)*] $mat:ty, $arr:ty, $half:ty) => {
+        impl<$($gen)*> Inverse for $mat {
+            type Output = Array2<$half>;
+
+            fn inv(&self) -> Result<Self::Output> {
+                Ok(demote(&promote(self).inv()?))
+            }
+        }
+
+        impl<$($gen)*> Cholesky for $mat {
+            type Output = Array2<$half>;
+
+            fn cholesky(&self, uplo: UPLO) -> Result<Self::Output> {
+                Ok(demote(&promote(self).cholesky(uplo)?))
+            }
+        }
+
+        impl<$($gen)*> Eigh for $mat {
+            type EigVal = Array1<$half>;
+            type EigVec = Array2<$half>;
+
+            fn eigh(&self, uplo: UPLO) -> Result<(Self::EigVal, Self::EigVec)> {
+                let (e, v) = promote(self).eigh(uplo)?;
+                Ok((demote(&e), demote(&v)))
+            }
+        }
+
+        impl<$($gen)*> SVD for $mat {
+            type U = Array2<$half>;
+            type VT = Array2<$half>;
+            type Sigma = Array1<$half>;
+
+            fn svd(&self, calc_u: bool, calc_vt: bool) -> Result<(Option<Self::U>, Self::Sigma, Option<Self::VT>)> {
+                let (u, s, vt) = promote(self).svd(calc_u, calc_vt)?;
+                Ok((u.map(|u| demote(&u)), demote(&s), vt.map(|vt| demote(&vt))))
+            }
+        }
+
+        impl<$($gen)*> OperationNorm for $mat {
+            type Output = $half;
+
+            fn opnorm(&self, t: NormType) -> Result<Self::Output> {
+                Ok(<$half>::from_f32(promote(self).opnorm(t)?))
+            }
+
+            fn opnorm_two(&self) -> Result<Self::Output> {
+                Ok(<$half>::from_f32(promote(self).opnorm_two()?))
+            }
+        }
+
+        impl<$($gen)* D: Dimension> Norm for $arr {
+            type Output = $half;
+
+            fn norm_l1(&self) -> Self::Output {
+                <$half>::from_f32(promote(self).norm_l1())
+            }
+            fn norm_l2(&self) -> Self::Output {
+                <$half>::from_f32(promote(self).norm_l2())
+            }
+            fn norm_l2_scaled(&self) -> (Self::Output, Self::Output) {
+                let (scale, sumsq) = promote(self).norm_l2_scaled();
+                (<$half>::from_f32(scale), <$half>::from_f32(sumsq))
+            }
+            fn norm_max(&self) -> Self::Output {
+                <$half>::from_f32(promote(self).norm_max())
+            }
+        }
+    };
+} // impl_half!
+
+impl_half!([] Array2<f16>, Array<f16, D>, f16);
+impl_half!(['a,] ArrayView2<'a, f16>, ArrayView<'a, f16, D>, f16);
+impl_half!([] Array2<bf16>, Array<bf16, D>, bf16);
+impl_half!(['a,] ArrayView2<'a, bf16>, ArrayView<'a, bf16, D>, bf16);
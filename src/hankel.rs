@@ -0,0 +1,214 @@
+//! Hankel matrices
+//!
+//! [Hankel](struct.Hankel.html) stores an `m x n` matrix whose elements are constant along each anti-diagonal,
+//! `H[(i, j)] = h_{i + j}`, by the sequence `h_0, ..., h_{m + n - 2}`.
+//! The matrix-vector product is computed as a cyclic convolution by the [FFT](../fft/index.html)
+//! in `O((m + n) log(m + n))` time.
+//! Reversing the order of the rows turns a square Hankel matrix into a [Toeplitz](../toeplitz/index.html) matrix,
+//! by which linear systems are solved by the Levinson recursion.
+//!
+//! The Hankel matrix of the impulse response (Markov parameters) `h_k = C A^k B` of a linear system
+//! has the rank of its minimal realization, which is identified by the SVD in the Ho-Kalman algorithm
+//! and the eigensystem realization algorithm (ERA).
+//!
+//! # Example
+//!
+//! ```
+//! #[macro_use]
+//! extern crate ndarray;
+//! extern crate ndarray_linalg;
+//!
+//! use ndarray::prelude::*;
+//! use ndarray_linalg::*;
+//! # fn main() {
+//!
+//! // Impulse response of a system of order 2, h_k = 0.5^k + (-0.8)^k
+//! let h = Array1::from_shape_fn(9, |k| 0.5f64.powi(k as i32) + (-0.8f64).powi(k as i32));
+//! let hankel = Hankel::from_sequence(h, 5).unwrap();
+//! let (_, s, _) = hankel.to_dense().svd(false, false).unwrap();
+//! assert!(s[2] < 1e-12 * s[0]);
+//! let x = array![1., 2., 3., 4., 5.];
+//! assert!(hankel.dot(&x).all_close(&hankel.to_dense().dot(&x), 1e-12));
+//! # }
+//! ```
+
+use ndarray::*;
+
+use crate::error::*;
+use crate::fft::*;
+use crate::operator::{AdjointOperator, LinearOperator};
+use crate::solve::*;
+use crate::toeplitz::*;
+use crate::types::*;
+
+/// Hankel matrix given by the sequence along its anti-diagonals
+#[derive(Debug, Clone)]
+pub struct Hankel<A> {
+    /// `h_0, ..., h_{m + n - 2}`
+    h: Array1<A>,
+    rows: usize,
+    cols: usize,
+}
+
+impl<A: Scalar> Hankel<A> {
+    /// Create from the first column and the last row, where the corner is taken from the column
+    pub fn new(c: Array1<A>, r: Array1<A>) -> Result<Self> {
+        if c.is_empty() || r.is_empty() {
+            return Err(ShapeError::from_kind(ErrorKind::IncompatibleShape).into());
+        }
+        let (rows, cols) = (c.len(), r.len());
+        let h = c.iter().chain(r.iter().skip(1)).cloned().collect();
+        Ok(Hankel { h, rows, cols })
+    }
+
+    /// Create an `rows x (h.len() - rows + 1)` matrix from the sequence `h`
+    pub fn from_sequence(h: Array1<A>, rows: usize) -> Result<Self> {
+        if rows == 0 || rows > h.len() {
+            return Err(ShapeError::from_kind(ErrorKind::IncompatibleShape).into());
+        }
+        let cols = h.len() - rows + 1;
+        Ok(Hankel { h, rows, cols })
+    }
+
+    /// Hankel matrix whose rows are those of the Toeplitz matrix `t` in the reversed order
+    pub fn from_toeplitz(t: &Toeplitz<A>) -> Self {
+        let n = t.size();
+        let c: Array1<A> = t.column().iter().rev().cloned().collect();
+        let mut r = t.row();
+        if n > 0 {
+            r[0] = c[n - 1];
+        }
+        let h = c.iter().chain(r.iter().skip(1)).cloned().collect();
+        Hankel { h, rows: n, cols: n }
+    }
+
+    /// Toeplitz matrix whose rows are those of the square Hankel matrix in the reversed order
+    pub fn flip(&self) -> Result<Toeplitz<A>> {
+        if self.rows != self.cols {
+            return Err(LinalgError::NotSquare {
+                rows: self.rows as i32,
+                cols: self.cols as i32,
+            });
+        }
+        let m = self.rows;
+        let c = Array1::from_shape_fn(m, |k| self.h[m - 1 - k]);
+        let r = Array1::from_shape_fn(m, |k| self.h[m - 1 + k]);
+        Toeplitz::new(c, r)
+    }
+
+    /// Sequence `h_0, ..., h_{m + n - 2}` along the anti-diagonals
+    pub fn sequence(&self) -> ArrayView1<A> {
+        self.h.view()
+    }
+
+    /// Shape `(m, n)` of the matrix
+    pub fn dim(&self) -> (usize, usize) {
+        (self.rows, self.cols)
+    }
+
+    /// `H[(i, j)]`
+    pub fn get(&self, i: usize, j: usize) -> A {
+        assert!(i < self.rows && j < self.cols);
+        self.h[i + j]
+    }
+
+    /// Convert to a dense matrix
+    pub fn to_dense(&self) -> Array2<A> {
+        Array2::from_shape_fn((self.rows, self.cols), |(i, j)| self.h[i + j])
+    }
+
+    /// Transposed matrix, which is the Hankel matrix of the same sequence
+    pub fn t(&self) -> Self {
+        Hankel {
+            h: self.h.clone(),
+            rows: self.cols,
+            cols: self.rows,
+        }
+    }
+
+    fn conj(&self) -> Self {
+        Hankel {
+            h: self.h.mapv(|x| x.conj()),
+            rows: self.rows,
+            cols: self.cols,
+        }
+    }
+
+    /// Solve through the Toeplitz matrix `J H`, where `J` reverses the order
+    fn solve_with<'a, S>(&self, rhs: &'a mut ArrayBase<S, Ix1>) -> Result<&'a mut ArrayBase<S, Ix1>>
+    where
+        S: DataMut<Elem = A>,
+    {
+        let t = self.flip()?;
+        if rhs.len() != self.rows {
+            return Err(ShapeError::from_kind(ErrorKind::IncompatibleShape).into());
+        }
+        let x = t.solve(&rhs.slice(s![..;-1]))?;
+        rhs.assign(&x);
+        Ok(rhs)
+    }
+}
+
+impl<A: Fourier> Hankel<A> {
+    /// Matrix-vector product `H x` by the FFT
+    ///
+    /// `(H x)_i = sum_j h_{i + j} x_j` is the `(n - 1 + i)`-th element of the convolution of `h` and the reversed `x`,
+    /// which is not affected by the wrap-around of the cyclic convolution of length `m + n - 1`.
+    pub fn dot<S>(&self, x: &ArrayBase<S, Ix1>) -> Array1<A>
+    where
+        S: Data<Elem = A>,
+    {
+        assert_eq!(x.len(), self.cols);
+        let n = self.cols;
+        let mut xr = Array1::zeros(self.h.len());
+        xr.slice_mut(s![..n]).assign(&x.slice(s![..;-1]));
+        let y = A::fft(&self.h) * A::fft(&xr);
+        A::ifft(&y).slice(s![n - 1..]).to_owned()
+    }
+}
+
+impl<A: Scalar> Solve<A> for Hankel<A> {
+    fn solve_inplace<'a, S>(&self, rhs: &'a mut ArrayBase<S, Ix1>) -> Result<&'a mut ArrayBase<S, Ix1>>
+    where
+        S: DataMut<Elem = A>,
+    {
+        self.solve_with(rhs)
+    }
+    fn solve_t_inplace<'a, S>(&self, rhs: &'a mut ArrayBase<S, Ix1>) -> Result<&'a mut ArrayBase<S, Ix1>>
+    where
+        S: DataMut<Elem = A>,
+    {
+        // Square Hankel matrices are symmetric
+        self.solve_with(rhs)
+    }
+    fn solve_h_inplace<'a, S>(&self, rhs: &'a mut ArrayBase<S, Ix1>) -> Result<&'a mut ArrayBase<S, Ix1>>
+    where
+        S: DataMut<Elem = A>,
+    {
+        self.conj().solve_with(rhs)
+    }
+}
+
+impl<A: Fourier> LinearOperator for Hankel<A> {
+    type Elem = A;
+
+    fn apply<S>(&self, a: &ArrayBase<S, Ix1>) -> Array1<S::Elem>
+    where
+        S: Data<Elem = A>,
+    {
+        self.dot(a)
+    }
+}
+
+impl<A: Fourier> AdjointOperator for Hankel<A> {
+    fn shape(&self) -> (usize, usize) {
+        self.dim()
+    }
+
+    fn apply_adjoint<S>(&self, a: &ArrayBase<S, Ix1>) -> Array1<A>
+    where
+        S: Data<Elem = A>,
+    {
+        self.t().conj().dot(a)
+    }
+}
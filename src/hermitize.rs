@@ -0,0 +1,84 @@
+//! Hermitization and symmetrization of matrices
+//!
+//! A matrix which should be Hermitian, e.g. `A^H B A` or a sample covariance, is usually not exactly so
+//! due to the rounding errors. [Hermitize](trait.Hermitize.html) replaces it by the nearest Hermitian
+//! (or complex symmetric) matrix in the Frobenius norm before [eigh](../eigh/index.html)
+//! or [cholesky](../cholesky/index.html), which only read one triangular part.
+//! It is also implemented for stacks of matrices (`Ix3` arrays whose leading axis is the batch axis).
+//!
+//! ```
+//! use ndarray::*;
+//! use ndarray_linalg::*;
+//!
+//! let mut a = arr2(&[[1.0, 2.0], [4.0, 3.0]]);
+//! a.hermitize().unwrap();
+//! assert_eq!(a, arr2(&[[1.0, 3.0], [3.0, 3.0]]));
+//! ```
+
+use ndarray::*;
+
+use crate::error::*;
+use crate::layout::*;
+use crate::types::*;
+
+/// Make square matrices Hermitian or symmetric in-place
+pub trait Hermitize {
+    /// `A <- (A + A^H) / 2`, whose diagonal is real
+    fn hermitize(&mut self) -> Result<()>;
+    /// `A <- (A + A^T) / 2`, which is the same as `hermitize` for real matrices
+    fn symmetrize(&mut self) -> Result<()>;
+}
+
+impl<A, S> Hermitize for ArrayBase<S, Ix2>
+where
+    A: Scalar,
+    S: DataMut<Elem = A>,
+{
+    fn hermitize(&mut self) -> Result<()> {
+        average_transpose(self, |x| x.conj())
+    }
+
+    fn symmetrize(&mut self) -> Result<()> {
+        average_transpose(self, |x| x)
+    }
+}
+
+impl<A, S> Hermitize for ArrayBase<S, Ix3>
+where
+    A: Scalar,
+    S: DataMut<Elem = A>,
+{
+    fn hermitize(&mut self) -> Result<()> {
+        for mut a in self.outer_iter_mut() {
+            a.hermitize()?;
+        }
+        Ok(())
+    }
+
+    fn symmetrize(&mut self) -> Result<()> {
+        for mut a in self.outer_iter_mut() {
+            a.symmetrize()?;
+        }
+        Ok(())
+    }
+}
+
+/// `A <- (A + f(A^T)) / 2` for `f` applied elementwise
+fn average_transpose<A, S, F>(a: &mut ArrayBase<S, Ix2>, f: F) -> Result<()>
+where
+    A: Scalar,
+    S: DataMut<Elem = A>,
+    F: Fn(A) -> A,
+{
+    a.ensure_square()?;
+    let half = A::real(0.5);
+    for i in 0..a.rows() {
+        a[(i, i)] = (a[(i, i)] + f(a[(i, i)])).mul_real(half);
+        for j in (i + 1)..a.cols() {
+            let x = (a[(i, j)] + f(a[(j, i)])).mul_real(half);
+            a[(i, j)] = x;
+            a[(j, i)] = f(x);
+        }
+    }
+    Ok(())
+}
@@ -0,0 +1,66 @@
+//! Hessenberg reduction
+//!
+//! A square matrix is reduced as `A = Q H Q^H` with a unitary `Q` and an upper Hessenberg `H`,
+//! i.e. `H[(i, j)] = 0` for `i > j + 1`.
+//!
+//! [Wikipedia article on Hessenberg matrix](https://en.wikipedia.org/wiki/Hessenberg_matrix)
+
+use ndarray::*;
+use num_traits::Zero;
+
+use crate::convert::*;
+use crate::error::*;
+use crate::layout::*;
+use crate::types::*;
+
+/// Hessenberg reduction of matrix reference
+pub trait Hessenberg {
+    type Q;
+    type H;
+    fn hessenberg(&self) -> Result<(Self::Q, Self::H)>;
+}
+
+/// Hessenberg reduction
+pub trait HessenbergInto: Sized {
+    type H;
+    /// Compute the Hessenberg reduction, where `Q` is stored into `self`
+    fn hessenberg_into(self) -> Result<(Self, Self::H)>;
+}
+
+impl<A, S> HessenbergInto for ArrayBase<S, Ix2>
+where
+    A: Scalar + Lapack,
+    S: DataMut<Elem = A>,
+{
+    type H = Array2<A>;
+
+    fn hessenberg_into(mut self) -> Result<(Self, Self::H)> {
+        let l = self.square_layout()?;
+        let h = unsafe { A::hessenberg(l, self.as_allocated_mut()?)? };
+        let mut h: Array2<A> = into_matrix(l, h)?;
+        // Clear the Householder reflectors stored below the subdiagonal
+        for ((i, j), val) in h.indexed_iter_mut() {
+            if i > j + 1 {
+                *val = A::zero();
+            }
+        }
+        Ok((self, h))
+    }
+}
+
+impl<A, S> Hessenberg for ArrayBase<S, Ix2>
+where
+    A: Scalar + Lapack,
+    S: Data<Elem = A>,
+{
+    type Q = Array2<A>;
+    type H = Array2<A>;
+
+    fn hessenberg(&self) -> Result<(Self::Q, Self::H)> {
+        self.ensure_square()?;
+        // Copied in Fortran layout, which LAPACKE would otherwise copy once more with `Q`
+        let n = self.rows() as i32;
+        let a: Array2<A> = clone_with_layout(MatrixLayout::F((n, n)), self);
+        a.hessenberg_into()
+    }
+}
@@ -0,0 +1,170 @@
+//! Interpolative decomposition (ID)
+//!
+//! The column ID approximates an `m x n` matrix `A` of numerical rank `k` by `k` of its own columns,
+//!
+//! ```text
+//! A ≈ A[:, J] X,
+//! ```
+//!
+//! where `J` is a set of `k` column indices and the `k x n` interpolation matrix `X`
+//! contains the identity in the columns `J`.
+//! Since the skeleton `A[:, J]` consists of actual columns, it keeps the sparsity and the physical meaning
+//! of them, which is used for model reduction and for the compression in fast direct solvers.
+//!
+//! The columns are selected by the strong rank-revealing QR factorization:
+//! starting from the QR factorization with column pivoting `A P = Q [R11 R12; 0 R22]`,
+//! a selected column and a remaining one are swapped while it increases `|det R11|` by more than a factor `f = 2`.
+//! Then every element of `X` is bounded by `f`, and the error is bounded by
+//! `sqrt(1 + f^2 k (n - k)) σ_{k+1}` with the `(k+1)`-th singular value `σ_{k+1}`.
+//!
+//! - M. Gu and S. C. Eisenstat, Efficient algorithms for computing a strong rank-revealing QR factorization,
+//!   SIAM J. Sci. Comput. 17 (1996)
+//! - H. Cheng, Z. Gimbutas, P. G. Martinsson and V. Rokhlin, On the compression of low rank matrices,
+//!   SIAM J. Sci. Comput. 26 (2005)
+//!
+//! # Example
+//!
+//! ```
+//! use ndarray::*;
+//! use ndarray_linalg::*;
+//!
+//! // Rank 2 matrix
+//! let u: Array2<f64> = random((20, 2));
+//! let v: Array2<f64> = random((2, 10));
+//! let a = u.dot(&v);
+//! let id = a.interpolative_decomposition(2).unwrap();
+//! assert_eq!(id.columns.len(), 2);
+//! assert_close_l2!(&id.skeleton(&a).dot(&id.coefficients), &a, 1e-10);
+//! ```
+
+use ndarray::*;
+use num_traits::Float;
+
+use crate::convert::*;
+use crate::error::*;
+use crate::layout::*;
+use crate::norm::Norm;
+use crate::qr::*;
+use crate::triangular::*;
+use crate::types::*;
+
+/// Growth factor `f` of the strong rank-revealing QR factorization
+const GROWTH_FACTOR: f64 = 2.0;
+
+/// Column interpolative decomposition `A ≈ A[:, columns] coefficients`
+#[derive(Debug, Clone, PartialEq)]
+pub struct InterpolativeDecomposition<A> {
+    /// Indices of the selected columns `J`
+    pub columns: Vec<usize>,
+    /// Interpolation matrix `X` of the shape `k x n`, where `X[:, J]` is the identity
+    pub coefficients: Array2<A>,
+}
+
+impl<A: Scalar> InterpolativeDecomposition<A> {
+    /// Rank `k` of the decomposition
+    pub fn rank(&self) -> usize {
+        self.columns.len()
+    }
+
+    /// Selected columns `A[:, J]` of the decomposed matrix
+    pub fn skeleton<S>(&self, a: &ArrayBase<S, Ix2>) -> Array2<A>
+    where
+        S: Data<Elem = A>,
+    {
+        a.select(Axis(1), &self.columns)
+    }
+}
+
+/// Column interpolative decomposition of a matrix reference
+pub trait InterpolativeDecompose {
+    type Elem;
+
+    /// Decompose into `k` columns and the interpolation matrix
+    ///
+    /// Fails with `OutOfBounds` if `k` is zero or exceeds the number of rows or columns,
+    /// and with the error of the triangular solver if the rank of the matrix is less than `k`.
+    fn interpolative_decomposition(&self, k: usize) -> Result<InterpolativeDecomposition<Self::Elem>>;
+}
+
+impl<A, S> InterpolativeDecompose for ArrayBase<S, Ix2>
+where
+    A: Scalar + Lapack,
+    S: Data<Elem = A>,
+{
+    type Elem = A;
+
+    fn interpolative_decomposition(&self, k: usize) -> Result<InterpolativeDecomposition<A>> {
+        let (m, n) = self.dim();
+        if k == 0 || k > ::std::cmp::min(m, n) {
+            return Err(ShapeError::from_kind(ErrorKind::OutOfBounds).into());
+        }
+        if k == n {
+            let perm: Vec<usize> = (0..n).collect();
+            return Ok(interpolation(&perm, Array2::zeros((k, 0))));
+        }
+        let mut perm = pivots(self)?;
+        let f = A::real(GROWTH_FACTOR);
+        // `|det R11|` increases by `f` for each swap, so that the number of swaps is bounded
+        loop {
+            let (_, r) = self.select(Axis(1), &perm).qr_into()?;
+            let r11 = r.slice(s![..k, ..k]).to_owned();
+            let t = r11.solve_triangular(UPLO::Upper, Diag::NonUnit, &r.slice(s![..k, k..]).to_owned())?;
+            // `|R11^{-1} R12|_{ij}^2 + (γ_j(R22) / ω_i(R11))^2` is the square of the ratio of `|det R11|`
+            // after swapping the `i`-th selected column and the `j`-th remaining one, where `γ_j` is
+            // the norm of the `j`-th column of `R22` and `1 / ω_i` is that of the `i`-th row of `R11^{-1}`
+            let r11_inv = r11.solve_triangular(UPLO::Upper, Diag::NonUnit, &Array2::eye(k))?;
+            let omega_inv: Vec<A::Real> = r11_inv.genrows().into_iter().map(|row| row.norm_l2()).collect();
+            let gamma: Vec<A::Real> = r
+                .slice(s![k.., k..])
+                .gencolumns()
+                .into_iter()
+                .map(|col| col.norm_l2())
+                .collect();
+            let mut swap = None;
+            let mut max_ratio = f;
+            for i in 0..k {
+                for j in 0..(n - k) {
+                    let ratio = Float::hypot(t[(i, j)].abs(), gamma[j] * omega_inv[i]);
+                    if ratio > max_ratio {
+                        max_ratio = ratio;
+                        swap = Some((i, j));
+                    }
+                }
+            }
+            match swap {
+                Some((i, j)) => perm.swap(i, k + j),
+                None => return Ok(interpolation(&perm, t)),
+            }
+        }
+    }
+}
+
+/// Column indices in the order of the QR factorization with column pivoting
+fn pivots<A, S>(a: &ArrayBase<S, Ix2>) -> Result<Vec<usize>>
+where
+    A: Scalar + Lapack,
+    S: Data<Elem = A>,
+{
+    // Copied in Fortran layout, which LAPACKE would otherwise copy once more for `*geqp3`
+    let (m, n) = a.dim();
+    let mut a: Array2<A> = clone_with_layout(MatrixLayout::F((n as i32, m as i32)), a);
+    let mut jpvt = vec![0; n];
+    unsafe { A::householder_pivoted(a.layout()?, a.as_allocated_mut()?, &mut jpvt)? };
+    Ok(jpvt.into_iter().map(|j| j as usize - 1).collect())
+}
+
+/// Decomposition from the permutation `perm` of the columns and `T = R11^{-1} R12`
+fn interpolation<A: Scalar>(perm: &[usize], t: Array2<A>) -> InterpolativeDecomposition<A> {
+    let (k, n) = (t.rows(), perm.len());
+    let mut coefficients = Array2::zeros((k, n));
+    for i in 0..k {
+        coefficients[(i, perm[i])] = A::one();
+    }
+    for (j, col) in t.gencolumns().into_iter().enumerate() {
+        coefficients.column_mut(perm[k + j]).assign(&col);
+    }
+    InterpolativeDecomposition {
+        columns: perm[..k].to_vec(),
+        coefficients,
+    }
+}
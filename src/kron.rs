@@ -0,0 +1,91 @@
+//! Kronecker product and Kronecker-structured linear systems
+//!
+//! [kron](fn.kron.html) forms the Kronecker product `A ⊗ B`, whose `(i, k)`-th block is `A[(i, j)] B`.
+//! [solve_kron](fn.solve_kron.html) solves `(A ⊗ B) x = c` without forming the product by the vec-trick:
+//! reshaping `x` and `c` row-wise into `n x m` matrices `X` and `C`, the system is `A X B^T = C`,
+//! which needs only the LU factorizations of `A` and `B`, i.e. `O(n^3 + m^3 + nm(n + m))` operations
+//! instead of `O(n^3 m^3)` for the dense `nm x nm` matrix.
+//!
+//! # Example
+//!
+//! ```
+//! #[macro_use]
+//! extern crate ndarray;
+//! extern crate ndarray_linalg;
+//!
+//! use ndarray::prelude::*;
+//! use ndarray_linalg::*;
+//! # fn main() {
+//!
+//! let a: Array2<f64> = array![[2., 1.], [0., 3.]];
+//! let b: Array2<f64> = array![[1., 2.], [3., 5.]];
+//! let k = kron(&a, &b);
+//! assert_eq!(k.row(0), array![2., 4., 1., 2.]);
+//! let x = array![1., 2., 3., 4.];
+//! let c = k.dot(&x);
+//! assert!(solve_kron((&a, &b), &c).unwrap().all_close(&x, 1e-12));
+//! # }
+//! ```
+
+use ndarray::*;
+
+use crate::error::*;
+use crate::layout::*;
+use crate::solve::*;
+use crate::types::*;
+
+/// Kronecker product `A ⊗ B`
+///
+/// For `A` of shape `(p, q)` and `B` of shape `(r, s)`, the product has the shape `(pr, qs)`
+/// and `(A ⊗ B)[(i r + k, j s + l)] = A[(i, j)] B[(k, l)]`.
+pub fn kron<A, Sa, Sb>(a: &ArrayBase<Sa, Ix2>, b: &ArrayBase<Sb, Ix2>) -> Array2<A>
+where
+    A: Scalar,
+    Sa: Data<Elem = A>,
+    Sb: Data<Elem = A>,
+{
+    let (p, q) = a.dim();
+    let (r, s) = b.dim();
+    let mut k = Array2::zeros((p * r, q * s));
+    for ((i, j), &aij) in a.indexed_iter() {
+        k.slice_mut(s![i * r..(i + 1) * r, j * s..(j + 1) * s])
+            .assign(&b.mapv(|x| aij * x));
+    }
+    k
+}
+
+/// Solve `(A ⊗ B) x = c` for square `A` and `B` by the vec-trick
+///
+/// `c` is reshaped row-wise into the `n x m` matrix `C`, where `n` and `m` are the sizes of `A` and `B`,
+/// and `X = A^{-1} C B^{-T}` is computed by solving with the LU factorizations of `A` and `B`.
+pub fn solve_kron<A, Sa, Sb, Sc>(
+    (a, b): (&ArrayBase<Sa, Ix2>, &ArrayBase<Sb, Ix2>),
+    c: &ArrayBase<Sc, Ix1>,
+) -> Result<Array1<A>>
+where
+    A: Scalar + Lapack,
+    Sa: Data<Elem = A>,
+    Sb: Data<Elem = A>,
+    Sc: Data<Elem = A>,
+{
+    let (n, _) = a.square_layout()?.size();
+    let (m, _) = b.square_layout()?.size();
+    let (n, m) = (n as usize, m as usize);
+    if c.len() != n * m {
+        return Err(ShapeError::from_kind(ErrorKind::IncompatibleShape).into());
+    }
+    let fa = a.factorize()?;
+    let fb = b.factorize()?;
+    let c = Array::from_iter(c.iter().cloned()).into_shape((n, m))?;
+    // `Z = A^{-1} C` column by column
+    let mut z = Array2::zeros((n, m));
+    for (mut zj, cj) in z.axis_iter_mut(Axis(1)).zip(c.axis_iter(Axis(1))) {
+        zj.assign(&fa.solve(&cj)?);
+    }
+    // `X = Z B^{-T}`, i.e. `B X^T = Z^T` row by row
+    let mut x = Array2::zeros((n, m));
+    for (mut xi, zi) in x.axis_iter_mut(Axis(0)).zip(z.axis_iter(Axis(0))) {
+        xi.assign(&fb.solve(&zi)?);
+    }
+    Ok(Array::from_iter(x.iter().cloned()))
+}
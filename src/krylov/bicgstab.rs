@@ -0,0 +1,148 @@
+//! BiCGSTAB method for general linear systems
+//!
+//! BiCGSTAB needs only a fixed number of vectors and two applications of the operator per iteration,
+//! which makes it a low-memory alternative to [GMRES](../gmres/index.html) for non-Hermitian operators,
+//! while its residual norm is not monotone and it may break down.
+//! A preconditioner is applied from the right by default as in GMRES, or from the left.
+//!
+//! - H. A. van der Vorst, Bi-CGSTAB: A fast and smoothly converging variant of Bi-CG for the solution of
+//!   nonsymmetric linear systems, SIAM J. Sci. Stat. Comput. 13 (1992)
+//!
+
+use super::*;
+use crate::{convergence::*, error::*, norm::*, operator::LinearOperator};
+use num_traits::Zero;
+
+/// Solve `A x = b` for a general operator `a` by BiCGSTAB with the preconditioner `m`
+///
+/// - `m` applies `M^{-1}`, e.g. [IdentityOperator](../../operator/struct.IdentityOperator.html)
+///   for no preconditioning, from `options.side`
+/// - `x0` is the initial guess, e.g. zeros
+/// - The error of each iterate is measured by `options.criterion` from the residual `b - A x`,
+///   where `report.history[0]` is the error of `x0`.
+///   For the left preconditioner, it is measured from `M^{-1} (b - A x)` against `M^{-1} b`
+///   except for the last iterate.
+/// - The iteration stops when the criterion is satisfied or after `options.max_iter` iterations,
+///   and `report.converged` tells which happened
+///
+/// The iteration also stops without convergence on a breakdown, i.e. if `r_0^H r` or `r_0^H A p`
+/// vanishes for the initial residual `r_0`.
+///
+/// ```
+/// use ndarray::*;
+/// use ndarray_linalg::{krylov::*, *};
+///
+/// let a = arr2(&[[2.0, 1.0, 0.0], [0.0, 2.0, 1.0], [1.0, 0.0, 2.0]]);
+/// let b = arr1(&[1.0, 2.0, 3.0]);
+/// let m = IdentityOperator::new();
+/// let options = SolverOptions::new().rtol(1e-12).max_iter(10);
+/// let sol = bicgstab(&a, &m, &b, &Array1::zeros(3), &options).unwrap();
+/// assert!(sol.report.converged);
+/// assert_close_l2!(&a.dot(&sol.x), &b, 1e-10);
+/// ```
+pub fn bicgstab<A, F, P, Sb, Sx>(
+    a: &F,
+    m: &P,
+    b: &ArrayBase<Sb, Ix1>,
+    x0: &ArrayBase<Sx, Ix1>,
+    options: &SolverOptions<A::Real>,
+) -> Result<LinearSolution<A>>
+where
+    A: Scalar + Lapack,
+    F: LinearOperator<Elem = A>,
+    P: LinearOperator<Elem = A>,
+    Sb: Data<Elem = A>,
+    Sx: Data<Elem = A>,
+{
+    bicgstab_with_callback(a, m, b, x0, options, |_| true)
+}
+
+/// [bicgstab](fn.bicgstab.html) calling `callback` after each iteration
+///
+/// The callback receives the norm of the residual `b - A x` of each iterate,
+/// and the iteration stops without convergence if it returns `false`.
+pub fn bicgstab_with_callback<A, F, P, Sb, Sx, C>(
+    a: &F,
+    m: &P,
+    b: &ArrayBase<Sb, Ix1>,
+    x0: &ArrayBase<Sx, Ix1>,
+    options: &SolverOptions<A::Real>,
+    mut callback: C,
+) -> Result<LinearSolution<A>>
+where
+    A: Scalar + Lapack,
+    F: LinearOperator<Elem = A>,
+    P: LinearOperator<Elem = A>,
+    Sb: Data<Elem = A>,
+    Sx: Data<Elem = A>,
+    C: FnMut(&IterationInfo<A::Real>) -> bool,
+{
+    let SolverOptions {
+        criterion,
+        max_iter,
+        side,
+        ..
+    } = *options;
+    let n = b.len();
+    if x0.len() != n {
+        return Err(ShapeError::from_kind(ErrorKind::IncompatibleShape).into());
+    }
+    // `(M^{-1} v, A M^{-1} v)` for the right preconditioner, and `(v, M^{-1} A v)` for the left one
+    let precondition = |v: &Array1<A>| match side {
+        PreconditionerSide::Left => (v.clone(), m.apply(&a.apply(v))),
+        PreconditionerSide::Right => {
+            let v_hat = m.apply(v);
+            let av = a.apply(&v_hat);
+            (v_hat, av)
+        }
+    };
+    let b_hat = match side {
+        PreconditionerSide::Left => m.apply(b),
+        PreconditionerSide::Right => b.to_owned(),
+    };
+    let error = |r: &Array1<A>, x: &Array1<A>| criterion.error(&Residual::new(r.view(), b_hat.view(), x.view()));
+    let mut monitor = Monitor::new(criterion, &mut callback);
+    let mut x = x0.to_owned();
+    let mut r = match side {
+        PreconditionerSide::Left => &b_hat - &m.apply(&a.apply(&x)),
+        PreconditionerSide::Right => b - &a.apply(&x),
+    };
+    if !monitor.record(error(&r, &x), r.norm_l2()) {
+        let r0 = r.clone();
+        let mut p: Array1<A> = Array1::zeros(n);
+        let mut v: Array1<A> = Array1::zeros(n);
+        let (mut rho, mut alpha, mut omega) = (A::one(), A::one(), A::one());
+        while monitor.is_running(max_iter) {
+            let rho_next = r0.inner(&r);
+            if rho_next.is_zero() {
+                break;
+            }
+            let beta = (rho_next / rho) * (alpha / omega);
+            rho = rho_next;
+            azip!(mut p(&mut p), r(&r), v(&v) in { *p = r + beta * (*p - omega * v) });
+            let (p_hat, ap) = precondition(&p);
+            v = ap;
+            let r0v = r0.inner(&v);
+            if r0v.is_zero() {
+                break;
+            }
+            alpha = rho / r0v;
+            // `s = r - α v` is stored in `r`
+            azip!(mut r(&mut r), v(&v) in { *r = *r - alpha * v });
+            let (s_hat, t) = precondition(&r);
+            let tt = t.inner(&t);
+            omega = if tt.is_zero() { A::zero() } else { t.inner(&r) / tt };
+            azip!(mut x(&mut x), p_hat(&p_hat), s_hat(&s_hat) in { *x = *x + alpha * p_hat + omega * s_hat });
+            azip!(mut r(&mut r), t(&t) in { *r = *r - omega * t });
+            if monitor.record(error(&r, &x), r.norm_l2()) || omega.is_zero() {
+                break;
+            }
+        }
+    }
+    if side == PreconditionerSide::Left {
+        r = b - &a.apply(&x);
+        monitor.replace_last(criterion.error(&Residual::new(r.view(), b.view(), x.view())));
+    }
+    let report = monitor.finish(r.norm_l2());
+    Ok(LinearSolution { x, report })
+}
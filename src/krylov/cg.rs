@@ -0,0 +1,191 @@
+//! Conjugate gradient method for Hermitian positive definite systems
+//!
+//! The operator is only accessed through [LinearOperator](../../operator/trait.LinearOperator.html),
+//! so that systems too large for the dense Cholesky factorization can be solved.
+//! Each iteration costs one application of the operator and `O(n)` vector operations,
+//! and the error in the `A`-norm decreases at least by the factor `(√κ - 1) / (√κ + 1)`
+//! where `κ` is the condition number of `A`.
+//! Since realistic problems are often ill-conditioned, [pcg](fn.pcg.html) accepts a preconditioner,
+//! see the [preconditioner](../preconditioner/index.html) module for the built-in ones.
+//!
+//! - M. R. Hestenes and E. Stiefel, Methods of conjugate gradients for solving linear systems,
+//!   J. Res. Nat. Bur. Standards 49 (1952)
+//!
+
+use super::*;
+use crate::{
+    convergence::*,
+    error::*,
+    norm::*,
+    operator::{IdentityOperator, LinearOperator},
+};
+use num_traits::Zero;
+
+/// Solve `A x = b` for a Hermitian (or real symmetric) positive definite operator `a` by the conjugate gradient method
+///
+/// - `x0` is the initial guess, e.g. zeros
+/// - The error of each iterate is measured by `options.criterion` from the residual `b - A x`,
+///   where `report.history[0]` is the error of `x0`
+/// - The iteration stops when the criterion is satisfied or after `options.max_iter` iterations,
+///   and `report.converged` tells which happened
+///
+/// The iteration also stops without convergence if `p^H A p <= 0` for a search direction `p`,
+/// i.e. if `a` turns out not to be positive definite.
+///
+/// ```
+/// use ndarray::*;
+/// use ndarray_linalg::{krylov::*, *};
+///
+/// let a = arr2(&[[4.0, 1.0], [1.0, 3.0]]);
+/// let b = arr1(&[1.0, 2.0]);
+/// let options = SolverOptions::new().rtol(1e-12).max_iter(10);
+/// let sol = cg(&a, &b, &Array1::zeros(2), &options).unwrap();
+/// assert!(sol.report.converged);
+/// assert_close_l2!(&sol.x, &arr1(&[1.0 / 11.0, 7.0 / 11.0]), 1e-10);
+/// ```
+pub fn cg<A, F, Sb, Sx>(
+    a: &F,
+    b: &ArrayBase<Sb, Ix1>,
+    x0: &ArrayBase<Sx, Ix1>,
+    options: &SolverOptions<A::Real>,
+) -> Result<LinearSolution<A>>
+where
+    A: Scalar + Lapack,
+    F: LinearOperator<Elem = A>,
+    Sb: Data<Elem = A>,
+    Sx: Data<Elem = A>,
+{
+    pcg_with_callback(a, &IdentityOperator::new(), b, x0, options, |_| true)
+}
+
+/// [cg](fn.cg.html) calling `callback` after each iteration
+///
+/// The callback receives the norm of the residual `b - A x` of each iterate,
+/// and the iteration stops without convergence if it returns `false`.
+///
+/// ```
+/// use ndarray::*;
+/// use ndarray_linalg::{krylov::*, *};
+///
+/// let a = arr2(&[[4.0, 1.0], [1.0, 3.0]]);
+/// let b = arr1(&[1.0, 2.0]);
+/// let mut residuals = Vec::new();
+/// let options = SolverOptions::new().rtol(1e-12).max_iter(10);
+/// let sol = cg_with_callback(&a, &b, &Array1::zeros(2), &options, |info| {
+///     residuals.push(info.residual_norm);
+///     true
+/// })
+/// .unwrap();
+/// assert_eq!(residuals.len(), sol.report.iterations + 1);
+/// ```
+pub fn cg_with_callback<A, F, Sb, Sx, C>(
+    a: &F,
+    b: &ArrayBase<Sb, Ix1>,
+    x0: &ArrayBase<Sx, Ix1>,
+    options: &SolverOptions<A::Real>,
+    callback: C,
+) -> Result<LinearSolution<A>>
+where
+    A: Scalar + Lapack,
+    F: LinearOperator<Elem = A>,
+    Sb: Data<Elem = A>,
+    Sx: Data<Elem = A>,
+    C: FnMut(&IterationInfo<A::Real>) -> bool,
+{
+    pcg_with_callback(a, &IdentityOperator::new(), b, x0, options, callback)
+}
+
+/// Solve `A x = b` by the preconditioned conjugate gradient method
+///
+/// The preconditioner `m` applies `M^{-1}` for a Hermitian positive definite `M ≈ A`,
+/// e.g. [jacobi](fn.jacobi.html) or [IncompleteCholesky](struct.IncompleteCholesky.html).
+/// The convergence then depends on the condition number of `M^{-1} A` instead of `A`,
+/// while the error is still measured from the unpreconditioned residual `b - A x`.
+/// The other arguments are the same as [cg](fn.cg.html).
+///
+/// ```
+/// use ndarray::*;
+/// use ndarray_linalg::{krylov::*, *};
+///
+/// let a = arr2(&[[100.0, 1.0], [1.0, 0.1]]);
+/// let b = arr1(&[1.0, 2.0]);
+/// let m = jacobi(&a).unwrap();
+/// let options = SolverOptions::new().rtol(1e-12).max_iter(10);
+/// let sol = pcg(&a, &m, &b, &Array1::zeros(2), &options).unwrap();
+/// assert!(sol.report.converged);
+/// assert_close_l2!(&a.dot(&sol.x), &b, 1e-10);
+/// ```
+pub fn pcg<A, F, P, Sb, Sx>(
+    a: &F,
+    m: &P,
+    b: &ArrayBase<Sb, Ix1>,
+    x0: &ArrayBase<Sx, Ix1>,
+    options: &SolverOptions<A::Real>,
+) -> Result<LinearSolution<A>>
+where
+    A: Scalar + Lapack,
+    F: LinearOperator<Elem = A>,
+    P: LinearOperator<Elem = A>,
+    Sb: Data<Elem = A>,
+    Sx: Data<Elem = A>,
+{
+    pcg_with_callback(a, m, b, x0, options, |_| true)
+}
+
+/// [pcg](fn.pcg.html) calling `callback` after each iteration
+///
+/// The callback receives the norm of the residual `b - A x` of each iterate,
+/// and the iteration stops without convergence if it returns `false`.
+pub fn pcg_with_callback<A, F, P, Sb, Sx, C>(
+    a: &F,
+    m: &P,
+    b: &ArrayBase<Sb, Ix1>,
+    x0: &ArrayBase<Sx, Ix1>,
+    options: &SolverOptions<A::Real>,
+    mut callback: C,
+) -> Result<LinearSolution<A>>
+where
+    A: Scalar + Lapack,
+    F: LinearOperator<Elem = A>,
+    P: LinearOperator<Elem = A>,
+    Sb: Data<Elem = A>,
+    Sx: Data<Elem = A>,
+    C: FnMut(&IterationInfo<A::Real>) -> bool,
+{
+    let SolverOptions {
+        criterion, max_iter, ..
+    } = *options;
+    if b.len() != x0.len() {
+        return Err(ShapeError::from_kind(ErrorKind::IncompatibleShape).into());
+    }
+    let mut monitor = Monitor::new(criterion, &mut callback);
+    let mut x = x0.to_owned();
+    let mut r = b - &a.apply(&x);
+    let error = |r: &Array1<A>, x: &Array1<A>| criterion.error(&Residual::new(r.view(), b.view(), x.view()));
+    if monitor.record(error(&r, &x), r.norm_l2()) {
+        let report = monitor.finish(r.norm_l2());
+        return Ok(LinearSolution { x, report });
+    }
+    let mut p = m.apply(&r);
+    let mut rz = r.inner(&p).re();
+    while monitor.is_running(max_iter) {
+        let ap = a.apply(&p);
+        let pap = p.inner(&ap).re();
+        if pap <= A::Real::zero() {
+            break;
+        }
+        let alpha = A::from_real(rz / pap);
+        azip!(mut x(&mut x), p(&p) in { *x = *x + alpha * p });
+        azip!(mut r(&mut r), ap(&ap) in { *r = *r - alpha * ap });
+        if monitor.record(error(&r, &x), r.norm_l2()) {
+            break;
+        }
+        let z = m.apply(&r);
+        let rz_next = r.inner(&z).re();
+        let beta = A::from_real(rz_next / rz);
+        rz = rz_next;
+        azip!(mut p(&mut p), z(&z) in { *p = z + beta * *p });
+    }
+    let report = monitor.finish(r.norm_l2());
+    Ok(LinearSolution { x, report })
+}
@@ -0,0 +1,189 @@
+//! Restarted GMRES method for general linear systems
+//!
+//! GMRES minimizes the residual norm `|b - A x|` over the Krylov subspace spanned by the Arnoldi vectors,
+//! which are orthogonalized by the [MGS](../mgs/struct.MGS.html) orthogonalizer.
+//! The least squares problem of the Hessenberg matrix is updated by Givens rotations in each iteration,
+//! so that the residual norm is known without forming the iterate.
+//! Since the basis grows with the iterations, the method is restarted after `m` iterations, i.e. GMRES(m).
+//!
+//! A preconditioner `M^{-1}` is applied from the right by default, `A M^{-1} u = b` with `x = M^{-1} u`,
+//! so that the minimized residual is the residual of the original system.
+//! If it is applied from the left, `M^{-1} A x = M^{-1} b`, the preconditioned residual `M^{-1} (b - A x)`
+//! is minimized instead.
+//!
+//! - Y. Saad and M. H. Schultz, GMRES: A generalized minimal residual algorithm for solving nonsymmetric
+//!   linear systems, SIAM J. Sci. Stat. Comput. 7 (1986)
+//!
+
+use super::*;
+use crate::{convergence::*, error::*, norm::*, operator::LinearOperator, triangular::*, UPLO};
+use num_traits::{Float, Zero};
+
+/// Solve `A x = b` for a general operator `a` by GMRES(m) with the preconditioner `m`
+///
+/// - `m` applies `M^{-1}`, e.g. [IdentityOperator](../../operator/struct.IdentityOperator.html)
+///   for no preconditioning, from `options.side`
+/// - `x0` is the initial guess, e.g. zeros
+/// - The method is restarted every `options.restart` iterations
+/// - The error of each iterate is measured by `options.criterion` from the residual norm `|b - A x|`,
+///   where `report.history[0]` is the error of `x0`.
+///   Within a restart cycle, the residual norm is the estimate given by the Givens rotations,
+///   and it is replaced by the true residual norm at the end of the cycle.
+///   For the left preconditioner, the estimate is `|M^{-1} (b - A x)|` compared with `|M^{-1} b|`.
+///   Since no residual vector is formed, `ComponentWise` is evaluated as `BackwardError`.
+/// - The iteration stops when the criterion is satisfied or after `options.max_iter` iterations in total,
+///   and `report.converged` tells which happened
+///
+/// ```
+/// use ndarray::*;
+/// use ndarray_linalg::{krylov::*, *};
+///
+/// let a = arr2(&[[2.0, 1.0, 0.0], [0.0, 2.0, 1.0], [1.0, 0.0, 2.0]]);
+/// let b = arr1(&[1.0, 2.0, 3.0]);
+/// let m = IdentityOperator::new();
+/// let options = SolverOptions::new().rtol(1e-12).max_iter(10).restart(3);
+/// let sol = gmres(&a, &m, &b, &Array1::zeros(3), &options).unwrap();
+/// assert!(sol.report.converged);
+/// assert_close_l2!(&a.dot(&sol.x), &b, 1e-10);
+/// ```
+pub fn gmres<A, F, P, Sb, Sx>(
+    a: &F,
+    m: &P,
+    b: &ArrayBase<Sb, Ix1>,
+    x0: &ArrayBase<Sx, Ix1>,
+    options: &SolverOptions<A::Real>,
+) -> Result<LinearSolution<A>>
+where
+    A: Scalar + Lapack,
+    F: LinearOperator<Elem = A>,
+    P: LinearOperator<Elem = A>,
+    Sb: Data<Elem = A>,
+    Sx: Data<Elem = A>,
+{
+    gmres_with_callback(a, m, b, x0, options, |_| true)
+}
+
+/// [gmres](fn.gmres.html) calling `callback` after each iteration
+///
+/// The callback receives the residual norm of each iterate estimated by the Givens rotations,
+/// and the iteration stops without convergence if it returns `false`.
+pub fn gmres_with_callback<A, F, P, Sb, Sx, C>(
+    a: &F,
+    m: &P,
+    b: &ArrayBase<Sb, Ix1>,
+    x0: &ArrayBase<Sx, Ix1>,
+    options: &SolverOptions<A::Real>,
+    mut callback: C,
+) -> Result<LinearSolution<A>>
+where
+    A: Scalar + Lapack,
+    F: LinearOperator<Elem = A>,
+    P: LinearOperator<Elem = A>,
+    Sb: Data<Elem = A>,
+    Sx: Data<Elem = A>,
+    C: FnMut(&IterationInfo<A::Real>) -> bool,
+{
+    let SolverOptions {
+        criterion,
+        max_iter,
+        restart,
+        side,
+    } = *options;
+    let n = b.len();
+    if x0.len() != n {
+        return Err(ShapeError::from_kind(ErrorKind::IncompatibleShape).into());
+    }
+    if restart == 0 {
+        return Err(ShapeError::from_kind(ErrorKind::OutOfBounds).into());
+    }
+    let restart = ::std::cmp::min(restart, n);
+    let b_norm = match side {
+        PreconditionerSide::Left => m.apply(b).norm_l2(),
+        PreconditionerSide::Right => b.norm_l2(),
+    };
+    let mut monitor = Monitor::new(criterion, &mut callback);
+    let mut x = x0.to_owned();
+    let mut r = b - &a.apply(&x);
+    let error = |r: &Array1<A>, x: &Array1<A>| criterion.error(&Residual::new(r.view(), b.view(), x.view()));
+    if monitor.record(error(&r, &x), r.norm_l2()) {
+        let report = monitor.finish(r.norm_l2());
+        return Ok(LinearSolution { x, report });
+    }
+
+    while monitor.is_running(max_iter) {
+        let r0 = match side {
+            PreconditionerSide::Left => m.apply(&r),
+            PreconditionerSide::Right => r.clone(),
+        };
+        let beta = r0.norm_l2();
+        let x_norm = x.norm_l2();
+        let mut mgs = MGS::new(n, A::Real::zero());
+        let mut basis = vec![r0.mapv(|r| r.div_real(beta))];
+        mgs.append(basis[0].view());
+        // Upper triangular factor of the Hessenberg matrix, rotations, and the rotated right-hand side
+        let mut h: Array2<A> = Array2::zeros((restart, restart));
+        let mut rotations: Vec<(A::Real, A)> = Vec::with_capacity(restart);
+        let mut g: Array1<A> = Array1::zeros(restart + 1);
+        g[0] = A::from_real(beta);
+
+        let mut k = 0;
+        while k < restart && monitor.is_running(max_iter) {
+            let mut w = match side {
+                PreconditionerSide::Left => m.apply(&a.apply(&basis[k])),
+                PreconditionerSide::Right => a.apply(&m.apply(&basis[k])),
+            };
+            let w_norm = w.norm_l2();
+            let mut hk = mgs.div_append(&mut w).into_coeff();
+            // The Krylov subspace is invariant, and the solution is exact in this cycle
+            let breakdown = hk[k + 1].abs() <= A::Real::epsilon() * w_norm;
+            for (i, &(c, s)) in rotations.iter().enumerate() {
+                let (h0, h1) = (hk[i], hk[i + 1]);
+                hk[i] = h0.mul_real(c) + s * h1;
+                hk[i + 1] = h1.mul_real(c) - s.conj() * h0;
+            }
+            let (c, s, diag) = givens(hk[k], hk[k + 1]);
+            rotations.push((c, s));
+            h.slice_mut(s![..k, k]).assign(&hk.slice(s![..k]));
+            h[(k, k)] = diag;
+            g[k + 1] = A::zero() - s.conj() * g[k];
+            g[k] = g[k].mul_real(c);
+            basis.push(w);
+            k += 1;
+            let estimate = g[k].abs();
+            if monitor.record(criterion.error_from_norms(estimate, b_norm, x_norm, None), estimate) || breakdown {
+                break;
+            }
+        }
+
+        // `x += M^{-1} V y` (or `x += V y` for the left preconditioner) with `R y = g`
+        let rk = h.slice(s![..k, ..k]).to_owned();
+        let y = rk.solve_triangular_into(UPLO::Upper, Diag::NonUnit, g.slice(s![..k]).to_owned())?;
+        let mut z = Array1::zeros(n);
+        for (v, &y) in basis.iter().zip(y.iter()) {
+            azip!(mut z(&mut z), v(v) in { *z = *z + y * v });
+        }
+        x = match side {
+            PreconditionerSide::Left => x + z,
+            PreconditionerSide::Right => x + m.apply(&z),
+        };
+        r = b - &a.apply(&x);
+        if monitor.replace_last(error(&r, &x)) {
+            break;
+        }
+    }
+    let report = monitor.finish(r.norm_l2());
+    Ok(LinearSolution { x, report })
+}
+
+/// Givens rotation `(c, s)` with real `c` eliminating `b` in `(a, b)`
+///
+/// Returns `(c, s, r)` such that `c a + s b = r` and `-conj(s) a + c b = 0`.
+fn givens<A: Scalar>(a: A, b: A) -> (A::Real, A, A) {
+    let abs_a = a.abs();
+    if abs_a.is_zero() {
+        return (A::Real::zero(), A::one(), b);
+    }
+    let norm = Float::hypot(abs_a, b.abs());
+    let phase = a.div_real(abs_a);
+    (abs_a / norm, phase * b.conj().div_real(norm), phase.mul_real(norm))
+}
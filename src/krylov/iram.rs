@@ -0,0 +1,280 @@
+//! Restarted Arnoldi method for a few eigenpairs of a general operator
+//!
+//! The Arnoldi decomposition `A V = V H + β v e^T` is restarted by the Krylov-Schur scheme,
+//! which is mathematically equivalent to the implicitly restarted Arnoldi method (IRAM) of ARPACK
+//! with exact shifts: the Schur form of `H` is reordered so that the wanted Ritz values come first,
+//! and the Krylov subspace is truncated to the corresponding invariant subspace of `H`.
+//!
+//! Eigenvalues near a shift `σ` are computed through the operator `(A - σI)^{-1}`,
+//! see [ShiftInvert](struct.ShiftInvert.html) and [iram_shift_invert](fn.iram_shift_invert.html).
+//!
+//! - G. W. Stewart, A Krylov-Schur algorithm for large eigenproblems,
+//!   SIAM J. Matrix Anal. Appl. 23 (2002)
+//!
+
+use super::*;
+use crate::{
+    convergence::*, convert::*, eig::Eig, error::*, generate::random, layout::*, norm::*, operator::LinearOperator,
+    schur::reorder_schur, solve::*,
+};
+use num_traits::Float;
+
+/// Eigenpairs computed by [iram](fn.iram.html)
+#[derive(Debug, Clone)]
+pub struct IramResult<A: Scalar> {
+    /// Ritz values ordered as requested by [Which](enum.Which.html)
+    pub values: Array1<A::Complex>,
+    /// Ritz vectors as columns, normalized `|x|_2 = 1`
+    pub vectors: Array2<A::Complex>,
+    /// Error of each Ritz pair measured by the stopping criterion from its residual norm estimate
+    pub errors: Array1<A::Real>,
+    /// Statistics of the restart cycles, where the residual norm and the errors are the largest of the wanted Ritz pairs
+    pub report: SolveReport<A::Real>,
+}
+
+/// Compute `k` eigenpairs of a general operator `a` at the part of the spectrum specified by `which`
+///
+/// - `x0` is the starting vector, whose length gives the dimension `n` of the operator
+/// - The Krylov subspace grows up to `min(n, max(2k + 1, 20))` dimensions before restarting
+/// - Each Ritz pair `(θ, x)` is tested by `criterion` as the eigen-residual `|A x - θ x|`
+///   against `|θ x|`, using the estimate of the residual norm given by the Arnoldi relation
+/// - The iteration stops when all `k` pairs converge or after `max_restarts` restarts,
+///   and `report.converged` tells which happened
+///
+/// For real operators, the complex conjugate pair of a wanted Ritz value is kept together on restart.
+pub fn iram<A, F, S>(
+    a: &F,
+    x0: &ArrayBase<S, Ix1>,
+    k: usize,
+    which: Which,
+    criterion: StoppingCriterion<A::Real>,
+    max_restarts: usize,
+) -> Result<IramResult<A>>
+where
+    A: Scalar + Lapack,
+    F: LinearOperator<Elem = A>,
+    S: Data<Elem = A>,
+{
+    iram_with_callback(a, x0, k, which, criterion, max_restarts, |_| true)
+}
+
+/// [iram](fn.iram.html) calling `callback` after each restart cycle
+///
+/// The callback receives the largest estimate of the residual norm `|A x - θ x|` and the largest error
+/// of the wanted Ritz pairs, and the iteration stops without convergence if it returns `false`.
+pub fn iram_with_callback<A, F, S, C>(
+    a: &F,
+    x0: &ArrayBase<S, Ix1>,
+    k: usize,
+    which: Which,
+    criterion: StoppingCriterion<A::Real>,
+    max_restarts: usize,
+    mut callback: C,
+) -> Result<IramResult<A>>
+where
+    A: Scalar + Lapack,
+    F: LinearOperator<Elem = A>,
+    S: Data<Elem = A>,
+    C: FnMut(&IterationInfo<A::Real>) -> bool,
+{
+    let n = x0.len();
+    if k == 0 || k > n {
+        return Err(ShapeError::from_kind(ErrorKind::OutOfBounds).into());
+    }
+    let m = ::std::cmp::min(n, ::std::cmp::max(2 * k + 1, 20));
+    let keep = k + (m - k) / 2;
+
+    // `v` holds the basis of the Krylov subspace and the next Arnoldi vector in its last column
+    let mut v: Array2<A> = Array2::zeros((n, m + 1).f());
+    let mut h: Array2<A> = Array2::zeros((m, m));
+    let mut monitor = Monitor::new(criterion, &mut callback);
+    let mut x = x0.to_owned();
+    let norm = x.norm_l2();
+    azip!(mut x(&mut x) in { *x = x.div_real(norm) });
+    v.column_mut(0).assign(&x);
+
+    let mut start = 0;
+    loop {
+        let beta = extend(a, &mut v, &mut h, start, m);
+        let (theta, y) = h.eig()?;
+        let order = which.sort(theta.as_slice().unwrap());
+
+        let r_norms: Array1<A::Real> = order.iter().take(k).map(|&i| beta * y[(m - 1, i)].abs()).collect();
+        let errors: Array1<A::Real> = order
+            .iter()
+            .zip(r_norms.iter())
+            .map(|(&i, &r)| criterion.error_from_norms(r, theta[i].abs(), A::Real::one(), None))
+            .collect();
+        let max_error = errors.fold(A::Real::zero(), |acc, &e| Float::max(acc, e));
+        let max_r_norm = r_norms.fold(A::Real::zero(), |acc, &r| Float::max(acc, r));
+        if monitor.record(max_error, max_r_norm) || !monitor.is_running(max_restarts) {
+            let basis = v.slice(s![.., ..m]).mapv(|x| x.as_c());
+            let mut vectors = Array2::zeros((n, k));
+            for (j, &i) in order.iter().take(k).enumerate() {
+                let mut x = basis.dot(&y.column(i));
+                let norm = x.iter().map(|x| x.square()).sum::<A::Real>().sqrt();
+                azip!(mut x(&mut x) in { *x = x.div_real(norm) });
+                vectors.column_mut(j).assign(&x);
+            }
+            return Ok(IramResult {
+                values: order.iter().take(k).map(|&i| theta[i]).collect(),
+                vectors,
+                errors,
+                report: monitor.finish(max_r_norm),
+            });
+        }
+        start = restart(&mut v, &mut h, beta, keep, which)?;
+    }
+}
+
+/// Compute `k` eigenpairs of a square matrix `a` whose eigenvalues are closest to the shift `σ`
+///
+/// The eigenvalues `μ` of largest magnitude of `(A - σI)^{-1}` are computed by [iram](fn.iram.html)
+/// using the LU factorization of `A - σI`, and transformed back as `λ = σ + 1 / μ`.
+/// The errors in the result are those for `(A - σI)^{-1}`.
+pub fn iram_shift_invert<A, Sa, Sx>(
+    a: &ArrayBase<Sa, Ix2>,
+    shift: A,
+    x0: &ArrayBase<Sx, Ix1>,
+    k: usize,
+    criterion: StoppingCriterion<A::Real>,
+    max_restarts: usize,
+) -> Result<IramResult<A>>
+where
+    A: Scalar + Lapack,
+    Sa: Data<Elem = A>,
+    Sx: Data<Elem = A>,
+{
+    let op = ShiftInvert::new(a, shift)?;
+    let mut result = iram(&op, x0, k, Which::LargestMagnitude, criterion, max_restarts)?;
+    let shift = shift.as_c();
+    result.values.mapv_inplace(|mu| shift + A::Complex::one() / mu);
+    Ok(result)
+}
+
+/// Shift-and-invert operator `(A - σI)^{-1}` using the LU factorization of `A - σI`
+///
+/// The eigenvalues `λ` of `A` closest to `σ` become the eigenvalues `1 / (λ - σ)` of largest magnitude
+/// with the same eigenvectors, which the Krylov eigensolvers find quickly.
+pub struct ShiftInvert<A: Scalar> {
+    lu: LUFactorized<OwnedRepr<A>>,
+}
+
+impl<A: Scalar + Lapack> ShiftInvert<A> {
+    /// Factorize `A - σI`, which fails if `σ` is an eigenvalue of `A`
+    pub fn new<S: Data<Elem = A>>(a: &ArrayBase<S, Ix2>, shift: A) -> Result<Self> {
+        let (rows, cols) = a.dim();
+        if rows != cols {
+            return Err(LinalgError::NotSquare {
+                rows: rows as i32,
+                cols: cols as i32,
+            });
+        }
+        let mut a = a.to_owned();
+        for d in a.diag_mut() {
+            *d = *d - shift;
+        }
+        Ok(ShiftInvert {
+            lu: a.factorize_into()?,
+        })
+    }
+}
+
+impl<A: Scalar + Lapack> LinearOperator for ShiftInvert<A> {
+    type Elem = A;
+
+    fn apply_mut<S>(&self, a: &mut ArrayBase<S, Ix1>)
+    where
+        S: DataMut<Elem = A>,
+    {
+        let x = self.lu.solve_into(a.to_owned()).expect("LU factors are contiguous");
+        a.assign(&x);
+    }
+}
+
+/// Extend the Arnoldi basis from the `start`-th column to `m` columns, and fill the Hessenberg matrix
+///
+/// Returns the norm of the residual `β`, and the next Arnoldi vector is stored in the `m`-th column.
+fn extend<A, F>(a: &F, v: &mut Array2<A>, h: &mut Array2<A>, start: usize, m: usize) -> A::Real
+where
+    A: Scalar + Lapack,
+    F: LinearOperator<Elem = A>,
+{
+    let n = v.rows();
+    let mut beta = A::Real::zero();
+    for j in start..m {
+        let mut w = a.apply(&v.column(j));
+        let norm_aw = w.norm_l2();
+        let c = orthogonalize(&v.slice(s![.., ..=j]), &mut w);
+        h.slice_mut(s![..=j, j]).assign(&c);
+
+        beta = w.norm_l2();
+        let breakdown = beta <= A::Real::epsilon() * norm_aw;
+        if j + 1 == m {
+            if breakdown {
+                // The subspace is invariant, and all Ritz pairs are exact
+                beta = A::Real::zero();
+                v.column_mut(m).fill(A::zero());
+            } else {
+                azip!(mut vm(v.column_mut(m)), w in { *vm = w.div_real(beta) });
+            }
+            break;
+        }
+        if breakdown {
+            // Continue with a random vector orthogonal to the invariant subspace
+            beta = A::Real::zero();
+            w = random(n);
+            orthogonalize(&v.slice(s![.., ..=j]), &mut w);
+            let norm = w.norm_l2();
+            azip!(mut w(&mut w) in { *w = w.div_real(norm) });
+            v.column_mut(j + 1).assign(&w);
+        } else {
+            azip!(mut vj(v.column_mut(j + 1)), w in { *vj = w.div_real(beta) });
+        }
+        h[(j + 1, j)] = A::from_real(beta);
+    }
+    beta
+}
+
+/// Truncate the Arnoldi decomposition to the invariant subspace of the `keep` wanted Ritz values
+/// (or `keep + 1` to keep a complex conjugate pair together), and return its dimension
+fn restart<A>(v: &mut Array2<A>, h: &mut Array2<A>, beta: A::Real, keep: usize, which: Which) -> Result<usize>
+where
+    A: Scalar + Lapack,
+{
+    let m = h.rows();
+    let l = MatrixLayout::F((m as i32, m as i32));
+    let mut t: Array2<A> = clone_with_layout(l, h);
+    let (eigs, q) = unsafe { A::schur(l, t.as_allocated_mut()?)? };
+    let q: Array2<A> = into_matrix(l, q)?;
+    let order = which.sort(&eigs);
+
+    // For real matrices, a split conjugate pair may enlarge the block up to `m`, where no restart happens
+    let mut p = keep;
+    let (q, t, p) = loop {
+        let mut select = vec![false; m];
+        for &i in order.iter().take(p) {
+            select[i] = true;
+        }
+        let mut qs = q.clone();
+        let mut ts = t.clone();
+        let kept = reorder_schur(&mut qs, &mut ts, &select)?;
+        if kept < m || p == 1 {
+            break (qs, ts, kept);
+        }
+        p -= 1;
+    };
+
+    let next = v.column(m).to_owned();
+    let basis = v.slice(s![.., ..m]).dot(&q.slice(s![.., ..p]));
+    v.slice_mut(s![.., ..p]).assign(&basis);
+    v.column_mut(p).assign(&next);
+
+    h.fill(A::zero());
+    h.slice_mut(s![..p, ..p]).assign(&t.slice(s![..p, ..p]));
+    if p < m {
+        let b = q.slice(s![m - 1, ..p]).mapv(|x| x.mul_real(beta));
+        h.slice_mut(s![p, ..p]).assign(&b);
+    }
+    Ok(p)
+}
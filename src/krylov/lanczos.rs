@@ -0,0 +1,185 @@
+//! Thick-restart Lanczos method for a few eigenpairs of a Hermitian operator
+//!
+//! The operator is only accessed through [LinearOperator](../../operator/trait.LinearOperator.html),
+//! so that it need not be stored as a dense matrix.
+//! The Krylov basis is kept orthonormal by full re-orthogonalization, and is restarted
+//! with the wanted Ritz vectors when it reaches its maximal dimension.
+//!
+//! - K. Wu and H. Simon, Thick-restart Lanczos method for large symmetric eigenvalue problems,
+//!   SIAM J. Matrix Anal. Appl. 22 (2000)
+//!
+
+use super::*;
+use crate::{convergence::*, eigh::Eigh, error::*, generate::random, norm::*, operator::LinearOperator, UPLO};
+use num_traits::Float;
+
+/// Eigenpairs computed by [lanczos](fn.lanczos.html)
+#[derive(Debug, Clone)]
+pub struct LanczosResult<A: Scalar> {
+    /// Ritz values ordered as requested by [Which](enum.Which.html)
+    pub values: Array1<A::Real>,
+    /// Ritz vectors as columns, normalized `|x|_2 = 1`
+    pub vectors: Array2<A>,
+    /// Error of each Ritz pair measured by the stopping criterion from its residual norm estimate
+    pub errors: Array1<A::Real>,
+    /// Statistics of the restart cycles, where the residual norm and the errors are the largest of the wanted Ritz pairs
+    pub report: SolveReport<A::Real>,
+}
+
+/// Compute `k` eigenpairs of a Hermitian operator `a` at the end of the spectrum specified by `which`
+///
+/// - `x0` is the starting vector, whose length gives the dimension `n` of the operator
+/// - The Krylov subspace grows up to `min(n, max(2k + 1, 20))` dimensions before restarting
+/// - Each Ritz pair `(θ, x)` is tested by `criterion` as the eigen-residual `|A x - θ x|`
+///   against `|θ x|`, using the estimate of the residual norm given by the Lanczos relation
+/// - The iteration stops when all `k` pairs converge or after `max_restarts` restarts,
+///   and `report.converged` tells which happened
+pub fn lanczos<A, F, S>(
+    a: &F,
+    x0: &ArrayBase<S, Ix1>,
+    k: usize,
+    which: Which,
+    criterion: StoppingCriterion<A::Real>,
+    max_restarts: usize,
+) -> Result<LanczosResult<A>>
+where
+    A: Scalar + Lapack,
+    F: LinearOperator<Elem = A>,
+    S: Data<Elem = A>,
+{
+    lanczos_with_callback(a, x0, k, which, criterion, max_restarts, |_| true)
+}
+
+/// [lanczos](fn.lanczos.html) calling `callback` after each restart cycle
+///
+/// The callback receives the largest estimate of the residual norm `|A x - θ x|` and the largest error
+/// of the wanted Ritz pairs, and the iteration stops without convergence if it returns `false`.
+pub fn lanczos_with_callback<A, F, S, C>(
+    a: &F,
+    x0: &ArrayBase<S, Ix1>,
+    k: usize,
+    which: Which,
+    criterion: StoppingCriterion<A::Real>,
+    max_restarts: usize,
+    mut callback: C,
+) -> Result<LanczosResult<A>>
+where
+    A: Scalar + Lapack,
+    F: LinearOperator<Elem = A>,
+    S: Data<Elem = A>,
+    C: FnMut(&IterationInfo<A::Real>) -> bool,
+{
+    let n = x0.len();
+    if k == 0 || k > n {
+        return Err(ShapeError::from_kind(ErrorKind::OutOfBounds).into());
+    }
+    let m = ::std::cmp::min(n, ::std::cmp::max(2 * k + 1, 20));
+    let keep = k + (m - k) / 2;
+
+    // `v` holds the basis of the Krylov subspace and the next Lanczos vector in its last column
+    let mut v: Array2<A> = Array2::zeros((n, m + 1).f());
+    let mut t: Array2<A> = Array2::zeros((m, m));
+    let mut monitor = Monitor::new(criterion, &mut callback);
+    let mut x = x0.to_owned();
+    let norm = x.norm_l2();
+    azip!(mut x(&mut x) in { *x = x.div_real(norm) });
+    v.column_mut(0).assign(&x);
+
+    let mut start = 0;
+    loop {
+        let beta = extend(a, &mut v, &mut t, start, m);
+        let (theta, y) = t.eigh(UPLO::Upper)?;
+        let order = which.sort(theta.as_slice().unwrap());
+
+        let r_norms: Array1<A::Real> = order.iter().map(|&i| beta * y[(m - 1, i)].abs()).collect();
+        let errors: Array1<A::Real> = order
+            .iter()
+            .zip(r_norms.iter())
+            .map(|(&i, &r)| criterion.error_from_norms(r, theta[i].abs(), A::Real::one(), None))
+            .collect();
+        let max_error = errors
+            .slice(s![..k])
+            .fold(A::Real::zero(), |acc, &e| Float::max(acc, e));
+        let max_r_norm = r_norms
+            .slice(s![..k])
+            .fold(A::Real::zero(), |acc, &r| Float::max(acc, r));
+        if monitor.record(max_error, max_r_norm) || !monitor.is_running(max_restarts) {
+            let basis = v.slice(s![.., ..m]);
+            let mut vectors = Array2::zeros((n, k));
+            for (j, &i) in order.iter().take(k).enumerate() {
+                vectors.column_mut(j).assign(&basis.dot(&y.column(i)));
+            }
+            return Ok(LanczosResult {
+                values: order.iter().take(k).map(|&i| theta[i]).collect(),
+                vectors,
+                errors: errors.slice(s![..k]).to_owned(),
+                report: monitor.finish(max_r_norm),
+            });
+        }
+
+        // Restart with the `keep` wanted Ritz vectors and the last Lanczos vector,
+        // where the projected matrix becomes diagonal with an arrowhead in its `keep`-th row and column
+        let ritz: Vec<Array1<A>> = order
+            .iter()
+            .take(keep)
+            .map(|&i| v.slice(s![.., ..m]).dot(&y.column(i)))
+            .collect();
+        let next = v.column(m).to_owned();
+        t.fill(A::zero());
+        for (j, (r, &i)) in ritz.iter().zip(order.iter()).enumerate() {
+            v.column_mut(j).assign(r);
+            t[(j, j)] = A::from_real(theta[i]);
+        }
+        v.column_mut(keep).assign(&next);
+        start = keep;
+    }
+}
+
+/// Extend the Lanczos basis from the `start`-th column to `m` columns, and fill the projected matrix
+///
+/// Returns the norm of the residual `β_m`, and the next Lanczos vector is stored in the `m`-th column.
+pub(crate) fn extend<A, F>(a: &F, v: &mut Array2<A>, t: &mut Array2<A>, start: usize, m: usize) -> A::Real
+where
+    A: Scalar + Lapack,
+    F: LinearOperator<Elem = A>,
+{
+    let n = v.rows();
+    let mut beta = A::Real::zero();
+    for j in start..m {
+        let mut w = a.apply(&v.column(j));
+        let norm_aw = w.norm_l2();
+        let h = orthogonalize(&v.slice(s![.., ..=j]), &mut w);
+        for i in 0..j {
+            t[(i, j)] = h[i];
+            t[(j, i)] = h[i].conj();
+        }
+        t[(j, j)] = A::from_real(h[j].re());
+
+        beta = w.norm_l2();
+        let breakdown = beta <= A::Real::epsilon() * norm_aw;
+        if j + 1 == m {
+            if breakdown {
+                // The subspace is invariant, and all Ritz pairs are exact
+                beta = A::Real::zero();
+                v.column_mut(m).fill(A::zero());
+            } else {
+                azip!(mut vm(v.column_mut(m)), w in { *vm = w.div_real(beta) });
+            }
+            break;
+        }
+        if breakdown {
+            // Continue with a random vector orthogonal to the invariant subspace
+            beta = A::Real::zero();
+            w = random(n);
+            orthogonalize(&v.slice(s![.., ..=j]), &mut w);
+            let norm = w.norm_l2();
+            azip!(mut w(&mut w) in { *w = w.div_real(norm) });
+            v.column_mut(j + 1).assign(&w);
+        } else {
+            azip!(mut vj(v.column_mut(j + 1)), w in { *vj = w.div_real(beta) });
+        }
+        t[(j + 1, j)] = A::from_real(beta);
+        t[(j, j + 1)] = A::from_real(beta);
+    }
+    beta
+}
@@ -0,0 +1,323 @@
+//! LSQR and LSMR methods for large least squares problems
+//!
+//! Both methods solve the damped least squares problem
+//!
+//! ```text
+//! min_x |A x - b|^2 + λ^2 |x|^2
+//! ```
+//!
+//! for an `m x n` operator `A` of any shape using only the products `A v` and `A^H u`,
+//! i.e. an [AdjointOperator](../../operator/trait.AdjointOperator.html).
+//! They are built on the Golub-Kahan bidiagonalization of `A`, and are mathematically equivalent to
+//! CG and MINRES for the normal equation `(A^H A + λ^2 I) x = A^H b` respectively,
+//! but more stable since `A^H A` is never formed.
+//! The residual `A^H (b - A x) - λ^2 x` of the normal equation decreases monotonically in LSMR,
+//! which makes LSMR safer to stop early.
+//!
+//! The error of each iterate is measured by the [StoppingCriterion](../../convergence/enum.StoppingCriterion.html)
+//! from the norm of the residual of the normal equation against `|A^H b|`,
+//! which vanishes for the least squares solution even if `A x = b` is inconsistent.
+//!
+//! - C. C. Paige and M. A. Saunders, LSQR: An algorithm for sparse linear equations and sparse least squares,
+//!   ACM Trans. Math. Softw. 8 (1982)
+//! - D. C.-L. Fong and M. A. Saunders, LSMR: An iterative algorithm for sparse least-squares problems,
+//!   SIAM J. Sci. Comput. 33 (2011)
+//!
+
+use super::*;
+use crate::{convergence::*, error::*, norm::*, operator::AdjointOperator};
+use num_traits::{Float, One, Zero};
+
+/// Solve the damped least squares problem `min |A x - b|^2 + damp^2 |x|^2` by LSQR
+///
+/// - The iteration starts from `x = 0`
+/// - The error of each iterate is measured by `options.criterion` from the estimate of the residual norm of
+///   the normal equation, and the last one is replaced by the true residual norm
+/// - The iteration stops when the criterion is satisfied or after `options.max_iter` iterations,
+///   and `report.converged` tells which happened
+/// - `report.residual_norm` is the norm of the residual `A^H (b - A x) - damp^2 x` of the normal equation
+///
+/// ```
+/// use ndarray::*;
+/// use ndarray_linalg::{krylov::*, *};
+///
+/// // Fit a line `y = c_0 + c_1 t`
+/// let a = arr2(&[[1.0, 0.0], [1.0, 1.0], [1.0, 2.0], [1.0, 3.0]]);
+/// let b = arr1(&[1.0, 2.9, 5.1, 7.0]);
+/// let options = SolverOptions::new().rtol(1e-12).max_iter(10);
+/// let sol = lsqr(&a, &b, 0.0, &options).unwrap();
+/// assert!(sol.report.converged);
+/// assert_close_l2!(&sol.x, &arr1(&[0.97, 2.02]), 1e-10);
+/// ```
+pub fn lsqr<A, F, S>(
+    a: &F,
+    b: &ArrayBase<S, Ix1>,
+    damp: A::Real,
+    options: &SolverOptions<A::Real>,
+) -> Result<LinearSolution<A>>
+where
+    A: Scalar + Lapack,
+    F: AdjointOperator<Elem = A>,
+    S: Data<Elem = A>,
+{
+    lsqr_with_callback(a, b, damp, options, |_| true)
+}
+
+/// [lsqr](fn.lsqr.html) calling `callback` after each iteration
+///
+/// The callback receives the estimated residual norm of the normal equation,
+/// and the iteration stops without convergence if it returns `false`.
+pub fn lsqr_with_callback<A, F, S, C>(
+    a: &F,
+    b: &ArrayBase<S, Ix1>,
+    damp: A::Real,
+    options: &SolverOptions<A::Real>,
+    mut callback: C,
+) -> Result<LinearSolution<A>>
+where
+    A: Scalar + Lapack,
+    F: AdjointOperator<Elem = A>,
+    S: Data<Elem = A>,
+    C: FnMut(&IterationInfo<A::Real>) -> bool,
+{
+    let SolverOptions {
+        criterion, max_iter, ..
+    } = *options;
+    let mut bd = Bidiagonalization::new(a, b)?;
+    let mut monitor = Monitor::new(criterion, &mut callback);
+    let mut x = Array1::zeros(bd.v.len());
+    if monitor.record(bd.initial_error(criterion), bd.normab) {
+        let report = monitor.finish(bd.normab);
+        return Ok(LinearSolution { x, report });
+    }
+    let mut w = bd.v.clone();
+    let (mut rhobar, mut phibar) = (bd.alpha, bd.beta);
+    while monitor.is_running(max_iter) {
+        bd.next(a);
+        // Eliminate the damping term, and then the subdiagonal of the bidiagonal matrix
+        let (cs1, _, rhobar1) = sym_ortho(rhobar, damp);
+        phibar = cs1 * phibar;
+        let (cs, sn, rho) = sym_ortho(rhobar1, bd.beta);
+        let theta = sn * bd.alpha;
+        rhobar = -cs * bd.alpha;
+        let phi = cs * phibar;
+        phibar = sn * phibar;
+
+        let (t1, t2) = (phi / rho, theta / rho);
+        azip!(mut x(&mut x), w(&w) in { *x = *x + w.mul_real(t1) });
+        azip!(mut w(&mut w), v(&bd.v) in { *w = v - w.mul_real(t2) });
+
+        let normar = bd.alpha * Float::abs(sn * phi);
+        if monitor.record(bd.error(criterion, normar, &x), normar) || bd.alpha.is_zero() || bd.beta.is_zero() {
+            break;
+        }
+    }
+    let normar = bd.replace_with_true_error(a, b, damp, &x, &mut monitor);
+    let report = monitor.finish(normar);
+    Ok(LinearSolution { x, report })
+}
+
+/// Solve the damped least squares problem `min |A x - b|^2 + damp^2 |x|^2` by LSMR
+///
+/// The arguments and the result are the same as [lsqr](fn.lsqr.html).
+///
+/// ```
+/// use ndarray::*;
+/// use ndarray_linalg::{krylov::*, *};
+///
+/// // Ridge regression `(A^T A + I) x = A^T b`
+/// let a = arr2(&[[1.0, 0.0], [1.0, 1.0], [1.0, 2.0]]);
+/// let b = arr1(&[1.0, 2.0, 4.0]);
+/// let options = SolverOptions::new().rtol(1e-12).max_iter(10);
+/// let sol = lsmr(&a, &b, 1.0, &options).unwrap();
+/// assert!(sol.report.converged);
+/// let ata = a.t().dot(&a) + Array2::eye(2);
+/// assert_close_l2!(&ata.dot(&sol.x), &a.t().dot(&b), 1e-10);
+/// ```
+pub fn lsmr<A, F, S>(
+    a: &F,
+    b: &ArrayBase<S, Ix1>,
+    damp: A::Real,
+    options: &SolverOptions<A::Real>,
+) -> Result<LinearSolution<A>>
+where
+    A: Scalar + Lapack,
+    F: AdjointOperator<Elem = A>,
+    S: Data<Elem = A>,
+{
+    lsmr_with_callback(a, b, damp, options, |_| true)
+}
+
+/// [lsmr](fn.lsmr.html) calling `callback` after each iteration
+///
+/// The callback receives the estimated residual norm of the normal equation,
+/// and the iteration stops without convergence if it returns `false`.
+pub fn lsmr_with_callback<A, F, S, C>(
+    a: &F,
+    b: &ArrayBase<S, Ix1>,
+    damp: A::Real,
+    options: &SolverOptions<A::Real>,
+    mut callback: C,
+) -> Result<LinearSolution<A>>
+where
+    A: Scalar + Lapack,
+    F: AdjointOperator<Elem = A>,
+    S: Data<Elem = A>,
+    C: FnMut(&IterationInfo<A::Real>) -> bool,
+{
+    let SolverOptions {
+        criterion, max_iter, ..
+    } = *options;
+    let mut bd = Bidiagonalization::new(a, b)?;
+    let mut monitor = Monitor::new(criterion, &mut callback);
+    let n = bd.v.len();
+    let mut x = Array1::zeros(n);
+    if monitor.record(bd.initial_error(criterion), bd.normab) {
+        let report = monitor.finish(bd.normab);
+        return Ok(LinearSolution { x, report });
+    }
+    let zero = A::Real::zero();
+    let one = A::Real::one();
+    let mut zetabar = bd.alpha * bd.beta;
+    let mut alphabar = bd.alpha;
+    let (mut rho, mut rhobar, mut cbar, mut sbar) = (one, one, one, zero);
+    let mut h = bd.v.clone();
+    let mut hbar: Array1<A> = Array1::zeros(n);
+    while monitor.is_running(max_iter) {
+        bd.next(a);
+        // Eliminate the damping term, and then the subdiagonal of the bidiagonal matrix
+        let (_, _, alphahat) = sym_ortho(alphabar, damp);
+        let rho_old = rho;
+        let (c, s, rho_next) = sym_ortho(alphahat, bd.beta);
+        rho = rho_next;
+        let theta_next = s * bd.alpha;
+        alphabar = c * bd.alpha;
+        // Second QR factorization of the upper bidiagonal matrix
+        let rhobar_old = rhobar;
+        let thetabar = sbar * rho;
+        let (cbar_next, sbar_next, rhobar_next) = sym_ortho(cbar * rho, theta_next);
+        cbar = cbar_next;
+        sbar = sbar_next;
+        rhobar = rhobar_next;
+        let zeta = cbar * zetabar;
+        zetabar = -sbar * zetabar;
+
+        let t_hbar = thetabar * rho / (rho_old * rhobar_old);
+        azip!(mut hbar(&mut hbar), h(&h) in { *hbar = h - hbar.mul_real(t_hbar) });
+        let t_x = zeta / (rho * rhobar);
+        azip!(mut x(&mut x), hbar(&hbar) in { *x = *x + hbar.mul_real(t_x) });
+        let t_h = theta_next / rho;
+        azip!(mut h(&mut h), v(&bd.v) in { *h = v - h.mul_real(t_h) });
+
+        let normar = Float::abs(zetabar);
+        if monitor.record(bd.error(criterion, normar, &x), normar) || bd.alpha.is_zero() || bd.beta.is_zero() {
+            break;
+        }
+    }
+    let normar = bd.replace_with_true_error(a, b, damp, &x, &mut monitor);
+    let report = monitor.finish(normar);
+    Ok(LinearSolution { x, report })
+}
+
+/// Golub-Kahan bidiagonalization `β_1 u_1 = b`, `α_1 v_1 = A^H u_1`,
+/// `β_{k+1} u_{k+1} = A v_k - α_k u_k`, and `α_{k+1} v_{k+1} = A^H u_{k+1} - β_{k+1} v_k`
+struct Bidiagonalization<A: Scalar> {
+    u: Array1<A>,
+    v: Array1<A>,
+    alpha: A::Real,
+    beta: A::Real,
+    /// `|A^H b| = α_1 β_1`
+    normab: A::Real,
+}
+
+impl<A: Scalar + Lapack> Bidiagonalization<A> {
+    fn new<F, S>(a: &F, b: &ArrayBase<S, Ix1>) -> Result<Self>
+    where
+        F: AdjointOperator<Elem = A>,
+        S: Data<Elem = A>,
+    {
+        if a.shape().0 != b.len() {
+            return Err(ShapeError::from_kind(ErrorKind::IncompatibleShape).into());
+        }
+        let mut u = b.to_owned();
+        let beta = normalize(&mut u);
+        let mut v = a.apply_adjoint(&u);
+        let alpha = normalize(&mut v);
+        Ok(Bidiagonalization {
+            u,
+            v,
+            alpha,
+            beta,
+            normab: alpha * beta,
+        })
+    }
+
+    fn next<F>(&mut self, a: &F)
+    where
+        F: AdjointOperator<Elem = A>,
+    {
+        let alpha = self.alpha;
+        let mut u = a.apply(&self.v);
+        azip!(mut u(&mut u), u0(&self.u) in { *u = *u - u0.mul_real(alpha) });
+        self.beta = normalize(&mut u);
+        self.u = u;
+        let beta = self.beta;
+        let mut v = a.apply_adjoint(&self.u);
+        azip!(mut v(&mut v), v0(&self.v) in { *v = *v - v0.mul_real(beta) });
+        self.alpha = normalize(&mut v);
+        self.v = v;
+    }
+
+    fn initial_error(&self, criterion: StoppingCriterion<A::Real>) -> A::Real {
+        criterion.error_from_norms(self.normab, self.normab, A::Real::zero(), None)
+    }
+
+    fn error(&self, criterion: StoppingCriterion<A::Real>, normar: A::Real, x: &Array1<A>) -> A::Real {
+        criterion.error_from_norms(normar, self.normab, x.norm_l2(), None)
+    }
+
+    /// Replace the last estimated error by the error of `A^H (b - A x) - damp^2 x`, and return its norm
+    fn replace_with_true_error<F, S>(
+        &self,
+        a: &F,
+        b: &ArrayBase<S, Ix1>,
+        damp: A::Real,
+        x: &Array1<A>,
+        monitor: &mut Monitor<A::Real>,
+    ) -> A::Real
+    where
+        F: AdjointOperator<Elem = A>,
+        S: Data<Elem = A>,
+    {
+        let r = b - &a.apply(x);
+        let damp2 = damp * damp;
+        let normar = Zip::from(&a.apply_adjoint(&r))
+            .and(x)
+            .fold_while(A::Real::zero(), |acc, &ar, &x| {
+                FoldWhile::Continue(acc + (ar - x.mul_real(damp2)).square())
+            })
+            .into_inner();
+        let normar = Float::sqrt(normar);
+        monitor.replace_last(self.error(monitor.criterion(), normar, x));
+        normar
+    }
+}
+
+/// Normalize `x` in place if it does not vanish, and return its norm
+fn normalize<A: Scalar + Lapack>(x: &mut Array1<A>) -> A::Real {
+    let norm = x.norm_l2();
+    if !norm.is_zero() {
+        x.mapv_inplace(|x| x.div_real(norm));
+    }
+    norm
+}
+
+/// Real Givens rotation `(c, s, r)` such that `c a + s b = r` and `-s a + c b = 0`
+fn sym_ortho<R: Float>(a: R, b: R) -> (R, R, R) {
+    let r = Float::hypot(a, b);
+    if r.is_zero() {
+        (R::one(), R::zero(), R::zero())
+    } else {
+        (a / r, b / r, r)
+    }
+}
@@ -0,0 +1,206 @@
+//! MINRES method for Hermitian indefinite systems
+//!
+//! MINRES minimizes the residual norm over the Krylov subspace like [GMRES](../gmres/index.html),
+//! but the Lanczos process of a Hermitian operator gives a short recurrence,
+//! so that it needs only a fixed number of vectors.
+//! Unlike [CG](../cg/index.html), it does not break down for indefinite operators,
+//! e.g. saddle-point (KKT) systems.
+//!
+//! A preconditioner `M^{-1}` must be Hermitian positive definite, and MINRES then minimizes
+//! the residual in the norm `|r|_{M^{-1}} = sqrt(r^H M^{-1} r)`.
+//!
+//! - C. C. Paige and M. A. Saunders, Solution of sparse indefinite systems of linear equations,
+//!   SIAM J. Numer. Anal. 12 (1975)
+//!
+
+use super::*;
+use crate::{convergence::*, error::*, norm::*, operator::LinearOperator};
+use num_traits::{Float, One, Zero};
+
+/// Solve `A x = b` for a Hermitian (or real symmetric) operator `a` by MINRES with the preconditioner `m`
+///
+/// - `m` applies `M^{-1}` for a Hermitian positive definite `M`,
+///   e.g. [IdentityOperator](../../operator/struct.IdentityOperator.html) for no preconditioning
+/// - `x0` is the initial guess, e.g. zeros
+/// - The error of each iterate is measured by `options.criterion` from the residual norm,
+///   where `report.history[0]` is the error of `x0`.
+///   The residual norm is the estimate given by the Lanczos process in the `M^{-1}`-norm,
+///   which is compared with `|b|_{M^{-1}}`, and `ComponentWise` is evaluated as `BackwardError`.
+///   When the estimate converges, it is replaced by the true residual norm `|b - A x|`,
+///   and the method is restarted from the last iterate if this has not converged.
+/// - The iteration stops when the criterion is satisfied or after `options.max_iter` iterations,
+///   and `report.converged` tells which happened
+///
+/// The iteration also stops without convergence if `m` turns out not to be positive definite.
+///
+/// ```
+/// use ndarray::*;
+/// use ndarray_linalg::{krylov::*, *};
+///
+/// // Symmetric indefinite matrix
+/// let a = arr2(&[[1.0, 2.0, 0.0], [2.0, -1.0, 1.0], [0.0, 1.0, 3.0]]);
+/// let b = arr1(&[1.0, 2.0, 3.0]);
+/// let m = IdentityOperator::new();
+/// let options = SolverOptions::new().rtol(1e-12).max_iter(10);
+/// let sol = minres(&a, &m, &b, &Array1::zeros(3), &options).unwrap();
+/// assert!(sol.report.converged);
+/// assert_close_l2!(&a.dot(&sol.x), &b, 1e-10);
+/// ```
+pub fn minres<A, F, P, Sb, Sx>(
+    a: &F,
+    m: &P,
+    b: &ArrayBase<Sb, Ix1>,
+    x0: &ArrayBase<Sx, Ix1>,
+    options: &SolverOptions<A::Real>,
+) -> Result<LinearSolution<A>>
+where
+    A: Scalar + Lapack,
+    F: LinearOperator<Elem = A>,
+    P: LinearOperator<Elem = A>,
+    Sb: Data<Elem = A>,
+    Sx: Data<Elem = A>,
+{
+    minres_with_callback(a, m, b, x0, options, |_| true)
+}
+
+/// [minres](fn.minres.html) calling `callback` after each iteration
+///
+/// The callback receives the residual norm of each iterate estimated in the `M^{-1}`-norm,
+/// and the iteration stops without convergence if it returns `false`.
+pub fn minres_with_callback<A, F, P, Sb, Sx, C>(
+    a: &F,
+    m: &P,
+    b: &ArrayBase<Sb, Ix1>,
+    x0: &ArrayBase<Sx, Ix1>,
+    options: &SolverOptions<A::Real>,
+    mut callback: C,
+) -> Result<LinearSolution<A>>
+where
+    A: Scalar + Lapack,
+    F: LinearOperator<Elem = A>,
+    P: LinearOperator<Elem = A>,
+    Sb: Data<Elem = A>,
+    Sx: Data<Elem = A>,
+    C: FnMut(&IterationInfo<A::Real>) -> bool,
+{
+    let SolverOptions {
+        criterion, max_iter, ..
+    } = *options;
+    let n = b.len();
+    if x0.len() != n {
+        return Err(ShapeError::from_kind(ErrorKind::IncompatibleShape).into());
+    }
+    let mut monitor = Monitor::new(criterion, &mut callback);
+    let mut x = x0.to_owned();
+    let mut r = b - &a.apply(&x);
+    let error = |r: &Array1<A>, x: &Array1<A>| criterion.error(&Residual::new(r.view(), b.view(), x.view()));
+    if !monitor.record(error(&r, &x), r.norm_l2()) {
+        if let Some(b_norm) = m_norm(m, b) {
+            // Restart from the last iterate if the true residual has not converged unlike the estimate
+            while monitor.is_running(max_iter) {
+                let positive = cycle(a, m, r, &mut x, b_norm, &mut monitor, max_iter);
+                r = b - &a.apply(&x);
+                if !positive || monitor.replace_last(error(&r, &x)) {
+                    break;
+                }
+            }
+        }
+    }
+    let report = monitor.finish(r.norm_l2());
+    Ok(LinearSolution { x, report })
+}
+
+/// MINRES iterations from the residual `r1` of `x` until the estimated residual norm converges
+///
+/// Returns `false` if `m` turns out not to be positive definite.
+fn cycle<A, F, P>(
+    a: &F,
+    m: &P,
+    mut r1: Array1<A>,
+    x: &mut Array1<A>,
+    b_norm: A::Real,
+    monitor: &mut Monitor<A::Real>,
+    max_iter: usize,
+) -> bool
+where
+    A: Scalar + Lapack,
+    F: LinearOperator<Elem = A>,
+    P: LinearOperator<Elem = A>,
+{
+    let n = r1.len();
+    let zero = A::Real::zero();
+    let mut y = m.apply(&r1);
+    let beta1 = r1.inner(&y).re();
+    if beta1 < zero {
+        return false;
+    }
+    let beta1 = Float::sqrt(beta1);
+
+    // Lanczos vectors `r1`, `r2`, the search directions `w`, `w1`, `w2`,
+    // and the Givens rotation `(cs, sn)` of the QR factorization of the tridiagonal matrix
+    let mut r2 = r1.clone();
+    let mut w: Array1<A> = Array1::zeros(n);
+    let mut w2: Array1<A> = Array1::zeros(n);
+    let (mut beta, mut old_beta) = (beta1, zero);
+    let (mut dbar, mut epsilon, mut phibar) = (zero, zero, beta1);
+    let (mut cs, mut sn) = (-A::Real::one(), zero);
+    let mut k = 0;
+    while monitor.is_running(max_iter) {
+        let v = y.mapv(|y| y.div_real(beta));
+        y = a.apply(&v);
+        if k > 0 {
+            let c = beta / old_beta;
+            azip!(mut y(&mut y), r1(&r1) in { *y = *y - r1.mul_real(c) });
+        }
+        k += 1;
+        let alpha = v.inner(&y).re();
+        let c = alpha / beta;
+        azip!(mut y(&mut y), r2(&r2) in { *y = *y - r2.mul_real(c) });
+        r1 = ::std::mem::replace(&mut r2, y);
+        y = m.apply(&r2);
+        old_beta = beta;
+        let beta_sq = r2.inner(&y).re();
+        if beta_sq < zero {
+            return false;
+        }
+        beta = Float::sqrt(beta_sq);
+
+        let old_epsilon = epsilon;
+        let delta = cs * dbar + sn * alpha;
+        let gbar = sn * dbar - cs * alpha;
+        epsilon = sn * beta;
+        dbar = -cs * beta;
+        let gamma = Float::max(Float::hypot(gbar, beta), A::Real::epsilon());
+        cs = gbar / gamma;
+        sn = beta / gamma;
+        let phi = cs * phibar;
+        phibar = sn * phibar;
+
+        let w1 = ::std::mem::replace(&mut w2, w);
+        w = Array1::from_shape_fn(n, |i| {
+            (v[i] - w1[i].mul_real(old_epsilon) - w2[i].mul_real(delta)).div_real(gamma)
+        });
+        azip!(mut x(&mut *x), w(&w) in { *x = *x + w.mul_real(phi) });
+
+        let error = monitor.criterion().error_from_norms(phibar, b_norm, x.norm_l2(), None);
+        if monitor.record(error, phibar) || beta.is_zero() {
+            break;
+        }
+    }
+    true
+}
+
+/// `|b|_{M^{-1}} = sqrt(b^H M^{-1} b)`, or `None` if `m` is not positive definite
+fn m_norm<A, P, S>(m: &P, b: &ArrayBase<S, Ix1>) -> Option<A::Real>
+where
+    A: Scalar,
+    P: LinearOperator<Elem = A>,
+    S: Data<Elem = A>,
+{
+    let norm_sq = b.inner(&m.apply(b)).re();
+    if norm_sq < A::Real::zero() {
+        None
+    } else {
+        Some(Float::sqrt(norm_sq))
+    }
+}
@@ -1,15 +1,33 @@
 //! Krylov subspace methods
 
-use crate::types::*;
+use crate::{inner::*, types::*};
 use ndarray::*;
 
 pub mod arnoldi;
+pub mod bicgstab;
+pub mod cg;
+pub mod gmres;
 pub mod householder;
+pub mod iram;
+pub mod lanczos;
+pub mod lsqr;
 pub mod mgs;
+pub mod minres;
+pub mod options;
+pub mod preconditioner;
 
 pub use arnoldi::{arnoldi_householder, arnoldi_mgs, Arnoldi};
+pub use bicgstab::{bicgstab, bicgstab_with_callback};
+pub use cg::{cg, cg_with_callback, pcg, pcg_with_callback};
+pub use gmres::{gmres, gmres_with_callback};
 pub use householder::{householder, Householder};
+pub use iram::{iram, iram_shift_invert, iram_with_callback, IramResult, ShiftInvert};
+pub use lanczos::{lanczos, lanczos_with_callback, LanczosResult};
+pub use lsqr::{lsmr, lsmr_with_callback, lsqr, lsqr_with_callback};
 pub use mgs::{mgs, MGS};
+pub use minres::{minres, minres_with_callback};
+pub use options::{PreconditionerSide, SolverOptions};
+pub use preconditioner::{jacobi, IncompleteCholesky, IncompleteLU};
 
 /// Q-matrix
 ///
@@ -209,3 +227,146 @@ where
     }
     (ortho.get_q(), r)
 }
+
+/// Approximate solution of a linear system computed by a Krylov solver
+#[derive(Debug, Clone)]
+pub struct LinearSolution<A: Scalar> {
+    /// Last iterate
+    pub x: Array1<A>,
+    /// Statistics of the iteration
+    pub report: SolveReport<A::Real>,
+}
+
+/// Statistics of a Krylov solver for linear systems, also used by the iterative eigensolvers
+#[derive(Debug, Clone, PartialEq)]
+pub struct SolveReport<R> {
+    /// Whether the last iterate satisfies the stopping criterion
+    pub converged: bool,
+    /// Number of iterations, which is zero if the initial guess has converged
+    pub iterations: usize,
+    /// Residual norm of the last iterate
+    pub residual_norm: R,
+    /// Errors measured by the stopping criterion for the initial guess and each iteration
+    pub history: Vec<R>,
+}
+
+/// Progress of a Krylov solver or an iterative eigensolver passed to the callback after each iteration
+///
+/// The callback returns whether to continue the iteration,
+/// so that it can log the progress or stop the solver by its own criterion.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct IterationInfo<R> {
+    /// Number of iterations, where `0` stands for the initial guess
+    pub iteration: usize,
+    /// Residual norm of the current iterate, which may be an estimate depending on the solver
+    pub residual_norm: R,
+    /// Error measured by the stopping criterion
+    pub error: R,
+}
+
+/// Convergence history of a Krylov solver with the callback
+pub(crate) struct Monitor<'a, R> {
+    report: crate::convergence::IterationReport<R>,
+    callback: &'a mut dyn FnMut(&IterationInfo<R>) -> bool,
+    stopped: bool,
+}
+
+impl<'a, R: num_traits::Float> Monitor<'a, R> {
+    pub(crate) fn new(
+        criterion: crate::convergence::StoppingCriterion<R>,
+        callback: &'a mut dyn FnMut(&IterationInfo<R>) -> bool,
+    ) -> Self {
+        Monitor {
+            report: crate::convergence::IterationReport::new(criterion),
+            callback,
+            stopped: false,
+        }
+    }
+
+    pub(crate) fn criterion(&self) -> crate::convergence::StoppingCriterion<R> {
+        self.report.criterion
+    }
+
+    /// Record the error of a new iterate, and return whether to stop,
+    /// i.e. it has converged or the callback has asked to stop
+    pub(crate) fn record(&mut self, error: R, residual_norm: R) -> bool {
+        self.report.record(error);
+        let info = IterationInfo {
+            iteration: self.report.iterations() - 1,
+            residual_norm,
+            error,
+        };
+        if !(self.callback)(&info) {
+            self.stopped = true;
+        }
+        self.report.converged || self.stopped
+    }
+
+    /// Replace the error of the last iterate, e.g. an estimate by the true one,
+    /// and return whether it has converged
+    pub(crate) fn replace_last(&mut self, error: R) -> bool {
+        self.report.history.pop();
+        self.report.record(error)
+    }
+
+    /// Whether another iteration is allowed within `max_iter` iterations
+    pub(crate) fn is_running(&self, max_iter: usize) -> bool {
+        !self.stopped && self.report.iterations() <= max_iter
+    }
+
+    pub(crate) fn finish(self, residual_norm: R) -> SolveReport<R> {
+        SolveReport {
+            converged: self.report.converged,
+            iterations: self.report.iterations() - 1,
+            residual_norm,
+            history: self.report.history,
+        }
+    }
+}
+
+/// Part of the spectrum computed by the Krylov eigensolvers
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Which {
+    /// Largest eigenvalues (in real part for non-Hermitian operators)
+    Largest,
+    /// Smallest eigenvalues (in real part for non-Hermitian operators)
+    Smallest,
+    /// Eigenvalues of largest magnitude
+    LargestMagnitude,
+    /// Eigenvalues of smallest magnitude
+    SmallestMagnitude,
+}
+
+impl Which {
+    /// Indices of `values` sorted so that the wanted eigenvalues come first
+    pub(crate) fn sort<T: Scalar>(self, values: &[T]) -> Vec<usize> {
+        let key = |x: &T| match self {
+            Which::Largest => -x.re(),
+            Which::Smallest => x.re(),
+            Which::LargestMagnitude => -x.abs(),
+            Which::SmallestMagnitude => x.abs(),
+        };
+        let mut idx: Vec<usize> = (0..values.len()).collect();
+        idx.sort_by(|&i, &j| key(&values[i]).partial_cmp(&key(&values[j])).unwrap());
+        idx
+    }
+}
+
+/// Full re-orthogonalization of `w` against the columns of `v` by twice-iterated Gram-Schmidt
+///
+/// Returns the coefficients `v^H w` of the original `w`.
+pub(crate) fn orthogonalize<A, S>(v: &ArrayBase<S, Ix2>, w: &mut Array1<A>) -> Array1<A>
+where
+    A: Scalar + Lapack,
+    S: Data<Elem = A>,
+{
+    let mut h = Array1::zeros(v.cols());
+    for _ in 0..2 {
+        for (i, vi) in v.axis_iter(Axis(1)).enumerate() {
+            let c = vi.inner(w);
+            azip!(mut w(&mut *w), vi in { *w = *w - c * vi });
+            h[i] += c;
+        }
+    }
+    h
+}
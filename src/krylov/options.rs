@@ -0,0 +1,118 @@
+//! Options shared by the Krylov linear solvers
+//!
+//! [SolverOptions](struct.SolverOptions.html) collects the stopping criterion, the iteration limits
+//! and the way of preconditioning, so that the solvers take one argument for them:
+//!
+//! ```
+//! use ndarray::*;
+//! use ndarray_linalg::{krylov::*, *};
+//!
+//! let a = arr2(&[[2.0, 1.0, 0.0], [0.0, 2.0, 1.0], [1.0, 0.0, 2.0]]);
+//! let b = arr1(&[1.0, 2.0, 3.0]);
+//! let options = SolverOptions::new().rtol(1e-12).atol(1e-14).max_iter(50).restart(2);
+//! let sol = gmres(&a, &IdentityOperator::new(), &b, &Array1::zeros(3), &options).unwrap();
+//! assert!(sol.report.converged);
+//! assert_close_l2!(&a.dot(&sol.x), &b, 1e-10);
+//! ```
+
+use crate::convergence::StoppingCriterion;
+use num_traits::Float;
+
+/// Side on which a preconditioner `M^{-1}` is applied
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum PreconditionerSide {
+    /// Solve `M^{-1} A x = M^{-1} b`
+    ///
+    /// The solver minimizes or measures the preconditioned residual `M^{-1} (b - A x)`,
+    /// which is replaced by the true residual for the last iterate.
+    Left,
+    /// Solve `A M^{-1} u = b` with `x = M^{-1} u`, where the residual is that of the original system
+    Right,
+}
+
+/// Options of the Krylov linear solvers
+///
+/// The fields are set by the builder methods, where [new](#method.new) gives the defaults.
+/// The solvers which do not use some of the fields ignore them,
+/// e.g. `restart` is only used by GMRES, and `side` by GMRES and BiCGSTAB
+/// (CG and MINRES apply a Hermitian positive definite preconditioner symmetrically).
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct SolverOptions<R> {
+    /// Criterion of the convergence, `RelativeResidual` with the square root of the machine epsilon by default
+    pub criterion: StoppingCriterion<R>,
+    /// Maximal number of iterations, 1000 by default
+    pub max_iter: usize,
+    /// Number of iterations between the restarts of GMRES, 30 by default
+    pub restart: usize,
+    /// Side of the preconditioner, `Right` by default
+    pub side: PreconditionerSide,
+}
+
+impl<R: Float> Default for SolverOptions<R> {
+    fn default() -> Self {
+        SolverOptions {
+            criterion: StoppingCriterion::default(),
+            max_iter: 1000,
+            restart: 30,
+            side: PreconditionerSide::Right,
+        }
+    }
+}
+
+impl<R: Float> SolverOptions<R> {
+    /// Default options
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Stop when `|b - A x| <= max(rtol |b|, atol)`
+    ///
+    /// This replaces the criterion by `StoppingCriterion::Mixed`, keeping `atol` set by [atol](#method.atol).
+    pub fn rtol(self, rtol: R) -> Self {
+        let (_, atol) = self.tolerances();
+        self.criterion(StoppingCriterion::Mixed { rtol, atol })
+    }
+
+    /// Stop when `|b - A x| <= max(rtol |b|, atol)`
+    ///
+    /// This replaces the criterion by `StoppingCriterion::Mixed`, keeping `rtol` set by [rtol](#method.rtol)
+    /// or the tolerance of the default criterion.
+    pub fn atol(self, atol: R) -> Self {
+        let (rtol, _) = self.tolerances();
+        self.criterion(StoppingCriterion::Mixed { rtol, atol })
+    }
+
+    /// Use any other criterion, e.g. the backward error
+    pub fn criterion(mut self, criterion: StoppingCriterion<R>) -> Self {
+        self.criterion = criterion;
+        self
+    }
+
+    /// Maximal number of iterations
+    pub fn max_iter(mut self, max_iter: usize) -> Self {
+        self.max_iter = max_iter;
+        self
+    }
+
+    /// Number of iterations between the restarts of GMRES
+    pub fn restart(mut self, restart: usize) -> Self {
+        self.restart = restart;
+        self
+    }
+
+    /// Side of the preconditioner
+    pub fn side(mut self, side: PreconditionerSide) -> Self {
+        self.side = side;
+        self
+    }
+
+    /// `(rtol, atol)` of the current criterion
+    fn tolerances(&self) -> (R, R) {
+        match self.criterion {
+            StoppingCriterion::Mixed { rtol, atol } => (rtol, atol),
+            StoppingCriterion::Absolute(atol) => (R::zero(), atol),
+            StoppingCriterion::RelativeResidual(rtol) => (rtol, R::zero()),
+            _ => (self.criterion.tolerance(), R::zero()),
+        }
+    }
+}
@@ -0,0 +1,269 @@
+//! Preconditioners for the Krylov solvers
+//!
+//! A preconditioner is a [LinearOperator](../../operator/trait.LinearOperator.html) applying `M^{-1}`
+//! for some `M ≈ A` which is much easier to invert than `A`.
+//!
+//! - [jacobi](fn.jacobi.html) takes `M = diag(A)`, which removes bad scaling of the rows and columns.
+//! - [IncompleteCholesky](struct.IncompleteCholesky.html) takes `M = L L^H` where `L` is the Cholesky factor
+//!   computed only on the non-zero pattern of `A`, i.e. IC(0).
+//! - [IncompleteLU](struct.IncompleteLU.html) takes `M = L U` computed only on the non-zero pattern of
+//!   a sparse [CsrMatrix](../../csr/struct.CsrMatrix.html) or a [BandedMatrix](../../banded/struct.BandedMatrix.html),
+//!   i.e. ILU(0), for general operators. For Hermitian matrices, this is IC(0) in the `L D L^H` form.
+//!
+//! Any other operator, e.g. a [CholeskyFactorized](../../cholesky/struct.CholeskyFactorized.html)
+//! of an approximation of `A`, can also be used as a preconditioner.
+
+use super::*;
+use crate::{banded::*, csr::*, diagonal::*, error::*, layout::*, operator::LinearOperator, triangular::*, UPLO};
+use num_traits::{Float, Zero};
+
+/// Jacobi (diagonal) preconditioner `M^{-1} = diag(A)^{-1}`
+///
+/// Fails with `LinalgError::Lapack { return_code: i }` if the `i`-th diagonal element is zero.
+pub fn jacobi<A, S>(a: &ArrayBase<S, Ix2>) -> Result<Diagonal<OwnedRepr<A>>>
+where
+    A: Scalar,
+    S: Data<Elem = A>,
+{
+    a.ensure_square()?;
+    if let Some(i) = a.diag().iter().position(|d| d.is_zero()) {
+        return Err(LinalgError::Lapack {
+            return_code: i as i32 + 1,
+        });
+    }
+    Ok(a.diag().mapv(|d| A::one() / d).into_diagonal())
+}
+
+/// Incomplete Cholesky factorization without fill-in, IC(0), of a Hermitian positive definite matrix
+///
+/// The lower triangular factor `L` has the same non-zero pattern as the lower triangular part of `A`,
+/// and `L L^H` agrees with `A` on this pattern.
+/// As a [LinearOperator](../../operator/trait.LinearOperator.html) it applies `(L L^H)^{-1}`
+/// by two triangular solves.
+/// For matrices without zeros in the lower triangular part, e.g. tridiagonal matrices,
+/// this is the exact Cholesky factorization.
+#[derive(Debug, Clone)]
+pub struct IncompleteCholesky<A> {
+    l: Array2<A>,
+    lh: Array2<A>,
+}
+
+impl<A: Scalar + Lapack> IncompleteCholesky<A> {
+    /// Factorize using the lower triangular part of `a`
+    ///
+    /// The factorization may break down even for positive definite `a`,
+    /// and then fails with `LinalgError::Lapack { return_code: k }` for the non-positive `k`-th pivot.
+    pub fn new<S>(a: &ArrayBase<S, Ix2>) -> Result<Self>
+    where
+        S: Data<Elem = A>,
+    {
+        a.ensure_square()?;
+        let n = a.rows();
+        let mut l = Array2::from_shape_fn((n, n), |(i, j)| if i >= j { a[(i, j)] } else { A::zero() });
+        for k in 0..n {
+            let d = l[(k, k)].re();
+            if d <= A::Real::zero() {
+                return Err(LinalgError::Lapack {
+                    return_code: k as i32 + 1,
+                });
+            }
+            let lkk = Float::sqrt(d);
+            l[(k, k)] = A::from_real(lkk);
+            for i in k + 1..n {
+                l[(i, k)] = l[(i, k)].div_real(lkk);
+            }
+            // Update the trailing part only on the non-zero pattern of `a`
+            for j in k + 1..n {
+                let ljk = l[(j, k)].conj();
+                if ljk.is_zero() {
+                    continue;
+                }
+                for i in j..n {
+                    if i == j || !a[(i, j)].is_zero() {
+                        l[(i, j)] = l[(i, j)] - l[(i, k)] * ljk;
+                    }
+                }
+            }
+        }
+        let lh = l.t().mapv(|x| x.conj());
+        Ok(IncompleteCholesky { l, lh })
+    }
+
+    /// Lower triangular factor `L`
+    pub fn factor(&self) -> ArrayView2<A> {
+        self.l.view()
+    }
+}
+
+impl<A: Scalar + Lapack> LinearOperator for IncompleteCholesky<A> {
+    type Elem = A;
+
+    fn apply<S>(&self, a: &ArrayBase<S, Ix1>) -> Array1<A>
+    where
+        S: Data<Elem = A>,
+    {
+        let y = self
+            .l
+            .solve_triangular_into(UPLO::Lower, Diag::NonUnit, a.to_owned())
+            .expect("IC(0) factor must be non-singular");
+        self.lh
+            .solve_triangular_into(UPLO::Upper, Diag::NonUnit, y)
+            .expect("IC(0) factor must be non-singular")
+    }
+}
+
+/// Incomplete LU factorization without fill-in, ILU(0), of a sparse matrix
+///
+/// The unit lower triangular `L` and the upper triangular `U` have the same non-zero pattern as `A`,
+/// and `L U` agrees with `A` on this pattern.
+/// As a [LinearOperator](../../operator/trait.LinearOperator.html) it applies `(L U)^{-1}`
+/// by the forward and backward substitutions in `O(nnz)` time.
+///
+/// For a Hermitian matrix, `U = D L^H` with the real [pivots](#method.pivots) `D`,
+/// i.e. this is IC(0) in the `L D L^H` form, and it is positive definite if all the pivots are positive.
+///
+/// ```
+/// use ndarray::*;
+/// use ndarray_linalg::{krylov::*, *};
+///
+/// let a = arr2(&[[4.0, -1.0, 0.0], [-1.0, 4.0, -1.0], [0.0, -1.0, 4.0]]);
+/// let m = IncompleteLU::new(&CsrMatrix::from_dense(&a)).unwrap();
+/// // Exact for tridiagonal matrices
+/// let x = m.apply(&arr1(&[1.0, 2.0, 3.0]));
+/// assert_close_l2!(&a.dot(&x), &arr1(&[1.0, 2.0, 3.0]), 1e-12);
+/// ```
+#[derive(Debug, Clone)]
+pub struct IncompleteLU<A> {
+    /// `L - I + U` on the pattern of `A`
+    lu: CsrMatrix<A>,
+    /// Positions of the diagonal elements in `lu.data()`
+    diag: Vec<usize>,
+}
+
+impl<A: Scalar> IncompleteLU<A> {
+    /// Factorize a square sparse matrix
+    ///
+    /// Fails with `LinalgError::Lapack { return_code: k }` if the `k`-th pivot vanishes,
+    /// including the case where the `k`-th diagonal element is not stored.
+    pub fn new(a: &CsrMatrix<A>) -> Result<Self> {
+        let (n, cols) = a.shape();
+        if n != cols {
+            return Err(LinalgError::NotSquare {
+                rows: n as i32,
+                cols: cols as i32,
+            });
+        }
+        let indptr = a.indptr();
+        let indices = a.indices();
+        let mut data = a.data().to_vec();
+        let mut diag = Vec::with_capacity(n);
+        // Position of each column in the current row
+        let mut pos: Vec<Option<usize>> = vec![None; n];
+        for i in 0..n {
+            let row = indptr[i]..indptr[i + 1];
+            for k in row.clone() {
+                pos[indices[k]] = Some(k);
+            }
+            let d = match pos[i] {
+                Some(d) => d,
+                None => {
+                    return Err(LinalgError::Lapack {
+                        return_code: i as i32 + 1,
+                    })
+                }
+            };
+            // Eliminate `A[(i, k)]` for `k < i` by the `k`-th row of `U` restricted to the pattern of the `i`-th row
+            for ik in indptr[i]..d {
+                let k = indices[ik];
+                let lik = data[ik] / data[diag[k]];
+                data[ik] = lik;
+                for kj in diag[k] + 1..indptr[k + 1] {
+                    if let Some(ij) = pos[indices[kj]] {
+                        data[ij] = data[ij] - lik * data[kj];
+                    }
+                }
+            }
+            if data[d].is_zero() {
+                return Err(LinalgError::Lapack {
+                    return_code: i as i32 + 1,
+                });
+            }
+            diag.push(d);
+            for k in row {
+                pos[indices[k]] = None;
+            }
+        }
+        let lu = CsrMatrix::from_raw_parts((n, n), indptr.to_vec(), indices.to_vec(), data);
+        Ok(IncompleteLU { lu, diag })
+    }
+
+    /// Factorize the non-zero elements of a band matrix
+    pub fn from_banded(a: &BandedMatrix<A>) -> Result<Self> {
+        Self::new(&CsrMatrix::from_banded(a))
+    }
+
+    /// Unit lower triangular factor `L`
+    pub fn lower(&self) -> CsrMatrix<A> {
+        self.triangle(true)
+    }
+
+    /// Upper triangular factor `U`
+    pub fn upper(&self) -> CsrMatrix<A> {
+        self.triangle(false)
+    }
+
+    /// Diagonal elements of `U`
+    pub fn pivots(&self) -> Array1<A> {
+        self.diag.iter().map(|&d| self.lu.data()[d]).collect()
+    }
+
+    fn triangle(&self, lower: bool) -> CsrMatrix<A> {
+        let n = self.diag.len();
+        let mut indptr = vec![0];
+        let mut indices = Vec::new();
+        let mut data = Vec::new();
+        for i in 0..n {
+            let range = self.lu.row_range(i);
+            let range = if lower {
+                range.start..self.diag[i]
+            } else {
+                self.diag[i]..range.end
+            };
+            indices.extend_from_slice(&self.lu.indices()[range.clone()]);
+            data.extend_from_slice(&self.lu.data()[range]);
+            if lower {
+                indices.push(i);
+                data.push(A::one());
+            }
+            indptr.push(data.len());
+        }
+        CsrMatrix::from_raw_parts((n, n), indptr, indices, data)
+    }
+}
+
+impl<A: Scalar> LinearOperator for IncompleteLU<A> {
+    type Elem = A;
+
+    fn apply_mut<S>(&self, a: &mut ArrayBase<S, Ix1>)
+    where
+        S: DataMut<Elem = A>,
+    {
+        let n = self.diag.len();
+        assert_eq!(a.len(), n);
+        let indices = self.lu.indices();
+        let data = self.lu.data();
+        // Forward substitution `L y = a`
+        for i in 0..n {
+            let start = self.lu.row_range(i).start;
+            let s = (start..self.diag[i]).fold(a[i], |acc, k| acc - data[k] * a[indices[k]]);
+            a[i] = s;
+        }
+        // Backward substitution `U x = y`
+        for i in (0..n).rev() {
+            let end = self.lu.row_range(i).end;
+            let d = self.diag[i];
+            let s = (d + 1..end).fold(a[i], |acc, k| acc - data[k] * a[indices[k]]);
+            a[i] = s / data[d];
+        }
+    }
+}
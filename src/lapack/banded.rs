@@ -0,0 +1,142 @@
+//! Solve linear problems with band matrices
+//!
+//! The matrices are stored in the LAPACK band storage in column-major order.
+//! See also [the manual of dgbtrf](http://www.netlib.org/lapack/explore-html/dc/db2/dgbtrf_8f.html)
+
+use lapacke;
+use num_traits::Zero;
+
+use crate::error::*;
+use crate::types::*;
+
+use super::{into_result, Pivot, Transpose, UPLO};
+
+/// Wraps `*gbtrf` and `*gbtrs`
+pub trait Banded_: Scalar + Sized {
+    /// LU factorization of the `n x n` band matrix with `kl` sub-diagonals and `ku` super-diagonals
+    ///
+    /// `ab` is the `(2 kl + ku + 1) x n` band storage, whose leading `kl` rows are overwritten by the fill-in.
+    unsafe fn lu_banded(n: i32, kl: i32, ku: i32, ab: &mut [Self]) -> Result<Pivot>;
+    /// Solve `A x = b` (or its transpose) using the LU factorization computed by `lu_banded`
+    unsafe fn solve_banded(
+        n: i32,
+        kl: i32,
+        ku: i32,
+        t: Transpose,
+        ab: &[Self],
+        ipiv: &Pivot,
+        b: &mut [Self],
+    ) -> Result<()>;
+}
+
+macro_rules! impl_banded {
+    ($scalar:ty, $gbtrf:path, $gbtrs:path) => {
+        impl Banded_ for $scalar {
+            unsafe fn lu_banded(n: i32, kl: i32, ku: i32, ab: &mut [Self]) -> Result<Pivot> {
+                let mut ipiv = vec![0; n as usize];
+                let ldab = 2 * kl + ku + 1;
+                let info = $gbtrf(lapacke::Layout::ColumnMajor, n, n, kl, ku, ab, ldab, &mut ipiv);
+                into_result(info, ipiv)
+            }
+
+            unsafe fn solve_banded(
+                n: i32,
+                kl: i32,
+                ku: i32,
+                t: Transpose,
+                ab: &[Self],
+                ipiv: &Pivot,
+                b: &mut [Self],
+            ) -> Result<()> {
+                let ldab = 2 * kl + ku + 1;
+                let nrhs = 1;
+                let info = $gbtrs(
+                    lapacke::Layout::ColumnMajor,
+                    t as u8,
+                    n,
+                    kl,
+                    ku,
+                    nrhs,
+                    ab,
+                    ldab,
+                    ipiv,
+                    b,
+                    n,
+                );
+                into_result(info, ())
+            }
+        }
+    };
+} // impl_banded!
+
+impl_banded!(f64, lapacke::dgbtrf, lapacke::dgbtrs);
+impl_banded!(f32, lapacke::sgbtrf, lapacke::sgbtrs);
+impl_banded!(c64, lapacke::zgbtrf, lapacke::zgbtrs);
+impl_banded!(c32, lapacke::cgbtrf, lapacke::cgbtrs);
+
+/// Wraps `*pbtrf`, `*pbtrs`, and `*pbcon`
+pub trait BandedCholesky_: Scalar + Sized {
+    /// Cholesky factorization of the `n x n` Hermitian positive definite band matrix with `kd` off-diagonals
+    ///
+    /// `ab` is the `(kd + 1) x n` band storage of the triangular part specified by `uplo`.
+    unsafe fn cholesky_banded(uplo: UPLO, n: i32, kd: i32, ab: &mut [Self]) -> Result<()>;
+    /// Solve `A x = b` using the Cholesky factorization computed by `cholesky_banded`
+    unsafe fn solve_cholesky_banded(uplo: UPLO, n: i32, kd: i32, ab: &[Self], b: &mut [Self]) -> Result<()>;
+    /// Estimates the reciprocal of the condition number in 1-norm from the Cholesky factorization
+    ///
+    /// `anorm` should be the 1-norm of the original matrix.
+    unsafe fn rcond_cholesky_banded(uplo: UPLO, n: i32, kd: i32, ab: &[Self], anorm: Self::Real) -> Result<Self::Real>;
+}
+
+macro_rules! impl_banded_cholesky {
+    ($scalar:ty, $pbtrf:path, $pbtrs:path, $pbcon:path) => {
+        impl BandedCholesky_ for $scalar {
+            unsafe fn cholesky_banded(uplo: UPLO, n: i32, kd: i32, ab: &mut [Self]) -> Result<()> {
+                let info = $pbtrf(lapacke::Layout::ColumnMajor, uplo as u8, n, kd, ab, kd + 1);
+                into_result(info, ())
+            }
+
+            unsafe fn solve_cholesky_banded(uplo: UPLO, n: i32, kd: i32, ab: &[Self], b: &mut [Self]) -> Result<()> {
+                let nrhs = 1;
+                let info = $pbtrs(
+                    lapacke::Layout::ColumnMajor,
+                    uplo as u8,
+                    n,
+                    kd,
+                    nrhs,
+                    ab,
+                    kd + 1,
+                    b,
+                    n,
+                );
+                into_result(info, ())
+            }
+
+            unsafe fn rcond_cholesky_banded(
+                uplo: UPLO,
+                n: i32,
+                kd: i32,
+                ab: &[Self],
+                anorm: Self::Real,
+            ) -> Result<Self::Real> {
+                let mut rcond = Self::Real::zero();
+                let info = $pbcon(
+                    lapacke::Layout::ColumnMajor,
+                    uplo as u8,
+                    n,
+                    kd,
+                    ab,
+                    kd + 1,
+                    anorm,
+                    &mut rcond,
+                );
+                into_result(info, rcond)
+            }
+        }
+    };
+} // impl_banded_cholesky!
+
+impl_banded_cholesky!(f64, lapacke::dpbtrf, lapacke::dpbtrs, lapacke::dpbcon);
+impl_banded_cholesky!(f32, lapacke::spbtrf, lapacke::spbtrs, lapacke::spbcon);
+impl_banded_cholesky!(c64, lapacke::zpbtrf, lapacke::zpbtrs, lapacke::zpbcon);
+impl_banded_cholesky!(c32, lapacke::cpbtrf, lapacke::cpbtrs, lapacke::cpbcon);
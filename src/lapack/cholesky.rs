@@ -1,14 +1,23 @@
 //! Cholesky decomposition
 
+// The `native` feature replaces the LAPACK implementations below by the kernels of `native`
+#![cfg_attr(feature = "native", allow(dead_code, unused_imports, unused_macros))]
+
 use lapacke;
+use ndarray::LinalgScalar;
+use std::cmp::max;
 
 use crate::error::*;
 use crate::layout::MatrixLayout;
 use crate::types::*;
 
-use super::{into_result, UPLO};
+use super::{conjugate_inplace, into_fortran, into_result, UPLO};
 
-pub trait Cholesky_: Sized {
+/// Wraps `*potrf`, `*potri` and `*potrs`
+///
+/// A Hermitian matrix in the C layout is read as its transpose `A^T = conj(A)` in the Fortran layout without copy,
+/// whose factor `U = L^T` (`L = U^T`) in the opposite triangle is `L` (`U`) of `A` in the C layout.
+pub trait Cholesky_: LinalgScalar {
     /// Cholesky: wrapper of `*potrf`
     ///
     /// **Warning: Only the portion of `a` corresponding to `UPLO` is written.**
@@ -21,33 +30,59 @@ pub trait Cholesky_: Sized {
     unsafe fn solve_cholesky(l: MatrixLayout, uplo: UPLO, a: &[Self], b: &mut [Self]) -> Result<()>;
 }
 
+/// Fortran layout and `uplo` of the same triangle on the memory
+fn fortran_uplo(l: MatrixLayout, uplo: UPLO) -> (MatrixLayout, UPLO, bool) {
+    let (l, transposed) = into_fortran(l);
+    let uplo = match (transposed, uplo) {
+        (true, UPLO::Upper) => UPLO::Lower,
+        (true, UPLO::Lower) => UPLO::Upper,
+        (false, uplo) => uplo,
+    };
+    (l, uplo, transposed)
+}
+
 macro_rules! impl_cholesky {
     ($scalar:ty, $trf:path, $tri:path, $trs:path) => {
         impl Cholesky_ for $scalar {
             unsafe fn cholesky(l: MatrixLayout, uplo: UPLO, a: &mut [Self]) -> Result<()> {
+                let (l, uplo, _) = fortran_uplo(l, uplo);
                 let (n, _) = l.size();
-                let info = $trf(l.lapacke_layout(), uplo as u8, n, a, n);
+                let info = $trf(l.lapacke_layout(), uplo as u8, n, a, l.lda());
                 into_result(info, ())
             }
 
             unsafe fn inv_cholesky(l: MatrixLayout, uplo: UPLO, a: &mut [Self]) -> Result<()> {
+                // `conj(A)^{-1} = (A^{-1})^T` in the Fortran layout is `A^{-1}` in the C layout
+                let (l, uplo, _) = fortran_uplo(l, uplo);
                 let (n, _) = l.size();
                 let info = $tri(l.lapacke_layout(), uplo as u8, n, a, l.lda());
                 into_result(info, ())
             }
 
             unsafe fn solve_cholesky(l: MatrixLayout, uplo: UPLO, a: &[Self], b: &mut [Self]) -> Result<()> {
+                // `A x = b` is `conj(A) conj(x) = conj(b)`
+                let (l, uplo, transposed) = fortran_uplo(l, uplo);
+                if transposed {
+                    conjugate_inplace(b);
+                }
                 let (n, _) = l.size();
                 let nrhs = 1;
-                let ldb = 1;
+                let ldb = max(1, n);
                 let info = $trs(l.lapacke_layout(), uplo as u8, n, nrhs, a, l.lda(), b, ldb);
+                if transposed {
+                    conjugate_inplace(b);
+                }
                 into_result(info, ())
             }
         }
     };
 } // end macro_rules
 
+#[cfg(not(feature = "native"))]
 impl_cholesky!(f64, lapacke::dpotrf, lapacke::dpotri, lapacke::dpotrs);
+#[cfg(not(feature = "native"))]
 impl_cholesky!(f32, lapacke::spotrf, lapacke::spotri, lapacke::spotrs);
+#[cfg(not(feature = "native"))]
 impl_cholesky!(c64, lapacke::zpotrf, lapacke::zpotri, lapacke::zpotrs);
+#[cfg(not(feature = "native"))]
 impl_cholesky!(c32, lapacke::cpotrf, lapacke::cpotri, lapacke::cpotrs);
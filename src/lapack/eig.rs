@@ -0,0 +1,571 @@
+//! Eigenvalue decomposition for general matrices
+
+use lapacke;
+use num_complex::Complex;
+use num_traits::{Float, Zero};
+use std::cmp::max;
+
+use crate::error::*;
+use crate::layout::MatrixLayout;
+use crate::types::*;
+
+use super::{into_result, lwork, vec_uninit, WorkSize};
+
+/// Balancing of a general matrix `A' = D^{-1} P^T A P D` by a permutation `P` and a diagonal scaling `D`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[repr(u8)]
+pub enum Balance {
+    /// Neither permute nor scale
+    None = b'N',
+    /// Permute only
+    Permute = b'P',
+    /// Scale only
+    Scale = b'S',
+    /// Both permute and scale, which is what `*geev` does
+    Both = b'B',
+}
+
+impl Default for Balance {
+    fn default() -> Self {
+        Balance::Both
+    }
+}
+
+/// Result of `*geevx`
+pub struct EigOutput<A: Scalar> {
+    /// Eigenvalues
+    pub eigs: Vec<A::Complex>,
+    /// Left eigenvectors `u_j^H A = λ_j u_j^H` stored as columns
+    pub vl: Option<Vec<A::Complex>>,
+    /// Right eigenvectors `A v_j = λ_j v_j` stored as columns
+    pub vr: Option<Vec<A::Complex>>,
+}
+
+/// Wraps `*geevx`
+///
+/// The eigenvectors are stored in the same layout as the input matrix,
+/// and back-transformed to those of the original (unbalanced) matrix.
+pub trait Eig_: Scalar {
+    unsafe fn eig(
+        l: MatrixLayout,
+        balance: Balance,
+        calc_vl: bool,
+        calc_vr: bool,
+        a: &mut [Self],
+    ) -> Result<EigOutput<Self>> {
+        let size = Self::eig_work_size(l, balance, calc_vl, calc_vr)?;
+        let mut work = vec![Self::zero(); size.work];
+        let mut rwork = vec![Self::Real::zero(); size.rwork];
+        Self::eig_with_work(l, balance, calc_vl, calc_vr, a, &mut work, &mut rwork)
+    }
+
+    /// Sizes of the work arrays of `eig_with_work`, queried by `lwork = -1`
+    unsafe fn eig_work_size(l: MatrixLayout, balance: Balance, calc_vl: bool, calc_vr: bool) -> Result<WorkSize>;
+
+    /// `eig` using the work arrays of the sizes given by `eig_work_size`
+    unsafe fn eig_with_work(
+        l: MatrixLayout,
+        balance: Balance,
+        calc_vl: bool,
+        calc_vr: bool,
+        a: &mut [Self],
+        work: &mut [Self],
+        rwork: &mut [Self::Real],
+    ) -> Result<EigOutput<Self>>;
+}
+
+macro_rules! impl_eig_real {
+    ($scalar:ty, $ev:path) => {
+        impl Eig_ for $scalar {
+            unsafe fn eig_work_size(
+                l: MatrixLayout,
+                balance: Balance,
+                calc_vl: bool,
+                calc_vr: bool,
+            ) -> Result<WorkSize> {
+                let (n, _) = l.size();
+                let ld = max(1, n);
+                let (mut ilo, mut ihi) = (0, 0);
+                let mut abnrm = Self::Real::zero();
+                let mut work = [Self::zero()];
+                let info = $ev(
+                    l.lapacke_layout(),
+                    balance as u8,
+                    if calc_vl { b'V' } else { b'N' },
+                    if calc_vr { b'V' } else { b'N' },
+                    b'N',
+                    n,
+                    &mut [],
+                    l.lda(),
+                    &mut [],
+                    &mut [],
+                    &mut [],
+                    ld,
+                    &mut [],
+                    ld,
+                    &mut ilo,
+                    &mut ihi,
+                    &mut [],
+                    &mut abnrm,
+                    &mut [],
+                    &mut [],
+                    &mut work,
+                    -1,
+                    &mut [],
+                );
+                into_result(
+                    info,
+                    WorkSize {
+                        work: lwork(work[0]),
+                        rwork: 0,
+                    },
+                )
+            }
+
+            unsafe fn eig_with_work(
+                l: MatrixLayout,
+                balance: Balance,
+                calc_vl: bool,
+                calc_vr: bool,
+                a: &mut [Self],
+                work: &mut [Self],
+                _rwork: &mut [Self::Real],
+            ) -> Result<EigOutput<Self>> {
+                let (n, _) = l.size();
+                let ld = max(1, n);
+                let (mut ilo, mut ihi) = (0, 0);
+                let mut scale = vec![Self::Real::zero(); n as usize];
+                let mut abnrm = Self::Real::zero();
+                let mut rconde = vec![Self::Real::zero(); n as usize];
+                let mut rcondv = vec![Self::Real::zero(); n as usize];
+                let mut wr = vec![Self::zero(); n as usize];
+                let mut wi = vec![Self::zero(); n as usize];
+                let (jobvl, mut vl) = if calc_vl {
+                    (b'V', vec_uninit((n * n) as usize))
+                } else {
+                    (b'N', Vec::new())
+                };
+                let (jobvr, mut vr) = if calc_vr {
+                    (b'V', vec_uninit((n * n) as usize))
+                } else {
+                    (b'N', Vec::new())
+                };
+                let info = $ev(
+                    l.lapacke_layout(),
+                    balance as u8,
+                    jobvl,
+                    jobvr,
+                    b'N',
+                    n,
+                    a,
+                    l.lda(),
+                    &mut wr,
+                    &mut wi,
+                    &mut vl,
+                    ld,
+                    &mut vr,
+                    ld,
+                    &mut ilo,
+                    &mut ihi,
+                    &mut scale,
+                    &mut abnrm,
+                    &mut rconde,
+                    &mut rcondv,
+                    work,
+                    work.len() as i32,
+                    &mut [],
+                );
+                into_result(info, ())?;
+                let eigs = wr
+                    .iter()
+                    .zip(wi.iter())
+                    .map(|(&re, &im)| Complex::new(re, im))
+                    .collect();
+                Ok(EigOutput {
+                    eigs,
+                    vl: if calc_vl {
+                        Some(reconstruct_eigenvectors(l, &wi, &vl))
+                    } else {
+                        None
+                    },
+                    vr: if calc_vr {
+                        Some(reconstruct_eigenvectors(l, &wi, &vr))
+                    } else {
+                        None
+                    },
+                })
+            }
+        }
+    };
+} // impl_eig_real!
+
+macro_rules! impl_eig_complex {
+    ($scalar:ty, $ev:path) => {
+        impl Eig_ for $scalar {
+            unsafe fn eig_work_size(
+                l: MatrixLayout,
+                balance: Balance,
+                calc_vl: bool,
+                calc_vr: bool,
+            ) -> Result<WorkSize> {
+                let (n, _) = l.size();
+                let ld = max(1, n);
+                let (mut ilo, mut ihi) = (0, 0);
+                let mut abnrm = Self::Real::zero();
+                let mut work = [Self::zero()];
+                let info = $ev(
+                    l.lapacke_layout(),
+                    balance as u8,
+                    if calc_vl { b'V' } else { b'N' },
+                    if calc_vr { b'V' } else { b'N' },
+                    b'N',
+                    n,
+                    &mut [],
+                    l.lda(),
+                    &mut [],
+                    &mut [],
+                    ld,
+                    &mut [],
+                    ld,
+                    &mut ilo,
+                    &mut ihi,
+                    &mut [],
+                    &mut abnrm,
+                    &mut [],
+                    &mut [],
+                    &mut work,
+                    -1,
+                    &mut [],
+                );
+                into_result(
+                    info,
+                    WorkSize {
+                        work: lwork(work[0]),
+                        rwork: 2 * n as usize,
+                    },
+                )
+            }
+
+            unsafe fn eig_with_work(
+                l: MatrixLayout,
+                balance: Balance,
+                calc_vl: bool,
+                calc_vr: bool,
+                a: &mut [Self],
+                work: &mut [Self],
+                rwork: &mut [Self::Real],
+            ) -> Result<EigOutput<Self>> {
+                let (n, _) = l.size();
+                let ld = max(1, n);
+                let (mut ilo, mut ihi) = (0, 0);
+                let mut scale = vec![Self::Real::zero(); n as usize];
+                let mut abnrm = Self::Real::zero();
+                let mut rconde = vec![Self::Real::zero(); n as usize];
+                let mut rcondv = vec![Self::Real::zero(); n as usize];
+                let mut eigs = vec![Self::zero(); n as usize];
+                let (jobvl, mut vl) = if calc_vl {
+                    (b'V', vec_uninit((n * n) as usize))
+                } else {
+                    (b'N', Vec::new())
+                };
+                let (jobvr, mut vr) = if calc_vr {
+                    (b'V', vec_uninit((n * n) as usize))
+                } else {
+                    (b'N', Vec::new())
+                };
+                let info = $ev(
+                    l.lapacke_layout(),
+                    balance as u8,
+                    jobvl,
+                    jobvr,
+                    b'N',
+                    n,
+                    a,
+                    l.lda(),
+                    &mut eigs,
+                    &mut vl,
+                    ld,
+                    &mut vr,
+                    ld,
+                    &mut ilo,
+                    &mut ihi,
+                    &mut scale,
+                    &mut abnrm,
+                    &mut rconde,
+                    &mut rcondv,
+                    work,
+                    work.len() as i32,
+                    rwork,
+                );
+                into_result(
+                    info,
+                    EigOutput {
+                        eigs,
+                        vl: if calc_vl { Some(vl) } else { None },
+                        vr: if calc_vr { Some(vr) } else { None },
+                    },
+                )
+            }
+        }
+    };
+} // impl_eig_complex!
+
+impl_eig_real!(f64, lapacke::dgeevx_work);
+impl_eig_real!(f32, lapacke::sgeevx_work);
+impl_eig_complex!(c64, lapacke::zgeevx_work);
+impl_eig_complex!(c32, lapacke::cgeevx_work);
+
+/// Result of `*ggev`
+///
+/// The generalized eigenvalues are `λ_j = α_j / β_j`, where `β_j` may vanish for infinite eigenvalues.
+pub struct EigGeneralizedOutput<A: Scalar> {
+    pub alpha: Vec<A::Complex>,
+    pub beta: Vec<A::Complex>,
+    /// Left eigenvectors `u_j^H A = λ_j u_j^H B` stored as columns
+    pub vl: Option<Vec<A::Complex>>,
+    /// Right eigenvectors `A v_j = λ_j B v_j` stored as columns
+    pub vr: Option<Vec<A::Complex>>,
+}
+
+/// Wraps `*ggev`
+///
+/// `a` and `b` must be stored in the same layout `l`, which is also used for the eigenvectors.
+pub trait EigGeneralized_: Scalar {
+    unsafe fn eig_generalized(
+        l: MatrixLayout,
+        calc_vl: bool,
+        calc_vr: bool,
+        a: &mut [Self],
+        b: &mut [Self],
+    ) -> Result<EigGeneralizedOutput<Self>>;
+}
+
+macro_rules! impl_eig_generalized_real {
+    ($scalar:ty, $ggev:path) => {
+        impl EigGeneralized_ for $scalar {
+            unsafe fn eig_generalized(
+                l: MatrixLayout,
+                calc_vl: bool,
+                calc_vr: bool,
+                a: &mut [Self],
+                b: &mut [Self],
+            ) -> Result<EigGeneralizedOutput<Self>> {
+                let (n, _) = l.size();
+                let ld = max(1, n);
+                let mut alphar = vec![Self::zero(); n as usize];
+                let mut alphai = vec![Self::zero(); n as usize];
+                let mut beta = vec![Self::zero(); n as usize];
+                let (jobvl, mut vl) = if calc_vl {
+                    (b'V', vec_uninit((n * n) as usize))
+                } else {
+                    (b'N', Vec::new())
+                };
+                let (jobvr, mut vr) = if calc_vr {
+                    (b'V', vec_uninit((n * n) as usize))
+                } else {
+                    (b'N', Vec::new())
+                };
+                let info = $ggev(
+                    l.lapacke_layout(),
+                    jobvl,
+                    jobvr,
+                    n,
+                    a,
+                    l.lda(),
+                    b,
+                    l.lda(),
+                    &mut alphar,
+                    &mut alphai,
+                    &mut beta,
+                    &mut vl,
+                    ld,
+                    &mut vr,
+                    ld,
+                );
+                into_result(info, ())?;
+                let alpha = alphar
+                    .iter()
+                    .zip(alphai.iter())
+                    .map(|(&re, &im)| Complex::new(re, im))
+                    .collect();
+                let beta = beta.iter().map(|&re| Complex::new(re, Self::zero())).collect();
+                Ok(EigGeneralizedOutput {
+                    alpha,
+                    beta,
+                    vl: if calc_vl {
+                        Some(reconstruct_eigenvectors(l, &alphai, &vl))
+                    } else {
+                        None
+                    },
+                    vr: if calc_vr {
+                        Some(reconstruct_eigenvectors(l, &alphai, &vr))
+                    } else {
+                        None
+                    },
+                })
+            }
+        }
+    };
+} // impl_eig_generalized_real!
+
+macro_rules! impl_eig_generalized_complex {
+    ($scalar:ty, $ggev:path) => {
+        impl EigGeneralized_ for $scalar {
+            unsafe fn eig_generalized(
+                l: MatrixLayout,
+                calc_vl: bool,
+                calc_vr: bool,
+                a: &mut [Self],
+                b: &mut [Self],
+            ) -> Result<EigGeneralizedOutput<Self>> {
+                let (n, _) = l.size();
+                let ld = max(1, n);
+                let mut alpha = vec![Self::zero(); n as usize];
+                let mut beta = vec![Self::zero(); n as usize];
+                let (jobvl, mut vl) = if calc_vl {
+                    (b'V', vec_uninit((n * n) as usize))
+                } else {
+                    (b'N', Vec::new())
+                };
+                let (jobvr, mut vr) = if calc_vr {
+                    (b'V', vec_uninit((n * n) as usize))
+                } else {
+                    (b'N', Vec::new())
+                };
+                let info = $ggev(
+                    l.lapacke_layout(),
+                    jobvl,
+                    jobvr,
+                    n,
+                    a,
+                    l.lda(),
+                    b,
+                    l.lda(),
+                    &mut alpha,
+                    &mut beta,
+                    &mut vl,
+                    ld,
+                    &mut vr,
+                    ld,
+                );
+                into_result(
+                    info,
+                    EigGeneralizedOutput {
+                        alpha,
+                        beta,
+                        vl: if calc_vl { Some(vl) } else { None },
+                        vr: if calc_vr { Some(vr) } else { None },
+                    },
+                )
+            }
+        }
+    };
+} // impl_eig_generalized_complex!
+
+impl_eig_generalized_real!(f64, lapacke::dggev);
+impl_eig_generalized_real!(f32, lapacke::sggev);
+impl_eig_generalized_complex!(c64, lapacke::zggev);
+impl_eig_generalized_complex!(c32, lapacke::cggev);
+
+/// Construct complex eigenvectors from the real output of `*geevx` or `*ggev`
+///
+/// For a complex conjugate pair `(λ_j, λ_{j+1})`, LAPACK returns the real part in the `j`-th column
+/// and the imaginary part in the `(j+1)`-th column.
+fn reconstruct_eigenvectors<T: Float>(l: MatrixLayout, wi: &[T], v: &[T]) -> Vec<Complex<T>> {
+    let n = wi.len();
+    let idx = |i: usize, j: usize| match l {
+        MatrixLayout::C(_) => i * n + j,
+        MatrixLayout::F(_) => i + j * n,
+    };
+    let mut out = vec![Complex::zero(); n * n];
+    let mut j = 0;
+    while j < n {
+        if wi[j].is_zero() {
+            for i in 0..n {
+                out[idx(i, j)] = Complex::new(v[idx(i, j)], T::zero());
+            }
+            j += 1;
+        } else {
+            for i in 0..n {
+                let re = v[idx(i, j)];
+                let im = v[idx(i, j + 1)];
+                out[idx(i, j)] = Complex::new(re, im);
+                out[idx(i, j + 1)] = Complex::new(re, -im);
+            }
+            j += 2;
+        }
+    }
+    out
+}
+
+/// Wraps `*gebal` and `*gebak`
+pub trait Balance_: Scalar {
+    /// Balance `a` in place, and returns `(ilo, ihi, scale)` (`ilo` and `ihi` are 1-origin)
+    unsafe fn balance(l: MatrixLayout, job: Balance, a: &mut [Self]) -> Result<(i32, i32, Vec<Self::Real>)>;
+
+    /// Transform the left (`left = true`) or right eigenvectors `v` of the balanced matrix
+    /// to those of the original matrix
+    unsafe fn balance_back(
+        l: MatrixLayout,
+        job: Balance,
+        left: bool,
+        ilo: i32,
+        ihi: i32,
+        scale: &[Self::Real],
+        v: &mut [Self],
+    ) -> Result<()>;
+}
+
+macro_rules! impl_balance {
+    ($scalar:ty, $gebal:path, $gebak:path) => {
+        impl Balance_ for $scalar {
+            unsafe fn balance(l: MatrixLayout, job: Balance, a: &mut [Self]) -> Result<(i32, i32, Vec<Self::Real>)> {
+                let (n, _) = l.size();
+                let (mut ilo, mut ihi) = (0, 0);
+                let mut scale = vec![Self::Real::zero(); n as usize];
+                let info = $gebal(
+                    l.lapacke_layout(),
+                    job as u8,
+                    n,
+                    a,
+                    l.lda(),
+                    &mut ilo,
+                    &mut ihi,
+                    &mut scale,
+                );
+                into_result(info, (ilo, ihi, scale))
+            }
+
+            unsafe fn balance_back(
+                l: MatrixLayout,
+                job: Balance,
+                left: bool,
+                ilo: i32,
+                ihi: i32,
+                scale: &[Self::Real],
+                v: &mut [Self],
+            ) -> Result<()> {
+                let (n, m) = l.size();
+                let side = if left { b'L' } else { b'R' };
+                let info = $gebak(
+                    l.lapacke_layout(),
+                    job as u8,
+                    side,
+                    n,
+                    ilo,
+                    ihi,
+                    scale,
+                    m,
+                    v,
+                    l.lda(),
+                );
+                into_result(info, ())
+            }
+        }
+    };
+} // impl_balance!
+
+impl_balance!(f64, lapacke::dgebal, lapacke::dgebak);
+impl_balance!(f32, lapacke::sgebal, lapacke::sgebak);
+impl_balance!(c64, lapacke::zgebal, lapacke::zgebak);
+impl_balance!(c32, lapacke::cgebal, lapacke::cgebak);
@@ -2,12 +2,13 @@
 
 use lapacke;
 use num_traits::Zero;
+use std::cmp::max;
 
 use crate::error::*;
 use crate::layout::MatrixLayout;
 use crate::types::*;
 
-use super::{into_result, UPLO};
+use super::{into_result, vec_uninit, UPLO};
 
 /// Wraps `*syev` for real and `*heev` for complex
 pub trait Eigh_: Scalar {
@@ -32,3 +33,379 @@ impl_eigh!(f64, lapacke::dsyev);
 impl_eigh!(f32, lapacke::ssyev);
 impl_eigh!(c64, lapacke::zheev);
 impl_eigh!(c32, lapacke::cheev);
+
+/// Type of the generalized Hermitian-definite eigenproblem solved by `*sygv` and `*hegv`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[repr(i32)]
+pub enum EighGeneralizedType {
+    /// `A x = λ B x`, where the eigenvectors are normalized as `Z^H B Z = I`
+    AxLambdaBx = 1,
+    /// `A B x = λ x`, where the eigenvectors are normalized as `Z^H B Z = I`
+    ABxLambdaX = 2,
+    /// `B A x = λ x`, where the eigenvectors are normalized as `Z^H B^{-1} Z = I`
+    BAxLambdaX = 3,
+}
+
+/// Wraps `*sygv` for real and `*hegv` for complex
+///
+/// `b` must be positive definite, and is overwritten by its Cholesky factor.
+pub trait EighGeneralized_: Scalar {
+    unsafe fn eigh_generalized(
+        calc_eigenvec: bool,
+        l: MatrixLayout,
+        itype: EighGeneralizedType,
+        uplo: UPLO,
+        a: &mut [Self],
+        b: &mut [Self],
+    ) -> Result<Vec<Self::Real>>;
+}
+
+macro_rules! impl_eigh_generalized {
+    ($scalar:ty, $gv:path) => {
+        impl EighGeneralized_ for $scalar {
+            unsafe fn eigh_generalized(
+                calc_v: bool,
+                l: MatrixLayout,
+                itype: EighGeneralizedType,
+                uplo: UPLO,
+                a: &mut [Self],
+                b: &mut [Self],
+            ) -> Result<Vec<Self::Real>> {
+                let (n, _) = l.size();
+                let jobz = if calc_v { b'V' } else { b'N' };
+                let mut w = vec![Self::Real::zero(); n as usize];
+                let info = $gv(
+                    l.lapacke_layout(),
+                    itype as i32,
+                    jobz,
+                    uplo as u8,
+                    n,
+                    a,
+                    l.lda(),
+                    b,
+                    l.lda(),
+                    &mut w,
+                );
+                into_result(info, w)
+            }
+        }
+    };
+} // impl_eigh_generalized!
+
+impl_eigh_generalized!(f64, lapacke::dsygv);
+impl_eigh_generalized!(f32, lapacke::ssygv);
+impl_eigh_generalized!(c64, lapacke::zhegv);
+impl_eigh_generalized!(c32, lapacke::chegv);
+
+/// Range of the eigenvalues to be computed
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum EigRange<R> {
+    /// All eigenvalues
+    All,
+    /// Eigenvalues in the half-open interval `(lower, upper]`
+    Value(R, R),
+    /// Eigenvalues from `start`-th to `(end - 1)`-th in ascending order (0-origin)
+    Index(usize, usize),
+}
+
+/// Wraps `*syevr` for real and `*heevr` for complex
+///
+/// Returns the selected eigenvalues and the eigenvectors, which are stored in the leading
+/// columns of `n x n` matrix of the layout `l`.
+pub trait EighRange_: Scalar {
+    unsafe fn eigh_range(
+        calc_eigenvec: bool,
+        l: MatrixLayout,
+        uplo: UPLO,
+        range: EigRange<Self::Real>,
+        a: &mut [Self],
+    ) -> Result<(Vec<Self::Real>, Vec<Self>)>;
+}
+
+macro_rules! impl_eigh_range {
+    ($scalar:ty, $evr:path) => {
+        impl EighRange_ for $scalar {
+            unsafe fn eigh_range(
+                calc_v: bool,
+                l: MatrixLayout,
+                uplo: UPLO,
+                range: EigRange<Self::Real>,
+                a: &mut [Self],
+            ) -> Result<(Vec<Self::Real>, Vec<Self>)> {
+                let (n, _) = l.size();
+                let jobz = if calc_v { b'V' } else { b'N' };
+                let zero = Self::Real::zero();
+                let (range, vl, vu, il, iu) = match range {
+                    EigRange::All => (b'A', zero, zero, 0, 0),
+                    EigRange::Value(lower, upper) => (b'V', lower, upper, 0, 0),
+                    EigRange::Index(start, end) => (b'I', zero, zero, start as i32 + 1, end as i32),
+                };
+                let ldz = max(1, n);
+                let mut m = 0;
+                let mut w = vec![Self::Real::zero(); n as usize];
+                let mut z = if calc_v {
+                    vec![Self::zero(); (n * n) as usize]
+                } else {
+                    Vec::new()
+                };
+                let mut isuppz = vec![0; 2 * ldz as usize];
+                let info = $evr(
+                    l.lapacke_layout(),
+                    jobz,
+                    range,
+                    uplo as u8,
+                    n,
+                    a,
+                    l.lda(),
+                    vl,
+                    vu,
+                    il,
+                    iu,
+                    zero,
+                    &mut m,
+                    &mut w,
+                    &mut z,
+                    ldz,
+                    &mut isuppz,
+                );
+                w.truncate(m as usize);
+                into_result(info, (w, z))
+            }
+        }
+    };
+} // impl_eigh_range!
+
+impl_eigh_range!(f64, lapacke::dsyevr);
+impl_eigh_range!(f32, lapacke::ssyevr);
+impl_eigh_range!(c64, lapacke::zheevr);
+impl_eigh_range!(c32, lapacke::cheevr);
+
+/// Driver routine for the symmetric/Hermitian eigenvalue problem
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EighDriver {
+    /// QR iteration (`*syev`/`*heev`)
+    Ev,
+    /// Divide-and-conquer (`*syevd`/`*heevd`), fastest for large matrices with eigenvectors
+    Evd,
+    /// Multiple relatively robust representations (`*syevr`/`*heevr`)
+    Evr,
+    /// Bisection and inverse iteration (`*syevx`/`*heevx`)
+    Evx,
+}
+
+impl Default for EighDriver {
+    fn default() -> Self {
+        EighDriver::Ev
+    }
+}
+
+/// Wraps `*syev`, `*syevd`, `*syevr`, and `*syevx` (or `*heev*` for complex)
+///
+/// As in `Eigh_`, `a` is overwritten by the eigenvectors if `calc_eigenvec`.
+pub trait EighDriver_: Scalar {
+    unsafe fn eigh_driver(
+        calc_eigenvec: bool,
+        l: MatrixLayout,
+        uplo: UPLO,
+        driver: EighDriver,
+        a: &mut [Self],
+    ) -> Result<Vec<Self::Real>>;
+}
+
+macro_rules! impl_eigh_driver {
+    ($scalar:ty, $evd:path, $evr:path, $evx:path) => {
+        impl EighDriver_ for $scalar {
+            unsafe fn eigh_driver(
+                calc_v: bool,
+                l: MatrixLayout,
+                uplo: UPLO,
+                driver: EighDriver,
+                a: &mut [Self],
+            ) -> Result<Vec<Self::Real>> {
+                let (n, _) = l.size();
+                let lda = l.lda();
+                let jobz = if calc_v { b'V' } else { b'N' };
+                let zero = Self::Real::zero();
+                let mut w = vec![zero; n as usize];
+                // `*syevr` and `*syevx` return the eigenvectors separately
+                let separate_z = calc_v && (driver == EighDriver::Evr || driver == EighDriver::Evx);
+                let mut z = if separate_z {
+                    vec_uninit(a.len())
+                } else {
+                    Vec::new()
+                };
+                let mut m = 0;
+                let info = match driver {
+                    EighDriver::Ev => return Self::eigh(calc_v, l, uplo, a),
+                    EighDriver::Evd => $evd(l.lapacke_layout(), jobz, uplo as u8, n, a, lda, &mut w),
+                    EighDriver::Evr => {
+                        let mut isuppz = vec![0; 2 * max(1, n) as usize];
+                        $evr(
+                            l.lapacke_layout(),
+                            jobz,
+                            b'A',
+                            uplo as u8,
+                            n,
+                            a,
+                            lda,
+                            zero,
+                            zero,
+                            0,
+                            0,
+                            zero,
+                            &mut m,
+                            &mut w,
+                            &mut z,
+                            lda,
+                            &mut isuppz,
+                        )
+                    }
+                    EighDriver::Evx => {
+                        let mut ifail = vec![0; n as usize];
+                        $evx(
+                            l.lapacke_layout(),
+                            jobz,
+                            b'A',
+                            uplo as u8,
+                            n,
+                            a,
+                            lda,
+                            zero,
+                            zero,
+                            0,
+                            0,
+                            zero,
+                            &mut m,
+                            &mut w,
+                            &mut z,
+                            lda,
+                            &mut ifail,
+                        )
+                    }
+                };
+                into_result(info, ())?;
+                if separate_z {
+                    a.copy_from_slice(&z);
+                }
+                Ok(w)
+            }
+        }
+    };
+} // impl_eigh_driver!
+
+impl_eigh_driver!(f64, lapacke::dsyevd, lapacke::dsyevr, lapacke::dsyevx);
+impl_eigh_driver!(f32, lapacke::ssyevd, lapacke::ssyevr, lapacke::ssyevx);
+impl_eigh_driver!(c64, lapacke::zheevd, lapacke::zheevr, lapacke::zheevx);
+impl_eigh_driver!(c32, lapacke::cheevd, lapacke::cheevr, lapacke::cheevx);
+
+/// Wraps `*stev` and `*stemr` for real symmetric tridiagonal matrices
+///
+/// The matrix is given by its diagonal `d` (length `n`) and off-diagonal `e` (length `n - 1`).
+/// These routines work on `Self::Real` so that they are also available for complex scalar types,
+/// e.g. to post-process the Lanczos iteration of a Hermitian operator.
+/// The eigenvectors are stored in Fortran layout.
+pub trait EighTridiagonal_: Scalar {
+    /// Compute all eigenvalues and, if `calc_eigenvec`, eigenvectors (`n x n`) by `*stev`
+    unsafe fn eigh_tridiagonal(
+        calc_eigenvec: bool,
+        d: &[Self::Real],
+        e: &[Self::Real],
+    ) -> Result<(Vec<Self::Real>, Vec<Self::Real>)>;
+
+    /// Compute the eigenvalues in `range` and, if `calc_eigenvec`, the eigenvectors (`n x m`) by `*stemr`
+    unsafe fn eigh_tridiagonal_range(
+        calc_eigenvec: bool,
+        range: EigRange<Self::Real>,
+        d: &[Self::Real],
+        e: &[Self::Real],
+    ) -> Result<(Vec<Self::Real>, Vec<Self::Real>)>;
+}
+
+macro_rules! impl_eigh_tridiagonal {
+    ($scalar:ty, $stev:path, $stemr:path) => {
+        impl EighTridiagonal_ for $scalar {
+            unsafe fn eigh_tridiagonal(
+                calc_v: bool,
+                d: &[Self::Real],
+                e: &[Self::Real],
+            ) -> Result<(Vec<Self::Real>, Vec<Self::Real>)> {
+                let n = d.len() as i32;
+                let jobz = if calc_v { b'V' } else { b'N' };
+                let mut w = d.to_vec();
+                let mut e = e.to_vec();
+                let mut z = if calc_v {
+                    vec_uninit((n * n) as usize)
+                } else {
+                    Vec::new()
+                };
+                let info = $stev(
+                    lapacke::Layout::ColumnMajor,
+                    jobz,
+                    n,
+                    &mut w,
+                    &mut e,
+                    &mut z,
+                    max(1, n),
+                );
+                into_result(info, (w, z))
+            }
+
+            unsafe fn eigh_tridiagonal_range(
+                calc_v: bool,
+                range: EigRange<Self::Real>,
+                d: &[Self::Real],
+                e: &[Self::Real],
+            ) -> Result<(Vec<Self::Real>, Vec<Self::Real>)> {
+                let n = d.len() as i32;
+                let jobz = if calc_v { b'V' } else { b'N' };
+                let zero = Self::Real::zero();
+                let (range, vl, vu, il, iu) = match range {
+                    EigRange::All => (b'A', zero, zero, 0, 0),
+                    EigRange::Value(lower, upper) => (b'V', lower, upper, 0, 0),
+                    EigRange::Index(start, end) => (b'I', zero, zero, start as i32 + 1, end as i32),
+                };
+                let ldz = max(1, n);
+                let mut d = d.to_vec();
+                // `*stemr` uses `e[n - 1]` as workspace
+                let mut e_work = vec![zero; n as usize];
+                e_work[..e.len()].copy_from_slice(e);
+                let mut m = 0;
+                let mut w = vec![zero; n as usize];
+                let mut z = if calc_v {
+                    vec![zero; (ldz * n) as usize]
+                } else {
+                    Vec::new()
+                };
+                let mut isuppz = vec![0; 2 * ldz as usize];
+                let mut tryrac = 1;
+                let info = $stemr(
+                    lapacke::Layout::ColumnMajor,
+                    jobz,
+                    range,
+                    n,
+                    &mut d,
+                    &mut e_work,
+                    vl,
+                    vu,
+                    il,
+                    iu,
+                    &mut m,
+                    &mut w,
+                    &mut z,
+                    ldz,
+                    n,
+                    &mut isuppz,
+                    &mut tryrac,
+                );
+                w.truncate(m as usize);
+                z.truncate((ldz * m) as usize);
+                into_result(info, (w, z))
+            }
+        }
+    };
+} // impl_eigh_tridiagonal!
+
+impl_eigh_tridiagonal!(f64, lapacke::dstev, lapacke::dstemr);
+impl_eigh_tridiagonal!(f32, lapacke::sstev, lapacke::sstemr);
+impl_eigh_tridiagonal!(c64, lapacke::dstev, lapacke::dstemr);
+impl_eigh_tridiagonal!(c32, lapacke::sstev, lapacke::sstemr);
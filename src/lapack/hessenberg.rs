@@ -0,0 +1,50 @@
+//! Hessenberg reduction
+
+use lapacke;
+use num_traits::Zero;
+
+use crate::error::*;
+use crate::layout::MatrixLayout;
+use crate::types::*;
+
+use super::into_result;
+
+/// Wraps `*gehrd` and `*orghr` (`*unghr` for complex numbers)
+pub trait Hessenberg_: Sized {
+    unsafe fn hessenberg_householder(l: MatrixLayout, a: &mut [Self]) -> Result<Vec<Self>>;
+    unsafe fn hessenberg_q(l: MatrixLayout, a: &mut [Self], tau: &[Self]) -> Result<()>;
+    /// Compute `A = Q H Q^H`. `a` is overwritten by `Q`, and `H` is returned
+    /// with the Householder reflectors left below its subdiagonal.
+    unsafe fn hessenberg(l: MatrixLayout, a: &mut [Self]) -> Result<Vec<Self>>;
+}
+
+macro_rules! impl_hessenberg {
+    ($scalar:ty, $hrd:path, $ghr:path) => {
+        impl Hessenberg_ for $scalar {
+            unsafe fn hessenberg_householder(l: MatrixLayout, mut a: &mut [Self]) -> Result<Vec<Self>> {
+                let (n, _) = l.size();
+                let mut tau = vec![Self::zero(); (n - 1).max(0) as usize];
+                let info = $hrd(l.lapacke_layout(), n, 1, n, &mut a, l.lda(), &mut tau);
+                into_result(info, tau)
+            }
+
+            unsafe fn hessenberg_q(l: MatrixLayout, mut a: &mut [Self], tau: &[Self]) -> Result<()> {
+                let (n, _) = l.size();
+                let info = $ghr(l.lapacke_layout(), n, 1, n, &mut a, l.lda(), &tau);
+                into_result(info, ())
+            }
+
+            unsafe fn hessenberg(l: MatrixLayout, a: &mut [Self]) -> Result<Vec<Self>> {
+                let tau = Self::hessenberg_householder(l, a)?;
+                let h = Vec::from(&*a);
+                Self::hessenberg_q(l, a, &tau)?;
+                Ok(h)
+            }
+        }
+    };
+} // endmacro
+
+impl_hessenberg!(f64, lapacke::dgehrd, lapacke::dorghr);
+impl_hessenberg!(f32, lapacke::sgehrd, lapacke::sorghr);
+impl_hessenberg!(c64, lapacke::zgehrd, lapacke::zunghr);
+impl_hessenberg!(c32, lapacke::cgehrd, lapacke::cunghr);
@@ -0,0 +1,278 @@
+//! Least squares
+
+use lapacke;
+use num_traits::Zero;
+use std::cmp::min;
+
+use crate::error::*;
+use crate::layout::MatrixLayout;
+use crate::types::*;
+
+use super::into_result;
+
+/// Result of the least-squares drivers
+pub struct LeastSquaresOutput<A: Scalar> {
+    /// Singular values of `A` in decreasing order
+    pub singular_values: Vec<A::Real>,
+    /// Effective rank of `A`
+    pub rank: i32,
+}
+
+/// Wraps `*gels`
+///
+/// `a` must have full rank. `b` must have `max(m, n)` rows and the same order as `a`,
+/// and on exit the first `n` rows hold the solution.
+pub trait LeastSquaresQr_: Scalar {
+    unsafe fn least_squares_qr(al: MatrixLayout, bl: MatrixLayout, a: &mut [Self], b: &mut [Self]) -> Result<()>;
+}
+
+macro_rules! impl_least_squares_qr {
+    ($scalar:ty, $gels:path) => {
+        impl LeastSquaresQr_ for $scalar {
+            unsafe fn least_squares_qr(
+                al: MatrixLayout,
+                bl: MatrixLayout,
+                a: &mut [Self],
+                b: &mut [Self],
+            ) -> Result<()> {
+                assert!(al.same_order(&bl));
+                let (m, n) = al.size();
+                let (_, nrhs) = bl.size();
+                let info = $gels(al.lapacke_layout(), b'N', m, n, nrhs, a, al.lda(), b, bl.lda());
+                into_result(info, ())
+            }
+        }
+    };
+} // impl_least_squares_qr!
+
+impl_least_squares_qr!(f64, lapacke::dgels);
+impl_least_squares_qr!(f32, lapacke::sgels);
+impl_least_squares_qr!(c64, lapacke::zgels);
+impl_least_squares_qr!(c32, lapacke::cgels);
+
+/// Driver routine for the rank-deficient least-squares problem
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LeastSquaresDriver {
+    /// Singular value decomposition by QR iteration (`*gelss`)
+    Svd,
+    /// Singular value decomposition by divide-and-conquer (`*gelsd`), fastest among the SVD-based drivers
+    SvdDc,
+    /// Complete orthogonal factorization using QR with column pivoting (`*gelsy`),
+    /// which is faster than the SVD but does not compute the singular values
+    Pivoted,
+}
+
+impl Default for LeastSquaresDriver {
+    fn default() -> Self {
+        LeastSquaresDriver::SvdDc
+    }
+}
+
+/// Wraps `*gelsy`
+///
+/// `b` must have `max(m, n)` rows and the same order as `a`. On exit, the first `n` rows hold the solution.
+/// The effective rank is the order of the largest leading triangular block of `R`
+/// in the QR factorization with column pivoting, whose estimated condition number is less than `1 / rcond`.
+pub trait LeastSquaresPivoted_: Scalar {
+    unsafe fn least_squares_pivoted(
+        al: MatrixLayout,
+        bl: MatrixLayout,
+        a: &mut [Self],
+        b: &mut [Self],
+        rcond: Self::Real,
+    ) -> Result<i32>;
+}
+
+macro_rules! impl_least_squares_pivoted {
+    ($scalar:ty, $gelsy:path) => {
+        impl LeastSquaresPivoted_ for $scalar {
+            unsafe fn least_squares_pivoted(
+                al: MatrixLayout,
+                bl: MatrixLayout,
+                a: &mut [Self],
+                b: &mut [Self],
+                rcond: Self::Real,
+            ) -> Result<i32> {
+                assert!(al.same_order(&bl));
+                let (m, n) = al.size();
+                let (_, nrhs) = bl.size();
+                // All columns are free to be pivoted
+                let mut jpvt = vec![0; n as usize];
+                let mut rank = 0;
+                let info = $gelsy(
+                    al.lapacke_layout(),
+                    m,
+                    n,
+                    nrhs,
+                    a,
+                    al.lda(),
+                    b,
+                    bl.lda(),
+                    &mut jpvt,
+                    rcond,
+                    &mut rank,
+                );
+                into_result(info, rank)
+            }
+        }
+    };
+} // impl_least_squares_pivoted!
+
+impl_least_squares_pivoted!(f64, lapacke::dgelsy);
+impl_least_squares_pivoted!(f32, lapacke::sgelsy);
+impl_least_squares_pivoted!(c64, lapacke::zgelsy);
+impl_least_squares_pivoted!(c32, lapacke::cgelsy);
+
+/// Wraps `*gelss` and `*gelsd`
+///
+/// `b` must have `max(m, n)` rows and the same order as `a`. On exit, the first `n` rows hold the solution.
+/// Singular values `s[i] <= rcond * s[0]` are treated as zero, and a negative `rcond` means the machine precision.
+pub trait LeastSquaresSvd_: Scalar {
+    unsafe fn least_squares_svd(
+        al: MatrixLayout,
+        bl: MatrixLayout,
+        a: &mut [Self],
+        b: &mut [Self],
+        rcond: Self::Real,
+    ) -> Result<LeastSquaresOutput<Self>>;
+    unsafe fn least_squares_svddc(
+        al: MatrixLayout,
+        bl: MatrixLayout,
+        a: &mut [Self],
+        b: &mut [Self],
+        rcond: Self::Real,
+    ) -> Result<LeastSquaresOutput<Self>>;
+}
+
+macro_rules! impl_least_squares {
+    ($scalar:ty, $gelss:path, $gelsd:path) => {
+        impl LeastSquaresSvd_ for $scalar {
+            unsafe fn least_squares_svd(
+                al: MatrixLayout,
+                bl: MatrixLayout,
+                a: &mut [Self],
+                b: &mut [Self],
+                rcond: Self::Real,
+            ) -> Result<LeastSquaresOutput<Self>> {
+                assert!(al.same_order(&bl));
+                let (m, n) = al.size();
+                let (_, nrhs) = bl.size();
+                let mut rank = 0;
+                let mut singular_values = vec![Self::Real::zero(); min(m, n) as usize];
+                let info = $gelss(
+                    al.lapacke_layout(),
+                    m,
+                    n,
+                    nrhs,
+                    a,
+                    al.lda(),
+                    b,
+                    bl.lda(),
+                    &mut singular_values,
+                    rcond,
+                    &mut rank,
+                );
+                into_result(
+                    info,
+                    LeastSquaresOutput {
+                        singular_values,
+                        rank,
+                    },
+                )
+            }
+
+            unsafe fn least_squares_svddc(
+                al: MatrixLayout,
+                bl: MatrixLayout,
+                a: &mut [Self],
+                b: &mut [Self],
+                rcond: Self::Real,
+            ) -> Result<LeastSquaresOutput<Self>> {
+                assert!(al.same_order(&bl));
+                let (m, n) = al.size();
+                let (_, nrhs) = bl.size();
+                let mut rank = 0;
+                let mut singular_values = vec![Self::Real::zero(); min(m, n) as usize];
+                let info = $gelsd(
+                    al.lapacke_layout(),
+                    m,
+                    n,
+                    nrhs,
+                    a,
+                    al.lda(),
+                    b,
+                    bl.lda(),
+                    &mut singular_values,
+                    rcond,
+                    &mut rank,
+                );
+                into_result(
+                    info,
+                    LeastSquaresOutput {
+                        singular_values,
+                        rank,
+                    },
+                )
+            }
+        }
+    };
+} // impl_least_squares!
+
+impl_least_squares!(f64, lapacke::dgelss, lapacke::dgelsd);
+impl_least_squares!(f32, lapacke::sgelss, lapacke::sgelsd);
+impl_least_squares!(c64, lapacke::zgelss, lapacke::zgelsd);
+impl_least_squares!(c32, lapacke::cgelss, lapacke::cgelsd);
+
+/// Wraps `*ggglm`
+///
+/// Solve the general Gauss-Markov linear model problem `min |y|_2` subject to `d = A x + B y`,
+/// where `A` is `n x m`, `B` is `n x p`, and `m <= n <= m + p`.
+/// `a`, `b`, and `d` are destroyed.
+pub trait LeastSquaresGlm_: Scalar {
+    unsafe fn least_squares_glm(
+        al: MatrixLayout,
+        bl: MatrixLayout,
+        a: &mut [Self],
+        b: &mut [Self],
+        d: &mut [Self],
+    ) -> Result<(Vec<Self>, Vec<Self>)>;
+}
+
+macro_rules! impl_least_squares_glm {
+    ($scalar:ty, $ggglm:path) => {
+        impl LeastSquaresGlm_ for $scalar {
+            unsafe fn least_squares_glm(
+                al: MatrixLayout,
+                bl: MatrixLayout,
+                a: &mut [Self],
+                b: &mut [Self],
+                d: &mut [Self],
+            ) -> Result<(Vec<Self>, Vec<Self>)> {
+                assert!(al.same_order(&bl));
+                let (n, m) = al.size();
+                let (_, p) = bl.size();
+                let mut x = vec![Self::zero(); m as usize];
+                let mut y = vec![Self::zero(); p as usize];
+                let info = $ggglm(
+                    al.lapacke_layout(),
+                    n,
+                    m,
+                    p,
+                    a,
+                    al.lda(),
+                    b,
+                    bl.lda(),
+                    d,
+                    &mut x,
+                    &mut y,
+                );
+                into_result(info, (x, y))
+            }
+        }
+    };
+} // impl_least_squares_glm!
+
+impl_least_squares_glm!(f64, lapacke::dggglm);
+impl_least_squares_glm!(f32, lapacke::sggglm);
+impl_least_squares_glm!(c64, lapacke::zggglm);
+impl_least_squares_glm!(c32, lapacke::cggglm);
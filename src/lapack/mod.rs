@@ -1,32 +1,86 @@
 //! Define traits wrapping LAPACK routines
 
+pub mod banded;
 pub mod cholesky;
+pub mod eig;
 pub mod eigh;
+pub mod hessenberg;
+pub mod least_squares;
+pub mod native;
 pub mod opnorm;
+pub mod packed;
 pub mod qr;
+pub mod schur;
 pub mod solve;
 pub mod solveh;
 pub mod svd;
 pub mod svddc;
 pub mod triangular;
+pub mod tridiagonal;
 
+pub use self::banded::*;
 pub use self::cholesky::*;
+pub use self::eig::*;
 pub use self::eigh::*;
+pub use self::hessenberg::*;
+pub use self::least_squares::*;
+pub use self::native::NativeLinalg;
 pub use self::opnorm::*;
+pub use self::packed::*;
 pub use self::qr::*;
+pub use self::schur::*;
 pub use self::solve::*;
 pub use self::solveh::*;
 pub use self::svd::*;
 pub use self::svddc::*;
 pub use self::triangular::*;
+pub use self::tridiagonal::*;
+
+use num_traits::ToPrimitive;
+use std::cmp::max;
 
 use super::error::*;
+use super::layout::MatrixLayout;
 use super::types::*;
 
 pub type Pivot = Vec<i32>;
 
 /// Trait for primitive types which implements LAPACK subroutines
-pub trait Lapack: OperatorNorm_ + QR_ + SVD_ + SVDDC_ + Solve_ + Solveh_ + Cholesky_ + Eigh_ + Triangular_ {}
+///
+/// The high-level traits of the LU and Cholesky decompositions only require
+/// [Solve_](solve/trait.Solve_.html) and [Cholesky_](cholesky/trait.Cholesky_.html),
+/// which are also implemented in pure Rust for the types implementing [NativeLinalg](native/trait.NativeLinalg.html).
+pub trait Lapack:
+    OperatorNorm_
+    + QR_
+    + SVD_
+    + SVDDC_
+    + Solve_
+    + Solveh_
+    + SolvehPacked_
+    + Banded_
+    + BandedCholesky_
+    + Tridiagonal_
+    + TridiagonalCholesky_
+    + Cholesky_
+    + Eig_
+    + Balance_
+    + EigGeneralized_
+    + Eigh_
+    + EighGeneralized_
+    + EighRange_
+    + EighDriver_
+    + EighTridiagonal_
+    + Hessenberg_
+    + Triangular_
+    + LeastSquaresQr_
+    + LeastSquaresSvd_
+    + LeastSquaresPivoted_
+    + LeastSquaresGlm_
+    + Schur_
+    + SchurGeneralized_
+{
+}
 
 impl Lapack for f32 {}
 impl Lapack for f64 {}
@@ -41,6 +95,51 @@ pub fn into_result<T>(return_code: i32, val: T) -> Result<T> {
     }
 }
 
+/// Read a matrix in the C layout as its transpose in the Fortran layout
+///
+/// LAPACKE copies a matrix in the C layout into a transposed buffer and back in every call.
+/// The wrappers of the routines whose results for the transpose are as good,
+/// e.g. `A^T = P L U` for the LU decomposition, avoid the copy by this,
+/// where the second element is `true` if the matrix is transposed.
+pub(crate) fn into_fortran(l: MatrixLayout) -> (MatrixLayout, bool) {
+    match l {
+        MatrixLayout::C(_) => (l.t(), true),
+        MatrixLayout::F(_) => (l, false),
+    }
+}
+
+/// Conjugate a vector in place, which does nothing for real numbers
+pub(crate) fn conjugate_inplace<A: Scalar>(a: &mut [A]) {
+    for x in a.iter_mut() {
+        *x = x.conj();
+    }
+}
+
+/// Allocate a vector of length `n` without initializing the elements
+///
+/// This is for the large outputs of LAPACK, e.g. eigenvectors or `U` and `V^T` of SVD,
+/// which would be filled by zeros only to be overwritten.
+/// The caller must not read any element before the routine writes all of them.
+pub(crate) unsafe fn vec_uninit<T: Copy>(n: usize) -> Vec<T> {
+    let mut v = Vec::with_capacity(n);
+    v.set_len(n);
+    v
+}
+
+/// Sizes of the work arrays of a LAPACK routine, queried by `lwork = -1`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct WorkSize {
+    /// Length of `work` in the scalar type
+    pub work: usize,
+    /// Length of `rwork` in the real type, which is used only by the complex routines
+    pub rwork: usize,
+}
+
+/// Length of `work` returned in its first element by the workspace query
+pub(crate) fn lwork<A: Scalar>(work0: A) -> usize {
+    max(1, work0.re().to_usize().unwrap_or(1))
+}
+
 /// Upper/Lower specification for seveal usages
 #[derive(Debug, Clone, Copy)]
 #[repr(u8)]
@@ -0,0 +1,547 @@
+//! Pure-Rust kernels for element types without LAPACK bindings
+//!
+//! The high-level traits of the LU decomposition, e.g. [Solve](../../solve/trait.Solve.html),
+//! [Inverse](../../solve/trait.Inverse.html) and [Factorize](../../solve/trait.Factorize.html),
+//! and those of the Cholesky decomposition, e.g. [Cholesky](../../cholesky/trait.Cholesky.html)
+//! and [SolveC](../../cholesky/trait.SolveC.html), only require the element type to implement
+//! [Solve_](../solve/trait.Solve_.html) and [Cholesky_](../cholesky/trait.Cholesky_.html)
+//! instead of the whole [Lapack](../trait.Lapack.html) and [Scalar](../../types/trait.Scalar.html).
+//! A user-defined number, e.g. a rational, interval or fixed-point number,
+//! implements them by the marker trait [NativeLinalg](trait.NativeLinalg.html),
+//! which provides the kernels of this module,
+//! on top of [NativeScalar](trait.NativeScalar.html) for the few operations they use:
+//!
+//! ```
+//! use ndarray::*;
+//! use ndarray_linalg::lapack::native::NativeScalar;
+//! use ndarray_linalg::*;
+//! use num_traits::{One, Zero};
+//! use std::ops::*;
+//!
+//! /// Number rounded to the multiple of `2^-20` after each operation
+//! #[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+//! struct Fixed(f64);
+//!
+//! impl Fixed {
+//!     fn new(x: f64) -> Self {
+//!         Fixed((x * 1048576.0).round() / 1048576.0)
+//!     }
+//! }
+//!
+//! macro_rules! impl_op {
+//!     ($op:ident, $f:ident) => {
+//!         impl $op for Fixed {
+//!             type Output = Fixed;
+//!             fn $f(self, rhs: Fixed) -> Fixed {
+//!                 Fixed::new(self.0.$f(rhs.0))
+//!             }
+//!         }
+//!     };
+//! }
+//! impl_op!(Add, add);
+//! impl_op!(Sub, sub);
+//! impl_op!(Mul, mul);
+//! impl_op!(Div, div);
+//!
+//! impl Neg for Fixed {
+//!     type Output = Fixed;
+//!     fn neg(self) -> Fixed {
+//!         Fixed(-self.0)
+//!     }
+//! }
+//!
+//! impl Zero for Fixed {
+//!     fn zero() -> Self {
+//!         Fixed(0.0)
+//!     }
+//!     fn is_zero(&self) -> bool {
+//!         self.0 == 0.0
+//!     }
+//! }
+//!
+//! impl One for Fixed {
+//!     fn one() -> Self {
+//!         Fixed(1.0)
+//!     }
+//! }
+//!
+//! impl NativeScalar for Fixed {
+//!     type Real = Fixed;
+//!     fn conj(self) -> Self {
+//!         self
+//!     }
+//!     fn abs(self) -> Fixed {
+//!         Fixed(self.0.abs())
+//!     }
+//!     fn re(self) -> Fixed {
+//!         self
+//!     }
+//!     fn from_real(re: Fixed) -> Self {
+//!         re
+//!     }
+//!     fn sqrt(re: Fixed) -> Fixed {
+//!         Fixed::new(re.0.sqrt())
+//!     }
+//! }
+//!
+//! impl NativeLinalg for Fixed {}
+//!
+//! let a = arr2(&[[4.0, 2.0], [2.0, 5.0]]).mapv(Fixed::new);
+//! let b = arr1(&[6.0, 7.0]).mapv(Fixed::new);
+//! assert_eq!(a.solve(&b).unwrap(), arr1(&[1.0, 1.0]).mapv(Fixed::new));
+//! let l = a.cholesky(UPLO::Lower).unwrap();
+//! assert_eq!(l, arr2(&[[2.0, 0.0], [1.0, 2.0]]).mapv(Fixed::new));
+//! assert_eq!(a.solvec(&b).unwrap(), arr1(&[1.0, 1.0]).mapv(Fixed::new));
+//! ```
+//!
+//! The kernels use only the arithmetic operations, `conj`, `abs` for the pivoting,
+//! and `sqrt` for the diagonal of the Cholesky factor.
+//! `NativeScalar` is not re-exported at the root of the crate,
+//! since the names of its methods coincide with those of `Scalar`.
+//! The kernels are also public to implement the wrapper traits by hand, e.g. with a different pivoting,
+//! where [rcond](fn.rcond.html) also requires `Scalar` as `Solve_::rcond` does.
+//!
+//! [lu](fn.lu.html) and [cholesky](fn.cholesky.html) factorize the columns in the panels of
+//! [BLOCK_SIZE](constant.BLOCK_SIZE.html) and update the rest of the matrix by `ndarray::linalg::general_mat_mul`,
+//! which is the SIMD kernel of `matrixmultiply` for `f32` and `f64`
+//! (or BLAS for the large matrices, since the `blas` feature of `ndarray` is enabled).
+//! The other routines are unblocked.
+//!
+//! With the `native` feature, `f32`, `f64`, `c32` and `c64` also implement `NativeLinalg`,
+//! so that their LU and Cholesky decompositions are computed by these kernels instead of LAPACK
+//! with the same high-level traits.
+
+use ndarray::linalg::general_mat_mul;
+use ndarray::*;
+use num_traits::{Float, One, Zero};
+use std::cmp::min;
+use std::ops::Neg;
+
+use crate::error::*;
+use crate::layout::MatrixLayout;
+use crate::types::*;
+
+use super::{into_result, Cholesky_, Pivot, Solve_, Transpose, UPLO};
+
+/// Number of the columns factorized at once by [lu](fn.lu.html) and [cholesky](fn.cholesky.html)
+pub const BLOCK_SIZE: usize = 64;
+
+/// Operations of the element types used by the kernels of this module
+///
+/// Besides the field operations of `LinalgScalar`, only the complex conjugate, the modulus for the pivoting,
+/// and the real part and the square root for the diagonal of the Cholesky factor are required.
+pub trait NativeScalar: LinalgScalar + Neg<Output = Self> {
+    /// Type of the modulus and the real part, e.g. `f64` for `c64`
+    type Real: LinalgScalar + PartialOrd;
+    /// Complex conjugate, which is the identity for a real number
+    fn conj(self) -> Self;
+    /// Modulus `|x|`
+    fn abs(self) -> Self::Real;
+    /// Real part
+    fn re(self) -> Self::Real;
+    /// Embed a real number
+    fn from_real(re: Self::Real) -> Self;
+    /// Square root of a positive real number
+    fn sqrt(re: Self::Real) -> Self::Real;
+}
+
+macro_rules! impl_native_real {
+    ($real:ty) => {
+        impl NativeScalar for $real {
+            type Real = $real;
+
+            fn conj(self) -> Self {
+                self
+            }
+
+            fn abs(self) -> $real {
+                Float::abs(self)
+            }
+
+            fn re(self) -> $real {
+                self
+            }
+
+            fn from_real(re: $real) -> Self {
+                re
+            }
+
+            fn sqrt(re: $real) -> $real {
+                Float::sqrt(re)
+            }
+        }
+    };
+}
+
+macro_rules! impl_native_complex {
+    ($complex:ty, $real:ty) => {
+        impl NativeScalar for $complex {
+            type Real = $real;
+
+            fn conj(self) -> Self {
+                <$complex>::conj(&self)
+            }
+
+            fn abs(self) -> $real {
+                self.norm()
+            }
+
+            fn re(self) -> $real {
+                self.re
+            }
+
+            fn from_real(re: $real) -> Self {
+                <$complex>::new(re, 0.0)
+            }
+
+            fn sqrt(re: $real) -> $real {
+                Float::sqrt(re)
+            }
+        }
+    };
+}
+
+impl_native_real!(f32);
+impl_native_real!(f64);
+impl_native_complex!(c32, f32);
+impl_native_complex!(c64, f64);
+
+/// Element types whose LU and Cholesky decompositions are computed by the pure-Rust kernels of this module
+pub trait NativeLinalg: NativeScalar {}
+
+#[cfg(feature = "native")]
+impl NativeLinalg for f32 {}
+#[cfg(feature = "native")]
+impl NativeLinalg for f64 {}
+#[cfg(feature = "native")]
+impl NativeLinalg for c32 {}
+#[cfg(feature = "native")]
+impl NativeLinalg for c64 {}
+
+impl<A: NativeLinalg> Solve_ for A {
+    unsafe fn lu(l: MatrixLayout, a: &mut [Self]) -> Result<Pivot> {
+        lu(l, a)
+    }
+
+    unsafe fn inv(l: MatrixLayout, a: &mut [Self], ipiv: &Pivot) -> Result<()> {
+        inv(l, a, ipiv)
+    }
+
+    unsafe fn rcond(l: MatrixLayout, a: &[Self], anorm: <A as Scalar>::Real) -> Result<<A as Scalar>::Real>
+    where
+        A: Scalar,
+    {
+        rcond(l, a, anorm)
+    }
+
+    unsafe fn solve(l: MatrixLayout, t: Transpose, a: &[Self], ipiv: &Pivot, b: &mut [Self]) -> Result<()> {
+        solve(l, t, a, ipiv, b)
+    }
+}
+
+impl<A: NativeLinalg> Cholesky_ for A {
+    unsafe fn cholesky(l: MatrixLayout, uplo: UPLO, a: &mut [Self]) -> Result<()> {
+        cholesky(l, uplo, a)
+    }
+
+    unsafe fn inv_cholesky(l: MatrixLayout, uplo: UPLO, a: &mut [Self]) -> Result<()> {
+        inv_cholesky(l, uplo, a)
+    }
+
+    unsafe fn solve_cholesky(l: MatrixLayout, uplo: UPLO, a: &[Self], b: &mut [Self]) -> Result<()> {
+        solve_cholesky(l, uplo, a, b)
+    }
+}
+
+/// Offset of the `(i, j)` element in the slice of the layout `l`
+fn index(l: MatrixLayout) -> impl Fn(usize, usize) -> usize {
+    let lda = l.lda() as usize;
+    let row_major = match l {
+        MatrixLayout::C(_) => true,
+        MatrixLayout::F(_) => false,
+    };
+    move |i, j| if row_major { i * lda + j } else { i + j * lda }
+}
+
+/// View of the matrix stored in the slice of the layout `l`
+fn as_matrix_mut<A>(l: MatrixLayout, a: &mut [A]) -> Result<ArrayViewMut2<A>> {
+    Ok(ArrayViewMut::from_shape(l.as_shape(), a)?)
+}
+
+/// LU decomposition with partial pivoting like `*getrf`
+///
+/// The columns are factorized in the panels of `BLOCK_SIZE`,
+/// and the trailing submatrix is updated by a matrix product for each panel.
+/// Fails with `Lapack { return_code: k }` if `U[(k - 1, k - 1)]` is exactly zero,
+/// where the decomposition has been completed as `*getrf` does.
+pub fn lu<A: NativeScalar>(l: MatrixLayout, a: &mut [A]) -> Result<Pivot> {
+    let mut a = as_matrix_mut(l, a)?;
+    let (m, n) = a.dim();
+    let k = min(m, n);
+    let mut ipiv = Vec::with_capacity(k);
+    let mut info = 0;
+    for j0 in (0..k).step_by(BLOCK_SIZE) {
+        let j1 = min(j0 + BLOCK_SIZE, k);
+        // Unblocked LU of the panel, where the rows are swapped in the whole matrix
+        for j in j0..j1 {
+            let p = (j..m).fold(j, |p, i| if a[(i, j)].abs() > a[(p, j)].abs() { i } else { p });
+            ipiv.push(p as i32 + 1);
+            if a[(p, j)].is_zero() {
+                if info == 0 {
+                    info = j as i32 + 1;
+                }
+                continue;
+            }
+            if p != j {
+                for c in 0..n {
+                    a.swap((j, c), (p, c));
+                }
+            }
+            let pivot = a[(j, j)];
+            for i in (j + 1)..m {
+                let l_ij = a[(i, j)] / pivot;
+                a[(i, j)] = l_ij;
+                for c in (j + 1)..j1 {
+                    a[(i, c)] = a[(i, c)] - l_ij * a[(j, c)];
+                }
+            }
+        }
+        if j1 == n {
+            continue;
+        }
+        let (left, mut right) = a.view_mut().split_at(Axis(1), j1);
+        // `U12 = L11^{-1} A12`
+        for i in (j0 + 1)..j1 {
+            let (upper, mut lower) = right.view_mut().split_at(Axis(0), i);
+            let mut row = lower.row_mut(0);
+            for p in j0..i {
+                row.scaled_add(-left[(i, p)], &upper.row(p));
+            }
+        }
+        // `A22 -= L21 U12`
+        if j1 < m {
+            let (u12, mut a22) = right.split_at(Axis(0), j1);
+            let l21 = left.slice(s![j1.., j0..j1]);
+            general_mat_mul(-A::one(), &l21, &u12.slice(s![j0.., ..]), A::one(), &mut a22);
+        }
+    }
+    into_result(info, ipiv)
+}
+
+/// Solve `A x = b`, `A^T x = b` or `A^H x = b` using the LU decomposition of `A` like `*getrs`
+pub fn solve<A: NativeScalar>(l: MatrixLayout, t: Transpose, a: &[A], ipiv: &Pivot, b: &mut [A]) -> Result<()> {
+    let n = l.size().0 as usize;
+    let ix = index(l);
+    match t {
+        Transpose::No => {
+            for (i, &p) in ipiv.iter().enumerate() {
+                b.swap(i, p as usize - 1);
+            }
+            for i in 0..n {
+                for j in 0..i {
+                    b[i] = b[i] - a[ix(i, j)] * b[j];
+                }
+            }
+            for i in (0..n).rev() {
+                for j in (i + 1)..n {
+                    b[i] = b[i] - a[ix(i, j)] * b[j];
+                }
+                b[i] = b[i] / a[ix(i, i)];
+            }
+        }
+        Transpose::Transpose | Transpose::Hermite => {
+            // `A^T = U^T L^T P^T`
+            let op = |x: A| match t {
+                Transpose::Hermite => x.conj(),
+                _ => x,
+            };
+            for i in 0..n {
+                for j in 0..i {
+                    b[i] = b[i] - op(a[ix(j, i)]) * b[j];
+                }
+                b[i] = b[i] / op(a[ix(i, i)]);
+            }
+            for i in (0..n).rev() {
+                for j in (i + 1)..n {
+                    b[i] = b[i] - op(a[ix(j, i)]) * b[j];
+                }
+            }
+            for (i, &p) in ipiv.iter().enumerate().rev() {
+                b.swap(i, p as usize - 1);
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Inverse matrix from the LU decomposition like `*getri`
+///
+/// Fails with `Lapack { return_code: k }` if `U[(k - 1, k - 1)]` is exactly zero.
+pub fn inv<A: NativeScalar>(l: MatrixLayout, a: &mut [A], ipiv: &Pivot) -> Result<()> {
+    let n = l.size().0 as usize;
+    let ix = index(l);
+    if let Some(k) = (0..n).find(|&k| a[ix(k, k)].is_zero()) {
+        return into_result(k as i32 + 1, ());
+    }
+    let factors = a.to_vec();
+    let mut col = vec![A::zero(); n];
+    for j in 0..n {
+        for (i, x) in col.iter_mut().enumerate() {
+            *x = if i == j { A::one() } else { A::zero() };
+        }
+        solve(l, Transpose::No, &factors, ipiv, &mut col)?;
+        for i in 0..n {
+            a[ix(i, j)] = col[i];
+        }
+    }
+    Ok(())
+}
+
+/// Reciprocal of the condition number in 1-norm from the LU decomposition like `*gecon`
+///
+/// `anorm` must be the 1-norm of the original matrix.
+/// Unlike `*gecon`, the 1-norm of the inverse matrix is computed exactly instead of estimated.
+pub fn rcond<A: Scalar + NativeScalar>(
+    l: MatrixLayout,
+    a: &[A],
+    anorm: <A as Scalar>::Real,
+) -> Result<<A as Scalar>::Real> {
+    // `Real` is qualified, since both `Scalar` and `NativeScalar` provide it
+    let n = l.size().0 as usize;
+    let ix = index(l);
+    if n == 0 {
+        return Ok(<A as Scalar>::Real::one());
+    }
+    if anorm.is_zero() || (0..n).any(|k| a[ix(k, k)].is_zero()) {
+        return Ok(<A as Scalar>::Real::zero());
+    }
+    // The permutation does not change the 1-norm of `A^{-1} = U^{-1} L^{-1} P^T`
+    let identity: Pivot = (1..=n as i32).collect();
+    let mut col = vec![A::zero(); n];
+    let mut inv_norm = <A as Scalar>::Real::zero();
+    for j in 0..n {
+        for (i, x) in col.iter_mut().enumerate() {
+            *x = if i == j { A::one() } else { A::zero() };
+        }
+        solve(l, Transpose::No, a, &identity, &mut col)?;
+        let norm = col.iter().fold(<A as Scalar>::Real::zero(), |s, &x| s + scalar_abs(x));
+        inv_norm = Float::max(inv_norm, norm);
+    }
+    Ok(Float::recip(anorm * inv_norm))
+}
+
+/// `|x|` by `Scalar`, which is not ambiguous with `NativeScalar::abs` under the single bound
+fn scalar_abs<A: Scalar>(x: A) -> A::Real {
+    x.abs()
+}
+
+/// `(i, j)` element of the lower triangular factor `L`, stored as `U = L^H` for `UPLO::Upper`
+fn get_lower<A: NativeScalar>(a: &[A], ix: &impl Fn(usize, usize) -> usize, uplo: UPLO, i: usize, j: usize) -> A {
+    match uplo {
+        UPLO::Lower => a[ix(i, j)],
+        UPLO::Upper => a[ix(j, i)].conj(),
+    }
+}
+
+fn set_lower<A: NativeScalar>(a: &mut [A], ix: &impl Fn(usize, usize) -> usize, uplo: UPLO, i: usize, j: usize, x: A) {
+    match uplo {
+        UPLO::Lower => a[ix(i, j)] = x,
+        UPLO::Upper => a[ix(j, i)] = x.conj(),
+    }
+}
+
+/// Cholesky decomposition like `*potrf`
+///
+/// Only the triangular part specified by `uplo` is read and written.
+/// The columns are factorized in the panels of `BLOCK_SIZE` as [lu](fn.lu.html).
+/// Fails with `Lapack { return_code: k }` if the leading minor of order `k` is not positive definite.
+pub fn cholesky<A: NativeScalar>(l: MatrixLayout, uplo: UPLO, a: &mut [A]) -> Result<()> {
+    // The transpose of `A = U^H U` is `A^T = U^T conj(U)`,
+    // so that `U` is computed as the lower factor of the transposed view
+    let a = as_matrix_mut(l, a)?;
+    let mut a = match uplo {
+        UPLO::Lower => a,
+        UPLO::Upper => a.reversed_axes(),
+    };
+    let n = a.rows();
+    for j0 in (0..n).step_by(BLOCK_SIZE) {
+        let j1 = min(j0 + BLOCK_SIZE, n);
+        // Unblocked Cholesky of the diagonal block, and `L21 = A21 L11^{-H}` below it
+        for j in j0..j1 {
+            let d = (j0..j).fold(a[(j, j)].re(), |d, k| d - (a[(j, k)] * a[(j, k)].conj()).re());
+            if !(d > A::Real::zero()) {
+                return into_result(j as i32 + 1, ());
+            }
+            let d = A::from_real(A::sqrt(d));
+            a[(j, j)] = d;
+            for i in (j + 1)..n {
+                let s = (j0..j).fold(a[(i, j)], |s, k| s - a[(i, k)] * a[(j, k)].conj());
+                a[(i, j)] = s / d;
+            }
+        }
+        // `A22 -= L21 L21^H` in the lower triangle, a block of columns at once
+        let (left, mut right) = a.view_mut().split_at(Axis(1), j1);
+        let l21 = left.slice(s![j1.., j0..j1]);
+        for c0 in (j1..n).step_by(BLOCK_SIZE) {
+            let c1 = min(c0 + BLOCK_SIZE, n);
+            let lh = l21.slice(s![(c0 - j1)..(c1 - j1), ..]).t().mapv(|x| x.conj());
+            let mut block = right.slice_mut(s![c0.., (c0 - j1)..(c1 - j1)]);
+            for c in 0..(c1 - c0) {
+                for i in c..(c1 - c0) {
+                    let s = l21.row(c0 - j1 + i).dot(&lh.column(c));
+                    block[(i, c)] = block[(i, c)] - s;
+                }
+            }
+            if c1 < n {
+                let below = l21.slice(s![(c1 - j1).., ..]);
+                let mut rest = block.slice_mut(s![(c1 - c0).., ..]);
+                general_mat_mul(-A::one(), &below, &lh, A::one(), &mut rest);
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Inverse matrix from the Cholesky decomposition like `*potri`
+///
+/// Only the triangular part specified by `uplo` is written.
+/// Fails with `Lapack { return_code: k }` if the `k`-th diagonal element of the factor is exactly zero.
+pub fn inv_cholesky<A: NativeScalar>(l: MatrixLayout, uplo: UPLO, a: &mut [A]) -> Result<()> {
+    let n = l.size().0 as usize;
+    let ix = index(l);
+    if let Some(k) = (0..n).find(|&k| a[ix(k, k)].is_zero()) {
+        return into_result(k as i32 + 1, ());
+    }
+    // `L^{-1}` stored row by row
+    let mut linv = vec![A::zero(); n * n];
+    for j in 0..n {
+        linv[j * n + j] = A::one() / get_lower(a, &ix, uplo, j, j);
+        for i in (j + 1)..n {
+            let s = (j..i).fold(A::zero(), |s, k| s + get_lower(a, &ix, uplo, i, k) * linv[k * n + j]);
+            linv[i * n + j] = -s / get_lower(a, &ix, uplo, i, i);
+        }
+    }
+    // `A^{-1} = L^{-H} L^{-1}`
+    for j in 0..n {
+        for i in j..n {
+            let x = (i..n).fold(A::zero(), |s, k| s + linv[k * n + i].conj() * linv[k * n + j]);
+            set_lower(a, &ix, uplo, i, j, x);
+        }
+    }
+    Ok(())
+}
+
+/// Solve `A x = b` using the Cholesky decomposition of `A` like `*potrs`
+pub fn solve_cholesky<A: NativeScalar>(l: MatrixLayout, uplo: UPLO, a: &[A], b: &mut [A]) -> Result<()> {
+    let n = l.size().0 as usize;
+    let ix = index(l);
+    for i in 0..n {
+        let s = (0..i).fold(b[i], |s, k| s - get_lower(a, &ix, uplo, i, k) * b[k]);
+        b[i] = s / get_lower(a, &ix, uplo, i, i);
+    }
+    for i in (0..n).rev() {
+        let s = ((i + 1)..n).fold(b[i], |s, k| s - get_lower(a, &ix, uplo, k, i).conj() * b[k]);
+        b[i] = s / get_lower(a, &ix, uplo, i, i).conj();
+    }
+    Ok(())
+}
@@ -6,14 +6,19 @@ use lapacke::Layout::ColumnMajor as cm;
 use crate::layout::MatrixLayout;
 use crate::types::*;
 
+use super::{Diag, UPLO};
+
 pub use super::NormType;
 
+/// Wraps `*lange` and `*lantr`
 pub trait OperatorNorm_: Scalar {
     unsafe fn opnorm(t: NormType, l: MatrixLayout, a: &[Self]) -> Self::Real;
+    /// Norm of the upper or lower trapezoidal part of `a`, whose diagonal is regarded as ones for `Diag::Unit`
+    unsafe fn opnorm_triangular(t: NormType, uplo: UPLO, diag: Diag, l: MatrixLayout, a: &[Self]) -> Self::Real;
 }
 
 macro_rules! impl_opnorm {
-    ($scalar:ty, $lange:path) => {
+    ($scalar:ty, $lange:path, $lantr:path) => {
         impl OperatorNorm_ for $scalar {
             unsafe fn opnorm(t: NormType, l: MatrixLayout, a: &[Self]) -> Self::Real {
                 match l {
@@ -21,11 +26,31 @@ macro_rules! impl_opnorm {
                     MatrixLayout::C((row, lda)) => $lange(cm, t.transpose() as u8, lda, row, a, lda),
                 }
             }
+
+            unsafe fn opnorm_triangular(
+                t: NormType,
+                uplo: UPLO,
+                diag: Diag,
+                l: MatrixLayout,
+                a: &[Self],
+            ) -> Self::Real {
+                let (m, n) = l.size();
+                $lantr(
+                    l.lapacke_layout(),
+                    t as u8,
+                    uplo as u8,
+                    diag as u8,
+                    m,
+                    n,
+                    a,
+                    l.lda(),
+                )
+            }
         }
     };
 } // impl_opnorm!
 
-impl_opnorm!(f64, lapacke::dlange);
-impl_opnorm!(f32, lapacke::slange);
-impl_opnorm!(c64, lapacke::zlange);
-impl_opnorm!(c32, lapacke::clange);
+impl_opnorm!(f64, lapacke::dlange, lapacke::dlantr);
+impl_opnorm!(f32, lapacke::slange, lapacke::slantr);
+impl_opnorm!(c64, lapacke::zlange, lapacke::zlantr);
+impl_opnorm!(c32, lapacke::clange, lapacke::clantr);
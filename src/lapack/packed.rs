@@ -0,0 +1,63 @@
+//! Solve Hermitian (or real symmetric) linear problems in the packed storage
+//! using the Bunch-Kaufman diagonal pivoting method.
+//!
+//! See also [the manual of dsptrf](http://www.netlib.org/lapack/explore-html/d5/d41/dsptrf_8f.html)
+
+use lapacke;
+
+use crate::error::*;
+use crate::types::*;
+
+use super::{into_result, Pivot, UPLO};
+
+/// Wraps `*sptrf`/`*hptrf`, `*sptri`/`*hptri`, and `*sptrs`/`*hptrs`
+///
+/// The triangular part `uplo` of the `n x n` matrix is packed column-wise in `n (n + 1) / 2` elements.
+pub trait SolvehPacked_: Sized {
+    /// Bunch-Kaufman factorization in the packed storage
+    unsafe fn bk_packed(uplo: UPLO, n: i32, ap: &mut [Self]) -> Result<Pivot>;
+    /// Inverse from the Bunch-Kaufman factorization in the packed storage
+    unsafe fn invh_packed(uplo: UPLO, n: i32, ap: &mut [Self], ipiv: &Pivot) -> Result<()>;
+    /// Solve `A x = b` using the Bunch-Kaufman factorization in the packed storage
+    unsafe fn solveh_packed(uplo: UPLO, n: i32, ap: &[Self], ipiv: &Pivot, b: &mut [Self]) -> Result<()>;
+}
+
+macro_rules! impl_solveh_packed {
+    ($scalar:ty, $trf:path, $tri:path, $trs:path) => {
+        impl SolvehPacked_ for $scalar {
+            unsafe fn bk_packed(uplo: UPLO, n: i32, ap: &mut [Self]) -> Result<Pivot> {
+                let mut ipiv = vec![0; n as usize];
+                if n == 0 {
+                    return Ok(ipiv);
+                }
+                let info = $trf(lapacke::Layout::ColumnMajor, uplo as u8, n, ap, &mut ipiv);
+                into_result(info, ipiv)
+            }
+
+            unsafe fn invh_packed(uplo: UPLO, n: i32, ap: &mut [Self], ipiv: &Pivot) -> Result<()> {
+                let info = $tri(lapacke::Layout::ColumnMajor, uplo as u8, n, ap, ipiv);
+                into_result(info, ())
+            }
+
+            unsafe fn solveh_packed(uplo: UPLO, n: i32, ap: &[Self], ipiv: &Pivot, b: &mut [Self]) -> Result<()> {
+                let nrhs = 1;
+                let info = $trs(
+                    lapacke::Layout::ColumnMajor,
+                    uplo as u8,
+                    n,
+                    nrhs,
+                    ap,
+                    ipiv,
+                    b,
+                    ::std::cmp::max(n, 1),
+                );
+                into_result(info, ())
+            }
+        }
+    };
+} // impl_solveh_packed!
+
+impl_solveh_packed!(f64, lapacke::dsptrf, lapacke::dsptri, lapacke::dsptrs);
+impl_solveh_packed!(f32, lapacke::ssptrf, lapacke::ssptri, lapacke::ssptrs);
+impl_solveh_packed!(c64, lapacke::zhptrf, lapacke::zhptri, lapacke::zhptrs);
+impl_solveh_packed!(c32, lapacke::chptrf, lapacke::chptri, lapacke::chptrs);
@@ -2,36 +2,71 @@
 
 use lapacke;
 use num_traits::Zero;
-use std::cmp::min;
+use std::cmp::{max, min};
 
 use crate::error::*;
 use crate::layout::MatrixLayout;
 use crate::types::*;
 
-use super::into_result;
+use super::{into_fortran, into_result, lwork, WorkSize};
 
-/// Wraps `*geqrf` and `*orgqr` (`*ungqr` for complex numbers)
+/// Wraps `*geqrf`, `*geqp3` and `*orgqr` (`*ungqr` for complex numbers)
+///
+/// A matrix in the C layout is read as its transpose in the Fortran layout without copy,
+/// and decomposed by `*gelqf` and `*orglq` (`*unglq`) as `A^T = L Q`, i.e. `A = Q^T L^T`,
+/// which is stored in the same form as the QR decomposition of `A` in the C layout.
+/// `*geqp3` has no such counterpart, and is called on the C layout through LAPACKE.
 pub trait QR_: Sized {
     unsafe fn householder(l: MatrixLayout, a: &mut [Self]) -> Result<Vec<Self>>;
+    /// Householder QR with column pivoting, where `jpvt` returns the 1-based indices of the pivoted columns
+    unsafe fn householder_pivoted(l: MatrixLayout, a: &mut [Self], jpvt: &mut [i32]) -> Result<Vec<Self>>;
+    /// Generate `Q` from the reflectors of `householder`
     unsafe fn q(l: MatrixLayout, a: &mut [Self], tau: &[Self]) -> Result<()>;
     unsafe fn qr(l: MatrixLayout, a: &mut [Self]) -> Result<Vec<Self>>;
+    /// Size of the work array of `qr_with_work`, queried by `lwork = -1` for both `*geqrf` and `*orgqr`
+    unsafe fn qr_work_size(l: MatrixLayout) -> Result<WorkSize>;
+    /// `qr` using the work array of the size given by `qr_work_size`
+    unsafe fn qr_with_work(l: MatrixLayout, a: &mut [Self], work: &mut [Self]) -> Result<Vec<Self>>;
 }
 
 macro_rules! impl_qr {
-    ($scalar:ty, $qrf:path, $gqr:path) => {
+    (
+        $scalar:ty,
+        $qp3:path,
+        [$qrf:path, $gqr:path, $qrf_work:path, $gqr_work:path],
+        [$lqf:path, $glq:path, $lqf_work:path, $glq_work:path]
+    ) => {
         impl QR_ for $scalar {
-            unsafe fn householder(l: MatrixLayout, mut a: &mut [Self]) -> Result<Vec<Self>> {
+            unsafe fn householder(l: MatrixLayout, a: &mut [Self]) -> Result<Vec<Self>> {
+                let (l, transposed) = into_fortran(l);
                 let (row, col) = l.size();
                 let k = min(row, col);
                 let mut tau = vec![Self::zero(); k as usize];
-                let info = $qrf(l.lapacke_layout(), row, col, &mut a, l.lda(), &mut tau);
+                let info = if transposed {
+                    $lqf(l.lapacke_layout(), row, col, a, l.lda(), &mut tau)
+                } else {
+                    $qrf(l.lapacke_layout(), row, col, a, l.lda(), &mut tau)
+                };
                 into_result(info, tau)
             }
 
-            unsafe fn q(l: MatrixLayout, mut a: &mut [Self], tau: &[Self]) -> Result<()> {
+            unsafe fn householder_pivoted(l: MatrixLayout, mut a: &mut [Self], jpvt: &mut [i32]) -> Result<Vec<Self>> {
                 let (row, col) = l.size();
                 let k = min(row, col);
-                let info = $gqr(l.lapacke_layout(), row, k, k, &mut a, l.lda(), &tau);
+                let mut tau = vec![Self::zero(); k as usize];
+                let info = $qp3(l.lapacke_layout(), row, col, &mut a, l.lda(), jpvt, &mut tau);
+                into_result(info, tau)
+            }
+
+            unsafe fn q(l: MatrixLayout, a: &mut [Self], tau: &[Self]) -> Result<()> {
+                let (l, transposed) = into_fortran(l);
+                let (row, col) = l.size();
+                let k = min(row, col);
+                let info = if transposed {
+                    $glq(l.lapacke_layout(), k, col, k, a, l.lda(), &tau)
+                } else {
+                    $gqr(l.lapacke_layout(), row, k, k, a, l.lda(), &tau)
+                };
                 into_result(info, ())
             }
 
@@ -41,11 +76,120 @@ macro_rules! impl_qr {
                 Self::q(l, a, &tau)?;
                 Ok(r)
             }
+
+            unsafe fn qr_work_size(l: MatrixLayout) -> Result<WorkSize> {
+                let (l, transposed) = into_fortran(l);
+                let (row, col) = l.size();
+                let k = min(row, col);
+                let mut qrf = [Self::zero()];
+                let mut gqr = [Self::zero()];
+                let layout = l.lapacke_layout();
+                let info = if transposed {
+                    $lqf_work(layout, row, col, &mut [], l.lda(), &mut [], &mut qrf, -1)
+                } else {
+                    $qrf_work(layout, row, col, &mut [], l.lda(), &mut [], &mut qrf, -1)
+                };
+                into_result(info, ())?;
+                let info = if transposed {
+                    $glq_work(layout, k, col, k, &mut [], l.lda(), &[], &mut gqr, -1)
+                } else {
+                    $gqr_work(layout, row, k, k, &mut [], l.lda(), &[], &mut gqr, -1)
+                };
+                into_result(
+                    info,
+                    WorkSize {
+                        work: max(lwork(qrf[0]), lwork(gqr[0])),
+                        rwork: 0,
+                    },
+                )
+            }
+
+            unsafe fn qr_with_work(l: MatrixLayout, a: &mut [Self], work: &mut [Self]) -> Result<Vec<Self>> {
+                let (l, transposed) = into_fortran(l);
+                let (row, col) = l.size();
+                let k = min(row, col);
+                let len = work.len() as i32;
+                let layout = l.lapacke_layout();
+                let mut tau = vec![Self::zero(); k as usize];
+                let info = if transposed {
+                    $lqf_work(layout, row, col, a, l.lda(), &mut tau, work, len)
+                } else {
+                    $qrf_work(layout, row, col, a, l.lda(), &mut tau, work, len)
+                };
+                into_result(info, ())?;
+                let r = Vec::from(&*a);
+                let info = if transposed {
+                    $glq_work(layout, k, col, k, a, l.lda(), &tau, work, len)
+                } else {
+                    $gqr_work(layout, row, k, k, a, l.lda(), &tau, work, len)
+                };
+                into_result(info, r)
+            }
         }
     };
 } // endmacro
 
-impl_qr!(f64, lapacke::dgeqrf, lapacke::dorgqr);
-impl_qr!(f32, lapacke::sgeqrf, lapacke::sorgqr);
-impl_qr!(c64, lapacke::zgeqrf, lapacke::zungqr);
-impl_qr!(c32, lapacke::cgeqrf, lapacke::cungqr);
+impl_qr!(
+    f64,
+    lapacke::dgeqp3,
+    [
+        lapacke::dgeqrf,
+        lapacke::dorgqr,
+        lapacke::dgeqrf_work,
+        lapacke::dorgqr_work
+    ],
+    [
+        lapacke::dgelqf,
+        lapacke::dorglq,
+        lapacke::dgelqf_work,
+        lapacke::dorglq_work
+    ]
+);
+impl_qr!(
+    f32,
+    lapacke::sgeqp3,
+    [
+        lapacke::sgeqrf,
+        lapacke::sorgqr,
+        lapacke::sgeqrf_work,
+        lapacke::sorgqr_work
+    ],
+    [
+        lapacke::sgelqf,
+        lapacke::sorglq,
+        lapacke::sgelqf_work,
+        lapacke::sorglq_work
+    ]
+);
+impl_qr!(
+    c64,
+    lapacke::zgeqp3,
+    [
+        lapacke::zgeqrf,
+        lapacke::zungqr,
+        lapacke::zgeqrf_work,
+        lapacke::zungqr_work
+    ],
+    [
+        lapacke::zgelqf,
+        lapacke::zunglq,
+        lapacke::zgelqf_work,
+        lapacke::zunglq_work
+    ]
+);
+impl_qr!(
+    c32,
+    lapacke::cgeqp3,
+    [
+        lapacke::cgeqrf,
+        lapacke::cungqr,
+        lapacke::cgeqrf_work,
+        lapacke::cungqr_work
+    ],
+    [
+        lapacke::cgelqf,
+        lapacke::cunglq,
+        lapacke::cgelqf_work,
+        lapacke::cunglq_work
+    ]
+);
@@ -0,0 +1,480 @@
+//! Schur decomposition
+
+use lapacke;
+use num_complex::Complex;
+use num_traits::Zero;
+use std::cmp::max;
+
+use crate::error::*;
+use crate::layout::MatrixLayout;
+use crate::types::*;
+
+use super::{into_result, vec_uninit};
+
+/// Wraps `*gees`, `*trsen`, and `*trsna`
+///
+/// For real matrices, `T` is quasi-upper triangular with `2x2` blocks corresponding to complex conjugate pairs.
+pub trait Schur_: Scalar {
+    /// Compute `A = Q T Q^H`. `a` is overwritten by `T`, and the eigenvalues and `Q` are returned.
+    unsafe fn schur(l: MatrixLayout, a: &mut [Self]) -> Result<(Vec<Self::Complex>, Vec<Self>)>;
+
+    /// Reorder the Schur decomposition so that the selected eigenvalues form the leading block of `T`.
+    ///
+    /// Returns the reordered eigenvalues, the dimension of the selected invariant subspace,
+    /// and if `calc_cond`, the reciprocal condition numbers of the average of the selected eigenvalues
+    /// and of the invariant subspace (zeros otherwise).
+    unsafe fn schur_reorder(
+        l: MatrixLayout,
+        calc_cond: bool,
+        select: &[bool],
+        t: &mut [Self],
+        q: &mut [Self],
+    ) -> Result<(Vec<Self::Complex>, usize, Self::Real, Self::Real)>;
+
+    /// Reciprocal condition numbers of the eigenvalues and the eigenvectors in the order of the diagonal of `T`
+    unsafe fn schur_condition(
+        l: MatrixLayout,
+        t: &mut [Self],
+        q: &[Self],
+    ) -> Result<(Vec<Self::Real>, Vec<Self::Real>)>;
+}
+
+macro_rules! impl_schur_real {
+    ($scalar:ty, $gees:path, $trsen:path, $trevc:path, $trsna:path) => {
+        impl Schur_ for $scalar {
+            unsafe fn schur(l: MatrixLayout, a: &mut [Self]) -> Result<(Vec<Self::Complex>, Vec<Self>)> {
+                let (n, _) = l.size();
+                let ldvs = max(1, n);
+                let mut sdim = 0;
+                let mut wr = vec![Self::zero(); n as usize];
+                let mut wi = vec![Self::zero(); n as usize];
+                let mut vs = vec_uninit((n * n) as usize);
+                let info = $gees(
+                    l.lapacke_layout(),
+                    b'V',
+                    b'N',
+                    None,
+                    n,
+                    a,
+                    l.lda(),
+                    &mut sdim,
+                    &mut wr,
+                    &mut wi,
+                    &mut vs,
+                    ldvs,
+                );
+                let eigs = wr
+                    .iter()
+                    .zip(wi.iter())
+                    .map(|(&re, &im)| Complex::new(re, im))
+                    .collect();
+                into_result(info, (eigs, vs))
+            }
+
+            unsafe fn schur_reorder(
+                l: MatrixLayout,
+                calc_cond: bool,
+                select: &[bool],
+                t: &mut [Self],
+                q: &mut [Self],
+            ) -> Result<(Vec<Self::Complex>, usize, Self::Real, Self::Real)> {
+                let (n, _) = l.size();
+                let select: Vec<i32> = select.iter().map(|&s| s as i32).collect();
+                let mut m = 0;
+                let mut s = Self::zero();
+                let mut sep = Self::zero();
+                let mut wr = vec![Self::zero(); n as usize];
+                let mut wi = vec![Self::zero(); n as usize];
+                let info = $trsen(
+                    l.lapacke_layout(),
+                    if calc_cond { b'B' } else { b'N' },
+                    b'V',
+                    &select,
+                    n,
+                    t,
+                    l.lda(),
+                    q,
+                    l.lda(),
+                    &mut wr,
+                    &mut wi,
+                    &mut m,
+                    &mut s,
+                    &mut sep,
+                );
+                let eigs = wr
+                    .iter()
+                    .zip(wi.iter())
+                    .map(|(&re, &im)| Complex::new(re, im))
+                    .collect();
+                into_result(info, (eigs, m as usize, s, sep))
+            }
+            unsafe fn schur_condition(
+                l: MatrixLayout,
+                t: &mut [Self],
+                q: &[Self],
+            ) -> Result<(Vec<Self::Real>, Vec<Self::Real>)> {
+                let (n, _) = l.size();
+                let mut select = vec![0; n as usize];
+                let mut m = 0;
+                // Eigenvectors of `A = Q T Q^H` are computed by back-transforming those of `T`
+                let mut vl = q.to_vec();
+                let mut vr = q.to_vec();
+                let info = $trevc(
+                    l.lapacke_layout(),
+                    b'B',
+                    b'B',
+                    &mut select,
+                    n,
+                    t,
+                    l.lda(),
+                    &mut vl,
+                    l.lda(),
+                    &mut vr,
+                    l.lda(),
+                    n,
+                    &mut m,
+                );
+                into_result(info, ())?;
+                let mut s = vec![Self::Real::zero(); n as usize];
+                let mut sep = vec![Self::Real::zero(); n as usize];
+                let info = $trsna(
+                    l.lapacke_layout(),
+                    b'B',
+                    b'A',
+                    &select,
+                    n,
+                    t,
+                    l.lda(),
+                    &vl,
+                    l.lda(),
+                    &vr,
+                    l.lda(),
+                    &mut s,
+                    &mut sep,
+                    n,
+                    &mut m,
+                );
+                into_result(info, (s, sep))
+            }
+        }
+    };
+} // impl_schur_real!
+
+macro_rules! impl_schur_complex {
+    ($scalar:ty, $gees:path, $trsen:path, $trevc:path, $trsna:path) => {
+        impl Schur_ for $scalar {
+            unsafe fn schur(l: MatrixLayout, a: &mut [Self]) -> Result<(Vec<Self::Complex>, Vec<Self>)> {
+                let (n, _) = l.size();
+                let ldvs = max(1, n);
+                let mut sdim = 0;
+                let mut w = vec![Self::zero(); n as usize];
+                let mut vs = vec_uninit((n * n) as usize);
+                let info = $gees(
+                    l.lapacke_layout(),
+                    b'V',
+                    b'N',
+                    None,
+                    n,
+                    a,
+                    l.lda(),
+                    &mut sdim,
+                    &mut w,
+                    &mut vs,
+                    ldvs,
+                );
+                into_result(info, (w, vs))
+            }
+
+            unsafe fn schur_reorder(
+                l: MatrixLayout,
+                calc_cond: bool,
+                select: &[bool],
+                t: &mut [Self],
+                q: &mut [Self],
+            ) -> Result<(Vec<Self::Complex>, usize, Self::Real, Self::Real)> {
+                let (n, _) = l.size();
+                let select: Vec<i32> = select.iter().map(|&s| s as i32).collect();
+                let mut m = 0;
+                let mut s = Self::Real::zero();
+                let mut sep = Self::Real::zero();
+                let mut w = vec![Self::zero(); n as usize];
+                let info = $trsen(
+                    l.lapacke_layout(),
+                    if calc_cond { b'B' } else { b'N' },
+                    b'V',
+                    &select,
+                    n,
+                    t,
+                    l.lda(),
+                    q,
+                    l.lda(),
+                    &mut w,
+                    &mut m,
+                    &mut s,
+                    &mut sep,
+                );
+                into_result(info, (w, m as usize, s, sep))
+            }
+            unsafe fn schur_condition(
+                l: MatrixLayout,
+                t: &mut [Self],
+                q: &[Self],
+            ) -> Result<(Vec<Self::Real>, Vec<Self::Real>)> {
+                let (n, _) = l.size();
+                let mut select = vec![0; n as usize];
+                let mut m = 0;
+                // Eigenvectors of `A = Q T Q^H` are computed by back-transforming those of `T`
+                let mut vl = q.to_vec();
+                let mut vr = q.to_vec();
+                let info = $trevc(
+                    l.lapacke_layout(),
+                    b'B',
+                    b'B',
+                    &mut select,
+                    n,
+                    t,
+                    l.lda(),
+                    &mut vl,
+                    l.lda(),
+                    &mut vr,
+                    l.lda(),
+                    n,
+                    &mut m,
+                );
+                into_result(info, ())?;
+                let mut s = vec![Self::Real::zero(); n as usize];
+                let mut sep = vec![Self::Real::zero(); n as usize];
+                let info = $trsna(
+                    l.lapacke_layout(),
+                    b'B',
+                    b'A',
+                    &select,
+                    n,
+                    t,
+                    l.lda(),
+                    &vl,
+                    l.lda(),
+                    &vr,
+                    l.lda(),
+                    &mut s,
+                    &mut sep,
+                    n,
+                    &mut m,
+                );
+                into_result(info, (s, sep))
+            }
+        }
+    };
+} // impl_schur_complex!
+
+impl_schur_real!(f64, lapacke::dgees, lapacke::dtrsen, lapacke::dtrevc, lapacke::dtrsna);
+impl_schur_real!(f32, lapacke::sgees, lapacke::strsen, lapacke::strevc, lapacke::strsna);
+impl_schur_complex!(c64, lapacke::zgees, lapacke::ztrsen, lapacke::ztrevc, lapacke::ztrsna);
+impl_schur_complex!(c32, lapacke::cgees, lapacke::ctrsen, lapacke::ctrevc, lapacke::ctrsna);
+
+/// Wraps `*gges` and `*tgsen`
+///
+/// For real matrices, `S` is quasi-upper triangular and `T` is upper triangular.
+pub trait SchurGeneralized_: Scalar {
+    /// Compute `A = Q S Z^H` and `B = Q T Z^H`. `a` and `b` are overwritten by `S` and `T`,
+    /// and the generalized eigenvalues `(α, β)`, `Q`, and `Z` are returned.
+    unsafe fn qz(
+        l: MatrixLayout,
+        a: &mut [Self],
+        b: &mut [Self],
+    ) -> Result<(Vec<Self::Complex>, Vec<Self::Complex>, Vec<Self>, Vec<Self>)>;
+
+    /// Reorder the generalized Schur decomposition so that the selected eigenvalues form the leading blocks
+    /// of `S` and `T`. Returns the reordered `(α, β)` and the dimension of the selected deflating subspace.
+    unsafe fn qz_reorder(
+        l: MatrixLayout,
+        select: &[bool],
+        s: &mut [Self],
+        t: &mut [Self],
+        q: &mut [Self],
+        z: &mut [Self],
+    ) -> Result<(Vec<Self::Complex>, Vec<Self::Complex>, usize)>;
+}
+
+macro_rules! impl_schur_generalized_real {
+    ($scalar:ty, $gges:path, $tgsen:path) => {
+        impl SchurGeneralized_ for $scalar {
+            unsafe fn qz(
+                l: MatrixLayout,
+                a: &mut [Self],
+                b: &mut [Self],
+            ) -> Result<(Vec<Self::Complex>, Vec<Self::Complex>, Vec<Self>, Vec<Self>)> {
+                let (n, _) = l.size();
+                let ld = max(1, n);
+                let mut sdim = 0;
+                let mut alphar = vec![Self::zero(); n as usize];
+                let mut alphai = vec![Self::zero(); n as usize];
+                let mut beta = vec![Self::zero(); n as usize];
+                let mut vsl = vec_uninit((n * n) as usize);
+                let mut vsr = vec_uninit((n * n) as usize);
+                let info = $gges(
+                    l.lapacke_layout(),
+                    b'V',
+                    b'V',
+                    b'N',
+                    None,
+                    n,
+                    a,
+                    l.lda(),
+                    b,
+                    l.lda(),
+                    &mut sdim,
+                    &mut alphar,
+                    &mut alphai,
+                    &mut beta,
+                    &mut vsl,
+                    ld,
+                    &mut vsr,
+                    ld,
+                );
+                let alpha = alphar
+                    .iter()
+                    .zip(alphai.iter())
+                    .map(|(&re, &im)| Complex::new(re, im))
+                    .collect();
+                let beta = beta.iter().map(|&re| Complex::new(re, Self::zero())).collect();
+                into_result(info, (alpha, beta, vsl, vsr))
+            }
+
+            unsafe fn qz_reorder(
+                l: MatrixLayout,
+                select: &[bool],
+                s: &mut [Self],
+                t: &mut [Self],
+                q: &mut [Self],
+                z: &mut [Self],
+            ) -> Result<(Vec<Self::Complex>, Vec<Self::Complex>, usize)> {
+                let (n, _) = l.size();
+                let select: Vec<i32> = select.iter().map(|&s| s as i32).collect();
+                let mut m = 0;
+                let mut pl = Self::zero();
+                let mut pr = Self::zero();
+                let mut dif = [Self::zero(); 2];
+                let mut alphar = vec![Self::zero(); n as usize];
+                let mut alphai = vec![Self::zero(); n as usize];
+                let mut beta = vec![Self::zero(); n as usize];
+                let info = $tgsen(
+                    l.lapacke_layout(),
+                    0,
+                    1,
+                    1,
+                    &select,
+                    n,
+                    s,
+                    l.lda(),
+                    t,
+                    l.lda(),
+                    &mut alphar,
+                    &mut alphai,
+                    &mut beta,
+                    q,
+                    l.lda(),
+                    z,
+                    l.lda(),
+                    &mut m,
+                    &mut pl,
+                    &mut pr,
+                    &mut dif,
+                );
+                let alpha = alphar
+                    .iter()
+                    .zip(alphai.iter())
+                    .map(|(&re, &im)| Complex::new(re, im))
+                    .collect();
+                let beta = beta.iter().map(|&re| Complex::new(re, Self::zero())).collect();
+                into_result(info, (alpha, beta, m as usize))
+            }
+        }
+    };
+} // impl_schur_generalized_real!
+
+macro_rules! impl_schur_generalized_complex {
+    ($scalar:ty, $gges:path, $tgsen:path) => {
+        impl SchurGeneralized_ for $scalar {
+            unsafe fn qz(
+                l: MatrixLayout,
+                a: &mut [Self],
+                b: &mut [Self],
+            ) -> Result<(Vec<Self::Complex>, Vec<Self::Complex>, Vec<Self>, Vec<Self>)> {
+                let (n, _) = l.size();
+                let ld = max(1, n);
+                let mut sdim = 0;
+                let mut alpha = vec![Self::zero(); n as usize];
+                let mut beta = vec![Self::zero(); n as usize];
+                let mut vsl = vec_uninit((n * n) as usize);
+                let mut vsr = vec_uninit((n * n) as usize);
+                let info = $gges(
+                    l.lapacke_layout(),
+                    b'V',
+                    b'V',
+                    b'N',
+                    None,
+                    n,
+                    a,
+                    l.lda(),
+                    b,
+                    l.lda(),
+                    &mut sdim,
+                    &mut alpha,
+                    &mut beta,
+                    &mut vsl,
+                    ld,
+                    &mut vsr,
+                    ld,
+                );
+                into_result(info, (alpha, beta, vsl, vsr))
+            }
+
+            unsafe fn qz_reorder(
+                l: MatrixLayout,
+                select: &[bool],
+                s: &mut [Self],
+                t: &mut [Self],
+                q: &mut [Self],
+                z: &mut [Self],
+            ) -> Result<(Vec<Self::Complex>, Vec<Self::Complex>, usize)> {
+                let (n, _) = l.size();
+                let select: Vec<i32> = select.iter().map(|&s| s as i32).collect();
+                let mut m = 0;
+                let mut pl = Self::Real::zero();
+                let mut pr = Self::Real::zero();
+                let mut dif = [Self::Real::zero(); 2];
+                let mut alpha = vec![Self::zero(); n as usize];
+                let mut beta = vec![Self::zero(); n as usize];
+                let info = $tgsen(
+                    l.lapacke_layout(),
+                    0,
+                    1,
+                    1,
+                    &select,
+                    n,
+                    s,
+                    l.lda(),
+                    t,
+                    l.lda(),
+                    &mut alpha,
+                    &mut beta,
+                    q,
+                    l.lda(),
+                    z,
+                    l.lda(),
+                    &mut m,
+                    &mut pl,
+                    &mut pr,
+                    &mut dif,
+                );
+                into_result(info, (alpha, beta, m as usize))
+            }
+        }
+    };
+} // impl_schur_generalized_complex!
+
+impl_schur_generalized_real!(f64, lapacke::dgges, lapacke::dtgsen);
+impl_schur_generalized_real!(f32, lapacke::sgges, lapacke::stgsen);
+impl_schur_generalized_complex!(c64, lapacke::zgges, lapacke::ztgsen);
+impl_schur_generalized_complex!(c32, lapacke::cgges, lapacke::ctgsen);
@@ -1,17 +1,27 @@
 //! Solve linear problem using LU decomposition
 
+// The `native` feature replaces the LAPACK implementations below by the kernels of `native`
+#![cfg_attr(feature = "native", allow(unused_imports, unused_macros))]
+
 use lapacke;
+use ndarray::LinalgScalar;
 use num_traits::Zero;
+use std::cmp::max;
 
 use crate::error::*;
 use crate::layout::MatrixLayout;
 use crate::types::*;
 
 use super::NormType;
-use super::{into_result, Pivot, Transpose};
+use super::{conjugate_inplace, into_fortran, into_result, Pivot, Transpose};
 
 /// Wraps `*getrf`, `*getri`, and `*getrs`
-pub trait Solve_: Scalar + Sized {
+///
+/// The LAPACK implementations factorize a matrix in the C layout as its transpose `A^T = P L U`
+/// in the Fortran layout without copy, so that `p` are the column interchanges of `A` in that case,
+/// while the [native](../native/index.html) kernels factorize `A` itself in both layouts.
+/// The other routines take the factors of the same implementation in the same layout.
+pub trait Solve_: LinalgScalar {
     /// Computes the LU factorization of a general `m x n` matrix `a` using
     /// partial pivoting with row interchanges.
     ///
@@ -25,7 +35,11 @@ pub trait Solve_: Scalar + Sized {
     /// Estimates the the reciprocal of the condition number of the matrix in 1-norm.
     ///
     /// `anorm` should be the 1-norm of the matrix `a`.
-    unsafe fn rcond(l: MatrixLayout, a: &[Self], anorm: Self::Real) -> Result<Self::Real>;
+    ///
+    /// Unlike the other methods, this requires `Scalar` for the real type of the norms.
+    unsafe fn rcond(l: MatrixLayout, a: &[Self], anorm: <Self as Scalar>::Real) -> Result<<Self as Scalar>::Real>
+    where
+        Self: Scalar;
     unsafe fn solve(l: MatrixLayout, t: Transpose, a: &[Self], p: &Pivot, b: &mut [Self]) -> Result<()>;
 }
 
@@ -33,6 +47,7 @@ macro_rules! impl_solve {
     ($scalar:ty, $getrf:path, $getri:path, $gecon:path, $getrs:path) => {
         impl Solve_ for $scalar {
             unsafe fn lu(l: MatrixLayout, a: &mut [Self]) -> Result<Pivot> {
+                let (l, _) = into_fortran(l);
                 let (row, col) = l.size();
                 let k = ::std::cmp::min(row, col);
                 let mut ipiv = vec![0; k as usize];
@@ -41,38 +56,62 @@ macro_rules! impl_solve {
             }
 
             unsafe fn inv(l: MatrixLayout, a: &mut [Self], ipiv: &Pivot) -> Result<()> {
+                // `(A^T)^{-1}` in the Fortran layout is `A^{-1}` in the C layout
+                let (l, _) = into_fortran(l);
                 let (n, _) = l.size();
                 let info = $getri(l.lapacke_layout(), n, a, l.lda(), ipiv);
                 into_result(info, ())
             }
 
-            unsafe fn rcond(l: MatrixLayout, a: &[Self], anorm: Self::Real) -> Result<Self::Real> {
+            unsafe fn rcond(
+                l: MatrixLayout,
+                a: &[Self],
+                anorm: <Self as Scalar>::Real,
+            ) -> Result<<Self as Scalar>::Real> {
+                // The 1-norm of `A` is the infinity-norm of `A^T`
+                let (l, transposed) = into_fortran(l);
+                let norm = if transposed {
+                    NormType::One.transpose()
+                } else {
+                    NormType::One
+                };
                 let (n, _) = l.size();
-                let mut rcond = Self::Real::zero();
-                let info = $gecon(
-                    l.lapacke_layout(),
-                    NormType::One as u8,
-                    n,
-                    a,
-                    l.lda(),
-                    anorm,
-                    &mut rcond,
-                );
+                let mut rcond = <Self as Scalar>::Real::zero();
+                let info = $gecon(l.lapacke_layout(), norm as u8, n, a, l.lda(), anorm, &mut rcond);
                 into_result(info, rcond)
             }
 
             unsafe fn solve(l: MatrixLayout, t: Transpose, a: &[Self], ipiv: &Pivot, b: &mut [Self]) -> Result<()> {
+                // For the factors of `A^T`, `A x = b` is `(A^T)^T x = b`,
+                // and `A^H x = b` is `A^T conj(x) = conj(b)`
+                let (l, transposed) = into_fortran(l);
+                let (t, conj) = match (transposed, t) {
+                    (false, t) => (t, false),
+                    (true, Transpose::No) => (Transpose::Transpose, false),
+                    (true, Transpose::Transpose) => (Transpose::No, false),
+                    (true, Transpose::Hermite) => (Transpose::No, true),
+                };
+                if conj {
+                    conjugate_inplace(b);
+                }
                 let (n, _) = l.size();
                 let nrhs = 1;
-                let ldb = 1;
+                let ldb = max(1, n);
                 let info = $getrs(l.lapacke_layout(), t as u8, n, nrhs, a, l.lda(), ipiv, b, ldb);
+                if conj {
+                    conjugate_inplace(b);
+                }
                 into_result(info, ())
             }
         }
     };
 } // impl_solve!
 
+#[cfg(not(feature = "native"))]
 impl_solve!(f64, lapacke::dgetrf, lapacke::dgetri, lapacke::dgecon, lapacke::dgetrs);
+#[cfg(not(feature = "native"))]
 impl_solve!(f32, lapacke::sgetrf, lapacke::sgetri, lapacke::sgecon, lapacke::sgetrs);
+#[cfg(not(feature = "native"))]
 impl_solve!(c64, lapacke::zgetrf, lapacke::zgetri, lapacke::zgecon, lapacke::zgetrs);
+#[cfg(not(feature = "native"))]
 impl_solve!(c32, lapacke::cgetrf, lapacke::cgetri, lapacke::cgecon, lapacke::cgetrs);
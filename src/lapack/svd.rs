@@ -2,12 +2,13 @@
 
 use lapacke;
 use num_traits::Zero;
+use std::cmp::min;
 
 use crate::error::*;
 use crate::layout::MatrixLayout;
 use crate::types::*;
 
-use super::into_result;
+use super::{into_fortran, into_result, lwork, vec_uninit, WorkSize};
 
 #[repr(u8)]
 enum FlagSVD {
@@ -29,58 +30,216 @@ pub struct SVDOutput<A: Scalar> {
 
 /// Wraps `*gesvd`
 pub trait SVD_: Scalar {
-    unsafe fn svd(l: MatrixLayout, calc_u: bool, calc_vt: bool, a: &mut [Self]) -> Result<SVDOutput<Self>>;
+    unsafe fn svd(l: MatrixLayout, calc_u: bool, calc_vt: bool, a: &mut [Self]) -> Result<SVDOutput<Self>> {
+        let size = Self::svd_work_size(l, calc_u, calc_vt)?;
+        let mut work = vec![Self::zero(); size.work];
+        let mut rwork = vec![Self::Real::zero(); size.rwork];
+        Self::svd_with_work(l, calc_u, calc_vt, a, &mut work, &mut rwork)
+    }
+
+    /// Sizes of the work arrays of `svd_with_work`, queried by `lwork = -1`
+    unsafe fn svd_work_size(l: MatrixLayout, calc_u: bool, calc_vt: bool) -> Result<WorkSize>;
+
+    /// `svd` using the work arrays of the sizes given by `svd_work_size`
+    unsafe fn svd_with_work(
+        l: MatrixLayout,
+        calc_u: bool,
+        calc_vt: bool,
+        a: &mut [Self],
+        work: &mut [Self],
+        rwork: &mut [Self::Real],
+    ) -> Result<SVDOutput<Self>>;
+}
+
+/// Fortran layout and the flags for the matrix on the same memory
+///
+/// A matrix in the C layout is read as its transpose `A^T = conj(V) Σ U^T` in the Fortran layout without copy,
+/// whose `U` and `V^T` in the Fortran layout are `V^H` and `U` of `A` in the C layout.
+/// The last element is `true` if the matrix is transposed, where `U` and `V^T` should be swapped back.
+fn fortran_uvt(l: MatrixLayout, calc_u: bool, calc_vt: bool) -> (MatrixLayout, bool, bool, bool) {
+    match into_fortran(l) {
+        (l, true) => (l, calc_vt, calc_u, true),
+        (l, false) => (l, calc_u, calc_vt, false),
+    }
 }
 
-macro_rules! impl_svd {
+/// Leading dimensions of `U` and `V^T`
+fn ld_uvt(l: MatrixLayout, calc_u: bool) -> (i32, i32) {
+    let (m, n) = l.size();
+    (if calc_u { m } else { 1 }, n)
+}
+
+macro_rules! impl_svd_real {
     ($scalar:ty, $gesvd:path) => {
         impl SVD_ for $scalar {
-            unsafe fn svd(l: MatrixLayout, calc_u: bool, calc_vt: bool, mut a: &mut [Self]) -> Result<SVDOutput<Self>> {
+            unsafe fn svd_work_size(l: MatrixLayout, calc_u: bool, calc_vt: bool) -> Result<WorkSize> {
+                let (l, calc_u, calc_vt, _) = fortran_uvt(l, calc_u, calc_vt);
                 let (m, n) = l.size();
-                let k = ::std::cmp::min(n, m);
-                let lda = l.lda();
-                let (ju, ldu, mut u) = if calc_u {
-                    (FlagSVD::All, m, vec![Self::zero(); (m * m) as usize])
+                let (ldu, ldvt) = ld_uvt(l, calc_u);
+                let ju = if calc_u { FlagSVD::All } else { FlagSVD::No };
+                let jvt = if calc_vt { FlagSVD::All } else { FlagSVD::No };
+                let mut work = [Self::zero()];
+                let info = $gesvd(
+                    l.lapacke_layout(),
+                    ju as u8,
+                    jvt as u8,
+                    m,
+                    n,
+                    &mut [],
+                    l.lda(),
+                    &mut [],
+                    &mut [],
+                    ldu,
+                    &mut [],
+                    ldvt,
+                    &mut work,
+                    -1,
+                );
+                into_result(
+                    info,
+                    WorkSize {
+                        work: lwork(work[0]),
+                        rwork: 0,
+                    },
+                )
+            }
+
+            unsafe fn svd_with_work(
+                l: MatrixLayout,
+                calc_u: bool,
+                calc_vt: bool,
+                a: &mut [Self],
+                work: &mut [Self],
+                _rwork: &mut [Self::Real],
+            ) -> Result<SVDOutput<Self>> {
+                let (l, calc_u, calc_vt, transposed) = fortran_uvt(l, calc_u, calc_vt);
+                let (m, n) = l.size();
+                let k = min(n, m);
+                let (ldu, ldvt) = ld_uvt(l, calc_u);
+                let (ju, mut u) = if calc_u {
+                    (FlagSVD::All, vec_uninit((m * m) as usize))
                 } else {
-                    (FlagSVD::No, 1, Vec::new())
+                    (FlagSVD::No, Vec::new())
                 };
-                let (jvt, ldvt, mut vt) = if calc_vt {
-                    (FlagSVD::All, n, vec![Self::zero(); (n * n) as usize])
+                let (jvt, mut vt) = if calc_vt {
+                    (FlagSVD::All, vec_uninit((n * n) as usize))
                 } else {
-                    (FlagSVD::No, n, Vec::new())
+                    (FlagSVD::No, Vec::new())
                 };
                 let mut s = vec![Self::Real::zero(); k as usize];
-                let mut superb = vec![Self::Real::zero(); (k - 1) as usize];
-                dbg!(ldvt);
                 let info = $gesvd(
                     l.lapacke_layout(),
                     ju as u8,
                     jvt as u8,
                     m,
                     n,
-                    &mut a,
-                    lda,
+                    a,
+                    l.lda(),
                     &mut s,
                     &mut u,
                     ldu,
                     &mut vt,
                     ldvt,
-                    &mut superb,
+                    work,
+                    work.len() as i32,
+                );
+                into_result(info, ())?;
+                let u = if calc_u { Some(u) } else { None };
+                let vt = if calc_vt { Some(vt) } else { None };
+                let (u, vt) = if transposed { (vt, u) } else { (u, vt) };
+                Ok(SVDOutput { s: s, u: u, vt: vt })
+            }
+        }
+    };
+} // impl_svd_real!
+
+macro_rules! impl_svd_complex {
+    ($scalar:ty, $gesvd:path) => {
+        impl SVD_ for $scalar {
+            unsafe fn svd_work_size(l: MatrixLayout, calc_u: bool, calc_vt: bool) -> Result<WorkSize> {
+                let (l, calc_u, calc_vt, _) = fortran_uvt(l, calc_u, calc_vt);
+                let (m, n) = l.size();
+                let (ldu, ldvt) = ld_uvt(l, calc_u);
+                let ju = if calc_u { FlagSVD::All } else { FlagSVD::No };
+                let jvt = if calc_vt { FlagSVD::All } else { FlagSVD::No };
+                let mut work = [Self::zero()];
+                let info = $gesvd(
+                    l.lapacke_layout(),
+                    ju as u8,
+                    jvt as u8,
+                    m,
+                    n,
+                    &mut [],
+                    l.lda(),
+                    &mut [],
+                    &mut [],
+                    ldu,
+                    &mut [],
+                    ldvt,
+                    &mut work,
+                    -1,
+                    &mut [],
                 );
                 into_result(
                     info,
-                    SVDOutput {
-                        s: s,
-                        u: if calc_u { Some(u) } else { None },
-                        vt: if calc_vt { Some(vt) } else { None },
+                    WorkSize {
+                        work: lwork(work[0]),
+                        rwork: 5 * min(m, n) as usize,
                     },
                 )
             }
+
+            unsafe fn svd_with_work(
+                l: MatrixLayout,
+                calc_u: bool,
+                calc_vt: bool,
+                a: &mut [Self],
+                work: &mut [Self],
+                rwork: &mut [Self::Real],
+            ) -> Result<SVDOutput<Self>> {
+                let (l, calc_u, calc_vt, transposed) = fortran_uvt(l, calc_u, calc_vt);
+                let (m, n) = l.size();
+                let k = min(n, m);
+                let (ldu, ldvt) = ld_uvt(l, calc_u);
+                let (ju, mut u) = if calc_u {
+                    (FlagSVD::All, vec_uninit((m * m) as usize))
+                } else {
+                    (FlagSVD::No, Vec::new())
+                };
+                let (jvt, mut vt) = if calc_vt {
+                    (FlagSVD::All, vec_uninit((n * n) as usize))
+                } else {
+                    (FlagSVD::No, Vec::new())
+                };
+                let mut s = vec![Self::Real::zero(); k as usize];
+                let info = $gesvd(
+                    l.lapacke_layout(),
+                    ju as u8,
+                    jvt as u8,
+                    m,
+                    n,
+                    a,
+                    l.lda(),
+                    &mut s,
+                    &mut u,
+                    ldu,
+                    &mut vt,
+                    ldvt,
+                    work,
+                    work.len() as i32,
+                    rwork,
+                );
+                into_result(info, ())?;
+                let u = if calc_u { Some(u) } else { None };
+                let vt = if calc_vt { Some(vt) } else { None };
+                let (u, vt) = if transposed { (vt, u) } else { (u, vt) };
+                Ok(SVDOutput { s: s, u: u, vt: vt })
+            }
         }
     };
-} // impl_svd!
+} // impl_svd_complex!
 
-impl_svd!(f64, lapacke::dgesvd);
-impl_svd!(f32, lapacke::sgesvd);
-impl_svd!(c64, lapacke::zgesvd);
-impl_svd!(c32, lapacke::cgesvd);
+impl_svd_real!(f64, lapacke::dgesvd_work);
+impl_svd_real!(f32, lapacke::sgesvd_work);
+impl_svd_complex!(c64, lapacke::zgesvd_work);
+impl_svd_complex!(c32, lapacke::cgesvd_work);
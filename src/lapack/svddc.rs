@@ -6,7 +6,7 @@ use crate::layout::MatrixLayout;
 use crate::types::*;
 use crate::svddc::UVTFlag;
 
-use super::{SVDOutput, into_result};
+use super::{into_result, vec_uninit, SVDOutput};
 
 pub trait SVDDC_: Scalar {
     unsafe fn svddc(l: MatrixLayout, jobz: UVTFlag, a: &mut [Self]) -> Result<SVDOutput<Self>>;
@@ -29,9 +29,9 @@ macro_rules! impl_svdd {
                     UVTFlag::None => (1, 1),
                 };
                 let mut s = vec![Self::Real::zero(); k.max(1) as usize];
-                let mut u = vec![Self::zero(); (m * ucol).max(1) as usize];
+                let mut u = vec_uninit((m * ucol).max(1) as usize);
                 let ldu = l.resized(m, ucol).lda();
-                let mut vt = vec![Self::zero(); (vtrow * n).max(1) as usize];
+                let mut vt = vec_uninit((vtrow * n).max(1) as usize);
                 let ldvt = l.resized(vtrow, n).lda();
                 let info = $gesdd(
                     l.lapacke_layout(),
@@ -0,0 +1,148 @@
+//! Solve linear problems with tridiagonal matrices
+//!
+//! See also [the manual of dgttrf](http://www.netlib.org/lapack/explore-html/d5/d86/dgttrf_8f.html)
+
+use lapacke;
+use num_traits::Zero;
+
+use crate::error::*;
+use crate::types::*;
+
+use super::{into_result, Pivot, Transpose, UPLO};
+
+/// Wraps `*gttrf` and `*gttrs`
+pub trait Tridiagonal_: Scalar + Sized {
+    /// LU factorization of the tridiagonal matrix given by the sub-diagonal `dl`,
+    /// the diagonal `d`, and the super-diagonal `du`
+    ///
+    /// They are overwritten by the factors, and the second super-diagonal of `U` is returned with the pivots.
+    unsafe fn lu_tridiagonal(dl: &mut [Self], d: &mut [Self], du: &mut [Self]) -> Result<(Vec<Self>, Pivot)>;
+    /// Solve `A x = b` (or its transpose) using the LU factorization computed by `lu_tridiagonal`
+    unsafe fn solve_tridiagonal(
+        t: Transpose,
+        dl: &[Self],
+        d: &[Self],
+        du: &[Self],
+        du2: &[Self],
+        ipiv: &Pivot,
+        b: &mut [Self],
+    ) -> Result<()>;
+}
+
+macro_rules! impl_tridiagonal {
+    ($scalar:ty, $gttrf:path, $gttrs:path) => {
+        impl Tridiagonal_ for $scalar {
+            unsafe fn lu_tridiagonal(dl: &mut [Self], d: &mut [Self], du: &mut [Self]) -> Result<(Vec<Self>, Pivot)> {
+                let n = d.len() as i32;
+                let mut du2 = vec![Self::zero(); ::std::cmp::max(n - 2, 0) as usize];
+                let mut ipiv = vec![0; n as usize];
+                let info = $gttrf(n, dl, d, du, &mut du2, &mut ipiv);
+                into_result(info, (du2, ipiv))
+            }
+
+            unsafe fn solve_tridiagonal(
+                t: Transpose,
+                dl: &[Self],
+                d: &[Self],
+                du: &[Self],
+                du2: &[Self],
+                ipiv: &Pivot,
+                b: &mut [Self],
+            ) -> Result<()> {
+                let n = d.len() as i32;
+                let nrhs = 1;
+                let info = $gttrs(
+                    lapacke::Layout::ColumnMajor,
+                    t as u8,
+                    n,
+                    nrhs,
+                    dl,
+                    d,
+                    du,
+                    du2,
+                    ipiv,
+                    b,
+                    ::std::cmp::max(n, 1),
+                );
+                into_result(info, ())
+            }
+        }
+    };
+} // impl_tridiagonal!
+
+impl_tridiagonal!(f64, lapacke::dgttrf, lapacke::dgttrs);
+impl_tridiagonal!(f32, lapacke::sgttrf, lapacke::sgttrs);
+impl_tridiagonal!(c64, lapacke::zgttrf, lapacke::zgttrs);
+impl_tridiagonal!(c32, lapacke::cgttrf, lapacke::cgttrs);
+
+/// Wraps `*pttrf` and `*pttrs`
+pub trait TridiagonalCholesky_: Scalar + Sized {
+    /// `L D L^H` factorization of the Hermitian positive definite tridiagonal matrix
+    /// given by the real diagonal `d` and the sub-diagonal `e`
+    ///
+    /// `d` is overwritten by `D` and `e` by the sub-diagonal of the unit lower bidiagonal `L`.
+    unsafe fn ldl_tridiagonal(d: &mut [Self::Real], e: &mut [Self]) -> Result<()>;
+    /// Solve `A x = b` using the factorization computed by `ldl_tridiagonal`
+    unsafe fn solve_ldl_tridiagonal(d: &[Self::Real], e: &[Self], b: &mut [Self]) -> Result<()>;
+}
+
+macro_rules! impl_tridiagonal_cholesky_real {
+    ($scalar:ty, $pttrf:path, $pttrs:path) => {
+        impl TridiagonalCholesky_ for $scalar {
+            unsafe fn ldl_tridiagonal(d: &mut [Self::Real], e: &mut [Self]) -> Result<()> {
+                let n = d.len() as i32;
+                let info = $pttrf(n, d, e);
+                into_result(info, ())
+            }
+
+            unsafe fn solve_ldl_tridiagonal(d: &[Self::Real], e: &[Self], b: &mut [Self]) -> Result<()> {
+                let n = d.len() as i32;
+                let nrhs = 1;
+                let info = $pttrs(
+                    lapacke::Layout::ColumnMajor,
+                    n,
+                    nrhs,
+                    d,
+                    e,
+                    b,
+                    ::std::cmp::max(n, 1),
+                );
+                into_result(info, ())
+            }
+        }
+    };
+} // impl_tridiagonal_cholesky_real!
+
+macro_rules! impl_tridiagonal_cholesky_complex {
+    ($scalar:ty, $pttrf:path, $pttrs:path) => {
+        impl TridiagonalCholesky_ for $scalar {
+            unsafe fn ldl_tridiagonal(d: &mut [Self::Real], e: &mut [Self]) -> Result<()> {
+                let n = d.len() as i32;
+                let info = $pttrf(n, d, e);
+                into_result(info, ())
+            }
+
+            unsafe fn solve_ldl_tridiagonal(d: &[Self::Real], e: &[Self], b: &mut [Self]) -> Result<()> {
+                let n = d.len() as i32;
+                let nrhs = 1;
+                // `e` is the sub-diagonal of `L` as computed by `*pttrf`
+                let info = $pttrs(
+                    lapacke::Layout::ColumnMajor,
+                    UPLO::Lower as u8,
+                    n,
+                    nrhs,
+                    d,
+                    e,
+                    b,
+                    ::std::cmp::max(n, 1),
+                );
+                into_result(info, ())
+            }
+        }
+    };
+} // impl_tridiagonal_cholesky_complex!
+
+impl_tridiagonal_cholesky_real!(f64, lapacke::dpttrf, lapacke::dpttrs);
+impl_tridiagonal_cholesky_real!(f32, lapacke::spttrf, lapacke::spttrs);
+impl_tridiagonal_cholesky_complex!(c64, lapacke::zpttrf, lapacke::zpttrs);
+impl_tridiagonal_cholesky_complex!(c32, lapacke::cpttrf, lapacke::cpttrs);
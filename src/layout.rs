@@ -65,6 +65,14 @@ impl MatrixLayout {
         }
     }
 
+    /// Layout of the transposed matrix on the same memory, e.g. `C((row, col))` is read as `F((row, col))`
+    pub fn t(&self) -> Self {
+        match *self {
+            MatrixLayout::C((row, col)) => MatrixLayout::F((row, col)),
+            MatrixLayout::F((col, row)) => MatrixLayout::C((col, row)),
+        }
+    }
+
     pub fn toggle_order(&self) -> Self {
         match *self {
             MatrixLayout::C((row, col)) => MatrixLayout::F((col, row)),
@@ -0,0 +1,728 @@
+//! Least squares
+//!
+//! Compute a least-squares solution to the equation `A x = b`, i.e. the vector
+//! `x` which minimizes `|b - A x|_2`.
+//!
+//! - [LeastSquares](trait.LeastSquares.html) uses the QR (or LQ) factorization of `A` (`*gels`).
+//!   `A` must have full rank: the least-squares solution is computed for an overdetermined system (`m >= n`),
+//!   and the minimum-norm solution for an underdetermined system (`m < n`).
+//! - [LeastSquaresSvd](trait.LeastSquaresSvd.html) uses the singular value decomposition of `A` (`*gelsd`),
+//!   which is slower but also works for rank-deficient matrices.
+//! - [LeastSquaresWith](trait.LeastSquaresWith.html) selects the driver for rank-deficient matrices
+//!   by [LeastSquaresDriver](enum.LeastSquaresDriver.html). The QR factorization with column pivoting (`*gelsy`)
+//!   is cheaper than the SVD for regression with many collinear columns.
+//! - [solve_ridge](fn.solve_ridge.html) and [RidgeFactorized](struct.RidgeFactorized.html) solve
+//!   the Tikhonov-regularized problem `min |b - A x|_2^2 + λ |x|_2^2`.
+//! - [solve_glm](fn.solve_glm.html) solves the general Gauss-Markov linear model,
+//!   i.e. regression with a general (possibly singular) error covariance.
+//! - [total_least_squares](fn.total_least_squares.html) solves the total least-squares problem
+//!   for errors-in-variables, where both `A` and `b` contain errors.
+//!
+//! The right-hand side may be a vector (`Ix1`) or a matrix (`Ix2`) whose columns are solved at once,
+//! e.g. for regression against many targets, and the residual norm `|b - A x|_2` of each column
+//! is returned as well when it is available from the factorization.
+//!
+//! Singular values `s[i] <= rcond * s[0]` are regarded as zero, and the
+//! effective rank of `A` is returned together with all singular values so that
+//! the collinearity of the columns of `A` can be diagnosed,
+//! e.g. by [LeastSquaresResult::is_rank_deficient](struct.LeastSquaresResult.html#method.is_rank_deficient).
+//!
+//! # Examples
+//!
+//! Fit a line `y = c0 + c1 t` to data points:
+//!
+//! ```
+//! #[macro_use]
+//! extern crate ndarray;
+//! extern crate ndarray_linalg;
+//!
+//! use ndarray::prelude::*;
+//! use ndarray_linalg::*;
+//! # fn main() {
+//!
+//! let a: Array2<f64> = array![[1., 0.], [1., 1.], [1., 2.]];
+//! let y: Array1<f64> = array![1., 2., 4.];
+//! let c = a.lstsq(&y).unwrap();
+//! assert!(c.all_close(&array![5. / 6., 1.5], 1e-9));
+//! # }
+//! ```
+//!
+//! Diagnose a rank-deficient matrix:
+//!
+//! ```
+//! #[macro_use]
+//! extern crate ndarray;
+//! extern crate ndarray_linalg;
+//!
+//! use ndarray::prelude::*;
+//! use ndarray_linalg::*;
+//! # fn main() {
+//!
+//! // The second column is twice the first one
+//! let a: Array2<f64> = array![[1., 2.], [2., 4.], [3., 6.]];
+//! let b: Array1<f64> = array![1., 2., 3.];
+//! let result = a.least_squares(&b, None).unwrap();
+//! assert_eq!(result.rank, 1);
+//! assert!(result.is_rank_deficient());
+//! // minimum-norm solution
+//! assert!(result.solution.all_close(&array![0.2, 0.4], 1e-9));
+//! # }
+//! ```
+
+use ndarray::*;
+use num_traits::{Float, One, Zero};
+
+use crate::convert::*;
+use crate::error::*;
+use crate::generate::conjugate;
+use crate::lapack::LeastSquaresOutput;
+use crate::layout::*;
+use crate::norm::Norm;
+use crate::svd::*;
+use crate::svddc::*;
+use crate::types::*;
+
+pub use crate::lapack::LeastSquaresDriver;
+
+/// Solve least squares for matrix reference using the QR (or LQ) factorization
+///
+/// The right-hand side `b` is a vector (`Ix1`) or a matrix (`Ix2`) whose columns are solved simultaneously.
+pub trait LeastSquares<A: Scalar, I: Dimension> {
+    /// Solve `min |b - A x|_2` where `A` is `self` and `b` is the argument.
+    ///
+    /// If `A` is wide (`m < n`), the solution of minimum norm `|x|_2` is returned.
+    /// This fails with `LinalgError::Lapack` if `A` does not have full rank.
+    fn lstsq<S: Data<Elem = A>>(&self, b: &ArrayBase<S, I>) -> Result<Array<A, I>>;
+
+    /// Solve `min |b - A x|_2` as [lstsq](#tymethod.lstsq),
+    /// and return the 2-norm of the residual `b - A x` for each right-hand side
+    ///
+    /// The residual norms are obtained from the factorization without forming `A x`,
+    /// and they are zero if `A` is wide.
+    fn lstsq_residual<S: Data<Elem = A>>(
+        &self,
+        b: &ArrayBase<S, I>,
+    ) -> Result<(Array<A, I>, Array<A::Real, I::Smaller>)>;
+}
+
+/// Solve least squares for matrix using the QR (or LQ) factorization
+pub trait LeastSquaresInto<A: Scalar, I: Dimension> {
+    /// Solve `min |b - A x|_2` where `A` is `self` and `b` is the argument.
+    ///
+    /// See [LeastSquares::lstsq](trait.LeastSquares.html#tymethod.lstsq).
+    fn lstsq_into<S: Data<Elem = A>>(self, b: ArrayBase<S, I>) -> Result<Array<A, I>>;
+}
+
+/// Solve least squares for mutable reference of matrix using the QR (or LQ) factorization
+///
+/// **The contents of the matrix are destroyed.**
+pub trait LeastSquaresMut<A: Scalar, I: Dimension> {
+    /// Solve `min |b - A x|_2` where `A` is `self` and `b` is the argument.
+    ///
+    /// See [LeastSquares::lstsq](trait.LeastSquares.html#tymethod.lstsq).
+    fn lstsq_mut<S: Data<Elem = A>>(&mut self, b: &ArrayBase<S, I>) -> Result<Array<A, I>>;
+}
+
+impl<A, Sa, I> LeastSquares<A, I> for ArrayBase<Sa, Ix2>
+where
+    A: Scalar + Lapack,
+    Sa: Data<Elem = A>,
+    I: Dimension,
+{
+    fn lstsq<S: Data<Elem = A>>(&self, b: &ArrayBase<S, I>) -> Result<Array<A, I>> {
+        let mut a: Array2<A> = replicate(self);
+        a.lstsq_mut(b)
+    }
+
+    fn lstsq_residual<S: Data<Elem = A>>(
+        &self,
+        b: &ArrayBase<S, I>,
+    ) -> Result<(Array<A, I>, Array<A::Real, I::Smaller>)> {
+        let mut a: Array2<A> = replicate(self);
+        let result = least_squares_rhs(&mut a, b, None, None)?;
+        let residual_norms = match result.residual_norms {
+            Some(r) => r,
+            // `A` is wide, and the equation is solved exactly
+            None => ArrayD::zeros(&b.shape()[1..]).into_dimensionality()?,
+        };
+        Ok((result.solution, residual_norms))
+    }
+}
+
+impl<A, Sa, I> LeastSquaresInto<A, I> for ArrayBase<Sa, Ix2>
+where
+    A: Scalar + Lapack,
+    Sa: DataMut<Elem = A>,
+    I: Dimension,
+{
+    fn lstsq_into<S: Data<Elem = A>>(mut self, b: ArrayBase<S, I>) -> Result<Array<A, I>> {
+        self.lstsq_mut(&b)
+    }
+}
+
+impl<A, Sa, I> LeastSquaresMut<A, I> for ArrayBase<Sa, Ix2>
+where
+    A: Scalar + Lapack,
+    Sa: DataMut<Elem = A>,
+    I: Dimension,
+{
+    fn lstsq_mut<S: Data<Elem = A>>(&mut self, b: &ArrayBase<S, I>) -> Result<Array<A, I>> {
+        Ok(least_squares_rhs(self, b, None, None)?.solution)
+    }
+}
+
+/// Result of a least-squares computation
+pub struct LeastSquaresResult<A: Scalar, I: Dimension> {
+    /// The singular values of the matrix `A` in `A x = b`
+    pub singular_values: Array1<A::Real>,
+    /// The solution `x` which minimizes `|b - A x|_2`
+    pub solution: Array<A, I>,
+    /// The effective rank of the matrix `A` in `A x = b`
+    pub rank: i32,
+    /// The 2-norm of the residual `b - A x` for each right-hand side
+    ///
+    /// This is obtained from the factorization without forming `A x`, and is only available
+    /// if `A` is tall (`m > n`) and has full column rank, and the driver is not `LeastSquaresDriver::Pivoted`.
+    pub residual_norms: Option<Array<A::Real, I::Smaller>>,
+}
+
+impl<A: Scalar, I: Dimension> LeastSquaresResult<A, I> {
+    /// Check if some columns (or rows) of `A` are collinear up to the cutoff `rcond`,
+    /// i.e. the effective rank is smaller than `min(m, n)`
+    ///
+    /// Then `solution` is the minimum-norm solution, which is not unique as a least-squares solution.
+    /// This requires the singular values, and is always `false` for `LeastSquaresDriver::Pivoted`.
+    pub fn is_rank_deficient(&self) -> bool {
+        (self.rank as usize) < self.singular_values.len()
+    }
+
+    /// 2-norm condition number `s[0] / s[min(m, n) - 1]` of `A`, which is infinite if `A` is rank deficient
+    ///
+    /// This is NaN if the singular values are not computed.
+    pub fn cond(&self) -> A::Real {
+        match self.singular_values.len() {
+            0 => A::Real::nan(),
+            k => self.singular_values[0] / self.singular_values[k - 1],
+        }
+    }
+
+    /// Condition number `s[0] / s[rank - 1]` of `A` restricted to the singular values above the cutoff,
+    /// which governs the sensitivity of `solution`
+    ///
+    /// This is NaN if the singular values are not computed or the rank is zero.
+    pub fn effective_cond(&self) -> A::Real {
+        if self.rank == 0 || self.singular_values.is_empty() {
+            return A::Real::nan();
+        }
+        self.singular_values[0] / self.singular_values[self.rank as usize - 1]
+    }
+}
+
+/// Solve least squares for matrix reference
+pub trait LeastSquaresSvd<D, A, I>
+where
+    D: Data<Elem = A>,
+    A: Scalar + Lapack,
+    I: Dimension,
+{
+    /// Solve `min |b - A x|_2` where `A` is `self` and `b` is the argument.
+    ///
+    /// Singular values `s[i] <= rcond * s[0]` are treated as zero.
+    /// If `rcond` is `None`, the machine precision is used.
+    fn least_squares(&self, rhs: &ArrayBase<D, I>, rcond: Option<A::Real>) -> Result<LeastSquaresResult<A, I>>;
+}
+
+/// Solve least squares for matrix
+pub trait LeastSquaresSvdInto<D, A, I>
+where
+    D: Data<Elem = A>,
+    A: Scalar + Lapack,
+    I: Dimension,
+{
+    /// Solve `min |b - A x|_2` where `A` is `self` and `b` is the argument.
+    ///
+    /// Singular values `s[i] <= rcond * s[0]` are treated as zero.
+    /// If `rcond` is `None`, the machine precision is used.
+    fn least_squares_into(self, rhs: ArrayBase<D, I>, rcond: Option<A::Real>) -> Result<LeastSquaresResult<A, I>>;
+}
+
+/// Solve least squares for mutable reference of matrix
+///
+/// **The contents of the matrix are destroyed.**
+pub trait LeastSquaresSvdInplace<D, A, I>
+where
+    D: Data<Elem = A>,
+    A: Scalar + Lapack,
+    I: Dimension,
+{
+    /// Solve `min |b - A x|_2` where `A` is `self` and `b` is the argument.
+    ///
+    /// Singular values `s[i] <= rcond * s[0]` are treated as zero.
+    /// If `rcond` is `None`, the machine precision is used.
+    fn least_squares_inplace(
+        &mut self,
+        rhs: &ArrayBase<D, I>,
+        rcond: Option<A::Real>,
+    ) -> Result<LeastSquaresResult<A, I>>;
+}
+
+impl<A, Sa, Sb, I> LeastSquaresSvd<Sb, A, I> for ArrayBase<Sa, Ix2>
+where
+    A: Scalar + Lapack,
+    Sa: Data<Elem = A>,
+    Sb: Data<Elem = A>,
+    I: Dimension,
+{
+    fn least_squares(&self, rhs: &ArrayBase<Sb, I>, rcond: Option<A::Real>) -> Result<LeastSquaresResult<A, I>> {
+        let mut a: Array2<A> = replicate(self);
+        a.least_squares_inplace(rhs, rcond)
+    }
+}
+
+impl<A, Sa, Sb, I> LeastSquaresSvdInto<Sb, A, I> for ArrayBase<Sa, Ix2>
+where
+    A: Scalar + Lapack,
+    Sa: DataMut<Elem = A>,
+    Sb: Data<Elem = A>,
+    I: Dimension,
+{
+    fn least_squares_into(mut self, rhs: ArrayBase<Sb, I>, rcond: Option<A::Real>) -> Result<LeastSquaresResult<A, I>> {
+        self.least_squares_inplace(&rhs, rcond)
+    }
+}
+
+impl<A, Sa, Sb, I> LeastSquaresSvdInplace<Sb, A, I> for ArrayBase<Sa, Ix2>
+where
+    A: Scalar + Lapack,
+    Sa: DataMut<Elem = A>,
+    Sb: Data<Elem = A>,
+    I: Dimension,
+{
+    fn least_squares_inplace(
+        &mut self,
+        rhs: &ArrayBase<Sb, I>,
+        rcond: Option<A::Real>,
+    ) -> Result<LeastSquaresResult<A, I>> {
+        least_squares_rhs(self, rhs, rcond, Some(LeastSquaresDriver::SvdDc))
+    }
+}
+
+/// Solve least squares for matrix reference using a specified LAPACK driver
+pub trait LeastSquaresWith<D, A, I>
+where
+    D: Data<Elem = A>,
+    A: Scalar + Lapack,
+    I: Dimension,
+{
+    /// Solve `min |b - A x|_2` where `A` is `self` and `b` is the argument.
+    ///
+    /// The meaning of `rcond` depends on `driver`:
+    ///
+    /// - For the SVD-based drivers, singular values `s[i] <= rcond * s[0]` are treated as zero.
+    ///   If `rcond` is `None`, the machine precision is used.
+    /// - For `LeastSquaresDriver::Pivoted`, the effective rank is the largest `r` such that
+    ///   the leading `r x r` block of the pivoted `R` factor has condition number less than `1 / rcond`.
+    ///   If `rcond` is `None`, the machine precision is used.
+    ///   `singular_values` of the result is empty since they are not computed.
+    fn least_squares_with(
+        &self,
+        rhs: &ArrayBase<D, I>,
+        rcond: Option<A::Real>,
+        driver: LeastSquaresDriver,
+    ) -> Result<LeastSquaresResult<A, I>>;
+}
+
+impl<A, Sa, Sb, I> LeastSquaresWith<Sb, A, I> for ArrayBase<Sa, Ix2>
+where
+    A: Scalar + Lapack,
+    Sa: Data<Elem = A>,
+    Sb: Data<Elem = A>,
+    I: Dimension,
+{
+    fn least_squares_with(
+        &self,
+        rhs: &ArrayBase<Sb, I>,
+        rcond: Option<A::Real>,
+        driver: LeastSquaresDriver,
+    ) -> Result<LeastSquaresResult<A, I>> {
+        let mut a: Array2<A> = replicate(self);
+        least_squares_rhs(&mut a, rhs, rcond, Some(driver))
+    }
+}
+
+/// Solve least squares for a vector (`Ix1`) or the columns of a matrix (`Ix2`) as the right-hand side
+///
+/// `driver` is `None` for the QR factorization (`*gels`).
+fn least_squares_rhs<A, Sa, Sb, I>(
+    a: &mut ArrayBase<Sa, Ix2>,
+    rhs: &ArrayBase<Sb, I>,
+    rcond: Option<A::Real>,
+    driver: Option<LeastSquaresDriver>,
+) -> Result<LeastSquaresResult<A, I>>
+where
+    A: Scalar + Lapack,
+    Sa: DataMut<Elem = A>,
+    Sb: Data<Elem = A>,
+    I: Dimension,
+{
+    let (rhs, vector) = as_columns(rhs)?;
+    let result = least_squares_columns(a, &rhs, rcond, driver)?;
+    Ok(LeastSquaresResult {
+        singular_values: result.singular_values,
+        solution: from_columns(result.solution, vector)?,
+        rank: result.rank,
+        residual_norms: match result.residual_norms {
+            Some(r) => Some(from_column_values(r, vector)?),
+            None => None,
+        },
+    })
+}
+
+/// View a vector (`Ix1`) as a matrix of a single column, or a matrix (`Ix2`) as it is,
+/// where the flag is `true` for a vector
+fn as_columns<A, S, I>(rhs: &ArrayBase<S, I>) -> Result<(ArrayView2<A>, bool)>
+where
+    S: Data<Elem = A>,
+    I: Dimension,
+{
+    let rhs = rhs.view().into_dyn();
+    match rhs.ndim() {
+        1 => Ok((rhs.insert_axis(Axis(1)).into_dimensionality()?, true)),
+        2 => Ok((rhs.into_dimensionality()?, false)),
+        _ => Err(ShapeError::from_kind(ErrorKind::IncompatibleShape).into()),
+    }
+}
+
+/// Inverse of `as_columns` for the solution
+fn from_columns<T, I: Dimension>(x: Array2<T>, vector: bool) -> Result<Array<T, I>> {
+    let x = if vector {
+        x.index_axis_move(Axis(1), 0).into_dyn()
+    } else {
+        x.into_dyn()
+    };
+    Ok(x.into_dimensionality()?)
+}
+
+/// Inverse of `as_columns` for a value of each column, e.g. the residual norm
+fn from_column_values<T, I: Dimension>(v: Array1<T>, vector: bool) -> Result<Array<T, I>> {
+    let v = if vector {
+        v.index_axis_move(Axis(0), 0).into_dyn()
+    } else {
+        v.into_dyn()
+    };
+    Ok(v.into_dimensionality()?)
+}
+
+/// 2-norm of each column of `b - A x`
+fn residual_norms<A, S>(a: &ArrayBase<S, Ix2>, x: &Array2<A>, b: &ArrayView2<A>) -> Array1<A::Real>
+where
+    A: Scalar,
+    S: Data<Elem = A>,
+{
+    (b - &a.dot(x)).map_axis(Axis(0), |r| r.norm_l2())
+}
+
+fn least_squares_columns<A, Sa>(
+    a: &mut ArrayBase<Sa, Ix2>,
+    rhs: &ArrayView2<A>,
+    rcond: Option<A::Real>,
+    driver: Option<LeastSquaresDriver>,
+) -> Result<LeastSquaresResult<A, Ix2>>
+where
+    A: Scalar + Lapack,
+    Sa: DataMut<Elem = A>,
+{
+    let (m, n) = (a.rows(), a.cols());
+    let (rows, k) = rhs.dim();
+    if rows != m {
+        return Err(ShapeError::from_kind(ErrorKind::IncompatibleShape).into());
+    }
+    let al = a.layout()?;
+    // The drivers require `max(m, n)` rows for the right-hand side in the same order as `A`
+    let bl = al.resized(::std::cmp::max(m, n) as i32, k as i32);
+    let mut b: Array2<A> = Array2::zeros(bl.as_shape());
+    b.slice_mut(s![..m, ..]).assign(rhs);
+    let a_mem = a.as_allocated_mut()?;
+    let b_mem = b.as_slice_memory_order_mut().unwrap();
+    let out = unsafe {
+        match driver {
+            None => {
+                A::least_squares_qr(al, bl, a_mem, b_mem)?;
+                LeastSquaresOutput {
+                    singular_values: Vec::new(),
+                    rank: ::std::cmp::min(m, n) as i32,
+                }
+            }
+            Some(LeastSquaresDriver::Svd) => {
+                A::least_squares_svd(al, bl, a_mem, b_mem, rcond.unwrap_or(-A::Real::one()))?
+            }
+            Some(LeastSquaresDriver::SvdDc) => {
+                A::least_squares_svddc(al, bl, a_mem, b_mem, rcond.unwrap_or(-A::Real::one()))?
+            }
+            Some(LeastSquaresDriver::Pivoted) => LeastSquaresOutput {
+                singular_values: Vec::new(),
+                rank: A::least_squares_pivoted(al, bl, a_mem, b_mem, rcond.unwrap_or_else(A::Real::epsilon))?,
+            },
+        }
+    };
+    // The rows `n..m` of the transformed right-hand side give the residual if `A` has full column rank
+    let residual_norms = if m > n && out.rank == n as i32 && driver != Some(LeastSquaresDriver::Pivoted) {
+        Some(b.slice(s![n.., ..]).map_axis(Axis(0), |r| r.norm_l2()))
+    } else {
+        None
+    };
+    Ok(LeastSquaresResult {
+        singular_values: ArrayBase::from_vec(out.singular_values),
+        solution: b.slice(s![..n, ..]).to_owned(),
+        rank: out.rank,
+        residual_norms,
+    })
+}
+
+/// Solve the ridge (Tikhonov-regularized) least-squares problem `min |b - A x|_2^2 + λ |x|_2^2`
+///
+/// The problem is solved as the least-squares problem of the augmented matrix `[A; √λ I]`
+/// by the QR factorization, which avoids the loss of precision of the normal equation `(A^H A + λI) x = A^H b`.
+/// `lambda` must be non-negative, and positive if `A` does not have full column rank.
+/// The right-hand side `b` is a vector (`Ix1`) or a matrix (`Ix2`) whose columns are solved at once.
+///
+/// Use [RidgeFactorized](struct.RidgeFactorized.html) to solve for many `λ` with a single factorization.
+pub fn solve_ridge<A, Sa, Sb, I>(a: &ArrayBase<Sa, Ix2>, b: &ArrayBase<Sb, I>, lambda: A::Real) -> Result<Array<A, I>>
+where
+    A: Scalar + Lapack,
+    Sa: Data<Elem = A>,
+    Sb: Data<Elem = A>,
+    I: Dimension,
+{
+    let (b, vector) = as_columns(b)?;
+    from_columns(ridge_columns(a, &b, lambda)?, vector)
+}
+
+/// Solve the ridge problem as [solve_ridge](fn.solve_ridge.html),
+/// and return the 2-norm of the residual `b - A x` (without the penalty) for each right-hand side
+pub fn solve_ridge_residual<A, Sa, Sb, I>(
+    a: &ArrayBase<Sa, Ix2>,
+    b: &ArrayBase<Sb, I>,
+    lambda: A::Real,
+) -> Result<(Array<A, I>, Array<A::Real, I::Smaller>)>
+where
+    A: Scalar + Lapack,
+    Sa: Data<Elem = A>,
+    Sb: Data<Elem = A>,
+    I: Dimension,
+{
+    let (b, vector) = as_columns(b)?;
+    let x = ridge_columns(a, &b, lambda)?;
+    let r = residual_norms(a, &x, &b);
+    Ok((from_columns(x, vector)?, from_column_values(r, vector)?))
+}
+
+fn ridge_columns<A, Sa>(a: &ArrayBase<Sa, Ix2>, b: &ArrayView2<A>, lambda: A::Real) -> Result<Array2<A>>
+where
+    A: Scalar + Lapack,
+    Sa: Data<Elem = A>,
+{
+    let (m, n) = a.dim();
+    if b.rows() != m {
+        return Err(ShapeError::from_kind(ErrorKind::IncompatibleShape).into());
+    }
+    let mut aug = Array2::zeros((m + n, n).f());
+    aug.slice_mut(s![..m, ..]).assign(a);
+    let sqrt_lambda = A::from_real(lambda.sqrt());
+    for i in 0..n {
+        aug[(m + i, i)] = sqrt_lambda;
+    }
+    let mut rhs = Array2::zeros((m + n, b.cols()));
+    rhs.slice_mut(s![..m, ..]).assign(b);
+    aug.lstsq_mut(&rhs)
+}
+
+/// Thin SVD `A = U Σ V^H` of a matrix for solving the ridge problem with several regularization parameters
+///
+/// The solution is `x = V diag(σ_i / (σ_i^2 + λ)) U^H b`,
+/// which costs only two matrix-vector products for each `λ` and `b`.
+pub struct RidgeFactorized<A: Scalar> {
+    u: Array2<A>,
+    s: Array1<A::Real>,
+    vt: Array2<A>,
+}
+
+impl<A: Scalar + Lapack> RidgeFactorized<A> {
+    /// Compute the thin SVD of `a` by divide-and-conquer
+    pub fn new<S: Data<Elem = A>>(a: &ArrayBase<S, Ix2>) -> Result<Self> {
+        let (u, s, vt) = a.svddc(UVTFlag::Some)?;
+        Ok(RidgeFactorized {
+            u: u.unwrap(),
+            s,
+            vt: vt.unwrap(),
+        })
+    }
+
+    /// Singular values of `A` in decreasing order
+    pub fn singular_values(&self) -> &Array1<A::Real> {
+        &self.s
+    }
+
+    /// Solve `min |b - A x|_2^2 + λ |x|_2^2`
+    ///
+    /// For `λ = 0`, this gives the minimum-norm least-squares solution
+    /// where the zero singular values are ignored.
+    /// The right-hand side `b` is a vector (`Ix1`) or a matrix (`Ix2`) whose columns are solved at once.
+    pub fn solve<S, I>(&self, b: &ArrayBase<S, I>, lambda: A::Real) -> Result<Array<A, I>>
+    where
+        S: Data<Elem = A>,
+        I: Dimension,
+    {
+        let (b, vector) = as_columns(b)?;
+        let c = self.filtered_coefficients(&b, lambda)?;
+        let v: Array2<A> = conjugate(&self.vt);
+        from_columns(v.dot(&c), vector)
+    }
+
+    /// Solve as [solve](#method.solve), and return the 2-norm of the residual `b - A x`
+    /// (without the penalty) for each right-hand side
+    pub fn solve_residual<S, I>(
+        &self,
+        b: &ArrayBase<S, I>,
+        lambda: A::Real,
+    ) -> Result<(Array<A, I>, Array<A::Real, I::Smaller>)>
+    where
+        S: Data<Elem = A>,
+        I: Dimension,
+    {
+        let (b, vector) = as_columns(b)?;
+        let mut c = self.filtered_coefficients(&b, lambda)?;
+        let v: Array2<A> = conjugate(&self.vt);
+        let x = v.dot(&c);
+        // `A x = U Σ c`
+        for (mut row, &s) in c.outer_iter_mut().zip(self.s.iter()) {
+            row.mapv_inplace(|c| c.mul_real(s));
+        }
+        let r = (&b - &self.u.dot(&c)).map_axis(Axis(0), |r| r.norm_l2());
+        Ok((from_columns(x, vector)?, from_column_values(r, vector)?))
+    }
+
+    /// `c = diag(σ_i / (σ_i^2 + λ)) U^H b`, where the solution is `x = V c`
+    fn filtered_coefficients(&self, b: &ArrayView2<A>, lambda: A::Real) -> Result<Array2<A>> {
+        if b.rows() != self.u.rows() {
+            return Err(ShapeError::from_kind(ErrorKind::IncompatibleShape).into());
+        }
+        let uh: Array2<A> = conjugate(&self.u);
+        let mut c = uh.dot(b);
+        for (mut row, &s) in c.outer_iter_mut().zip(self.s.iter()) {
+            let d = s * s + lambda;
+            let f = if d.is_zero() { A::Real::zero() } else { s / d };
+            row.mapv_inplace(|c| c.mul_real(f));
+        }
+        Ok(c)
+    }
+}
+
+/// Solve the general Gauss-Markov linear model (GLM) problem `min |y|_2` subject to `d = A x + B y`
+///
+/// Returns `(x, y)`. This is the generalized least-squares regression `d = A x + ε`
+/// whose error `ε = B y` has the covariance `B B^H`, which may be singular,
+/// and `|y|_2` is the residual norm of the regression.
+/// `A` is `n x m` and `B` is `n x p` with `m <= n <= m + p`,
+/// and `A` and `[A, B]` must have full column and row rank respectively.
+/// `d` is a vector (`Ix1`) or a matrix (`Ix2`), for whose columns `x` and `y` are the columns of the results.
+///
+/// This uses the generalized QR factorization of `(A, B)` (`*ggglm`)
+/// without forming the inverse of the covariance.
+/// The factorization is recomputed for each column of `d`, since `*ggglm` takes a single right-hand side.
+pub fn solve_glm<A, Sa, Sb, Sd, I>(
+    a: &ArrayBase<Sa, Ix2>,
+    b: &ArrayBase<Sb, Ix2>,
+    d: &ArrayBase<Sd, I>,
+) -> Result<(Array<A, I>, Array<A, I>)>
+where
+    A: Scalar + Lapack,
+    Sa: Data<Elem = A>,
+    Sb: Data<Elem = A>,
+    Sd: Data<Elem = A>,
+    I: Dimension,
+{
+    let (d, vector) = as_columns(d)?;
+    let (n, m) = a.dim();
+    let p = b.cols();
+    if b.rows() != n || d.rows() != n || m > n || n > m + p {
+        return Err(ShapeError::from_kind(ErrorKind::IncompatibleShape).into());
+    }
+    let al = MatrixLayout::F((m as i32, n as i32));
+    let bl = MatrixLayout::F((p as i32, n as i32));
+    let mut x = Array2::zeros((m, d.cols()));
+    let mut y = Array2::zeros((p, d.cols()));
+    for (j, dj) in d.axis_iter(Axis(1)).enumerate() {
+        // `*ggglm` destroys `A`, `B` and `d`
+        let mut a: Array2<A> = clone_with_layout(al, a);
+        let mut b: Array2<A> = clone_with_layout(bl, b);
+        let mut dj = dj.to_owned();
+        let (xj, yj) = unsafe {
+            A::least_squares_glm(
+                al,
+                bl,
+                a.as_allocated_mut()?,
+                b.as_allocated_mut()?,
+                dj.as_slice_mut().unwrap(),
+            )?
+        };
+        x.column_mut(j).assign(&aview1(&xj));
+        y.column_mut(j).assign(&aview1(&yj));
+    }
+    Ok((from_columns(x, vector)?, from_columns(y, vector)?))
+}
+
+/// Result of [total_least_squares](fn.total_least_squares.html)
+#[derive(Debug, Clone)]
+pub struct TotalLeastSquaresResult<A: Scalar, I: Dimension = Ix1> {
+    /// The solution `x` of the corrected equation `(A + ΔA) x = b + Δb`
+    pub solution: Array<A, I>,
+    /// The Frobenius norm of the minimal correction `|[ΔA, Δb]|_F` for each right-hand side,
+    /// which is the smallest singular value of `[A, b]`
+    pub correction_norm: Array<A::Real, I::Smaller>,
+}
+
+/// Solve the total least-squares (TLS) problem for `A x ≈ b`
+///
+/// While the ordinary least squares assumes errors only in `b`, the TLS problem for errors-in-variables
+/// finds the smallest correction `[ΔA, Δb]` in the Frobenius norm such that `(A + ΔA) x = b + Δb` is solvable.
+/// It is solved by the SVD of the augmented matrix `[A, b]`: if `v` is the right singular vector of its smallest
+/// singular value, the solution is `x = -v[..n] / v[n]`.
+///
+/// `b` is a vector (`Ix1`) or a matrix (`Ix2`), whose columns are the independent TLS problems
+/// with their own corrections of `A`.
+/// If `v[n]` vanishes, the TLS problem has no solution and the solution becomes infinite.
+pub fn total_least_squares<A, Sa, Sb, I>(
+    a: &ArrayBase<Sa, Ix2>,
+    b: &ArrayBase<Sb, I>,
+) -> Result<TotalLeastSquaresResult<A, I>>
+where
+    A: Scalar + Lapack,
+    Sa: Data<Elem = A>,
+    Sb: Data<Elem = A>,
+    I: Dimension,
+{
+    let (b, vector) = as_columns(b)?;
+    let (m, n) = a.dim();
+    if b.rows() != m {
+        return Err(ShapeError::from_kind(ErrorKind::IncompatibleShape).into());
+    }
+    let mut solution = Array2::zeros((n, b.cols()));
+    let mut correction_norm = Array1::zeros(b.cols());
+    let mut c = Array2::zeros((m, n + 1));
+    c.slice_mut(s![.., ..n]).assign(a);
+    for (j, bj) in b.axis_iter(Axis(1)).enumerate() {
+        c.column_mut(n).assign(&bj);
+        let (_, s, vt) = c.svd(false, true)?;
+        let vt = vt.unwrap();
+        let v = vt.row(n).mapv(|v| v.conj());
+        let scale = -v[n];
+        solution.column_mut(j).assign(&v.slice(s![..n]).mapv(|v| v / scale));
+        // The singular values are only `min(m, n + 1)`, and the smallest one is zero for `m <= n`
+        correction_norm[j] = if m > n { s[n] } else { A::Real::zero() };
+    }
+    Ok(TotalLeastSquaresResult {
+        solution: from_columns(solution, vector)?,
+        correction_norm: from_column_values(correction_norm, vector)?,
+    })
+}
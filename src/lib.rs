@@ -7,13 +7,42 @@
 //! - Decomposition methods:
 //!     - [QR decomposition](qr/index.html)
 //!     - [Cholesky/LU decomposition](cholesky/index.html)
+//!     - [Eigenvalue decomposition for general matrices](eig/index.html)
 //!     - [Eigenvalue decomposition for Hermite matrices](eigh/index.html)
+//!     - [Schur decomposition](schur/index.html)
+//!     - [Hessenberg reduction](hessenberg/index.html)
+//!     - [Power and inverse iteration for a single eigenpair](power/index.html)
+//!     - [LOBPCG for extreme eigenpairs of Hermitian operators](lobpcg/index.html)
 //!     - [**S**ingular **V**alue **D**ecomposition](svd/index.html)
+//!     - [Interpolative decomposition](interpolative/index.html)
+//!     - [CUR decomposition](cur/index.html)
 //! - Solution of linear systems:
 //!    - [General matrices](solve/index.html)
 //!    - [Triangular matrices](triangular/index.html)
+//!    - [Band matrices](banded/index.html)
+//!    - [Tridiagonal matrices](tridiagonal/index.html)
+//!    - [Toeplitz matrices](toeplitz/index.html)
+//!    - [Hankel matrices](hankel/index.html)
+//!    - [Circulant matrices](circulant/index.html)
+//!    - [Block-diagonal matrices](block_diag/index.html)
 //!    - [Hermitian/real symmetric matrices](solveh/index.html)
+//!    - [Hermitian/real symmetric matrices in the packed storage](packed/index.html)
+//!    - [Kronecker-structured systems](kron/index.html)
+//!    - [Diagonal-plus-low-rank matrices](woodbury/index.html)
+//!    - [Least squares](least_squares/index.html)
+//!    - [Iterative solvers with preconditioners for large operators](krylov/index.html)
+//! - [Randomized low-rank approximation](randomized/index.html)
 //! - [Inverse matrix computation](solve/trait.Inverse.html)
+//! - [Condition numbers](cond/index.html)
+//! - [Roots of polynomials by companion matrices](companion/index.html)
+//! - [Algebraic Riccati equations for optimal control](riccati/index.html)
+//! - Matrix functions:
+//!     - [Matrix exponential](expm/index.html)
+//!     - [Matrix square root](sqrtm/index.html)
+//!     - [Integer matrix power](powi/index.html)
+//!     - [Matrix sine and cosine](trigm/index.html)
+//!     - [General matrix functions](funm/index.html)
+//!     - [Geometric mean and geodesics of positive definite matrices](geodesic/index.html)
 //!
 //! Naming Convention
 //! -----------------------
@@ -34,50 +63,148 @@
 //!  -----------
 //!  - [Assertions for array](index.html#macros)
 //!  - [Random matrix generators](generate/index.html)
+//!  - [Classic test matrices](gallery/index.html)
+//!  - [Hermitization of nearly Hermitian matrices](hermitize/index.html)
+//!  - [Discrete Fourier transform](fft/index.html)
+//!  - [Sparse matrices in the CSR format](csr/index.html)
 //!  - [Scalar trait](types/trait.Scalar.html)
+//!  - [Parallel decompositions of stacks of matrices](batch/index.html) with the `parallel` feature
+//!  - [Number of threads of OpenBLAS or Intel MKL](threads/index.html)
+//!  - [Entry points for real symmetric matrices](real/index.html)
+//!  - [Closed-form routines for 2x2 and 3x3 matrices](small/index.html)
+//!  - [Fixed-size matrices on the stack](fixed/index.html)
+//!  - [Reusable LAPACK workspaces for decompositions in a loop](workspace/index.html)
+//!  - [Half-precision matrices](half_precision/index.html) with the `half` feature
+//!  - [Extended precision by double-double arithmetic](double_double/index.html)
+//!  - [Arbitrary-precision matrices by MPFR](mpfr/index.html) with the `rug` feature
 
 extern crate blas_src;
 extern crate lapack_src;
 
 pub mod assert;
+pub mod banded;
+pub mod batch;
+pub mod block_diag;
 pub mod cholesky;
+pub mod circulant;
+pub mod companion;
+pub mod cond;
+pub mod convergence;
 pub mod convert;
+pub mod csr;
+pub mod cur;
 pub mod diagonal;
+pub mod double_double;
+pub mod eig;
 pub mod eigh;
 pub mod error;
+pub mod expm;
+pub mod fft;
+pub mod fixed;
+pub mod funm;
+pub mod gallery;
 pub mod generate;
+pub mod geodesic;
+pub mod hankel;
+#[cfg(feature = "half")]
+pub mod half_precision;
+pub mod hermitize;
+pub mod hessenberg;
 pub mod inner;
+pub mod interpolative;
+pub mod kron;
 pub mod krylov;
 pub mod lapack;
 pub mod layout;
+pub mod least_squares;
+pub mod lobpcg;
+#[cfg(feature = "rug")]
+pub mod mpfr;
 pub mod norm;
 pub mod operator;
 pub mod opnorm;
+pub mod packed;
+pub mod pencil;
+pub mod power;
+pub mod powi;
 pub mod qr;
+pub mod randomized;
+pub mod real;
+pub mod riccati;
+pub mod schur;
+pub mod small;
 pub mod solve;
 pub mod solveh;
+pub mod sqrtm;
 pub mod svd;
 pub mod svddc;
+pub mod threads;
+pub mod toeplitz;
 pub mod trace;
 pub mod triangular;
+pub mod tridiagonal;
+pub mod trigm;
 pub mod types;
+pub mod woodbury;
+pub mod workspace;
 
 pub use assert::*;
+pub use banded::*;
+pub use batch::*;
+pub use block_diag::*;
 pub use cholesky::*;
+pub use circulant::*;
+pub use companion::*;
+pub use cond::*;
+pub use convergence::*;
 pub use convert::*;
+pub use csr::*;
+pub use cur::*;
 pub use diagonal::*;
+pub use double_double::*;
+pub use eig::*;
 pub use eigh::*;
+pub use expm::*;
+pub use fft::*;
+pub use fixed::*;
+pub use funm::*;
 pub use generate::*;
+pub use geodesic::*;
+pub use hankel::*;
+#[cfg(feature = "half")]
+pub use half_precision::*;
+pub use hermitize::*;
+pub use hessenberg::*;
 pub use inner::*;
+pub use interpolative::*;
+pub use kron::*;
 pub use layout::*;
+pub use least_squares::*;
+pub use lobpcg::*;
 pub use norm::*;
 pub use operator::*;
 pub use opnorm::*;
+pub use packed::*;
+pub use pencil::*;
+pub use power::*;
+pub use powi::*;
 pub use qr::*;
+pub use randomized::*;
+pub use real::*;
+pub use riccati::*;
+pub use schur::*;
+pub use small::*;
 pub use solve::*;
 pub use solveh::*;
+pub use sqrtm::*;
 pub use svd::*;
 pub use svddc::*;
+pub use threads::*;
+pub use toeplitz::*;
 pub use trace::*;
 pub use triangular::*;
+pub use tridiagonal::*;
+pub use trigm::*;
 pub use types::*;
+pub use woodbury::*;
+pub use workspace::*;
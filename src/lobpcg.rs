@@ -0,0 +1,310 @@
+//! Locally Optimal Block Preconditioned Conjugate Gradient (LOBPCG)
+//!
+//! LOBPCG computes a few extreme eigenpairs of the generalized Hermitian eigenproblem
+//! `A x = λ B x`, where `B` is positive definite (the identity for standard problems).
+//! Both operators and the preconditioner `T ≈ A^{-1}` are only accessed through
+//! [LinearOperator](../operator/trait.LinearOperator.html), so that large sparse or matrix-free
+//! problems can be solved without the dense path.
+//!
+//! Each iteration performs the Rayleigh-Ritz procedure on the subspace spanned by the current
+//! iterate `X`, the preconditioned residual `W = T (A X - B X Λ)` and the previous search direction `P`,
+//! where the bases are `B`-orthonormalized using the Cholesky factorization of their Gram matrices.
+//!
+//! - A. V. Knyazev, Toward the optimal preconditioned eigensolver: Locally optimal block preconditioned
+//!   conjugate gradient method, SIAM J. Sci. Comput. 23 (2001)
+//!
+//! # Example
+//!
+//! ```
+//! extern crate ndarray;
+//! extern crate ndarray_linalg;
+//!
+//! use ndarray::*;
+//! use ndarray_linalg::*;
+//! # fn main() {
+//!
+//! let a: Array2<f64> = from_diag(&Array1::range(1.0, 51.0, 1.0).to_vec());
+//! let x0: Array2<f64> = random((50, 2));
+//! let result = lobpcg(&a, &x0, Order::Smallest, StoppingCriterion::RelativeResidual(1e-8), 500).unwrap();
+//! assert!(result.report.converged);
+//! assert!(result.values.all_close(&arr1(&[1.0, 2.0]), 1e-6));
+//! # }
+//! ```
+
+use ndarray::*;
+use num_traits::Float;
+
+use crate::cholesky::*;
+use crate::convergence::*;
+use crate::eigh::*;
+use crate::error::*;
+use crate::generate::conjugate;
+use crate::krylov::{IterationInfo, Monitor, SolveReport};
+use crate::norm::Norm;
+use crate::operator::*;
+use crate::triangular::*;
+use crate::types::*;
+use crate::UPLO;
+
+/// End of the spectrum computed by [lobpcg](fn.lobpcg.html)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Order {
+    /// Largest eigenvalues
+    Largest,
+    /// Smallest eigenvalues
+    Smallest,
+}
+
+/// Eigenpairs computed by [lobpcg](fn.lobpcg.html)
+#[derive(Debug, Clone)]
+pub struct LobpcgResult<A: Scalar> {
+    /// Eigenvalues ordered as requested by [Order](enum.Order.html)
+    pub values: Array1<A::Real>,
+    /// Eigenvectors as columns, `B`-orthonormalized `X^H B X = I`
+    pub vectors: Array2<A>,
+    /// Error of each eigenpair measured by the stopping criterion
+    pub errors: Array1<A::Real>,
+    /// Statistics of the iteration, where the residual norm and the errors are the largest of the eigenpairs
+    pub report: SolveReport<A::Real>,
+}
+
+/// Compute the extreme eigenpairs of a Hermitian operator `a` without preconditioning
+///
+/// The number of eigenpairs is the number of columns of the initial block `x0`.
+/// See [lobpcg_generalized](fn.lobpcg_generalized.html) for details.
+pub fn lobpcg<A, F, S>(
+    a: &F,
+    x0: &ArrayBase<S, Ix2>,
+    order: Order,
+    criterion: StoppingCriterion<A::Real>,
+    max_iter: usize,
+) -> Result<LobpcgResult<A>>
+where
+    A: Scalar + Lapack,
+    F: LinearOperator<Elem = A>,
+    S: Data<Elem = A>,
+{
+    lobpcg_with_callback(a, x0, order, criterion, max_iter, |_| true)
+}
+
+/// [lobpcg](fn.lobpcg.html) calling `callback` after each iteration
+///
+/// See [lobpcg_generalized_with_callback](fn.lobpcg_generalized_with_callback.html).
+pub fn lobpcg_with_callback<A, F, S, C>(
+    a: &F,
+    x0: &ArrayBase<S, Ix2>,
+    order: Order,
+    criterion: StoppingCriterion<A::Real>,
+    max_iter: usize,
+    callback: C,
+) -> Result<LobpcgResult<A>>
+where
+    A: Scalar + Lapack,
+    F: LinearOperator<Elem = A>,
+    S: Data<Elem = A>,
+    C: FnMut(&IterationInfo<A::Real>) -> bool,
+{
+    let identity = IdentityOperator::new();
+    lobpcg_generalized_with_callback(a, &identity, &identity, x0, order, criterion, max_iter, callback)
+}
+
+/// Compute the extreme eigenpairs of `A x = λ B x` with a preconditioner `T ≈ A^{-1}`
+///
+/// - `a` must be Hermitian and `b` Hermitian positive definite.
+///   Use [IdentityOperator](../operator/struct.IdentityOperator.html) for `b` or `precond` to omit them.
+/// - The number `k` of eigenpairs is the number of columns of the initial block `x0`,
+///   and `3k` must not exceed the dimension `n`.
+/// - Each eigenpair `(λ, x)` is tested by `criterion` as the residual `|A x - λ B x|` against `|λ B x|`.
+/// - The iteration stops when all pairs converge or after `max_iter` iterations,
+///   and `report.converged` tells which happened.
+pub fn lobpcg_generalized<A, F, G, P, S>(
+    a: &F,
+    b: &G,
+    precond: &P,
+    x0: &ArrayBase<S, Ix2>,
+    order: Order,
+    criterion: StoppingCriterion<A::Real>,
+    max_iter: usize,
+) -> Result<LobpcgResult<A>>
+where
+    A: Scalar + Lapack,
+    F: LinearOperator<Elem = A>,
+    G: LinearOperator<Elem = A>,
+    P: LinearOperator<Elem = A>,
+    S: Data<Elem = A>,
+{
+    lobpcg_generalized_with_callback(a, b, precond, x0, order, criterion, max_iter, |_| true)
+}
+
+/// [lobpcg_generalized](fn.lobpcg_generalized.html) calling `callback` after each iteration
+///
+/// The callback receives the largest residual norm `|A x - λ B x|` and the largest error of the eigenpairs,
+/// where the iteration `0` is the Rayleigh-Ritz approximation in the span of `x0`,
+/// and the iteration stops without convergence if it returns `false`.
+///
+/// ```
+/// use ndarray::*;
+/// use ndarray_linalg::*;
+///
+/// let a: Array2<f64> = from_diag(&Array1::range(1.0, 51.0, 1.0).to_vec());
+/// let x0: Array2<f64> = random((50, 2));
+/// let mut errors = Vec::new();
+/// let criterion = StoppingCriterion::RelativeResidual(1e-8);
+/// let result = lobpcg_with_callback(&a, &x0, Order::Smallest, criterion, 500, |info| {
+///     errors.push(info.error);
+///     true
+/// })
+/// .unwrap();
+/// assert_eq!(errors.len(), result.report.iterations + 1);
+/// ```
+#[allow(clippy::too_many_arguments)]
+pub fn lobpcg_generalized_with_callback<A, F, G, P, S, C>(
+    a: &F,
+    b: &G,
+    precond: &P,
+    x0: &ArrayBase<S, Ix2>,
+    order: Order,
+    criterion: StoppingCriterion<A::Real>,
+    max_iter: usize,
+    mut callback: C,
+) -> Result<LobpcgResult<A>>
+where
+    A: Scalar + Lapack,
+    F: LinearOperator<Elem = A>,
+    G: LinearOperator<Elem = A>,
+    P: LinearOperator<Elem = A>,
+    S: Data<Elem = A>,
+    C: FnMut(&IterationInfo<A::Real>) -> bool,
+{
+    let (n, k) = x0.dim();
+    if k == 0 || 3 * k > n {
+        return Err(ShapeError::from_kind(ErrorKind::IncompatibleShape).into());
+    }
+
+    // Rayleigh-Ritz on the initial block
+    let (x, bx) = b_orthonormalize(b, x0.to_owned())?;
+    let ax = a.apply2(&x);
+    let (mut theta, c) = rayleigh_ritz(&x, &ax, None, order, k)?;
+    let mut x = x.dot(&c);
+    let mut ax = ax.dot(&c);
+    let mut bx = bx.dot(&c);
+    let mut p: Option<(Array2<A>, Array2<A>, Array2<A>)> = None;
+
+    let mut monitor = Monitor::new(criterion, &mut callback);
+    loop {
+        let r = &ax - &scale_columns(&bx, &theta);
+        let r_norms: Array1<A::Real> = r.axis_iter(Axis(1)).map(|r| r.norm_l2()).collect();
+        let errors: Array1<A::Real> = (0..k)
+            .map(|i| {
+                let bx_norm = bx.column(i).norm_l2() * theta[i].abs();
+                criterion.error_from_norms(r_norms[i], bx_norm, x.column(i).norm_l2(), None)
+            })
+            .collect();
+        let max_error = errors.fold(A::Real::zero(), |acc, &e| Float::max(acc, e));
+        let max_r_norm = r_norms.fold(A::Real::zero(), |acc, &r| Float::max(acc, r));
+        if monitor.record(max_error, max_r_norm) || !monitor.is_running(max_iter) {
+            return Ok(LobpcgResult {
+                values: theta,
+                vectors: x,
+                errors,
+                report: monitor.finish(max_r_norm),
+            });
+        }
+        // Converged pairs are kept in X but do not contribute to W and P (soft locking)
+        let active: Vec<usize> = (0..k).filter(|&i| !criterion.is_converged(errors[i])).collect();
+
+        // Preconditioned residual, B-orthogonal to X
+        let w = precond.apply2(&r.select(Axis(1), &active));
+        let bxh: Array2<A> = conjugate(&bx);
+        let w = &w - &x.dot(&bxh.dot(&w));
+        let (w, bw) = b_orthonormalize(b, w)?;
+        let aw = a.apply2(&w);
+
+        let mut s = stack(Axis(1), &[x.view(), w.view()])?;
+        let mut as_ = stack(Axis(1), &[ax.view(), aw.view()])?;
+        let mut bs = stack(Axis(1), &[bx.view(), bw.view()])?;
+        if let Some((ref p, ref ap, ref bp)) = p {
+            s = stack(Axis(1), &[s.view(), p.select(Axis(1), &active).view()])?;
+            as_ = stack(Axis(1), &[as_.view(), ap.select(Axis(1), &active).view()])?;
+            bs = stack(Axis(1), &[bs.view(), bp.select(Axis(1), &active).view()])?;
+        }
+        // The Gram matrix of [X, W, P] may be ill-conditioned close to convergence,
+        // then the search direction is dropped
+        let (theta_new, c) = match rayleigh_ritz(&s, &as_, Some(&bs), order, k) {
+            Ok(rr) => rr,
+            Err(_) if p.is_some() => {
+                let l = k + active.len();
+                s = s.slice(s![.., ..l]).to_owned();
+                as_ = as_.slice(s![.., ..l]).to_owned();
+                bs = bs.slice(s![.., ..l]).to_owned();
+                rayleigh_ritz(&s, &as_, Some(&bs), order, k)?
+            }
+            Err(e) => return Err(e),
+        };
+
+        // The new search direction is the part of the update in [W, P]
+        let cp = c.slice(s![k.., ..]);
+        p = Some((
+            s.slice(s![.., k..]).dot(&cp),
+            as_.slice(s![.., k..]).dot(&cp),
+            bs.slice(s![.., k..]).dot(&cp),
+        ));
+        x = s.dot(&c);
+        ax = as_.dot(&c);
+        bx = bs.dot(&c);
+        theta = theta_new;
+    }
+}
+
+/// Rayleigh-Ritz procedure for the subspace spanned by `s`
+///
+/// Returns `k` Ritz values in `order` and their coefficients.
+/// If `bs = B S` is given, the Gram matrix `S^H B S` is used, otherwise `S` is assumed to be `B`-orthonormal.
+fn rayleigh_ritz<A>(
+    s: &Array2<A>,
+    as_: &Array2<A>,
+    bs: Option<&Array2<A>>,
+    order: Order,
+    k: usize,
+) -> Result<(Array1<A::Real>, Array2<A>)>
+where
+    A: Scalar + Lapack,
+{
+    let sh: Array2<A> = conjugate(s);
+    let ga = sh.dot(as_);
+    let (theta, c) = match bs {
+        Some(bs) => ga.eigh_generalized(&sh.dot(bs), EighGeneralizedType::AxLambdaBx, UPLO::Upper)?,
+        None => ga.eigh(UPLO::Upper)?,
+    };
+    let m = theta.len() as isize;
+    let (start, end, step) = match order {
+        Order::Smallest => (0, k as isize, 1),
+        Order::Largest => (m - k as isize, m, -1),
+    };
+    Ok((
+        theta.slice(s![start..end; step]).to_owned(),
+        c.slice(s![.., start..end; step]).to_owned(),
+    ))
+}
+
+/// `B`-orthonormalize the columns of `v`, and return `(V, B V)`
+fn b_orthonormalize<A, G>(b: &G, v: Array2<A>) -> Result<(Array2<A>, Array2<A>)>
+where
+    A: Scalar + Lapack,
+    G: LinearOperator<Elem = A>,
+{
+    let bv = b.apply2(&v);
+    let vh: Array2<A> = conjugate(&v);
+    let r = vh.dot(&bv).cholesky(UPLO::Upper)?;
+    let k = r.rows();
+    let rinv = r.solve_triangular(UPLO::Upper, Diag::NonUnit, &Array2::eye(k))?;
+    Ok((v.dot(&rinv), bv.dot(&rinv)))
+}
+
+fn scale_columns<A: Scalar>(a: &Array2<A>, d: &Array1<A::Real>) -> Array2<A> {
+    let mut a = a.clone();
+    for (mut col, &d) in a.axis_iter_mut(Axis(1)).zip(d.iter()) {
+        col.mapv_inplace(|x| x.mul_real(d));
+    }
+    a
+}
@@ -0,0 +1,287 @@
+//! Arbitrary-precision matrices by MPFR
+//!
+//! With the `rug` feature, the matrices of [rug::Float](https://docs.rs/rug/*/rug/struct.Float.html)
+//! are decomposed through the same traits as those of `f64`:
+//! [Factorize](../solve/trait.Factorize.html) and [Inverse](../solve/trait.Inverse.html) by the LU decomposition,
+//! [Cholesky](../cholesky/trait.Cholesky.html) and [QR](../qr/trait.QR.html).
+//! They are computed in pure Rust in the highest precision of the elements,
+//! which is meant to verify the results of the hard cases, e.g. of ill-conditioned matrices, in small sizes.
+//!
+//! `rug::Float` is not `Copy` and thus not a [Scalar](../types/trait.Scalar.html),
+//! so that the traits taking the scalar type, e.g. [Solve](../solve/trait.Solve.html), are not implemented.
+//! [LUFactorized](../solve/struct.LUFactorized.html) has the inherent method `solve` instead.
+//!
+//! ```ignore
+//! use ndarray::*;
+//! use ndarray_linalg::*;
+//! use rug::Float;
+//!
+//! // Hilbert matrix in 256 bits, whose inverse has the integer elements
+//! let n = 12;
+//! let h = Array2::from_shape_fn((n, n), |(i, j)| Float::with_val(256, 1) / (i + j + 1) as u32);
+//! let inv = h.inv().unwrap();
+//! assert!((inv[(0, 0)].clone() - (n * n) as u32).abs() < 1e-50);
+//! ```
+
+use ndarray::*;
+use rug::Float;
+use std::cmp::{min, Ordering};
+
+use crate::cholesky::Cholesky;
+use crate::error::*;
+use crate::lapack::into_result;
+use crate::layout::*;
+use crate::qr::QR;
+use crate::solve::{Factorize, Inverse, LUFactorized};
+use crate::UPLO;
+
+/// Highest precision of the elements, or that of `f64` for an empty array
+fn precision<S, D>(a: &ArrayBase<S, D>) -> u32
+where
+    S: Data<Elem = Float>,
+    D: Dimension,
+{
+    a.iter().map(Float::prec).max().unwrap_or(53)
+}
+
+/// Copy in the precision `prec`
+fn promote<S, D>(a: &ArrayBase<S, D>, prec: u32) -> Array<Float, D>
+where
+    S: Data<Elem = Float>,
+    D: Dimension,
+{
+    a.map(|x| Float::with_val(prec, x))
+}
+
+/// LU decomposition with partial pivoting in the same form as `*getrf`
+fn lu(mut a: Array2<Float>) -> Result<LUFactorized<OwnedRepr<Float>>> {
+    let (m, n) = a.dim();
+    let prec = precision(&a);
+    let mut ipiv = Vec::with_capacity(min(m, n));
+    let mut info = 0;
+    for j in 0..min(m, n) {
+        let p = (j..m).fold(j, |p, i| {
+            if a[(i, j)].cmp_abs(&a[(p, j)]) == Some(Ordering::Greater) {
+                i
+            } else {
+                p
+            }
+        });
+        ipiv.push(p as i32 + 1);
+        if a[(p, j)].is_zero() {
+            if info == 0 {
+                info = j as i32 + 1;
+            }
+            continue;
+        }
+        if p != j {
+            for c in 0..n {
+                a.swap((j, c), (p, c));
+            }
+        }
+        for i in (j + 1)..m {
+            let l = Float::with_val(prec, &a[(i, j)] / &a[(j, j)]);
+            for c in (j + 1)..n {
+                let t = Float::with_val(prec, &l * &a[(j, c)]);
+                a[(i, c)] -= &t;
+            }
+            a[(i, j)] = l;
+        }
+    }
+    into_result(info, LUFactorized { a, ipiv })
+}
+
+impl LUFactorized<OwnedRepr<Float>> {
+    /// Solves `A x = b` in the precision of the factors
+    pub fn solve<S>(&self, b: &ArrayBase<S, Ix1>) -> Result<Array1<Float>>
+    where
+        S: Data<Elem = Float>,
+    {
+        self.a.ensure_square()?;
+        let n = self.a.rows();
+        if b.len() != n {
+            return Err(ShapeError::from_kind(ErrorKind::IncompatibleShape).into());
+        }
+        let prec = precision(&self.a);
+        let mut x = promote(b, prec);
+        for (i, &p) in self.ipiv.iter().enumerate() {
+            x.swap(i, p as usize - 1);
+        }
+        for i in 0..n {
+            for j in 0..i {
+                let t = Float::with_val(prec, &self.a[(i, j)] * &x[j]);
+                x[i] -= &t;
+            }
+        }
+        for i in (0..n).rev() {
+            for j in (i + 1)..n {
+                let t = Float::with_val(prec, &self.a[(i, j)] * &x[j]);
+                x[i] -= &t;
+            }
+            x[i] /= &self.a[(i, i)];
+        }
+        Ok(x)
+    }
+}
+
+impl Inverse for LUFactorized<OwnedRepr<Float>> {
+    type Output = Array2<Float>;
+
+    fn inv(&self) -> Result<Array2<Float>> {
+        self.a.ensure_square()?;
+        let n = self.a.rows();
+        if let Some(k) = (0..n).find(|&k| self.a[(k, k)].is_zero()) {
+            return Err(LinalgError::Lapack {
+                return_code: k as i32 + 1,
+            });
+        }
+        let prec = precision(&self.a);
+        let mut inv = Array2::from_elem((n, n), Float::new(prec));
+        for j in 0..n {
+            let e = Array1::from_shape_fn(n, |i| Float::with_val(prec, if i == j { 1 } else { 0 }));
+            inv.column_mut(j).assign(&self.solve(&e)?);
+        }
+        Ok(inv)
+    }
+}
+
+/// Cholesky factor of a real symmetric positive definite matrix
+fn cholesky<S>(a: &ArrayBase<S, Ix2>, uplo: UPLO) -> Result<Array2<Float>>
+where
+    S: Data<Elem = Float>,
+{
+    a.ensure_square()?;
+    let n = a.rows();
+    let prec = precision(a);
+    // Lower triangular part of `A`
+    let lower = |i: usize, j: usize| match uplo {
+        UPLO::Lower => &a[(i, j)],
+        UPLO::Upper => &a[(j, i)],
+    };
+    let mut l = Array2::from_elem((n, n), Float::new(prec));
+    for j in 0..n {
+        let mut d = Float::with_val(prec, lower(j, j));
+        for k in 0..j {
+            d -= Float::with_val(prec, l[(j, k)].square_ref());
+        }
+        if d.cmp0() != Some(Ordering::Greater) {
+            return Err(LinalgError::Lapack {
+                return_code: j as i32 + 1,
+            });
+        }
+        d.sqrt_mut();
+        for i in (j + 1)..n {
+            let mut s = Float::with_val(prec, lower(i, j));
+            for k in 0..j {
+                s -= Float::with_val(prec, &l[(i, k)] * &l[(j, k)]);
+            }
+            s /= &d;
+            l[(i, j)] = s;
+        }
+        l[(j, j)] = d;
+    }
+    Ok(match uplo {
+        UPLO::Lower => l,
+        UPLO::Upper => l.reversed_axes(),
+    })
+}
+
+/// QR decomposition by Householder reflections, where `Q` is `m x k` and `R` is `k x n` for `k = min(m, n)`
+fn qr<S>(a: &ArrayBase<S, Ix2>) -> (Array2<Float>, Array2<Float>)
+where
+    S: Data<Elem = Float>,
+{
+    let (m, n) = a.dim();
+    let k = min(m, n);
+    let prec = precision(a);
+    let mut r = promote(a, prec);
+    let mut q = Array2::from_shape_fn((m, m), |(i, j)| Float::with_val(prec, if i == j { 1 } else { 0 }));
+    for j in 0..k {
+        // Reflector `H = I - 2 v v^T / (v^T v)` with `H x = alpha e_1` for `x = r[j.., j]`
+        let mut norm = Float::new(prec);
+        for i in j..m {
+            norm += Float::with_val(prec, r[(i, j)].square_ref());
+        }
+        if norm.is_zero() {
+            continue;
+        }
+        norm.sqrt_mut();
+        let alpha = if r[(j, j)].cmp0() == Some(Ordering::Greater) {
+            -norm
+        } else {
+            norm
+        };
+        let mut v: Vec<Float> = (j..m).map(|i| r[(i, j)].clone()).collect();
+        v[0] -= &alpha;
+        let mut vv = Float::new(prec);
+        for x in &v {
+            vv += Float::with_val(prec, x.square_ref());
+        }
+        for c in j..n {
+            let mut f = Float::new(prec);
+            for i in j..m {
+                f += Float::with_val(prec, &v[i - j] * &r[(i, c)]);
+            }
+            f = Float::with_val(prec, &f / &vv) * 2;
+            for i in j..m {
+                r[(i, c)] -= Float::with_val(prec, &f * &v[i - j]);
+            }
+        }
+        for row in 0..m {
+            let mut f = Float::new(prec);
+            for i in j..m {
+                f += Float::with_val(prec, &q[(row, i)] * &v[i - j]);
+            }
+            f = Float::with_val(prec, &f / &vv) * 2;
+            for i in j..m {
+                q[(row, i)] -= Float::with_val(prec, &f * &v[i - j]);
+            }
+        }
+        r[(j, j)] = alpha;
+        for i in (j + 1)..m {
+            r[(i, j)] = Float::new(prec);
+        }
+    }
+    (q.slice(s![.., ..k]).to_owned(), r.slice(s![..k, ..]).to_owned())
+}
+
+// The traits are implemented for the concrete arrays and views,
+// since those for `ArrayBase<S, Ix2>` with `S: Data<Elem = Float>` would conflict with the generic ones.
+macro_rules! impl_mpfr {
+    ([$($gen:tt)*] $mat:ty) => {
+        impl<$($gen)*> Factorize<OwnedRepr<Float>> for $mat {
+            fn factorize(&self) -> Result<LUFactorized<OwnedRepr<Float>>> {
+                lu(promote(self, precision(self)))
+            }
+        }
+
+        impl<$($gen)*> Inverse for $mat {
+            type Output = Array2<Float>;
+
+            fn inv(&self) -> Result<Array2<Float>> {
+                self.ensure_square()?;
+                self.factorize()?.inv()
+            }
+        }
+
+        impl<$($gen)*> Cholesky for $mat {
+            type Output = Array2<Float>;
+
+            fn cholesky(&self, uplo: UPLO) -> Result<Array2<Float>> {
+                cholesky(self, uplo)
+            }
+        }
+
+        impl<$($gen)*> QR for $mat {
+            type Q = Array2<Float>;
+            type R = Array2<Float>;
+
+            fn qr(&self) -> Result<(Array2<Float>, Array2<Float>)> {
+                Ok(qr(self))
+            }
+        }
+    };
+} // impl_mpfr!
+
+impl_mpfr!([] Array2<Float>);
+impl_mpfr!(['a] ArrayView2<'a, Float>);
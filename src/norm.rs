@@ -1,7 +1,20 @@
 //! Norm of vectors
+//!
+//! [Norm](trait.Norm.html) regards a whole array as a vector,
+//! while [NormAxis](trait.NormAxis.html) computes the norms of the lanes along an axis,
+//! e.g. of each row or column of a matrix:
+//!
+//! ```
+//! use ndarray::*;
+//! use ndarray_linalg::*;
+//!
+//! let a = arr2(&[[3.0, 0.0], [4.0, -1.0]]);
+//! assert_eq!(a.norm_axis(Axis(0), NormOrd::L2), arr1(&[5.0, 1.0]));
+//! assert_eq!(a.norm_axis(Axis(1), NormOrd::L1), arr1(&[3.0, 5.0]));
+//! ```
 
 use ndarray::*;
-use num_traits::Zero;
+use num_traits::{Float, One, Zero};
 
 use super::types::*;
 
@@ -17,7 +30,16 @@ pub trait Norm {
     /// L-1 norm
     fn norm_l1(&self) -> Self::Output;
     /// L-2 norm
+    ///
+    /// This is computed from [norm_l2_scaled](#tymethod.norm_l2_scaled),
+    /// and does not overflow or underflow unless the norm itself does.
     fn norm_l2(&self) -> Self::Output;
+    /// L-2 norm as `(scale, sumsq)` where `norm_l2 = scale * sqrt(sumsq)`
+    ///
+    /// `scale` is the largest absolute value of the (real and imaginary parts of the) elements,
+    /// and `1 <= sumsq <= len` for non-zero arrays (`sumsq <= 2 len` for complex ones)
+    /// as in LAPACK `*lassq`, so that the squared norm is available without overflow.
+    fn norm_l2_scaled(&self) -> (Self::Output, Self::Output);
     /// maximum norm
     fn norm_max(&self) -> Self::Output;
 }
@@ -33,7 +55,15 @@ where
         self.iter().map(|x| x.abs()).sum()
     }
     fn norm_l2(&self) -> Self::Output {
-        self.iter().map(|x| x.square()).sum::<A::Real>().sqrt()
+        let (scale, sumsq) = self.norm_l2_scaled();
+        scale * Float::sqrt(sumsq)
+    }
+    fn norm_l2_scaled(&self) -> (Self::Output, Self::Output) {
+        self.iter()
+            .fold((A::Real::zero(), A::Real::one()), |(scale, sumsq), x| {
+                let (scale, sumsq) = lassq(scale, sumsq, x.re());
+                lassq(scale, sumsq, x.im())
+            })
     }
     fn norm_max(&self) -> Self::Output {
         self.iter().fold(A::Real::zero(), |f, &val| {
@@ -47,6 +77,63 @@ where
     }
 }
 
+/// Update `(scale, sumsq)` by `x` keeping `scale^2 sumsq` equal to the sum of squares
+fn lassq<R: Float>(scale: R, sumsq: R, x: R) -> (R, R) {
+    if x.is_zero() {
+        return (scale, sumsq);
+    }
+    let a = x.abs();
+    if scale < a {
+        let r = scale / a;
+        (a, R::one() + sumsq * r * r)
+    } else {
+        let r = a / scale;
+        (scale, sumsq + r * r)
+    }
+}
+
+/// Vector norm used by [NormAxis](trait.NormAxis.html)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum NormOrd {
+    /// Sum of absolute values
+    L1,
+    /// Square root of sum of squares
+    L2,
+    /// Maximum of absolute values
+    Max,
+}
+
+/// Norms of the lanes along an axis
+pub trait NormAxis {
+    type Output;
+    /// Norms of the 1-D lanes along `axis`, which is removed from the shape
+    ///
+    /// For a matrix, `Axis(0)` gives the norms of the columns, and `Axis(1)` those of the rows.
+    ///
+    /// **Panics** if `axis` is out of bounds.
+    fn norm_axis(&self, axis: Axis, ord: NormOrd) -> Self::Output;
+}
+
+impl<A, S, D> NormAxis for ArrayBase<S, D>
+where
+    A: Scalar + Lapack,
+    S: Data<Elem = A>,
+    D: RemoveAxis,
+{
+    type Output = Array<A::Real, D::Smaller>;
+
+    fn norm_axis(&self, axis: Axis, ord: NormOrd) -> Self::Output {
+        if self.len_of(axis) == 0 {
+            return Array::zeros(self.raw_dim().remove_axis(axis));
+        }
+        self.map_axis(axis, |lane| match ord {
+            NormOrd::L1 => lane.norm_l1(),
+            NormOrd::L2 => lane.norm_l2(),
+            NormOrd::Max => lane.norm_max(),
+        })
+    }
+}
+
 pub enum NormalizeAxis {
     Row = 0,
     Column = 1,
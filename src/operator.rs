@@ -1,8 +1,16 @@
 //! Linear operator algebra
+//!
+//! [LinearOperator](trait.LinearOperator.html) abstracts the action `x -> A x` of a matrix,
+//! and [AdjointOperator](trait.AdjointOperator.html) adds the shape and the adjoint action `x -> A^H x`,
+//! so that the iterative solvers and eigensolvers work without forming the matrix.
+//! They are implemented for dense matrices, the structured matrices of this crate, and the factorizations
+//! (as the action of the inverse, e.g. for preconditioners).
+//! Closures are turned into operators by [FnOperator](struct.FnOperator.html).
 
 use crate::generate::hstack;
 use crate::types::*;
 use ndarray::*;
+use std::marker::PhantomData;
 
 /// Abstracted linear operator as an action to vector (`ArrayBase<S, Ix1>`) and matrix
 /// (`ArrayBase<S, Ix2`)
@@ -79,4 +87,223 @@ where
     {
         self.dot(a)
     }
+
+    fn apply2<S>(&self, a: &ArrayBase<S, Ix2>) -> Array2<A>
+    where
+        S: Data<Elem = A>,
+    {
+        self.dot(a)
+    }
+}
+
+/// Linear operator with its shape and its adjoint
+pub trait AdjointOperator: LinearOperator {
+    /// Shape `(m, n)` of the operator as an `m x n` matrix
+    fn shape(&self) -> (usize, usize);
+
+    /// Apply the adjoint (conjugate transpose) operator `A^H` out-place
+    fn apply_adjoint<S>(&self, a: &ArrayBase<S, Ix1>) -> Array1<Self::Elem>
+    where
+        S: Data<Elem = Self::Elem>;
+
+    /// Apply the adjoint operator to matrix out-place
+    fn apply2_adjoint<S>(&self, a: &ArrayBase<S, Ix2>) -> Array2<Self::Elem>
+    where
+        S: Data<Elem = Self::Elem>,
+    {
+        let cols: Vec<_> = a.axis_iter(Axis(1)).map(|col| self.apply_adjoint(&col)).collect();
+        hstack(&cols).unwrap()
+    }
+}
+
+impl<A, Sa> AdjointOperator for ArrayBase<Sa, Ix2>
+where
+    A: Scalar,
+    Sa: Data<Elem = A>,
+{
+    fn shape(&self) -> (usize, usize) {
+        self.dim()
+    }
+
+    fn apply_adjoint<S>(&self, a: &ArrayBase<S, Ix1>) -> Array1<A>
+    where
+        S: Data<Elem = A>,
+    {
+        // `A^H x = conj(A^T conj(x))` without copying the matrix
+        self.t().dot(&a.mapv(|x| x.conj())).mapv_into(|x| x.conj())
+    }
+
+    fn apply2_adjoint<S>(&self, a: &ArrayBase<S, Ix2>) -> Array2<A>
+    where
+        S: Data<Elem = A>,
+    {
+        self.t().dot(&a.mapv(|x| x.conj())).mapv_into(|x| x.conj())
+    }
+}
+
+/// Linear operator defined by closures
+///
+/// ```
+/// use ndarray::*;
+/// use ndarray_linalg::*;
+///
+/// // Forward difference `(A x)_i = x_{i+1} - x_i` and its adjoint
+/// let diff = FnOperator::new((3, 4), |x: ArrayView1<f64>| &x.slice(s![1..]) - &x.slice(s![..-1])).with_adjoint(
+///     |y: ArrayView1<f64>| {
+///         let mut x = Array1::zeros(4);
+///         x.slice_mut(s![1..]).scaled_add(1.0, &y);
+///         x.slice_mut(s![..-1]).scaled_add(-1.0, &y);
+///         x
+///     },
+/// );
+/// let x = arr1(&[1.0, 2.0, 4.0, 7.0]);
+/// assert_eq!(diff.apply(&x), arr1(&[1.0, 2.0, 3.0]));
+/// assert_eq!(diff.apply_adjoint(&arr1(&[1.0, 0.0, 0.0])), arr1(&[-1.0, 1.0, 0.0, 0.0]));
+/// ```
+pub struct FnOperator<A, F, G = ()> {
+    shape: (usize, usize),
+    f: F,
+    adjoint: G,
+    phantom: PhantomData<A>,
+}
+
+impl<A, F> FnOperator<A, F>
+where
+    A: Scalar,
+    F: Fn(ArrayView1<A>) -> Array1<A>,
+{
+    /// Operator of the shape `(m, n)` whose action is `f`
+    pub fn new(shape: (usize, usize), f: F) -> Self {
+        FnOperator {
+            shape,
+            f,
+            adjoint: (),
+            phantom: PhantomData,
+        }
+    }
+
+    /// Attach the adjoint action `g`, which makes it an [AdjointOperator](trait.AdjointOperator.html)
+    pub fn with_adjoint<G>(self, g: G) -> FnOperator<A, F, G>
+    where
+        G: Fn(ArrayView1<A>) -> Array1<A>,
+    {
+        FnOperator {
+            shape: self.shape,
+            f: self.f,
+            adjoint: g,
+            phantom: PhantomData,
+        }
+    }
+}
+
+impl<A, F, G> LinearOperator for FnOperator<A, F, G>
+where
+    A: Scalar,
+    F: Fn(ArrayView1<A>) -> Array1<A>,
+{
+    type Elem = A;
+
+    fn apply<S>(&self, a: &ArrayBase<S, Ix1>) -> Array1<A>
+    where
+        S: Data<Elem = A>,
+    {
+        assert_eq!(a.len(), self.shape.1);
+        (self.f)(a.view())
+    }
+}
+
+impl<A, F, G> AdjointOperator for FnOperator<A, F, G>
+where
+    A: Scalar,
+    F: Fn(ArrayView1<A>) -> Array1<A>,
+    G: Fn(ArrayView1<A>) -> Array1<A>,
+{
+    fn shape(&self) -> (usize, usize) {
+        self.shape
+    }
+
+    fn apply_adjoint<S>(&self, a: &ArrayBase<S, Ix1>) -> Array1<A>
+    where
+        S: Data<Elem = A>,
+    {
+        assert_eq!(a.len(), self.shape.0);
+        (self.adjoint)(a.view())
+    }
+}
+
+/// Identity operator, e.g. for an omitted mass matrix or preconditioner
+///
+/// The identity applies to vectors of any length, but it needs a size
+/// to be an [AdjointOperator](trait.AdjointOperator.html), which is given by [with_size](#method.with_size).
+#[derive(Debug, Clone, Copy)]
+pub struct IdentityOperator<A, N = ()> {
+    size: N,
+    phantom: PhantomData<A>,
+}
+
+impl<A: Scalar> IdentityOperator<A> {
+    pub fn new() -> Self {
+        IdentityOperator {
+            size: (),
+            phantom: PhantomData,
+        }
+    }
+
+    /// Identity of `n x n` matrices, which makes it an [AdjointOperator](trait.AdjointOperator.html)
+    pub fn with_size(n: usize) -> IdentityOperator<A, usize> {
+        IdentityOperator {
+            size: n,
+            phantom: PhantomData,
+        }
+    }
+}
+
+impl<A: Scalar> Default for IdentityOperator<A> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<A: Scalar, N> LinearOperator for IdentityOperator<A, N> {
+    type Elem = A;
+
+    fn apply_mut<S>(&self, _a: &mut ArrayBase<S, Ix1>)
+    where
+        S: DataMut<Elem = A>,
+    {
+    }
+
+    fn apply2<S>(&self, a: &ArrayBase<S, Ix2>) -> Array2<A>
+    where
+        S: Data<Elem = A>,
+    {
+        a.to_owned()
+    }
+
+    fn apply2_mut<S>(&self, _a: &mut ArrayBase<S, Ix2>)
+    where
+        S: DataMut<Elem = A>,
+    {
+    }
+}
+
+/// The identity is self-adjoint
+impl<A: Scalar> AdjointOperator for IdentityOperator<A, usize> {
+    fn shape(&self) -> (usize, usize) {
+        (self.size, self.size)
+    }
+
+    fn apply_adjoint<S>(&self, a: &ArrayBase<S, Ix1>) -> Array1<A>
+    where
+        S: Data<Elem = A>,
+    {
+        a.to_owned()
+    }
+
+    fn apply2_adjoint<S>(&self, a: &ArrayBase<S, Ix2>) -> Array2<A>
+    where
+        S: Data<Elem = A>,
+    {
+        a.to_owned()
+    }
 }
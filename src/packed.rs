@@ -0,0 +1,265 @@
+//! Hermitian (or real symmetric) matrices in the packed storage
+//!
+//! [PackedHermitian](struct.PackedHermitian.html) stores only one triangular part of the matrix column-wise
+//! in `n (n + 1) / 2` elements, which halves the memory of the full storage.
+//! Indefinite systems are solved by the Bunch-Kaufman factorization `A = P U D U^H P^T`
+//! (or `P L D L^H P^T`) in the packed storage (`*sptrf`/`*hptrf`),
+//! which also gives the inverse in the packed storage.
+//!
+//! # Example
+//!
+//! ```
+//! #[macro_use]
+//! extern crate ndarray;
+//! extern crate ndarray_linalg;
+//!
+//! use ndarray::prelude::*;
+//! use ndarray_linalg::*;
+//! # fn main() {
+//!
+//! let a: Array2<f64> = array![[3., 2., -1.], [2., -2., 4.], [-1., 4., 5.]];
+//! let p = PackedHermitian::from_dense(&a, UPLO::Upper).unwrap();
+//! assert_eq!(p.as_slice(), &[3., 2., -2., -1., 4., 5.]);
+//! let x = p.solveh(&array![11., -12., 1.]).unwrap();
+//! assert!(x.all_close(&array![1., 3., -2.], 1e-9));
+//! # }
+//! ```
+
+use ndarray::*;
+
+use crate::error::*;
+use crate::operator::{AdjointOperator, LinearOperator};
+use crate::solveh::*;
+use crate::types::*;
+
+/// Hermitian (or real symmetric) matrix whose triangular part `uplo` is packed column-wise
+#[derive(Debug, Clone)]
+pub struct PackedHermitian<A> {
+    n: usize,
+    uplo: UPLO,
+    ap: Vec<A>,
+}
+
+impl<A: Scalar> PackedHermitian<A> {
+    /// Create from the packed elements of the triangular part `uplo` of an `n x n` matrix
+    ///
+    /// For `UPLO::Upper`, `A[(i, j)]` with `i <= j` is `ap[i + j (j + 1) / 2]`,
+    /// and for `UPLO::Lower`, `A[(i, j)]` with `i >= j` is `ap[i + j (2n - j - 1) / 2]`.
+    pub fn new(n: usize, uplo: UPLO, ap: Vec<A>) -> Result<Self> {
+        if ap.len() != n * (n + 1) / 2 {
+            return Err(ShapeError::from_kind(ErrorKind::IncompatibleShape).into());
+        }
+        Ok(PackedHermitian { n, uplo, ap })
+    }
+
+    /// Pack the triangular part `uplo` of a square matrix, where the other part is ignored
+    pub fn from_dense<S>(a: &ArrayBase<S, Ix2>, uplo: UPLO) -> Result<Self>
+    where
+        S: Data<Elem = A>,
+    {
+        let (rows, cols) = a.dim();
+        if rows != cols {
+            return Err(LinalgError::NotSquare {
+                rows: rows as i32,
+                cols: cols as i32,
+            });
+        }
+        let n = rows;
+        let mut ap = Vec::with_capacity(n * (n + 1) / 2);
+        for j in 0..n {
+            let rows = match uplo {
+                UPLO::Upper => 0..j + 1,
+                UPLO::Lower => j..n,
+            };
+            ap.extend(rows.map(|i| a[(i, j)]));
+        }
+        Ok(PackedHermitian { n, uplo, ap })
+    }
+
+    /// Convert to a dense Hermitian matrix
+    pub fn to_dense(&self) -> Array2<A> {
+        Array2::from_shape_fn((self.n, self.n), |(i, j)| self.get(i, j))
+    }
+
+    /// `A[(i, j)]`, which is the conjugate of the stored element if it is out of the triangular part
+    pub fn get(&self, i: usize, j: usize) -> A {
+        assert!(i < self.n && j < self.n);
+        let stored = match self.uplo {
+            UPLO::Upper => i <= j,
+            UPLO::Lower => i >= j,
+        };
+        if stored {
+            self.ap[self.index(i, j)]
+        } else {
+            self.ap[self.index(j, i)].conj()
+        }
+    }
+
+    /// Size `n` of the matrix
+    pub fn size(&self) -> usize {
+        self.n
+    }
+
+    /// Stored triangular part
+    pub fn uplo(&self) -> UPLO {
+        self.uplo
+    }
+
+    /// Packed elements
+    pub fn as_slice(&self) -> &[A] {
+        &self.ap
+    }
+
+    /// Matrix-vector product `A x`
+    pub fn dot<S>(&self, x: &ArrayBase<S, Ix1>) -> Array1<A>
+    where
+        S: Data<Elem = A>,
+    {
+        assert_eq!(x.len(), self.n);
+        Array1::from_shape_fn(self.n, |i| {
+            (0..self.n).fold(A::zero(), |acc, j| acc + self.get(i, j) * x[j])
+        })
+    }
+
+    /// Position of `A[(i, j)]` in the triangular part
+    fn index(&self, i: usize, j: usize) -> usize {
+        match self.uplo {
+            UPLO::Upper => i + j * (j + 1) / 2,
+            UPLO::Lower => i + j * (2 * self.n - j - 1) / 2,
+        }
+    }
+}
+
+impl<A: Scalar + Lapack> PackedHermitian<A> {
+    /// Compute the Bunch-Kaufman factorization in the packed storage
+    pub fn factorizeh(&self) -> Result<PackedBKFactorized<A>> {
+        self.clone().factorizeh_into()
+    }
+
+    /// Compute the Bunch-Kaufman factorization in the packed storage, consuming the matrix
+    pub fn factorizeh_into(mut self) -> Result<PackedBKFactorized<A>> {
+        let ipiv = unsafe { A::bk_packed(self.uplo, self.n as i32, &mut self.ap)? };
+        Ok(PackedBKFactorized { a: self, ipiv })
+    }
+}
+
+/// Bunch-Kaufman factorization of a Hermitian (or real symmetric) matrix in the packed storage
+#[derive(Debug, Clone)]
+pub struct PackedBKFactorized<A> {
+    a: PackedHermitian<A>,
+    ipiv: Pivot,
+}
+
+impl<A: Scalar + Lapack> SolveH<A> for PackedBKFactorized<A> {
+    fn solveh_inplace<'a, S>(&self, b: &'a mut ArrayBase<S, Ix1>) -> Result<&'a mut ArrayBase<S, Ix1>>
+    where
+        S: DataMut<Elem = A>,
+    {
+        if b.len() != self.a.n {
+            return Err(ShapeError::from_kind(ErrorKind::IncompatibleShape).into());
+        }
+        unsafe {
+            A::solveh_packed(
+                self.a.uplo,
+                self.a.n as i32,
+                &self.a.ap,
+                &self.ipiv,
+                b.as_slice_mut().unwrap(),
+            )?
+        };
+        Ok(b)
+    }
+}
+
+/// The factorization acts as the inverse `A^{-1}`, which is Hermitian
+///
+/// Panics if `A` is singular.
+impl<A: Scalar + Lapack> LinearOperator for PackedBKFactorized<A> {
+    type Elem = A;
+
+    fn apply<S>(&self, a: &ArrayBase<S, Ix1>) -> Array1<A>
+    where
+        S: Data<Elem = A>,
+    {
+        self.solveh(a).expect("Bunch-Kaufman factors must be non-singular")
+    }
+}
+
+impl<A: Scalar + Lapack> AdjointOperator for PackedBKFactorized<A> {
+    fn shape(&self) -> (usize, usize) {
+        (self.a.n, self.a.n)
+    }
+
+    fn apply_adjoint<S>(&self, a: &ArrayBase<S, Ix1>) -> Array1<A>
+    where
+        S: Data<Elem = A>,
+    {
+        self.apply(a)
+    }
+}
+
+impl<A: Scalar + Lapack> SolveH<A> for PackedHermitian<A> {
+    fn solveh_inplace<'a, S>(&self, b: &'a mut ArrayBase<S, Ix1>) -> Result<&'a mut ArrayBase<S, Ix1>>
+    where
+        S: DataMut<Elem = A>,
+    {
+        self.factorizeh()?.solveh_inplace(b)
+    }
+}
+
+impl<A: Scalar + Lapack> InverseHInto for PackedBKFactorized<A> {
+    type Output = PackedHermitian<A>;
+
+    fn invh_into(mut self) -> Result<PackedHermitian<A>> {
+        unsafe { A::invh_packed(self.a.uplo, self.a.n as i32, &mut self.a.ap, &self.ipiv)? };
+        Ok(self.a)
+    }
+}
+
+impl<A: Scalar + Lapack> InverseH for PackedBKFactorized<A> {
+    type Output = PackedHermitian<A>;
+
+    fn invh(&self) -> Result<PackedHermitian<A>> {
+        self.clone().invh_into()
+    }
+}
+
+impl<A: Scalar + Lapack> InverseHInto for PackedHermitian<A> {
+    type Output = PackedHermitian<A>;
+
+    fn invh_into(self) -> Result<PackedHermitian<A>> {
+        self.factorizeh_into()?.invh_into()
+    }
+}
+
+impl<A: Scalar + Lapack> InverseH for PackedHermitian<A> {
+    type Output = PackedHermitian<A>;
+
+    fn invh(&self) -> Result<PackedHermitian<A>> {
+        self.factorizeh()?.invh_into()
+    }
+}
+
+impl<A: Scalar> LinearOperator for PackedHermitian<A> {
+    type Elem = A;
+
+    fn apply<S>(&self, a: &ArrayBase<S, Ix1>) -> Array1<S::Elem>
+    where
+        S: Data<Elem = A>,
+    {
+        self.dot(a)
+    }
+}
+
+impl<A: Scalar> AdjointOperator for PackedHermitian<A> {
+    fn shape(&self) -> (usize, usize) {
+        (self.n, self.n)
+    }
+
+    fn apply_adjoint<S>(&self, a: &ArrayBase<S, Ix1>) -> Array1<A>
+    where
+        S: Data<Elem = A>,
+    {
+        self.dot(a)
+    }
+}
@@ -0,0 +1,185 @@
+//! Kronecker structure of matrix pencils `A - λB`
+//!
+//! A pencil whose generalized eigenvalue problem looks like "infinite
+//! eigenvalues everywhere" is usually singular (`det(A - λB)` vanishes
+//! identically) or has a non-trivial structure at infinity. The staircase
+//! algorithm (the first stage of GUPTRI) detects this structure with
+//! rank-revealing SVDs and deflates it, leaving the regular part whose
+//! eigenvalues are all finite.
+//!
+//! - P. Van Dooren, The computation of Kronecker's canonical form of a singular pencil,
+//!   Linear Algebra Appl. 27 (1979)
+//! - J. Demmel and B. Kågström, The generalized Schur decomposition of an arbitrary pencil A - λB,
+//!   ACM Trans. Math. Software 19 (1993)
+//!
+//! # Example
+//!
+//! ```
+//! #[macro_use]
+//! extern crate ndarray;
+//! extern crate ndarray_linalg;
+//!
+//! use ndarray::prelude::*;
+//! use ndarray_linalg::*;
+//! # fn main() {
+//!
+//! // B is singular: one eigenvalue at infinity
+//! let a: Array2<f64> = Array::eye(3);
+//! let b: Array2<f64> = array![[1., 0., 0.], [0., 1., 0.], [0., 0., 0.]];
+//! let st = pencil_structure(&a, &b, None).unwrap();
+//! assert!(st.is_regular());
+//! assert_eq!(st.infinite_blocks, vec![1]);
+//! assert_eq!(st.regular_b.dim(), (2, 2));
+//! # }
+//! ```
+
+use ndarray::*;
+use num_traits::Float;
+
+use crate::error::*;
+use crate::generate::conjugate;
+use crate::norm::Norm;
+use crate::svd::*;
+use crate::types::*;
+
+/// Kronecker structure of a pencil `A - λB` and its regular part
+#[derive(Debug, Clone)]
+pub struct PencilStructure<A: Scalar> {
+    /// Right (column) minimal indices `ε`, i.e. there are `L_ε` blocks of size `ε x (ε + 1)`
+    pub right_minimal_indices: Vec<usize>,
+    /// Left (row) minimal indices `η`, i.e. there are `L_η^T` blocks of size `(η + 1) x η`
+    pub left_minimal_indices: Vec<usize>,
+    /// Sizes of the Jordan blocks of the infinite eigenvalues
+    pub infinite_blocks: Vec<usize>,
+    /// `A` of the regular part whose eigenvalues are all finite
+    pub regular_a: Array2<A>,
+    /// `B` of the regular part, which is non-singular
+    pub regular_b: Array2<A>,
+}
+
+impl<A: Scalar> PencilStructure<A> {
+    /// Check the pencil has no singular blocks, i.e. `det(A - λB)` does not vanish identically
+    pub fn is_regular(&self) -> bool {
+        self.right_minimal_indices.is_empty() && self.left_minimal_indices.is_empty()
+    }
+}
+
+/// Detect the Kronecker structure of a pencil `A - λB` and extract its regular part
+///
+/// Singular values smaller than `tol` are regarded as zero.
+/// If `tol` is `None`, `max(m, n) * eps * max(|A|_F, |B|_F)` is used.
+pub fn pencil_structure<A, Sa, Sb>(
+    a: &ArrayBase<Sa, Ix2>,
+    b: &ArrayBase<Sb, Ix2>,
+    tol: Option<A::Real>,
+) -> Result<PencilStructure<A>>
+where
+    A: Scalar + Lapack,
+    Sa: Data<Elem = A>,
+    Sb: Data<Elem = A>,
+{
+    if a.dim() != b.dim() {
+        return Err(ShapeError::from_kind(ErrorKind::IncompatibleShape).into());
+    }
+    let (m, n) = a.dim();
+    let tol = tol.unwrap_or_else(|| {
+        let norm = Float::max(a.norm_l2(), b.norm_l2());
+        A::Real::from(::std::cmp::max(m, n)).unwrap() * A::Real::epsilon() * norm
+    });
+
+    // Right singular blocks and infinite eigenvalues
+    let (nu, rho, a1, b1) = staircase(a.to_owned(), b.to_owned(), tol)?;
+    let mut right_minimal_indices = Vec::new();
+    let mut infinite_blocks = Vec::new();
+    collect_structure(&nu, &rho, &mut right_minimal_indices, &mut infinite_blocks);
+
+    // Left singular blocks appear as right singular blocks of the conjugate transposed pencil
+    let (nu, rho, a2, b2) = staircase(conjugate(&a1), conjugate(&b1), tol)?;
+    let mut left_minimal_indices = Vec::new();
+    collect_structure(&nu, &rho, &mut left_minimal_indices, &mut infinite_blocks);
+    infinite_blocks.sort();
+
+    Ok(PencilStructure {
+        right_minimal_indices,
+        left_minimal_indices,
+        infinite_blocks,
+        regular_a: conjugate(&a2),
+        regular_b: conjugate(&b2),
+    })
+}
+
+/// Staircase reduction of `A - λB` by column compressions of `B`
+///
+/// Returns the column nullities `ν_i`, the ranks `ρ_i`, and the deflated pencil whose `B` has full column rank.
+fn staircase<A>(
+    mut a: Array2<A>,
+    mut b: Array2<A>,
+    tol: A::Real,
+) -> Result<(Vec<usize>, Vec<usize>, Array2<A>, Array2<A>)>
+where
+    A: Scalar + Lapack,
+{
+    let mut nu = Vec::new();
+    let mut rho = Vec::new();
+    loop {
+        let q = a.cols();
+        let (_, sb, vb) = svd_full(&b)?;
+        let rank_b = numerical_rank(&sb, tol);
+        let nullity = q - rank_b;
+        if nullity == 0 {
+            break;
+        }
+        // Move the null space of B to the leading columns
+        let mut qm = Array2::zeros((q, q));
+        qm.slice_mut(s![.., ..nullity]).assign(&vb.slice(s![.., rank_b..]));
+        qm.slice_mut(s![.., nullity..]).assign(&vb.slice(s![.., ..rank_b]));
+        let aq = a.dot(&qm);
+        let bq = b.dot(&qm);
+
+        // Compress the rows of A restricted to the null space of B
+        let (uc, sc, _) = svd_full(&aq.slice(s![.., ..nullity]).to_owned())?;
+        let rank_c = numerical_rank(&sc, tol);
+        let uh: Array2<A> = conjugate(&uc);
+        let pa = uh.dot(&aq);
+        let pb = uh.dot(&bq);
+
+        nu.push(nullity);
+        rho.push(rank_c);
+        a = pa.slice(s![rank_c.., nullity..]).to_owned();
+        b = pb.slice(s![rank_c.., nullity..]).to_owned();
+    }
+    Ok((nu, rho, a, b))
+}
+
+/// Read off the minimal indices and the infinite Jordan blocks from the staircase (Van Dooren 1979)
+///
+/// - There are `ν_i - ρ_i` blocks `L_{i-1}`
+/// - There are `ρ_i - ν_{i+1}` Jordan blocks of size `i`
+fn collect_structure(nu: &[usize], rho: &[usize], minimal_indices: &mut Vec<usize>, infinite: &mut Vec<usize>) {
+    for i in 0..nu.len() {
+        for _ in 0..nu[i].saturating_sub(rho[i]) {
+            minimal_indices.push(i);
+        }
+        let nu_next = if i + 1 < nu.len() { nu[i + 1] } else { 0 };
+        for _ in 0..rho[i].saturating_sub(nu_next) {
+            infinite.push(i + 1);
+        }
+    }
+}
+
+/// Full SVD `a = U diag(s) V^H` returning `(U, s, V)`, which also accepts empty matrices
+fn svd_full<A>(a: &Array2<A>) -> Result<(Array2<A>, Array1<A::Real>, Array2<A>)>
+where
+    A: Scalar + Lapack,
+{
+    let (m, n) = a.dim();
+    if m == 0 || n == 0 {
+        return Ok((Array2::eye(m), Array1::zeros(0), Array2::eye(n)));
+    }
+    let (u, s, vt) = a.svd(true, true)?;
+    Ok((u.unwrap(), s, conjugate(&vt.unwrap())))
+}
+
+fn numerical_rank<R: Float>(s: &Array1<R>, tol: R) -> usize {
+    s.iter().filter(|&&s| s > tol).count()
+}
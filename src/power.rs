@@ -0,0 +1,195 @@
+//! Power iteration and inverse iteration for a single eigenpair
+//!
+//! These methods compute only one eigenpair and are cheap per iteration,
+//! which suits large dense matrices when e.g. only the spectral radius is required.
+//!
+//! - [dominant_eigenpair](fn.dominant_eigenpair.html) applies the operator repeatedly
+//!   and converges to the eigenvalue of largest modulus.
+//! - [inverse_iteration](fn.inverse_iteration.html) applies `(A - σI)^{-1}` using a single
+//!   LU factorization, and converges to the eigenvalue closest to the shift `σ`.
+//!   [inverse_iteration_h](fn.inverse_iteration_h.html) uses the Cholesky factorization instead.
+//!
+//! The eigenvalue is estimated by the Rayleigh quotient `x^H A x` of the normalized iterate,
+//! and the convergence is tested for the eigen-residual `A x - λ x` as described in the
+//! [convergence](../convergence/index.html) module.
+//!
+//! # Example
+//!
+//! ```
+//! #[macro_use]
+//! extern crate ndarray;
+//! extern crate ndarray_linalg;
+//!
+//! use ndarray::prelude::*;
+//! use ndarray_linalg::*;
+//! # fn main() {
+//!
+//! let a: Array2<f64> = array![[2.0, 1.0], [1.0, 3.0]];
+//! let x0 = array![1.0, 1.0];
+//! let pair = dominant_eigenpair(&a, &x0, StoppingCriterion::RelativeResidual(1e-10), 1000);
+//! assert!(pair.report.converged);
+//! assert!((pair.value - (5.0 + 5.0_f64.sqrt()) / 2.0).abs() < 1e-8);
+//! # }
+//! ```
+
+use ndarray::*;
+
+use crate::cholesky::*;
+use crate::convergence::*;
+use crate::error::*;
+use crate::inner::InnerProduct;
+use crate::norm::Norm;
+use crate::operator::LinearOperator;
+use crate::solve::*;
+use crate::types::*;
+use crate::UPLO;
+
+/// An eigenpair computed by an iterative method
+#[derive(Debug, Clone)]
+pub struct EigenPair<A: Scalar> {
+    /// Eigenvalue estimated by the Rayleigh quotient
+    pub value: A,
+    /// Normalized eigenvector `|x|_2 = 1`
+    pub vector: Array1<A>,
+    /// Convergence history of the eigen-residual
+    pub report: IterationReport<A::Real>,
+}
+
+/// Compute the eigenpair of largest modulus by the power method
+///
+/// The iteration stops when `criterion` is satisfied or after `max_iter` applications of `a`,
+/// and `report.converged` tells which happened.
+/// It does not converge if the dominant eigenvalue is not unique in modulus,
+/// e.g. a complex conjugate pair of a real matrix.
+/// The initial vector `x0` must not be orthogonal to the dominant eigenvector.
+pub fn dominant_eigenpair<A, F, S>(
+    a: &F,
+    x0: &ArrayBase<S, Ix1>,
+    criterion: StoppingCriterion<A::Real>,
+    max_iter: usize,
+) -> EigenPair<A>
+where
+    A: Scalar + Lapack,
+    F: LinearOperator<Elem = A>,
+    S: Data<Elem = A>,
+{
+    iterate(a, x0, criterion, max_iter, |x, ax| {
+        x.assign(ax);
+        Ok(())
+    })
+    .unwrap()
+}
+
+/// Compute the eigenpair whose eigenvalue is closest to `shift` by inverse iteration
+///
+/// `A - σI` is LU-factorized once, and each iteration solves a linear system with the factors.
+/// The shift must not be an exact eigenvalue, otherwise the factorization fails.
+pub fn inverse_iteration<A, Sa, Sx>(
+    a: &ArrayBase<Sa, Ix2>,
+    shift: A,
+    x0: &ArrayBase<Sx, Ix1>,
+    criterion: StoppingCriterion<A::Real>,
+    max_iter: usize,
+) -> Result<EigenPair<A>>
+where
+    A: Scalar + Lapack,
+    Sa: Data<Elem = A>,
+    Sx: Data<Elem = A>,
+{
+    let f = shifted(a, shift)?.factorize_into()?;
+    iterate(a, x0, criterion, max_iter, |x, _| {
+        f.solve_inplace(x)?;
+        Ok(())
+    })
+}
+
+/// Inverse iteration for a Hermitian (or real symmetric) matrix using the Cholesky factorization
+///
+/// `A - σI` must be positive definite, i.e. `shift` must lie below the spectrum,
+/// so that this converges to the smallest eigenvalue.
+/// Only the triangular part of `a` specified by `uplo` is used for the factorization.
+pub fn inverse_iteration_h<A, Sa, Sx>(
+    a: &ArrayBase<Sa, Ix2>,
+    shift: A,
+    uplo: UPLO,
+    x0: &ArrayBase<Sx, Ix1>,
+    criterion: StoppingCriterion<A::Real>,
+    max_iter: usize,
+) -> Result<EigenPair<A>>
+where
+    A: Scalar + Lapack,
+    Sa: Data<Elem = A>,
+    Sx: Data<Elem = A>,
+{
+    let f = shifted(a, shift)?.factorizec_into(uplo)?;
+    iterate(a, x0, criterion, max_iter, |x, _| {
+        f.solvec_inplace(x)?;
+        Ok(())
+    })
+}
+
+/// `A - σI` as an owned matrix
+fn shifted<A, S>(a: &ArrayBase<S, Ix2>, shift: A) -> Result<Array2<A>>
+where
+    A: Scalar,
+    S: Data<Elem = A>,
+{
+    let (rows, cols) = a.dim();
+    if rows != cols {
+        return Err(LinalgError::NotSquare {
+            rows: rows as i32,
+            cols: cols as i32,
+        });
+    }
+    let mut a = a.to_owned();
+    for d in a.diag_mut() {
+        *d = *d - shift;
+    }
+    Ok(a)
+}
+
+/// Common loop of the power method
+///
+/// `step(x, ax)` overwrites the normalized iterate `x` by the next one (not normalized)
+fn iterate<A, F, S, Step>(
+    a: &F,
+    x0: &ArrayBase<S, Ix1>,
+    criterion: StoppingCriterion<A::Real>,
+    max_iter: usize,
+    mut step: Step,
+) -> Result<EigenPair<A>>
+where
+    A: Scalar + Lapack,
+    F: LinearOperator<Elem = A>,
+    S: Data<Elem = A>,
+    Step: FnMut(&mut Array1<A>, &Array1<A>) -> Result<()>,
+{
+    let mut report = IterationReport::new(criterion);
+    let mut x = x0.to_owned();
+    normalize(&mut x);
+    let mut ax = a.apply(&x);
+    let mut value = x.inner(&ax);
+    for i in 0..=max_iter {
+        if i > 0 {
+            step(&mut x, &ax)?;
+            normalize(&mut x);
+            ax = a.apply(&x);
+            value = x.inner(&ax);
+        }
+        let lx = x.mapv(|v| v * value);
+        let r = &ax - &lx;
+        if report.record(criterion.error(&Residual::new(r.view(), lx.view(), x.view()))) {
+            break;
+        }
+    }
+    Ok(EigenPair {
+        value,
+        vector: x,
+        report,
+    })
+}
+
+fn normalize<A: Scalar + Lapack>(x: &mut Array1<A>) {
+    let norm = x.norm_l2();
+    azip!(mut x(x) in { *x = x.div_real(norm) });
+}
@@ -0,0 +1,71 @@
+//! Integer powers of square matrices
+//!
+//! [Powi](trait.Powi.html) computes `A^n` by binary exponentiation (repeated squaring),
+//! which takes about `2 log2(|n|)` matrix products.
+//! Negative powers are computed from the inverse obtained by the LU factorization of `A`.
+//!
+//! # Example
+//!
+//! ```
+//! #[macro_use]
+//! extern crate ndarray;
+//! extern crate ndarray_linalg;
+//!
+//! use ndarray::prelude::*;
+//! use ndarray_linalg::*;
+//! # fn main() {
+//!
+//! // Fibonacci numbers
+//! let a: Array2<f64> = array![[1., 1.], [1., 0.]];
+//! assert!(a.powi(10).unwrap().all_close(&array![[89., 55.], [55., 34.]], 1e-12));
+//! assert!(a.powi(-1).unwrap().all_close(&array![[0., 1.], [1., -1.]], 1e-12));
+//! # }
+//! ```
+
+use ndarray::*;
+
+use crate::error::*;
+use crate::layout::*;
+use crate::solve::*;
+use crate::types::*;
+
+/// Integer power of a square matrix
+pub trait Powi {
+    type Output;
+    /// Compute `A^n` by binary exponentiation
+    ///
+    /// `A^0` is the identity, and a negative `n` fails if `A` is singular.
+    fn powi(&self, n: i32) -> Result<Self::Output>;
+}
+
+impl<A, S> Powi for ArrayBase<S, Ix2>
+where
+    A: Scalar + Lapack,
+    S: Data<Elem = A>,
+{
+    type Output = Array2<A>;
+
+    fn powi(&self, n: i32) -> Result<Array2<A>> {
+        self.square_layout()?;
+        let base = if n < 0 { self.inv()? } else { self.to_owned() };
+        Ok(binary_power(base, (n as i64).abs() as u64))
+    }
+}
+
+/// `a^e` by repeated squaring, where each product allocates a new matrix
+fn binary_power<A: Scalar>(mut a: Array2<A>, mut e: u64) -> Array2<A> {
+    let mut result: Option<Array2<A>> = None;
+    while e > 0 {
+        if e & 1 == 1 {
+            result = Some(match result {
+                Some(r) => r.dot(&a),
+                None => a.clone(),
+            });
+        }
+        e >>= 1;
+        if e > 0 {
+            a = a.dot(&a);
+        }
+    }
+    result.unwrap_or_else(|| Array2::eye(a.rows()))
+}
@@ -1,10 +1,14 @@
 //! QR decomposition
 //!
 //! [Wikipedia article on QR decomposition](https://en.wikipedia.org/wiki/QR_decomposition)
+//!
+//! [QR](trait.QR.html) is also implemented for stacks of matrices (`Ix3` arrays whose leading axis is the batch axis),
+//! which are decomposed in parallel if the `parallel` feature is enabled.
 
 use ndarray::*;
 use num_traits::Zero;
 
+use crate::batch::*;
 use crate::convert::*;
 use crate::error::*;
 use crate::layout::*;
@@ -128,7 +132,24 @@ where
     }
 }
 
-fn take_slice<A, S1, S2>(a: &ArrayBase<S1, Ix2>, n: usize, m: usize) -> ArrayBase<S2, Ix2>
+impl<A, S> QR for ArrayBase<S, Ix3>
+where
+    A: Scalar + Lapack + Send + Sync,
+    S: Data<Elem = A>,
+{
+    type Q = Array3<A>;
+    type R = Array3<A>;
+
+    fn qr(&self) -> Result<(Self::Q, Self::R)> {
+        let (_, n, m) = self.dim();
+        let k = ::std::cmp::min(n, m);
+        let res = map_stack(self, |a| a.qr())?;
+        let (qs, rs): (Vec<_>, Vec<_>) = res.into_iter().unzip();
+        Ok((stack_matrices((n, k), &qs), stack_matrices((k, m), &rs)))
+    }
+}
+
+pub(crate) fn take_slice<A, S1, S2>(a: &ArrayBase<S1, Ix2>, n: usize, m: usize) -> ArrayBase<S2, Ix2>
 where
     A: Copy,
     S1: Data<Elem = A>,
@@ -140,7 +161,7 @@ where
     a
 }
 
-fn take_slice_upper<A, S1, S2>(a: &ArrayBase<S1, Ix2>, n: usize, m: usize) -> ArrayBase<S2, Ix2>
+pub(crate) fn take_slice_upper<A, S1, S2>(a: &ArrayBase<S1, Ix2>, n: usize, m: usize) -> ArrayBase<S2, Ix2>
 where
     A: Copy + Zero,
     S1: Data<Elem = A>,
@@ -0,0 +1,102 @@
+//! Stochastic trace estimation
+
+use ndarray::*;
+use num_traits::{Float, One};
+use rand::{thread_rng, Rng};
+
+use super::gaussian;
+use crate::error::*;
+use crate::inner::*;
+use crate::operator::AdjointOperator;
+use crate::types::*;
+
+/// Distribution of the probe vectors `z` with `E[z z^H] = I`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ProbeKind {
+    /// Independent `±1` elements, which gives the smallest variance for real matrices
+    Rademacher,
+    /// Independent standard normal elements
+    Gaussian,
+}
+
+/// Estimated trace with its standard error
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct TraceEstimate<A: Scalar> {
+    /// Mean of `z^H A z` over the probes
+    pub value: A,
+    /// Standard error of `value` estimated from the sample variance, or infinity for a single probe
+    pub standard_error: A::Real,
+}
+
+/// Random probe vector of the length `n`
+pub(crate) fn probe<A, R>(n: usize, kind: ProbeKind, rng: &mut R) -> Array1<A>
+where
+    A: Scalar,
+    R: Rng,
+{
+    match kind {
+        ProbeKind::Rademacher => Array1::from_shape_fn(n, |_| if rng.gen() { A::one() } else { -A::one() }),
+        ProbeKind::Gaussian => gaussian(n, rng),
+    }
+}
+
+/// Estimate the trace of a square operator `op` by the Hutchinson estimator `tr A ≈ (1/N) Σ z_i^H A z_i`
+/// with `N = n_probes` random probes `z_i`
+///
+/// Only the action of `op` is used, so that the trace of e.g. `A^{-1}` is estimated
+/// by the [operator of a factorization](../../operator/index.html) without forming the inverse.
+/// The variance of `z^H A z` is `2 |A|_F^2` for the Gaussian probes
+/// and `2 (|A|_F^2 - Σ |A_ii|^2)` for the Rademacher probes of a real symmetric `A`,
+/// so that the relative error decreases as `1 / sqrt(N)` for positive semidefinite operators.
+///
+/// Fails with `NotSquare` if `op` is not square, and with `OutOfBounds` if `n_probes` is zero.
+///
+/// - M. F. Hutchinson, A stochastic estimator of the trace of the influence matrix for Laplacian smoothing
+///   splines, Commun. Stat. Simul. Comput. 18 (1989)
+///
+/// ```
+/// use ndarray::*;
+/// use ndarray_linalg::*;
+///
+/// let a: Array2<f64> = random_hpd(100);
+/// let est = trace_estimate(&a, 1000, ProbeKind::Rademacher).unwrap();
+/// let tr = a.trace().unwrap();
+/// assert!((est.value - tr).abs() < 5.0 * est.standard_error);
+/// ```
+pub fn trace_estimate<A, F>(op: &F, n_probes: usize, probe_kind: ProbeKind) -> Result<TraceEstimate<A>>
+where
+    A: Scalar,
+    F: AdjointOperator<Elem = A>,
+{
+    let (m, n) = op.shape();
+    if m != n {
+        return Err(LinalgError::NotSquare {
+            rows: m as i32,
+            cols: n as i32,
+        });
+    }
+    if n_probes == 0 {
+        return Err(ShapeError::from_kind(ErrorKind::OutOfBounds).into());
+    }
+    let mut rng = thread_rng();
+    let samples: Vec<A> = (0..n_probes)
+        .map(|_| {
+            let z: Array1<A> = probe(n, probe_kind, &mut rng);
+            z.inner(&op.apply(&z))
+        })
+        .collect();
+    Ok(sample_mean(&samples))
+}
+
+/// Mean of the samples `z_i^H A z_i` with its standard error
+pub(crate) fn sample_mean<A: Scalar>(samples: &[A]) -> TraceEstimate<A> {
+    let count = A::real(samples.len());
+    let value = samples.iter().fold(A::zero(), |acc, &s| acc + s).div_real(count);
+    let standard_error = if samples.len() > 1 {
+        let variance = samples.iter().map(|&s| (s - value).square()).sum::<A::Real>() / (count - A::Real::one());
+        Float::sqrt(variance / count)
+    } else {
+        A::Real::infinity()
+    };
+    TraceEstimate { value, standard_error }
+}
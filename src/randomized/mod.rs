@@ -0,0 +1,46 @@
+//! Randomized methods for low-rank approximation
+//!
+//! These methods sample the range of a large matrix or operator by applying it to a few random vectors,
+//! so that the expensive factorizations are only carried out on small matrices.
+//! [randomized_range_finder](fn.randomized_range_finder.html) is the common first step,
+//! which computes an orthonormal basis `Q` with `A ≈ Q Q^H A`,
+//! and [nystrom](fn.nystrom.html) gives the top eigenpairs of a positive semidefinite operator from it.
+//! The [random projections](projection/index.html) reduce the dimension of data while preserving the distances,
+//! and [trace_estimate](fn.trace_estimate.html) estimates the trace of an implicit operator by random probes,
+//! which [logdet_estimate](fn.logdet_estimate.html) combines with the Lanczos process for `log det A`.
+//!
+//! - N. Halko, P. G. Martinsson and J. A. Tropp, Finding structure with randomness: Probabilistic
+//!   algorithms for constructing approximate matrix decompositions, SIAM Rev. 53 (2011)
+//!
+
+use ndarray::*;
+use rand::Rng;
+
+use crate::generate::{random_with_using, Normal};
+use crate::types::*;
+
+pub mod hutchinson;
+pub mod nystrom;
+pub mod projection;
+pub mod range_finder;
+pub mod slq;
+
+pub use hutchinson::{trace_estimate, ProbeKind, TraceEstimate};
+pub use nystrom::nystrom;
+pub use projection::{gaussian_projection, johnson_lindenstrauss_min_dim, sparse_projection};
+pub use range_finder::randomized_range_finder;
+pub use slq::logdet_estimate;
+
+/// Array of independent standard normal samples
+///
+/// The real and imaginary parts of complex samples are independent with the variance `1/2`,
+/// so that `E[|x|^2] = 1` in both cases.
+pub(crate) fn gaussian<A, Sh, D, R>(shape: Sh, rng: &mut R) -> Array<A, D>
+where
+    A: Scalar,
+    Sh: ShapeBuilder<Dim = D>,
+    D: Dimension,
+    R: Rng,
+{
+    random_with_using(shape, &Normal::<A>::standard(), rng)
+}
@@ -0,0 +1,80 @@
+//! Randomized Nyström approximation of positive semidefinite operators
+
+use ndarray::*;
+use num_traits::{Float, Zero};
+
+use super::range_finder::randomized_range_finder;
+use crate::cholesky::*;
+use crate::error::*;
+use crate::generate::conjugate;
+use crate::norm::Norm;
+use crate::operator::AdjointOperator;
+use crate::qr::*;
+use crate::svd::*;
+use crate::triangular::*;
+use crate::types::*;
+
+/// Top `k` eigenpairs of a Hermitian (or real symmetric) positive semidefinite `n x n` operator `op`
+/// by the randomized Nyström approximation
+///
+/// With the orthonormal basis `Q` given by
+/// [randomized_range_finder](fn.randomized_range_finder.html)`(op, k, p, q)`,
+/// the operator is approximated by `A ≈ (A Q) (Q^H A Q)^{-1} (A Q)^H`,
+/// which is much more accurate than `Q Q^H A Q Q^H` for the same number `k + p` of applications of `op`.
+/// `A Q` is shifted by a small multiple of `Q` before the Cholesky factorization of `Q^H A Q`
+/// to keep it stable for numerically low-rank operators, as in the reference below.
+///
+/// The eigenvalues are returned in the descending order with the corresponding eigenvectors as columns.
+/// Fails with `NotSquare` if `op` is not square, and with the Cholesky error if `op` is not positive semidefinite.
+///
+/// - J. A. Tropp, A. Yurtsever, M. Udell and V. Cevher, Fixed-rank approximation of a positive-semidefinite
+///   matrix from streaming data, NeurIPS (2017)
+///
+/// ```
+/// use ndarray::*;
+/// use ndarray_linalg::*;
+///
+/// // Gram matrix of rank 3
+/// let x: Array2<f64> = random((100, 3));
+/// let a = x.dot(&x.t());
+/// let (e, v) = nystrom(&a, 3, 5, 0).unwrap();
+/// let e_truth = a.eigvalsh(UPLO::Upper).unwrap();
+/// assert_close_l2!(&e, &arr1(&[e_truth[99], e_truth[98], e_truth[97]]), 1e-8);
+/// assert_close_l2!(&a.dot(&v), &(v.clone() * &e), 1e-8);
+/// ```
+pub fn nystrom<A, F>(op: &F, k: usize, p: usize, q: usize) -> Result<(Array1<A::Real>, Array2<A>)>
+where
+    A: Scalar + Lapack,
+    F: AdjointOperator<Elem = A>,
+{
+    let (m, n) = op.shape();
+    if m != n {
+        return Err(LinalgError::NotSquare {
+            rows: m as i32,
+            cols: n as i32,
+        });
+    }
+    let basis = randomized_range_finder(op, k, p, q)?;
+    let mut y = op.apply2(&basis);
+    let shift = Float::sqrt(A::real(n)) * A::Real::epsilon() * y.norm_l2();
+    y.scaled_add(A::from_real(shift), &basis);
+
+    // Cholesky factorization `Q^H Y = L L^H` of the Hermitian part
+    let qh: Array2<A> = conjugate(&basis);
+    let b = qh.dot(&y);
+    let bh: Array2<A> = conjugate(&b);
+    let b = (b + &bh).mapv_into(|x| x.div_real(A::real(2)));
+    let l = b.cholesky(UPLO::Lower)?;
+
+    // `A ≈ F F^H` with `F = Y L^{-H}`, whose SVD `F = U Σ V^H` gives `A ≈ U Σ^2 U^H`
+    let yh: Array2<A> = conjugate(&y);
+    let fh = l.solve_triangular(UPLO::Lower, Diag::NonUnit, &yh)?;
+    let f: Array2<A> = conjugate(&fh);
+    let (qf, rf) = f.qr_into()?;
+    let (u, s, _) = rf.svd(true, false)?;
+    let u = qf.dot(&u.unwrap());
+
+    let zero = A::Real::zero();
+    let values = s.slice(s![..k]).mapv(|s| Float::max(s * s - shift, zero));
+    Ok((values, u.slice(s![.., ..k]).to_owned()))
+}
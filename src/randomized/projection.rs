@@ -0,0 +1,85 @@
+//! Random projections for the dimensionality reduction
+//!
+//! A random `k x d` matrix `P` scaled as `E[P^H P] = I` preserves the pairwise distances of `n` points
+//! up to the factor `1 ± ε` with high probability if `k` is of the order of `log(n) / ε^2`
+//! (the Johnson-Lindenstrauss lemma), independently of the dimension `d`.
+//! [gaussian_projection](fn.gaussian_projection.html) gives a dense Gaussian matrix,
+//! and [sparse_projection](fn.sparse_projection.html) a sparse one in the CSR format,
+//! whose [apply](../../operator/trait.LinearOperator.html#method.apply) costs only the non-zero elements.
+//!
+//! - D. Achlioptas, Database-friendly random projections: Johnson-Lindenstrauss with binary coins,
+//!   J. Comput. Syst. Sci. 66 (2003)
+//! - P. Li, T. J. Hastie and K. W. Church, Very sparse random projections, KDD (2006)
+//!
+
+use ndarray::*;
+use num_traits::Float;
+use rand::{thread_rng, Rng};
+
+use super::gaussian;
+use crate::csr::CsrMatrix;
+use crate::types::*;
+
+/// Dense `k x d` projection matrix of independent `N(0, 1/k)` elements
+///
+/// ```
+/// use ndarray::*;
+/// use ndarray_linalg::*;
+///
+/// let p: Array2<f64> = gaussian_projection(400, 1000);
+/// let x: Array1<f64> = random(1000);
+/// let ratio = p.dot(&x).norm_l2() / x.norm_l2();
+/// assert!((ratio - 1.0).abs() < 0.3);
+/// ```
+pub fn gaussian_projection<A: Scalar>(k: usize, d: usize) -> Array2<A> {
+    let scale = Float::recip(Float::sqrt(A::real(k)));
+    let p: Array2<A> = gaussian((k, d), &mut thread_rng());
+    p.mapv_into(|x| x.mul_real(scale))
+}
+
+/// Sparse `k x d` projection matrix whose elements are `±1 / sqrt(density k)` with the probability `density / 2`
+/// each, and zero otherwise
+///
+/// `density = 1/3` gives the projection of Achlioptas, and `density = 1 / sqrt(d)` the very sparse one of Li et al.,
+/// which has the same guarantee for the data without too heavy tails.
+///
+/// Panics if `density` is not in `(0, 1]`.
+///
+/// ```
+/// use ndarray::*;
+/// use ndarray_linalg::*;
+///
+/// let p: CsrMatrix<f64> = sparse_projection(400, 1000, 1.0 / 3.0);
+/// let x: Array1<f64> = random(1000);
+/// let ratio = p.apply(&x).norm_l2() / x.norm_l2();
+/// assert!((ratio - 1.0).abs() < 0.3);
+/// ```
+pub fn sparse_projection<A: Scalar>(k: usize, d: usize, density: f64) -> CsrMatrix<A> {
+    assert!(density > 0.0 && density <= 1.0, "density must be in (0, 1]");
+    let mut rng = thread_rng();
+    let value = A::from_real(A::real((density * k as f64).sqrt().recip()));
+    let mut indptr = vec![0];
+    let mut indices = Vec::new();
+    let mut data = Vec::new();
+    for _ in 0..k {
+        for j in 0..d {
+            let u: f64 = rng.gen();
+            if u < density {
+                indices.push(j);
+                data.push(if u < density / 2.0 { value } else { -value });
+            }
+        }
+        indptr.push(data.len());
+    }
+    CsrMatrix::from_raw_parts((k, d), indptr, indices, data)
+}
+
+/// Minimal dimension `k` of the projection to preserve the pairwise distances of `n_samples` points
+/// up to the factor `1 ± eps` with high probability, `4 ln(n) / (eps^2 / 2 - eps^3 / 3)`
+///
+/// This bound is conservative, and a few hundreds are usually enough in practice.
+pub fn johnson_lindenstrauss_min_dim(n_samples: usize, eps: f64) -> usize {
+    assert!(eps > 0.0 && eps < 1.0, "eps must be in (0, 1)");
+    let denominator = eps.powi(2) / 2.0 - eps.powi(3) / 3.0;
+    (4.0 * (n_samples as f64).ln() / denominator).ceil() as usize
+}
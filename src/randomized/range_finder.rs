@@ -0,0 +1,54 @@
+//! Randomized range finder
+
+use ndarray::*;
+use rand::thread_rng;
+
+use super::gaussian;
+use crate::error::*;
+use crate::operator::AdjointOperator;
+use crate::qr::*;
+use crate::types::*;
+
+/// Orthonormal basis `Q` approximately spanning the range of an `m x n` operator `op`
+///
+/// `op` is applied to `k + p` Gaussian random vectors, where `k` is the target rank and `p` is the
+/// oversampling (5 or 10 is usually enough), and the result is orthonormalized by the QR decomposition.
+/// `q` steps of the power iteration `(A A^H)^q A` are added for the operators whose singular values
+/// decay slowly, where the basis is re-orthonormalized after each application of `A` or `A^H`
+/// to keep the small singular directions.
+///
+/// The returned `Q` is an `m x min(k + p, m, n)` matrix with `Q^H Q = I`,
+/// which satisfies `|A - Q Q^H A| ≈ σ_{k+1}` with high probability.
+/// Fails with `OutOfBounds` if `k` is zero or exceeds `min(m, n)`.
+///
+/// ```
+/// use ndarray::*;
+/// use ndarray_linalg::*;
+///
+/// // Rank 2 matrix
+/// let u: Array2<f64> = random((50, 2));
+/// let v: Array2<f64> = random((2, 30));
+/// let a = u.dot(&v);
+/// let q = randomized_range_finder(&a, 2, 5, 1).unwrap();
+/// assert_eq!(q.dim(), (50, 7));
+/// assert_close_l2!(&q.dot(&q.t().dot(&a)), &a, 1e-10);
+/// ```
+pub fn randomized_range_finder<A, F>(op: &F, k: usize, p: usize, q: usize) -> Result<Array2<A>>
+where
+    A: Scalar + Lapack,
+    F: AdjointOperator<Elem = A>,
+{
+    let (m, n) = op.shape();
+    let min_dim = ::std::cmp::min(m, n);
+    if k == 0 || k > min_dim {
+        return Err(ShapeError::from_kind(ErrorKind::OutOfBounds).into());
+    }
+    let l = ::std::cmp::min(k + p, min_dim);
+    let omega: Array2<A> = gaussian((n, l), &mut thread_rng());
+    let (mut basis, _) = op.apply2(&omega).qr_into()?;
+    for _ in 0..q {
+        let (w, _) = op.apply2_adjoint(&basis).qr_into()?;
+        basis = op.apply2(&w).qr_into()?.0;
+    }
+    Ok(basis)
+}
@@ -0,0 +1,78 @@
+//! Entry points for real symmetric matrices
+//!
+//! The traits of this crate are generic over real and complex numbers,
+//! so that a generic function calling them needs bounds like `A: Scalar + Lapack`
+//! and has to distinguish `A` from `A::Real`, e.g. for the eigenvalues.
+//! The functions of this module take the real matrices of [RealScalar](trait.RealScalar.html),
+//! i.e. `f32` or `f64`, and return everything in the same type:
+//!
+//! ```
+//! use ndarray::*;
+//! use ndarray_linalg::*;
+//!
+//! fn smallest_eigenvalue<A: RealScalar>(a: &Array2<A>) -> A {
+//!     eigvalsh_real(a, UPLO::Lower).unwrap()[0]
+//! }
+//!
+//! let a = arr2(&[[2.0, 1.0], [1.0, 2.0]]);
+//! assert_rclose!(smallest_eigenvalue(&a), 1.0, 1e-9);
+//! ```
+
+use ndarray::*;
+
+use crate::cholesky::Cholesky;
+use crate::eigh::{EigValsh, Eigh};
+use crate::error::*;
+use crate::solveh::SolveH;
+use crate::types::*;
+use crate::UPLO;
+
+/// Real scalars computed by LAPACK, i.e. `f32` and `f64`, whose real type is themselves
+pub trait RealScalar: Scalar<Real = Self> + Lapack {}
+
+impl RealScalar for f32 {}
+impl RealScalar for f64 {}
+
+/// Eigenvalues in ascending order and the orthonormal eigenvectors of a real symmetric matrix
+///
+/// Only the triangular part specified by `uplo` is read.
+pub fn eigh_real<A, S>(a: &ArrayBase<S, Ix2>, uplo: UPLO) -> Result<(Array1<A>, Array2<A>)>
+where
+    A: RealScalar,
+    S: Data<Elem = A>,
+{
+    a.eigh(uplo)
+}
+
+/// Eigenvalues in ascending order of a real symmetric matrix
+///
+/// Only the triangular part specified by `uplo` is read.
+pub fn eigvalsh_real<A, S>(a: &ArrayBase<S, Ix2>, uplo: UPLO) -> Result<Array1<A>>
+where
+    A: RealScalar,
+    S: Data<Elem = A>,
+{
+    a.eigvalsh(uplo)
+}
+
+/// Cholesky factor `L` of `A = L L^T` for `UPLO::Lower`, or `U` of `A = U^T U` for `UPLO::Upper`,
+/// of a real symmetric positive definite matrix
+///
+/// Only the triangular part specified by `uplo` is read.
+pub fn cholesky_real<A, S>(a: &ArrayBase<S, Ix2>, uplo: UPLO) -> Result<Array2<A>>
+where
+    A: RealScalar,
+    S: Data<Elem = A>,
+{
+    a.cholesky(uplo)
+}
+
+/// Solve `A x = b` for a real symmetric, possibly indefinite, matrix by the Bunch-Kaufman factorization
+pub fn solveh_real<A, Sa, Sb>(a: &ArrayBase<Sa, Ix2>, b: &ArrayBase<Sb, Ix1>) -> Result<Array1<A>>
+where
+    A: RealScalar,
+    Sa: Data<Elem = A>,
+    Sb: Data<Elem = A>,
+{
+    a.solveh(b)
+}
@@ -0,0 +1,227 @@
+//! Algebraic Riccati equations
+//!
+//! The continuous-time algebraic Riccati equation (CARE)
+//!
+//! ```text
+//! A^H X + X A - X B R^{-1} B^H X + Q = 0
+//! ```
+//!
+//! gives the optimal state feedback `u = -K x`, `K = R^{-1} B^H X` of the linear-quadratic regulator (LQR)
+//! which minimizes `∫ (x^H Q x + u^H R u) dt` subject to `dx/dt = A x + B u`.
+//! Its stabilizing solution `X`, for which `A - B K` is stable, is computed by [care](fn.care.html).
+//!
+//! Instead of the Hamiltonian matrix, which needs `R^{-1}`, the extended pencil
+//!
+//! ```text
+//!     | A    0    B |       | I 0 0 |
+//! M = | -Q  -A^H  0 |,  N = | 0 I 0 |
+//!     | 0    B^H  R |       | 0 0 0 |
+//! ```
+//!
+//! is decomposed by the QZ algorithm and reordered so that its `n` stable eigenvalues come first.
+//! The leading `n` columns `[Z1; Z2; Z3]` of `Z` span the stable deflating subspace, and `X = Z2 Z1^{-1}`.
+//!
+//! Similarly, the discrete-time algebraic Riccati equation (DARE)
+//!
+//! ```text
+//! A^H X A - X - A^H X B (R + B^H X B)^{-1} B^H X A + Q = 0
+//! ```
+//!
+//! gives the optimal feedback `K = (R + B^H X B)^{-1} B^H X A` for `x_{k+1} = A x_k + B u_k`,
+//! and also the steady-state Kalman filter by the duality `A -> A^H`, `B -> C^H`.
+//! [dare](fn.dare.html) computes its stabilizing solution from the extended symplectic pencil
+//!
+//! ```text
+//!     | A   0  B |       | I  0    0 |
+//! M = | -Q  I  0 |,  N = | 0  A^H  0 |
+//!     | 0   0  R |       | 0 -B^H  0 |
+//! ```
+//!
+//! whose stable eigenvalues are those inside the unit circle, without inverting `A`.
+//!
+//! - W. F. Arnold and A. J. Laub, Generalized eigenproblem algorithms and software for algebraic Riccati
+//!   equations, Proc. IEEE 72 (1984)
+//! - P. Van Dooren, A generalized eigenvalue approach for solving Riccati equations,
+//!   SIAM J. Sci. Stat. Comput. 2 (1981)
+//!
+//! # Example
+//!
+//! ```
+//! use ndarray::*;
+//! use ndarray_linalg::*;
+//!
+//! // Double integrator `d^2 y / dt^2 = u`
+//! let a = arr2(&[[0.0, 1.0], [0.0, 0.0]]);
+//! let b = arr2(&[[0.0], [1.0]]);
+//! let q = Array2::eye(2);
+//! let r = arr2(&[[1.0]]);
+//! let x = care(&a, &b, &q, &r).unwrap();
+//! let s3 = 3.0_f64.sqrt();
+//! assert_close_l2!(&x, &arr2(&[[s3, 1.0], [1.0, s3]]), 1e-10);
+//! ```
+
+use ndarray::*;
+use num_traits::Zero;
+
+use crate::error::*;
+use crate::generate::conjugate;
+use crate::layout::*;
+use crate::schur::*;
+use crate::solve::{solve_columns, Factorize};
+use crate::types::*;
+
+/// Stabilizing solution `X` of the continuous-time algebraic Riccati equation
+/// `A^H X + X A - X B R^{-1} B^H X + Q = 0`
+///
+/// - `a` is `n x n` and `b` is `n x m`
+/// - `q` is `n x n` Hermitian positive semidefinite, and `r` is `m x m` Hermitian positive definite
+///
+/// The returned `X` is Hermitian, and `A - B R^{-1} B^H X` is stable (all the eigenvalues have negative real parts).
+/// Such a solution exists if `(A, B)` is stabilizable and `(Q, A)` has no unobservable modes on the imaginary axis.
+///
+/// Fails with `IncompatibleShape` for the inconsistent shapes, and with `LinalgError::Lapack { return_code: k + 1 }`
+/// if only `k < n` eigenvalues of the pencil are stable, i.e. there is no stabilizing solution.
+pub fn care<A, Sa, Sb, Sq, Sr>(
+    a: &ArrayBase<Sa, Ix2>,
+    b: &ArrayBase<Sb, Ix2>,
+    q: &ArrayBase<Sq, Ix2>,
+    r: &ArrayBase<Sr, Ix2>,
+) -> Result<Array2<A>>
+where
+    A: Scalar + Lapack,
+    Sa: Data<Elem = A>,
+    Sb: Data<Elem = A>,
+    Sq: Data<Elem = A>,
+    Sr: Data<Elem = A>,
+{
+    let (n, m) = check_shapes(a, b, q, r)?;
+    let size = 2 * n + m;
+    let ah: Array2<A> = conjugate(a);
+    let bh: Array2<A> = conjugate(b);
+    let mut pm = Array2::zeros((size, size));
+    pm.slice_mut(s![..n, ..n]).assign(a);
+    pm.slice_mut(s![..n, 2 * n..]).assign(b);
+    pm.slice_mut(s![n..2 * n, ..n]).assign(&q.mapv(|x| -x));
+    pm.slice_mut(s![n..2 * n, n..2 * n]).assign(&ah.mapv(|x| -x));
+    pm.slice_mut(s![2 * n.., n..2 * n]).assign(&bh);
+    pm.slice_mut(s![2 * n.., 2 * n..]).assign(r);
+    let mut pn = Array2::zeros((size, size));
+    for i in 0..2 * n {
+        pn[(i, i)] = A::one();
+    }
+    let schur = pm.qz(&pn)?;
+    // `Re(α / β) < 0`, where the infinite eigenvalues `β = 0` are not selected
+    let select: Vec<bool> = schur
+        .alpha
+        .iter()
+        .zip(schur.beta.iter())
+        .map(|(&alpha, &beta)| (alpha * beta.conj()).re() < A::Real::zero())
+        .collect();
+    stabilizing_solution(schur, &select, n)
+}
+
+/// Stabilizing solution `X` of the discrete-time algebraic Riccati equation
+/// `A^H X A - X - A^H X B (R + B^H X B)^{-1} B^H X A + Q = 0`
+///
+/// - `a` is `n x n` and `b` is `n x m`
+/// - `q` is `n x n` Hermitian positive semidefinite, and `r` is `m x m` Hermitian positive definite
+///
+/// The returned `X` is Hermitian, and `A - B K` with `K = (R + B^H X B)^{-1} B^H X A` is stable
+/// (all the eigenvalues are inside the unit circle).
+/// `A` may be singular, e.g. for systems with delays.
+///
+/// Fails with `IncompatibleShape` for the inconsistent shapes, and with `LinalgError::Lapack { return_code: k + 1 }`
+/// if only `k < n` eigenvalues of the pencil are stable, i.e. there is no stabilizing solution.
+///
+/// ```
+/// use ndarray::*;
+/// use ndarray_linalg::*;
+///
+/// // `x^2 - x - 1 = 0` for the scalar system `a = b = q = r = 1`
+/// let one = arr2(&[[1.0]]);
+/// let x = dare(&one, &one, &one, &one).unwrap();
+/// assert!((x[(0, 0)] - (1.0 + 5.0_f64.sqrt()) / 2.0).abs() < 1e-12);
+/// ```
+pub fn dare<A, Sa, Sb, Sq, Sr>(
+    a: &ArrayBase<Sa, Ix2>,
+    b: &ArrayBase<Sb, Ix2>,
+    q: &ArrayBase<Sq, Ix2>,
+    r: &ArrayBase<Sr, Ix2>,
+) -> Result<Array2<A>>
+where
+    A: Scalar + Lapack,
+    Sa: Data<Elem = A>,
+    Sb: Data<Elem = A>,
+    Sq: Data<Elem = A>,
+    Sr: Data<Elem = A>,
+{
+    let (n, m) = check_shapes(a, b, q, r)?;
+    let size = 2 * n + m;
+    let ah: Array2<A> = conjugate(a);
+    let bh: Array2<A> = conjugate(b);
+    let mut pm = Array2::zeros((size, size));
+    pm.slice_mut(s![..n, ..n]).assign(a);
+    pm.slice_mut(s![..n, 2 * n..]).assign(b);
+    pm.slice_mut(s![n..2 * n, ..n]).assign(&q.mapv(|x| -x));
+    pm.slice_mut(s![2 * n.., 2 * n..]).assign(r);
+    let mut pn = Array2::zeros((size, size));
+    for i in 0..n {
+        pm[(n + i, n + i)] = A::one();
+        pn[(i, i)] = A::one();
+    }
+    pn.slice_mut(s![n..2 * n, n..2 * n]).assign(&ah);
+    pn.slice_mut(s![2 * n.., n..2 * n]).assign(&bh.mapv(|x| -x));
+    let schur = pm.qz(&pn)?;
+    // `|α / β| < 1`, where the infinite eigenvalues `β = 0` are not selected
+    let select: Vec<bool> = schur
+        .alpha
+        .iter()
+        .zip(schur.beta.iter())
+        .map(|(&alpha, &beta)| alpha.abs() < beta.abs())
+        .collect();
+    stabilizing_solution(schur, &select, n)
+}
+
+/// Check the shapes of `A`, `B`, `Q` and `R`, and return `(n, m)`
+fn check_shapes<A, Sa, Sb, Sq, Sr>(
+    a: &ArrayBase<Sa, Ix2>,
+    b: &ArrayBase<Sb, Ix2>,
+    q: &ArrayBase<Sq, Ix2>,
+    r: &ArrayBase<Sr, Ix2>,
+) -> Result<(usize, usize)>
+where
+    A: Scalar,
+    Sa: Data<Elem = A>,
+    Sb: Data<Elem = A>,
+    Sq: Data<Elem = A>,
+    Sr: Data<Elem = A>,
+{
+    let (n, _) = a.square_layout()?.size();
+    let n = n as usize;
+    let m = b.cols();
+    if b.rows() != n || q.dim() != (n, n) || r.dim() != (m, m) {
+        return Err(ShapeError::from_kind(ErrorKind::IncompatibleShape).into());
+    }
+    Ok((n, m))
+}
+
+/// `X = Z2 Z1^{-1}` from the leading `n` columns `[Z1; Z2; Z3]` of `Z` after moving the selected eigenvalues first
+fn stabilizing_solution<A: Scalar + Lapack>(
+    mut schur: GeneralizedSchur<A>,
+    select: &[bool],
+    n: usize,
+) -> Result<Array2<A>> {
+    let count = schur.reorder(select)?;
+    if count != n {
+        return Err(LinalgError::Lapack {
+            return_code: count as i32 + 1,
+        });
+    }
+    // `Z1^H X^H = Z2^H`
+    let z1h: Array2<A> = conjugate(&schur.z.slice(s![..n, ..n]));
+    let z2h: Array2<A> = conjugate(&schur.z.slice(s![n..2 * n, ..n]));
+    let xh = solve_columns(&z1h.factorize()?, &z2h)?;
+    let x: Array2<A> = conjugate(&xh);
+    // Remove the rounding errors breaking the symmetry
+    Ok((x + &xh).mapv_into(|x| x.div_real(A::real(2))))
+}
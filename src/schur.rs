@@ -0,0 +1,258 @@
+//! Schur decomposition
+//!
+//! A square matrix is decomposed as `A = Q T Q^H` with a unitary `Q` and an upper triangular `T`
+//! whose diagonal elements are the eigenvalues of `A`.
+//! For real matrices, the real Schur form is computed, i.e. `Q` is orthogonal and `T` is quasi-upper triangular,
+//! where each complex conjugate pair of eigenvalues appears as a `2x2` diagonal block.
+//!
+//! The sensitivities of the eigenvalues, eigenvectors, and invariant subspaces are estimated from the Schur form
+//! by [schur_condition](fn.schur_condition.html) and
+//! [invariant_subspace_condition](fn.invariant_subspace_condition.html).
+//!
+//! The generalized Schur (QZ) decomposition of a pencil `(A, B)` is computed by
+//! [SchurGeneralized](trait.SchurGeneralized.html).
+//!
+//! [Wikipedia article on Schur decomposition](https://en.wikipedia.org/wiki/Schur_decomposition)
+
+use ndarray::*;
+
+use crate::convert::*;
+use crate::error::*;
+use crate::layout::*;
+use crate::types::*;
+
+/// Schur decomposition of matrix reference
+pub trait Schur {
+    type Q;
+    type T;
+    fn schur(&self) -> Result<(Self::Q, Self::T)>;
+}
+
+/// Schur decomposition
+pub trait SchurInto: Sized {
+    type Q;
+    /// Compute the Schur decomposition, where `T` is stored into `self`
+    fn schur_into(self) -> Result<(Self::Q, Self)>;
+}
+
+impl<A, S> Schur for ArrayBase<S, Ix2>
+where
+    A: Scalar + Lapack,
+    S: Data<Elem = A>,
+{
+    type Q = Array2<A>;
+    type T = Array2<A>;
+
+    fn schur(&self) -> Result<(Self::Q, Self::T)> {
+        let a: Array2<A> = replicate(self);
+        a.schur_into()
+    }
+}
+
+impl<A, S> SchurInto for ArrayBase<S, Ix2>
+where
+    A: Scalar + Lapack,
+    S: DataMut<Elem = A>,
+{
+    type Q = Array2<A>;
+
+    fn schur_into(mut self) -> Result<(Self::Q, Self)> {
+        let l = self.square_layout()?;
+        let (_, q) = unsafe { A::schur(l, self.as_allocated_mut()?)? };
+        Ok((into_matrix(l, q)?, self))
+    }
+}
+
+/// Reorder the Schur decomposition `A = Q T Q^H` so that the eigenvalues `T[(i, i)]` where `select[i]` is true
+/// move to the leading block of `T`
+///
+/// `q` and `t` are updated in place, and the number of the selected eigenvalues `m` is returned.
+/// The leading `m` columns of `q` span the invariant subspace corresponding to the selected eigenvalues.
+/// For real matrices, a complex conjugate pair is selected if either of them is selected.
+pub fn reorder_schur<A, Sq, St>(
+    q: &mut ArrayBase<Sq, Ix2>,
+    t: &mut ArrayBase<St, Ix2>,
+    select: &[bool],
+) -> Result<usize>
+where
+    A: Scalar + Lapack,
+    Sq: DataMut<Elem = A>,
+    St: DataMut<Elem = A>,
+{
+    let (n, _) = t.square_layout()?.size();
+    if q.dim() != t.dim() || select.len() != n as usize {
+        return Err(ShapeError::from_kind(ErrorKind::IncompatibleShape).into());
+    }
+    let l = MatrixLayout::F((n, n));
+    let mut qf: Array2<A> = clone_with_layout(l, q);
+    let mut tf: Array2<A> = clone_with_layout(l, t);
+    let (_, m, _, _) = unsafe { A::schur_reorder(l, false, select, tf.as_allocated_mut()?, qf.as_allocated_mut()?)? };
+    q.assign(&qf);
+    t.assign(&tf);
+    Ok(m)
+}
+
+/// Reciprocal condition numbers of the eigenvalues and the eigenvectors from the Schur decomposition `A = Q T Q^H`
+///
+/// Returns `(s, sep)` in the order of the diagonal of `T`.
+/// The computed eigenvalue `T[(j, j)]` has the error about `eps |A| / s[j]`,
+/// and the angle between the computed and exact eigenvectors is about `eps |A| / sep[j]`.
+pub fn schur_condition<A, Sq, St>(
+    q: &ArrayBase<Sq, Ix2>,
+    t: &ArrayBase<St, Ix2>,
+) -> Result<(Array1<A::Real>, Array1<A::Real>)>
+where
+    A: Scalar + Lapack,
+    Sq: Data<Elem = A>,
+    St: Data<Elem = A>,
+{
+    let (n, _) = t.square_layout()?.size();
+    if q.dim() != t.dim() {
+        return Err(ShapeError::from_kind(ErrorKind::IncompatibleShape).into());
+    }
+    let l = MatrixLayout::F((n, n));
+    let qf: Array2<A> = clone_with_layout(l, q);
+    let mut tf: Array2<A> = clone_with_layout(l, t);
+    let (s, sep) = unsafe { A::schur_condition(l, tf.as_allocated_mut()?, qf.as_slice_memory_order().unwrap())? };
+    Ok((ArrayBase::from_vec(s), ArrayBase::from_vec(sep)))
+}
+
+/// Reciprocal condition numbers of the average of the selected eigenvalues and of the corresponding invariant
+/// subspace from the Schur decomposition `A = Q T Q^H`
+///
+/// Returns `(s, sep)`. The average of the selected eigenvalues has the error about `eps |A| / s`,
+/// and the invariant subspace has the error about `eps |A| / sep` in angle.
+/// Unlike [reorder_schur](fn.reorder_schur.html), `q` and `t` are not modified.
+pub fn invariant_subspace_condition<A, Sq, St>(
+    q: &ArrayBase<Sq, Ix2>,
+    t: &ArrayBase<St, Ix2>,
+    select: &[bool],
+) -> Result<(A::Real, A::Real)>
+where
+    A: Scalar + Lapack,
+    Sq: Data<Elem = A>,
+    St: Data<Elem = A>,
+{
+    let (n, _) = t.square_layout()?.size();
+    if q.dim() != t.dim() || select.len() != n as usize {
+        return Err(ShapeError::from_kind(ErrorKind::IncompatibleShape).into());
+    }
+    let l = MatrixLayout::F((n, n));
+    let mut qf: Array2<A> = clone_with_layout(l, q);
+    let mut tf: Array2<A> = clone_with_layout(l, t);
+    let (_, _, s, sep) = unsafe { A::schur_reorder(l, true, select, tf.as_allocated_mut()?, qf.as_allocated_mut()?)? };
+    Ok((s, sep))
+}
+
+/// Eigenvalues with their condition numbers
+pub trait EigCondition {
+    type EigVal;
+    type Cond;
+    /// Calculate eigenvalues and the reciprocal condition numbers of the eigenvalues and the eigenvectors
+    /// via the Schur decomposition (see [schur_condition](fn.schur_condition.html))
+    fn eig_condition(&self) -> Result<(Self::EigVal, Self::Cond, Self::Cond)>;
+}
+
+impl<A, S> EigCondition for ArrayBase<S, Ix2>
+where
+    A: Scalar + Lapack,
+    S: Data<Elem = A>,
+{
+    type EigVal = Array1<A::Complex>;
+    type Cond = Array1<A::Real>;
+
+    fn eig_condition(&self) -> Result<(Self::EigVal, Self::Cond, Self::Cond)> {
+        let (n, _) = self.square_layout()?.size();
+        let l = MatrixLayout::F((n, n));
+        let mut t: Array2<A> = clone_with_layout(l, self);
+        let (e, q) = unsafe { A::schur(l, t.as_allocated_mut()?)? };
+        let (s, sep) = unsafe { A::schur_condition(l, t.as_allocated_mut()?, &q)? };
+        Ok((ArrayBase::from_vec(e), ArrayBase::from_vec(s), ArrayBase::from_vec(sep)))
+    }
+}
+
+/// Generalized Schur decomposition `A = Q S Z^H`, `B = Q T Z^H` of a pencil `(A, B)`
+///
+/// The generalized eigenvalues are `λ_j = α_j / β_j` in the order of the diagonal (blocks) of `S` and `T`.
+/// For real pencils, `S` is quasi-upper triangular and a complex conjugate pair appears as a `2x2` block.
+#[derive(Debug, Clone)]
+pub struct GeneralizedSchur<A: Scalar> {
+    pub q: Array2<A>,
+    pub z: Array2<A>,
+    pub s: Array2<A>,
+    pub t: Array2<A>,
+    pub alpha: Array1<A::Complex>,
+    pub beta: Array1<A::Complex>,
+}
+
+impl<A> GeneralizedSchur<A>
+where
+    A: Scalar + Lapack,
+{
+    /// Reorder the decomposition so that the eigenvalues `(alpha[i], beta[i])` where `select[i]` is true
+    /// move to the leading blocks of `S` and `T`
+    ///
+    /// The number of the selected eigenvalues `m` is returned.
+    /// The leading `m` columns of `Q` and `Z` span the left and right deflating subspaces, respectively.
+    /// For real pencils, a complex conjugate pair is selected if either of them is selected.
+    pub fn reorder(&mut self, select: &[bool]) -> Result<usize> {
+        let n = self.alpha.len();
+        if select.len() != n {
+            return Err(ShapeError::from_kind(ErrorKind::IncompatibleShape).into());
+        }
+        let l = self.s.square_layout()?;
+        for x in &[&self.t, &self.q, &self.z] {
+            if x.square_layout()? != l {
+                return Err(ShapeError::from_kind(ErrorKind::IncompatibleLayout).into());
+            }
+        }
+        let (alpha, beta, m) = unsafe {
+            A::qz_reorder(
+                l,
+                select,
+                self.s.as_allocated_mut()?,
+                self.t.as_allocated_mut()?,
+                self.q.as_allocated_mut()?,
+                self.z.as_allocated_mut()?,
+            )?
+        };
+        self.alpha = ArrayBase::from_vec(alpha);
+        self.beta = ArrayBase::from_vec(beta);
+        Ok(m)
+    }
+}
+
+/// Generalized Schur (QZ) decomposition of a pencil `(A, B)` where `A` is `self` and `B` is the argument
+pub trait SchurGeneralized<Rhs> {
+    type Output;
+    fn qz(&self, b: &Rhs) -> Result<Self::Output>;
+}
+
+impl<A, Sa, Sb> SchurGeneralized<ArrayBase<Sb, Ix2>> for ArrayBase<Sa, Ix2>
+where
+    A: Scalar + Lapack,
+    Sa: Data<Elem = A>,
+    Sb: Data<Elem = A>,
+{
+    type Output = GeneralizedSchur<A>;
+
+    fn qz(&self, b: &ArrayBase<Sb, Ix2>) -> Result<Self::Output> {
+        let (n, _) = self.square_layout()?.size();
+        if b.dim() != self.dim() {
+            return Err(ShapeError::from_kind(ErrorKind::IncompatibleShape).into());
+        }
+        // All matrices are kept in Fortran layout for `reorder`
+        let l = MatrixLayout::F((n, n));
+        let mut s: Array2<A> = clone_with_layout(l, self);
+        let mut t: Array2<A> = clone_with_layout(l, b);
+        let (alpha, beta, q, z) = unsafe { A::qz(l, s.as_allocated_mut()?, t.as_allocated_mut()?)? };
+        Ok(GeneralizedSchur {
+            q: into_matrix(l, q)?,
+            z: into_matrix(l, z)?,
+            s,
+            t,
+            alpha: ArrayBase::from_vec(alpha),
+            beta: ArrayBase::from_vec(beta),
+        })
+    }
+}
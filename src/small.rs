@@ -0,0 +1,196 @@
+//! Closed-form routines for 2x2 and 3x3 matrices
+//!
+//! For such small matrices the LAPACK routines spend most of the time not in the arithmetic
+//! but in the FFI calls, the copies into the Fortran layout and the allocation of the work arrays.
+//! [SmallMatrix](trait.SmallMatrix.html) computes the determinant, the inverse, the solution of a linear system
+//! and the eigenvalues of a Hermitian matrix by the explicit formulas instead,
+//! e.g. for the rotations and the inertia tensors called in a tight loop:
+//!
+//! ```
+//! use ndarray::*;
+//! use ndarray_linalg::*;
+//!
+//! let a = arr2(&[[4.0, 1.0], [2.0, 3.0]]);
+//! assert_rclose!(a.det_small().unwrap(), 10.0, 1e-12);
+//! let b = arr1(&[1.0, 2.0]);
+//! let x = a.solve_small(&b).unwrap();
+//! assert_close_l2!(&a.dot(&x), &b, 1e-12);
+//!
+//! let s = arr2(&[[2.0, 1.0], [1.0, 2.0]]);
+//! assert_close_l2!(&s.eigvalsh_small(UPLO::Upper).unwrap(), &arr1(&[1.0, 3.0]), 1e-12);
+//! ```
+//!
+//! The inverse and the solution are computed through the adjugate matrix,
+//! which is as accurate as the LU decomposition only for well-conditioned matrices.
+//! `1x1` matrices are also accepted, and those of the other sizes are rejected by `IncompatibleShape`.
+
+use ndarray::*;
+use num_traits::{Float, One, Zero};
+use std::f64::consts::PI;
+
+use crate::error::*;
+use crate::layout::*;
+use crate::types::*;
+use crate::UPLO;
+
+/// Closed-form routines for matrices up to `3x3`
+pub trait SmallMatrix<A: Scalar> {
+    /// Determinant
+    fn det_small(&self) -> Result<A>;
+    /// Inverse matrix
+    ///
+    /// A singular matrix, i.e. of the zero determinant, is rejected by `LinalgError::Lapack`
+    /// with the return code `n`, where `n` is the size of the matrix.
+    fn inv_small(&self) -> Result<Array2<A>>;
+    /// Solve `A x = b`, where a singular `A` is rejected as [inv_small](#tymethod.inv_small)
+    fn solve_small<S: Data<Elem = A>>(&self, b: &ArrayBase<S, Ix1>) -> Result<Array1<A>>;
+    /// Eigenvalues in ascending order of a Hermitian (or real symmetric) matrix
+    ///
+    /// Only the triangular part specified by `uplo` is read, as [EigValsh](../eigh/trait.EigValsh.html).
+    fn eigvalsh_small(&self, uplo: UPLO) -> Result<Array1<A::Real>>;
+}
+
+/// Size of a square matrix up to `3x3`
+fn small_size<A, S>(a: &ArrayBase<S, Ix2>) -> Result<usize>
+where
+    S: Data<Elem = A>,
+{
+    a.ensure_square()?;
+    match a.rows() {
+        n @ 1..=3 => Ok(n),
+        _ => Err(ShapeError::from_kind(ErrorKind::IncompatibleShape).into()),
+    }
+}
+
+/// Adjugate matrix, i.e. the transpose of the cofactor matrix
+fn adjugate<A, S>(a: &ArrayBase<S, Ix2>, n: usize) -> Array2<A>
+where
+    A: Scalar,
+    S: Data<Elem = A>,
+{
+    let e = |i: usize, j: usize| a[(i, j)];
+    match n {
+        1 => arr2(&[[A::one()]]),
+        2 => arr2(&[[e(1, 1), -e(0, 1)], [-e(1, 0), e(0, 0)]]),
+        _ => arr2(&[
+            [
+                e(1, 1) * e(2, 2) - e(1, 2) * e(2, 1),
+                e(0, 2) * e(2, 1) - e(0, 1) * e(2, 2),
+                e(0, 1) * e(1, 2) - e(0, 2) * e(1, 1),
+            ],
+            [
+                e(1, 2) * e(2, 0) - e(1, 0) * e(2, 2),
+                e(0, 0) * e(2, 2) - e(0, 2) * e(2, 0),
+                e(0, 2) * e(1, 0) - e(0, 0) * e(1, 2),
+            ],
+            [
+                e(1, 0) * e(2, 1) - e(1, 1) * e(2, 0),
+                e(0, 1) * e(2, 0) - e(0, 0) * e(2, 1),
+                e(0, 0) * e(1, 1) - e(0, 1) * e(1, 0),
+            ],
+        ]),
+    }
+}
+
+/// Determinant by the expansion along the first row, which reuses the first column of the adjugate
+fn det_with_adjugate<A, S>(a: &ArrayBase<S, Ix2>, adj: &Array2<A>) -> A
+where
+    A: Scalar,
+    S: Data<Elem = A>,
+{
+    (0..adj.rows()).fold(A::zero(), |acc, k| acc + a[(0, k)] * adj[(k, 0)])
+}
+
+/// Adjugate and determinant, where a zero determinant is an error
+fn nonsingular_adjugate<A, S>(a: &ArrayBase<S, Ix2>) -> Result<(Array2<A>, A)>
+where
+    A: Scalar,
+    S: Data<Elem = A>,
+{
+    let n = small_size(a)?;
+    let adj = adjugate(a, n);
+    let det = det_with_adjugate(a, &adj);
+    if det.is_zero() {
+        return Err(LinalgError::Lapack { return_code: n as i32 });
+    }
+    Ok((adj, det))
+}
+
+fn sort_ascending<R: Float>(mut e: Vec<R>) -> Array1<R> {
+    e.sort_by(|x, y| x.partial_cmp(y).unwrap_or(::std::cmp::Ordering::Equal));
+    Array1::from_vec(e)
+}
+
+/// Eigenvalues of a Hermitian matrix by the trigonometric solution of the characteristic cubic for `3x3`
+fn eigvalsh<A, S>(a: &ArrayBase<S, Ix2>, n: usize, uplo: UPLO) -> Array1<A::Real>
+where
+    A: Scalar,
+    S: Data<Elem = A>,
+{
+    let d = |i: usize| a[(i, i)].re();
+    // element `(i, j)` for `i < j`
+    let off = |i: usize, j: usize| match uplo {
+        UPLO::Upper => a[(i, j)],
+        UPLO::Lower => a[(j, i)].conj(),
+    };
+    let two = A::real(2.0);
+    match n {
+        1 => arr1(&[d(0)]),
+        2 => {
+            let mean = (d(0) + d(1)) / two;
+            let r = Float::hypot((d(0) - d(1)) / two, off(0, 1).abs());
+            arr1(&[mean - r, mean + r])
+        }
+        _ => {
+            let (b01, b02, b12) = (off(0, 1), off(0, 2), off(1, 2));
+            let p1 = b01.square() + b02.square() + b12.square();
+            if p1.is_zero() {
+                return sort_ascending(vec![d(0), d(1), d(2)]);
+            }
+            let three = A::real(3.0);
+            let q = (d(0) + d(1) + d(2)) / three;
+            let (e0, e1, e2) = (d(0) - q, d(1) - q, d(2) - q);
+            let p = Float::sqrt((e0 * e0 + e1 * e1 + e2 * e2 + two * p1) / A::real(6.0));
+            // det(A - qI), which is real for a Hermitian matrix
+            let det = e0 * e1 * e2 + two * (b01 * b12 * b02.conj()).re()
+                - e0 * b12.square()
+                - e1 * b02.square()
+                - e2 * b01.square();
+            let r = Float::min(Float::max(det / (two * p * p * p), -A::Real::one()), A::Real::one());
+            let phi = Float::acos(r) / three;
+            let largest = q + two * p * Float::cos(phi);
+            let smallest = q + two * p * Float::cos(phi + A::real(2.0 * PI / 3.0));
+            let middle = three * q - largest - smallest;
+            arr1(&[smallest, middle, largest])
+        }
+    }
+}
+
+impl<A, S> SmallMatrix<A> for ArrayBase<S, Ix2>
+where
+    A: Scalar,
+    S: Data<Elem = A>,
+{
+    fn det_small(&self) -> Result<A> {
+        let n = small_size(self)?;
+        Ok(det_with_adjugate(self, &adjugate(self, n)))
+    }
+
+    fn inv_small(&self) -> Result<Array2<A>> {
+        let (adj, det) = nonsingular_adjugate(self)?;
+        Ok(adj.mapv(|x| x / det))
+    }
+
+    fn solve_small<Sb: Data<Elem = A>>(&self, b: &ArrayBase<Sb, Ix1>) -> Result<Array1<A>> {
+        let (adj, det) = nonsingular_adjugate(self)?;
+        if b.len() != adj.rows() {
+            return Err(ShapeError::from_kind(ErrorKind::IncompatibleShape).into());
+        }
+        Ok(adj.dot(b).mapv(|x| x / det))
+    }
+
+    fn eigvalsh_small(&self, uplo: UPLO) -> Result<Array1<A::Real>> {
+        let n = small_size(self)?;
+        Ok(eigvalsh(self, n, uplo))
+    }
+}
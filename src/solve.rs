@@ -45,13 +45,20 @@
 //!
 //! # }
 //! ```
+//!
+//! A stack of systems (`Ix3` array of the matrices whose leading axis is the batch axis)
+//! is solved by [SolveStack](trait.SolveStack.html), and inverted by [Inverse](trait.Inverse.html),
+//! in parallel if the `parallel` feature is enabled.
 
 use ndarray::*;
 use num_traits::{Float, Zero};
 
+use crate::batch::*;
 use crate::convert::*;
 use crate::error::*;
+use crate::lapack::Solve_;
 use crate::layout::*;
+use crate::operator::{AdjointOperator, LinearOperator};
 use crate::opnorm::OperationNorm;
 use crate::types::*;
 
@@ -74,7 +81,7 @@ pub use crate::lapack::{Pivot, Transpose};
 /// If you plan to solve many equations with the same `A` matrix but different
 /// `b` vectors, it's faster to factor the `A` matrix once using the
 /// `Factorize` trait, and then solve using the `LUFactorized` struct.
-pub trait Solve<A: Scalar> {
+pub trait Solve<A: LinalgScalar> {
     /// Solves a system of linear equations `A * x = b` where `A` is `self`, `b`
     /// is the argument, and `x` is the successful result.
     fn solve<S: Data<Elem = A>>(&self, b: &ArrayBase<S, Ix1>) -> Result<Array1<A>> {
@@ -147,7 +154,7 @@ pub struct LUFactorized<S: Data> {
 
 impl<A, S> Solve<A> for LUFactorized<S>
 where
-    A: Scalar + Lapack,
+    A: Solve_,
     S: Data<Elem = A>,
 {
     fn solve_inplace<'a, Sb>(&self, rhs: &'a mut ArrayBase<Sb, Ix1>) -> Result<&'a mut ArrayBase<Sb, Ix1>>
@@ -197,9 +204,44 @@ where
     }
 }
 
+/// The factorization acts as the inverse `A^{-1}`, e.g. as a preconditioner
+///
+/// Panics if `A` is singular.
+impl<A, S> LinearOperator for LUFactorized<S>
+where
+    A: Scalar + Solve_,
+    S: Data<Elem = A>,
+{
+    type Elem = A;
+
+    fn apply<Sb>(&self, a: &ArrayBase<Sb, Ix1>) -> Array1<A>
+    where
+        Sb: Data<Elem = A>,
+    {
+        self.solve(a).expect("LU factors must be non-singular")
+    }
+}
+
+impl<A, S> AdjointOperator for LUFactorized<S>
+where
+    A: Scalar + Solve_,
+    S: Data<Elem = A>,
+{
+    fn shape(&self) -> (usize, usize) {
+        self.a.dim()
+    }
+
+    fn apply_adjoint<Sb>(&self, a: &ArrayBase<Sb, Ix1>) -> Array1<A>
+    where
+        Sb: Data<Elem = A>,
+    {
+        self.solve_h(a).expect("LU factors must be non-singular")
+    }
+}
+
 impl<A, S> Solve<A> for ArrayBase<S, Ix2>
 where
-    A: Scalar + Lapack,
+    A: Solve_,
     S: Data<Elem = A>,
 {
     fn solve_inplace<'a, Sb>(&self, rhs: &'a mut ArrayBase<Sb, Ix1>) -> Result<&'a mut ArrayBase<Sb, Ix1>>
@@ -225,6 +267,21 @@ where
     }
 }
 
+/// Solve `A X = B` for each column of `B` using the LU factorization of `A`
+pub(crate) fn solve_columns<A, S, Sb>(f: &LUFactorized<S>, b: &ArrayBase<Sb, Ix2>) -> Result<Array2<A>>
+where
+    A: Scalar + Solve_,
+    S: Data<Elem = A>,
+    Sb: Data<Elem = A>,
+{
+    let mut x = Array2::zeros(b.dim().f());
+    for (mut x, b) in x.axis_iter_mut(Axis(1)).zip(b.axis_iter(Axis(1))) {
+        x.assign(&b);
+        f.solve_inplace(&mut x)?;
+    }
+    Ok(x)
+}
+
 /// An interface for computing LU factorizations of matrix refs.
 pub trait Factorize<S: Data> {
     /// Computes the LU factorization `A = P*L*U`, where `P` is a permutation
@@ -241,7 +298,7 @@ pub trait FactorizeInto<S: Data> {
 
 impl<A, S> FactorizeInto<S> for ArrayBase<S, Ix2>
 where
-    A: Scalar + Lapack,
+    A: Solve_,
     S: DataMut<Elem = A>,
 {
     fn factorize_into(mut self) -> Result<LUFactorized<S>> {
@@ -252,7 +309,7 @@ where
 
 impl<A, Si> Factorize<OwnedRepr<A>> for ArrayBase<Si, Ix2>
 where
-    A: Scalar + Lapack,
+    A: Solve_,
     Si: Data<Elem = A>,
 {
     fn factorize(&self) -> Result<LUFactorized<OwnedRepr<A>>> {
@@ -262,6 +319,25 @@ where
     }
 }
 
+/// An interface for solving a stack of systems of linear equations.
+pub trait SolveStack<A: LinalgScalar> {
+    /// Solves `A_i * x_i = b_i` for each matrix `A_i` in the stack `self` and the row `b_i` of `b`,
+    /// and returns `x_i` as the rows.
+    fn solve_stack<S: Data<Elem = A>>(&self, b: &ArrayBase<S, Ix2>) -> Result<Array2<A>>;
+}
+
+impl<A, S> SolveStack<A> for ArrayBase<S, Ix3>
+where
+    A: Solve_ + Send + Sync,
+    S: Data<Elem = A>,
+{
+    fn solve_stack<Sb: Data<Elem = A>>(&self, b: &ArrayBase<Sb, Ix2>) -> Result<Array2<A>> {
+        let (_, n, _) = self.dim();
+        let xs = map_stack_with(self, b, |a, b| a.solve(&b))?;
+        Ok(stack_vectors(n, &xs))
+    }
+}
+
 /// An interface for inverting matrix refs.
 pub trait Inverse {
     type Output;
@@ -278,7 +354,7 @@ pub trait InverseInto {
 
 impl<A, S> InverseInto for LUFactorized<S>
 where
-    A: Scalar + Lapack,
+    A: Solve_,
     S: DataMut<Elem = A>,
 {
     type Output = ArrayBase<S, Ix2>;
@@ -291,7 +367,7 @@ where
 
 impl<A, S> Inverse for LUFactorized<S>
 where
-    A: Scalar + Lapack,
+    A: Solve_,
     S: Data<Elem = A>,
 {
     type Output = Array2<A>;
@@ -307,7 +383,7 @@ where
 
 impl<A, S> InverseInto for ArrayBase<S, Ix2>
 where
-    A: Scalar + Lapack,
+    A: Solve_,
     S: DataMut<Elem = A>,
 {
     type Output = Self;
@@ -320,7 +396,7 @@ where
 
 impl<A, Si> Inverse for ArrayBase<Si, Ix2>
 where
-    A: Scalar + Lapack,
+    A: Solve_,
     Si: Data<Elem = A>,
 {
     type Output = Array2<A>;
@@ -331,6 +407,20 @@ where
     }
 }
 
+impl<A, S> Inverse for ArrayBase<S, Ix3>
+where
+    A: Solve_ + Send + Sync,
+    S: Data<Elem = A>,
+{
+    type Output = Array3<A>;
+
+    fn inv(&self) -> Result<Self::Output> {
+        let (_, n, _) = self.dim();
+        let res = map_stack(self, |a| a.inv())?;
+        Ok(stack_matrices((n, n), &res))
+    }
+}
+
 /// An interface for calculating determinants of matrix refs.
 pub trait Determinant<A: Scalar> {
     /// Computes the determinant of the matrix.
@@ -383,7 +473,7 @@ pub trait DeterminantInto<A: Scalar>: Sized {
 
 fn lu_sln_det<'a, A, P, U>(ipiv_iter: P, u_diag_iter: U) -> (A, A::Real)
 where
-    A: Scalar + Lapack,
+    A: Scalar + Solve_,
     P: Iterator<Item = i32>,
     U: Iterator<Item = &'a A>,
 {
@@ -407,7 +497,7 @@ where
 
 impl<A, S> Determinant<A> for LUFactorized<S>
 where
-    A: Scalar + Lapack,
+    A: Scalar + Solve_,
     S: Data<Elem = A>,
 {
     fn sln_det(&self) -> Result<(A, A::Real)> {
@@ -418,7 +508,7 @@ where
 
 impl<A, S> DeterminantInto<A> for LUFactorized<S>
 where
-    A: Scalar + Lapack,
+    A: Scalar + Solve_,
     S: Data<Elem = A>,
 {
     fn sln_det_into(self) -> Result<(A, A::Real)> {
@@ -429,7 +519,7 @@ where
 
 impl<A, S> Determinant<A> for ArrayBase<S, Ix2>
 where
-    A: Scalar + Lapack,
+    A: Scalar + Solve_,
     S: Data<Elem = A>,
 {
     fn sln_det(&self) -> Result<(A, A::Real)> {
@@ -447,7 +537,7 @@ where
 
 impl<A, S> DeterminantInto<A> for ArrayBase<S, Ix2>
 where
-    A: Scalar + Lapack,
+    A: Scalar + Solve_,
     S: DataMut<Elem = A>,
 {
     fn sln_det_into(self) -> Result<(A, A::Real)> {
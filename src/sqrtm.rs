@@ -0,0 +1,98 @@
+//! Principal square root of general square matrices
+//!
+//! The square root is computed by the Schur method: `A = Q T Q^H` is reduced to the complex Schur form,
+//! and the square root `R` of the upper triangular `T` is computed column by column from `R^2 = T`.
+//! Then `sqrt(A) = Q R Q^H`.
+//!
+//! The principal square root, whose eigenvalues lie in the open right half-plane, exists and is unique
+//! if `A` has no eigenvalues on the closed negative real axis.
+//! If `A` has negative real eigenvalues, the square root is still computed using the principal branch
+//! `sqrt(λ) = i sqrt(|λ|)`, but it is not a primary function of `A` if they are repeated.
+//! A repeated zero eigenvalue in a nontrivial Jordan block, e.g. of `[[0, 1], [0, 0]]`, has no square root,
+//! which is reported by `LinalgError::Lapack { return_code: k }`, where `k` is the column of `T`
+//! in which the recurrence divides by `r_ii + r_kk = 0`.
+//! For Hermitian positive (semi-)definite matrices, use
+//! [HermitianSqrt](../eigh/trait.HermitianSqrt.html) which keeps the result Hermitian.
+//!
+//! - Å. Björck and S. Hammarling, A Schur method for the square root of a matrix,
+//!   Linear Algebra Appl. 52/53 (1983)
+//!
+//! # Example
+//!
+//! ```
+//! #[macro_use]
+//! extern crate ndarray;
+//! extern crate ndarray_linalg;
+//!
+//! use ndarray::prelude::*;
+//! use ndarray_linalg::*;
+//! # fn main() {
+//!
+//! let a: Array2<f64> = array![[4., 1.], [0., 9.]];
+//! // Real input gives complex output, whose imaginary part vanishes here
+//! let r = a.sqrtm().unwrap().mapv(|x| x.re);
+//! assert!(r.all_close(&array![[2., 0.2], [0., 3.]], 1e-12));
+//! # }
+//! ```
+
+use ndarray::*;
+
+use crate::error::*;
+use crate::generate::conjugate;
+use crate::layout::*;
+use crate::schur::*;
+use crate::types::*;
+
+/// Principal square root of a general square matrix
+pub trait Sqrtm {
+    type Output;
+    /// Compute the principal square root `R` such that `R^2 = A` by the Schur method
+    ///
+    /// The result is complex since the square root of a real matrix is not real in general.
+    fn sqrtm(&self) -> Result<Self::Output>;
+}
+
+impl<A, S> Sqrtm for ArrayBase<S, Ix2>
+where
+    A: Scalar + Lapack,
+    A::Complex: Lapack,
+    S: Data<Elem = A>,
+{
+    type Output = Array2<A::Complex>;
+
+    fn sqrtm(&self) -> Result<Self::Output> {
+        self.square_layout()?;
+        let a: Array2<A::Complex> = self.mapv(|x| x.as_c());
+        let (q, t) = a.schur()?;
+        let r = sqrt_triangular(&t)?;
+        let qh: Array2<A::Complex> = conjugate(&q);
+        Ok(q.dot(&r).dot(&qh))
+    }
+}
+
+/// Square root of an upper triangular matrix, which is also upper triangular
+///
+/// `r_ij` is undetermined if `r_ii + r_jj = 0`, which happens only if `t_ii = t_jj = 0`,
+/// and it is taken to be zero if `t_ij - s` also vanishes, e.g. for a zero matrix.
+fn sqrt_triangular<A: Scalar>(t: &Array2<A>) -> Result<Array2<A>> {
+    let n = t.rows();
+    let mut r = Array2::zeros((n, n));
+    for j in 0..n {
+        r[(j, j)] = t[(j, j)].sqrt();
+        for i in (0..j).rev() {
+            let s = (i + 1..j).fold(A::zero(), |acc, k| acc + r[(i, k)] * r[(k, j)]);
+            let d = r[(i, i)] + r[(j, j)];
+            let x = t[(i, j)] - s;
+            r[(i, j)] = if !d.is_zero() {
+                x / d
+            } else if x.is_zero() {
+                A::zero()
+            } else {
+                return Err(LinalgError::Lapack {
+                    return_code: j as i32 + 1,
+                });
+            };
+        }
+    }
+    Ok(r)
+}
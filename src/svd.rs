@@ -1,9 +1,13 @@
 //! Singular-value decomposition (SVD)
 //!
 //! [Wikipedia article on SVD](https://en.wikipedia.org/wiki/Singular_value_decomposition)
+//!
+//! The traits are also implemented for stacks of matrices (`Ix3` arrays whose leading axis is the batch axis).
+//! The matrices are decomposed in parallel if the `parallel` feature is enabled.
 
 use ndarray::*;
 
+use super::batch::*;
 use super::convert::*;
 use super::error::*;
 use super::layout::*;
@@ -85,3 +89,43 @@ where
         Ok((u, s, vt))
     }
 }
+
+impl<A, S> SVD for ArrayBase<S, Ix3>
+where
+    A: Scalar + Lapack + Send + Sync,
+    A::Real: Send + Sync,
+    S: Data<Elem = A>,
+{
+    type U = Array3<A>;
+    type VT = Array3<A>;
+    type Sigma = Array2<A::Real>;
+
+    fn svd(&self, calc_u: bool, calc_vt: bool) -> Result<(Option<Self::U>, Self::Sigma, Option<Self::VT>)> {
+        let (_, m, n) = self.dim();
+        let res = map_stack(self, |a| a.svd(calc_u, calc_vt))?;
+        let (mut us, mut ss, mut vts) = (Vec::new(), Vec::new(), Vec::new());
+        for (u, s, vt) in res {
+            us.extend(u);
+            ss.push(s);
+            vts.extend(vt);
+        }
+        let u = if calc_u { Some(stack_matrices((m, m), &us)) } else { None };
+        let vt = if calc_vt { Some(stack_matrices((n, n), &vts)) } else { None };
+        Ok((u, stack_vectors(::std::cmp::min(m, n), &ss), vt))
+    }
+}
+
+impl<A, S> SVDInto for ArrayBase<S, Ix3>
+where
+    A: Scalar + Lapack + Send + Sync,
+    A::Real: Send + Sync,
+    S: Data<Elem = A>,
+{
+    type U = Array3<A>;
+    type VT = Array3<A>;
+    type Sigma = Array2<A::Real>;
+
+    fn svd_into(self, calc_u: bool, calc_vt: bool) -> Result<(Option<Self::U>, Self::Sigma, Option<Self::VT>)> {
+        self.svd(calc_u, calc_vt)
+    }
+}
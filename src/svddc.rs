@@ -1,7 +1,10 @@
 //! Singular-value decomposition (SVD) by divide-and-conquer (?gesdd)
+//!
+//! The traits are also implemented for stacks of matrices (`Ix3` arrays whose leading axis is the batch axis).
 
 use ndarray::*;
 
+use super::batch::*;
 use super::convert::*;
 use super::error::*;
 use super::layout::*;
@@ -48,7 +51,7 @@ pub trait SVDDCInplace {
 impl<A, S> SVDDC for ArrayBase<S, Ix2>
 where
     A: Scalar + Lapack,
-    S: DataMut<Elem = A>,
+    S: Data<Elem = A>,
 {
     type U = Array2<A>;
     type VT = Array2<A>;
@@ -108,3 +111,53 @@ where
         Ok((u, s, vt))
     }
 }
+
+impl<A, S> SVDDC for ArrayBase<S, Ix3>
+where
+    A: Scalar + Lapack + Send + Sync,
+    A::Real: Send + Sync,
+    S: Data<Elem = A>,
+{
+    type U = Array3<A>;
+    type VT = Array3<A>;
+    type Sigma = Array2<A::Real>;
+
+    fn svddc(&self, uvt_flag: UVTFlag) -> Result<(Option<Self::U>, Self::Sigma, Option<Self::VT>)> {
+        let (_, m, n) = self.dim();
+        let k = m.min(n);
+        let res = map_stack(self, |a| a.svddc(uvt_flag))?;
+        let (mut us, mut ss, mut vts) = (Vec::new(), Vec::new(), Vec::new());
+        for (u, s, vt) in res {
+            us.extend(u);
+            ss.push(s);
+            vts.extend(vt);
+        }
+        let (u, vt) = match uvt_flag {
+            UVTFlag::Full => (
+                Some(stack_matrices((m, m), &us)),
+                Some(stack_matrices((n, n), &vts)),
+            ),
+            UVTFlag::Some => (
+                Some(stack_matrices((m, k), &us)),
+                Some(stack_matrices((k, n), &vts)),
+            ),
+            UVTFlag::None => (None, None),
+        };
+        Ok((u, stack_vectors(k, &ss), vt))
+    }
+}
+
+impl<A, S> SVDDCInto for ArrayBase<S, Ix3>
+where
+    A: Scalar + Lapack + Send + Sync,
+    A::Real: Send + Sync,
+    S: Data<Elem = A>,
+{
+    type U = Array3<A>;
+    type VT = Array3<A>;
+    type Sigma = Array2<A::Real>;
+
+    fn svddc_into(self, uvt_flag: UVTFlag) -> Result<(Option<Self::U>, Self::Sigma, Option<Self::VT>)> {
+        self.svddc(uvt_flag)
+    }
+}
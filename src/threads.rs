@@ -0,0 +1,77 @@
+//! Number of threads of the BLAS/LAPACK backend
+//!
+//! OpenBLAS and Intel MKL parallelize a single routine, e.g. a large matrix product or `*getrf`,
+//! over their own thread pools.
+//! When the routines are called from many Rayon tasks at once,
+//! e.g. by the [batched decompositions](../batch/index.html), the threads of both compete for the cores.
+//! The usual remedy is to run the backend single-threaded in the parallel part of the application:
+//!
+//! ```
+//! use ndarray_linalg::*;
+//!
+//! let saved = get_num_threads();
+//! set_num_threads(1);
+//! // ... call the decompositions from the Rayon tasks ...
+//! set_num_threads(saved);
+//! ```
+//!
+//! The backend is selected by the features:
+//!
+//! - `openblas` calls `openblas_set_num_threads` and `openblas_get_num_threads`
+//! - `intel-mkl` calls `MKL_Set_Num_Threads` and `MKL_Get_Max_Threads`
+//! - otherwise, e.g. for `netlib`, the backend is single-threaded,
+//!   so that `set_num_threads` does nothing and `get_num_threads` returns `1`
+
+#[cfg(any(feature = "openblas", feature = "intel-mkl"))]
+use std::os::raw::c_int;
+
+#[cfg(feature = "openblas")]
+extern "C" {
+    fn openblas_set_num_threads(n: c_int);
+    fn openblas_get_num_threads() -> c_int;
+}
+
+#[cfg(all(feature = "intel-mkl", not(feature = "openblas")))]
+extern "C" {
+    fn MKL_Set_Num_Threads(n: c_int);
+    fn MKL_Get_Max_Threads() -> c_int;
+}
+
+#[cfg(any(feature = "openblas", feature = "intel-mkl"))]
+fn to_c_int(n: usize) -> c_int {
+    if n > c_int::max_value() as usize {
+        c_int::max_value()
+    } else {
+        n as c_int
+    }
+}
+
+/// Set the number of threads used by the backend in each routine
+///
+/// The default is given by the backend, usually the number of the cores
+/// unless it is limited by the environment variables, e.g. `OPENBLAS_NUM_THREADS` or `MKL_NUM_THREADS`.
+/// The setting is global to the process, and `0` is ignored.
+pub fn set_num_threads(n: usize) {
+    if n == 0 {
+        return;
+    }
+    #[cfg(feature = "openblas")]
+    unsafe {
+        openblas_set_num_threads(to_c_int(n))
+    }
+    #[cfg(all(feature = "intel-mkl", not(feature = "openblas")))]
+    unsafe {
+        MKL_Set_Num_Threads(to_c_int(n))
+    }
+}
+
+/// Number of threads the backend uses in each routine
+pub fn get_num_threads() -> usize {
+    #[cfg(feature = "openblas")]
+    let n = unsafe { openblas_get_num_threads() };
+    #[cfg(all(feature = "intel-mkl", not(feature = "openblas")))]
+    let n = unsafe { MKL_Get_Max_Threads() };
+    #[cfg(not(any(feature = "openblas", feature = "intel-mkl")))]
+    let n = 1;
+    n.max(1) as usize
+}
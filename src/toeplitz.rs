@@ -0,0 +1,277 @@
+//! Toeplitz matrices
+//!
+//! [Toeplitz](struct.Toeplitz.html) stores an `n x n` matrix whose elements are constant along each diagonal,
+//! `T[(i, j)] = t_{i - j}`, by its first column and first row.
+//! Linear systems are solved in `O(n^2)` time and `O(n)` memory by the Levinson recursion,
+//! which requires all leading principal submatrices to be non-singular (e.g. positive definite matrices).
+//! The recursion is not stable for indefinite matrices in general; densify the matrix and use
+//! [Solve](../solve/trait.Solve.html) for them.
+//!
+//! [levinson_durbin](fn.levinson_durbin.html) solves the Yule-Walker equations for the coefficients
+//! of an autoregressive model from the autocorrelation sequence.
+//!
+//! - G. H. Golub and C. F. Van Loan, Matrix Computations, 4th ed., Section 4.7
+//!
+//! # Example
+//!
+//! ```
+//! #[macro_use]
+//! extern crate ndarray;
+//! extern crate ndarray_linalg;
+//!
+//! use ndarray::prelude::*;
+//! use ndarray_linalg::*;
+//! # fn main() {
+//!
+//! let t = Toeplitz::new(array![4., 1., 0.5], array![4., 2., 1.]).unwrap();
+//! let x = array![1., 2., 3.];
+//! let b = t.dot(&x);
+//! assert!(t.solve(&b).unwrap().all_close(&x, 1e-12));
+//! assert!(t.to_dense().dot(&x).all_close(&b, 1e-12));
+//! # }
+//! ```
+
+use ndarray::*;
+use num_traits::Zero;
+
+use crate::error::*;
+use crate::operator::{AdjointOperator, LinearOperator};
+use crate::solve::*;
+use crate::types::*;
+
+/// Toeplitz matrix given by its first column and first row
+#[derive(Debug, Clone)]
+pub struct Toeplitz<A> {
+    /// First column `t_0, t_1, ..., t_{n-1}`
+    c: Array1<A>,
+    /// First row `t_0, t_{-1}, ..., t_{-(n-1)}`, whose first element is ignored
+    r: Array1<A>,
+}
+
+impl<A: Scalar> Toeplitz<A> {
+    /// Create from the first column and the first row, where the diagonal is taken from the column
+    pub fn new(c: Array1<A>, r: Array1<A>) -> Result<Self> {
+        if c.len() != r.len() {
+            return Err(ShapeError::from_kind(ErrorKind::IncompatibleShape).into());
+        }
+        Ok(Toeplitz { c, r })
+    }
+
+    /// Hermitian (or real symmetric) Toeplitz matrix given by its first column
+    pub fn hermitian(c: Array1<A>) -> Self {
+        let r = c.mapv(|x| x.conj());
+        Toeplitz { c, r }
+    }
+
+    /// Convert to a dense matrix
+    pub fn to_dense(&self) -> Array2<A> {
+        Array2::from_shape_fn((self.size(), self.size()), |(i, j)| self.get(i, j))
+    }
+
+    /// `T[(i, j)]`
+    pub fn get(&self, i: usize, j: usize) -> A {
+        if i >= j {
+            self.c[i - j]
+        } else {
+            self.r[j - i]
+        }
+    }
+
+    /// Size `n` of the matrix
+    pub fn size(&self) -> usize {
+        self.c.len()
+    }
+
+    /// First column
+    pub fn column(&self) -> ArrayView1<A> {
+        self.c.view()
+    }
+
+    /// First row, whose first element is replaced by the diagonal of the first column
+    pub fn row(&self) -> Array1<A> {
+        let mut r = self.r.clone();
+        if !r.is_empty() {
+            r[0] = self.c[0];
+        }
+        r
+    }
+
+    /// Transposed matrix
+    pub fn t(&self) -> Self {
+        Toeplitz {
+            c: self.row(),
+            r: self.c.clone(),
+        }
+    }
+
+    /// Matrix-vector product `T x` in `O(n^2)` time
+    pub fn dot<S>(&self, x: &ArrayBase<S, Ix1>) -> Array1<A>
+    where
+        S: Data<Elem = A>,
+    {
+        let n = self.size();
+        assert_eq!(x.len(), n);
+        Array1::from_shape_fn(n, |i| (0..n).fold(A::zero(), |acc, j| acc + self.get(i, j) * x[j]))
+    }
+
+    /// Solve `T x = b` by the Levinson recursion
+    ///
+    /// Fails with `LinalgError::Lapack { return_code: k }` if the leading `k x k` submatrix is singular,
+    /// as the LU factorization reports a zero pivot.
+    fn levinson(&self, b: &[A]) -> Result<Array1<A>> {
+        let n = self.size();
+        if n == 0 {
+            return Ok(Array1::zeros(0));
+        }
+        let (c, r) = (&self.c, &self.r);
+        if c[0].is_zero() {
+            return Err(LinalgError::Lapack { return_code: 1 });
+        }
+        // Forward and backward vectors satisfying `T_m f = e_0` and `T_m b = e_{m-1}`
+        let mut f = vec![A::one() / c[0]];
+        let mut bw = vec![A::one() / c[0]];
+        let mut x = vec![b[0] / c[0]];
+        for m in 1..n {
+            let ef = (0..m).fold(A::zero(), |acc, i| acc + c[m - i] * f[i]);
+            let eb = (0..m).fold(A::zero(), |acc, i| acc + r[i + 1] * bw[i]);
+            let denom = A::one() - ef * eb;
+            if denom.is_zero() {
+                return Err(LinalgError::Lapack {
+                    return_code: m as i32 + 1,
+                });
+            }
+            let mut f_new = Vec::with_capacity(m + 1);
+            let mut b_new = Vec::with_capacity(m + 1);
+            for i in 0..=m {
+                let fi = if i < m { f[i] } else { A::zero() };
+                let bi = if i > 0 { bw[i - 1] } else { A::zero() };
+                f_new.push((fi - ef * bi) / denom);
+                b_new.push((bi - eb * fi) / denom);
+            }
+            let ex = (0..m).fold(A::zero(), |acc, i| acc + c[m - i] * x[i]);
+            let d = b[m] - ex;
+            x.push(A::zero());
+            for i in 0..=m {
+                x[i] = x[i] + d * b_new[i];
+            }
+            f = f_new;
+            bw = b_new;
+        }
+        Ok(Array1::from_vec(x))
+    }
+
+    fn conj(&self) -> Self {
+        Toeplitz {
+            c: self.c.mapv(|x| x.conj()),
+            r: self.r.mapv(|x| x.conj()),
+        }
+    }
+
+    fn solve_with<'a, S>(&self, rhs: &'a mut ArrayBase<S, Ix1>) -> Result<&'a mut ArrayBase<S, Ix1>>
+    where
+        S: DataMut<Elem = A>,
+    {
+        if rhs.len() != self.size() {
+            return Err(ShapeError::from_kind(ErrorKind::IncompatibleShape).into());
+        }
+        let x = self.levinson(&rhs.to_vec())?;
+        rhs.assign(&x);
+        Ok(rhs)
+    }
+}
+
+impl<A: Scalar> Solve<A> for Toeplitz<A> {
+    fn solve_inplace<'a, S>(&self, rhs: &'a mut ArrayBase<S, Ix1>) -> Result<&'a mut ArrayBase<S, Ix1>>
+    where
+        S: DataMut<Elem = A>,
+    {
+        self.solve_with(rhs)
+    }
+    fn solve_t_inplace<'a, S>(&self, rhs: &'a mut ArrayBase<S, Ix1>) -> Result<&'a mut ArrayBase<S, Ix1>>
+    where
+        S: DataMut<Elem = A>,
+    {
+        self.t().solve_with(rhs)
+    }
+    fn solve_h_inplace<'a, S>(&self, rhs: &'a mut ArrayBase<S, Ix1>) -> Result<&'a mut ArrayBase<S, Ix1>>
+    where
+        S: DataMut<Elem = A>,
+    {
+        self.t().conj().solve_with(rhs)
+    }
+}
+
+impl<A: Scalar> LinearOperator for Toeplitz<A> {
+    type Elem = A;
+
+    fn apply<S>(&self, a: &ArrayBase<S, Ix1>) -> Array1<S::Elem>
+    where
+        S: Data<Elem = A>,
+    {
+        self.dot(a)
+    }
+}
+
+impl<A: Scalar> AdjointOperator for Toeplitz<A> {
+    fn shape(&self) -> (usize, usize) {
+        (self.size(), self.size())
+    }
+
+    fn apply_adjoint<S>(&self, a: &ArrayBase<S, Ix1>) -> Array1<A>
+    where
+        S: Data<Elem = A>,
+    {
+        self.t().conj().dot(a)
+    }
+}
+
+/// Autoregressive model computed by [levinson_durbin](fn.levinson_durbin.html)
+#[derive(Debug, Clone)]
+pub struct LevinsonDurbinResult<A: Scalar> {
+    /// Coefficients `a_1, ..., a_p` of the model `x_t = a_1 x_{t-1} + ... + a_p x_{t-p} + e_t`
+    pub coefficients: Array1<A>,
+    /// Reflection (partial autocorrelation) coefficients `κ_1, ..., κ_p`
+    pub reflection: Array1<A>,
+    /// Variance of the prediction error `e_t`
+    pub error: A::Real,
+}
+
+/// Solve the Yule-Walker equations for an autoregressive model of order `p` by the Levinson-Durbin recursion
+///
+/// `r` is the autocorrelation sequence `r_0, ..., r_p` with `r_k = E[x_t conj(x_{t-k})]`.
+/// The equations `sum_j r_{i-j} a_j = r_i` for `i = 1, ..., p`, where `r_{-k} = conj(r_k)`,
+/// are solved in `O(p^2)` time.
+/// Fails with `LinalgError::Lapack { return_code: k }` if the Toeplitz matrix of `r_0, ..., r_{k-1}`
+/// is not positive definite, i.e. the prediction error vanishes or becomes negative.
+pub fn levinson_durbin<A, S>(r: &ArrayBase<S, Ix1>) -> Result<LevinsonDurbinResult<A>>
+where
+    A: Scalar,
+    S: Data<Elem = A>,
+{
+    if r.is_empty() {
+        return Err(ShapeError::from_kind(ErrorKind::IncompatibleShape).into());
+    }
+    let p = r.len() - 1;
+    let mut error = r[0].re();
+    let mut a: Vec<A> = Vec::with_capacity(p);
+    let mut reflection = Vec::with_capacity(p);
+    for k in 1..=p {
+        if error <= A::Real::zero() {
+            return Err(LinalgError::Lapack { return_code: k as i32 });
+        }
+        let acc = (1..k).fold(r[k], |acc, j| acc - a[j - 1] * r[k - j]);
+        let kappa = acc.div_real(error);
+        let prev = a.clone();
+        for j in 0..k - 1 {
+            a[j] = prev[j] - kappa * prev[k - 2 - j].conj();
+        }
+        a.push(kappa);
+        reflection.push(kappa);
+        error = error * (A::Real::one() - kappa.square());
+    }
+    Ok(LevinsonDurbinResult {
+        coefficients: Array1::from_vec(a),
+        reflection: Array1::from_vec(reflection),
+        error,
+    })
+}
@@ -7,6 +7,7 @@ use super::convert::*;
 use super::error::*;
 use super::lapack::*;
 use super::layout::*;
+use super::opnorm::*;
 use super::types::*;
 
 pub use super::lapack::Diag;
@@ -157,3 +158,53 @@ where
         self
     }
 }
+
+/// Triangular part of a matrix regarded as a triangular matrix without copying
+///
+/// The other triangular part, and also the diagonal for `Diag::Unit`, is not referenced.
+/// The operator norms are computed by `*lantr` except for the 2-norm,
+/// which needs the singular values of the dense copy.
+#[derive(Debug, Clone)]
+pub struct TriangularView<'a, A> {
+    a: ArrayView2<'a, A>,
+    uplo: UPLO,
+    diag: Diag,
+}
+
+impl<'a, A: Scalar> TriangularView<'a, A> {
+    /// View the upper or lower triangular part of `a`, whose diagonal is regarded as ones for `Diag::Unit`
+    pub fn new(a: ArrayView2<'a, A>, uplo: UPLO, diag: Diag) -> Self {
+        TriangularView { a, uplo, diag }
+    }
+
+    pub fn uplo(&self) -> UPLO {
+        self.uplo
+    }
+
+    pub fn diag(&self) -> Diag {
+        self.diag
+    }
+
+    /// Copy into a dense matrix with the explicit zeros (and ones on the diagonal for `Diag::Unit`)
+    pub fn to_dense(&self) -> Array2<A> {
+        let mut a = self.a.to_owned().into_triangular(self.uplo);
+        if let Diag::Unit = self.diag {
+            a.diag_mut().fill(A::one());
+        }
+        a
+    }
+}
+
+impl<'a, A: Scalar + Lapack> OperationNorm for TriangularView<'a, A> {
+    type Output = A::Real;
+
+    fn opnorm(&self, t: NormType) -> Result<Self::Output> {
+        let l = self.a.layout()?;
+        let a = self.a.as_allocated()?;
+        Ok(unsafe { A::opnorm_triangular(t, self.uplo, self.diag, l, a) })
+    }
+
+    fn opnorm_two(&self) -> Result<Self::Output> {
+        self.to_dense().opnorm_two()
+    }
+}
@@ -0,0 +1,391 @@
+//! Tridiagonal matrices
+//!
+//! [Tridiagonal](struct.Tridiagonal.html) holds the sub-diagonal, the diagonal, and the super-diagonal of a matrix
+//! as three vectors. Linear systems are solved in `O(n)` time by the LU factorization with partial pivoting
+//! (`*gttrf`/`*gttrs`), which is a stable variant of the Thomas algorithm.
+//! The factorization can be kept to solve many systems with the same matrix, e.g. in implicit time stepping.
+//!
+//! [HermitianTridiagonal](struct.HermitianTridiagonal.html) stores a Hermitian (or real symmetric) matrix
+//! by its real diagonal and sub-diagonal. If it is positive definite, as for the 1-D Poisson problem and
+//! the cubic spline interpolation, the `L D L^H` factorization (`*pttrf`/`*pttrs`) needs no pivoting
+//! and halves the work.
+//!
+//! # Example
+//!
+//! ```
+//! #[macro_use]
+//! extern crate ndarray;
+//! extern crate ndarray_linalg;
+//!
+//! use ndarray::prelude::*;
+//! use ndarray_linalg::*;
+//! # fn main() {
+//!
+//! // 1-D Laplacian with the Dirichlet boundary condition
+//! let n = 5;
+//! let off = Array1::from_elem(n - 1, -1.0);
+//! let a = Tridiagonal::new(off.clone(), Array1::from_elem(n, 2.0), off).unwrap();
+//! let f = a.factorize().unwrap();
+//! let x = f.solve(&Array1::ones(n)).unwrap();
+//! assert!(a.dot(&x).all_close(&Array1::ones(n), 1e-12));
+//! # }
+//! ```
+
+use ndarray::*;
+
+use crate::cholesky::SolveC;
+use crate::error::*;
+use crate::operator::{AdjointOperator, LinearOperator};
+use crate::solve::*;
+use crate::types::*;
+
+/// Tridiagonal matrix
+#[derive(Debug, Clone)]
+pub struct Tridiagonal<A> {
+    /// Sub-diagonal of length `n - 1`
+    pub dl: Array1<A>,
+    /// Diagonal of length `n`
+    pub d: Array1<A>,
+    /// Super-diagonal of length `n - 1`
+    pub du: Array1<A>,
+}
+
+impl<A: Scalar> Tridiagonal<A> {
+    /// Create from the sub-diagonal, the diagonal, and the super-diagonal
+    pub fn new(dl: Array1<A>, d: Array1<A>, du: Array1<A>) -> Result<Self> {
+        let t = Tridiagonal { dl, d, du };
+        t.check_shape()?;
+        Ok(t)
+    }
+
+    /// Copy the tridiagonal part of a dense square matrix
+    pub fn from_dense<S>(a: &ArrayBase<S, Ix2>) -> Result<Self>
+    where
+        S: Data<Elem = A>,
+    {
+        let (rows, cols) = a.dim();
+        if rows != cols {
+            return Err(LinalgError::NotSquare {
+                rows: rows as i32,
+                cols: cols as i32,
+            });
+        }
+        let n = rows;
+        Ok(Tridiagonal {
+            dl: (1..n).map(|i| a[(i, i - 1)]).collect(),
+            d: a.diag().to_owned(),
+            du: (1..n).map(|i| a[(i - 1, i)]).collect(),
+        })
+    }
+
+    /// Convert to a dense matrix
+    pub fn to_dense(&self) -> Array2<A> {
+        let n = self.d.len();
+        let mut a = Array2::zeros((n, n));
+        for i in 0..n {
+            a[(i, i)] = self.d[i];
+            if i > 0 {
+                a[(i, i - 1)] = self.dl[i - 1];
+                a[(i - 1, i)] = self.du[i - 1];
+            }
+        }
+        a
+    }
+
+    /// Size `n` of the matrix
+    pub fn size(&self) -> usize {
+        self.d.len()
+    }
+
+    /// Matrix-vector product `A x`
+    pub fn dot<S>(&self, x: &ArrayBase<S, Ix1>) -> Array1<A>
+    where
+        S: Data<Elem = A>,
+    {
+        let n = self.d.len();
+        assert_eq!(x.len(), n);
+        Array1::from_shape_fn(n, |i| {
+            let mut y = self.d[i] * x[i];
+            if i > 0 {
+                y = y + self.dl[i - 1] * x[i - 1];
+            }
+            if i + 1 < n {
+                y = y + self.du[i] * x[i + 1];
+            }
+            y
+        })
+    }
+
+    fn check_shape(&self) -> Result<()> {
+        let m = if self.d.is_empty() { 0 } else { self.d.len() - 1 };
+        if self.dl.len() != m || self.du.len() != m {
+            return Err(ShapeError::from_kind(ErrorKind::IncompatibleShape).into());
+        }
+        Ok(())
+    }
+}
+
+impl<A: Scalar + Lapack> Tridiagonal<A> {
+    /// Compute the LU factorization `A = P L U` with partial pivoting
+    pub fn factorize(&self) -> Result<TridiagonalLUFactorized<A>> {
+        self.clone().factorize_into()
+    }
+
+    /// Compute the LU factorization `A = P L U` with partial pivoting, consuming the matrix
+    pub fn factorize_into(mut self) -> Result<TridiagonalLUFactorized<A>> {
+        self.check_shape()?;
+        let (du2, ipiv) = unsafe {
+            A::lu_tridiagonal(
+                self.dl.as_slice_mut().unwrap(),
+                self.d.as_slice_mut().unwrap(),
+                self.du.as_slice_mut().unwrap(),
+            )?
+        };
+        Ok(TridiagonalLUFactorized { lu: self, du2, ipiv })
+    }
+}
+
+/// LU factorization of a tridiagonal matrix
+///
+/// `U` has two super-diagonals due to the pivoting.
+#[derive(Debug, Clone)]
+pub struct TridiagonalLUFactorized<A> {
+    lu: Tridiagonal<A>,
+    du2: Vec<A>,
+    ipiv: Pivot,
+}
+
+impl<A: Scalar + Lapack> TridiagonalLUFactorized<A> {
+    fn solve_with<'a, S>(&self, t: Transpose, rhs: &'a mut ArrayBase<S, Ix1>) -> Result<&'a mut ArrayBase<S, Ix1>>
+    where
+        S: DataMut<Elem = A>,
+    {
+        if rhs.len() != self.lu.d.len() {
+            return Err(ShapeError::from_kind(ErrorKind::IncompatibleShape).into());
+        }
+        unsafe {
+            A::solve_tridiagonal(
+                t,
+                self.lu.dl.as_slice().unwrap(),
+                self.lu.d.as_slice().unwrap(),
+                self.lu.du.as_slice().unwrap(),
+                &self.du2,
+                &self.ipiv,
+                rhs.as_slice_mut().unwrap(),
+            )?
+        };
+        Ok(rhs)
+    }
+}
+
+impl<A: Scalar + Lapack> Solve<A> for TridiagonalLUFactorized<A> {
+    fn solve_inplace<'a, S>(&self, rhs: &'a mut ArrayBase<S, Ix1>) -> Result<&'a mut ArrayBase<S, Ix1>>
+    where
+        S: DataMut<Elem = A>,
+    {
+        self.solve_with(Transpose::No, rhs)
+    }
+    fn solve_t_inplace<'a, S>(&self, rhs: &'a mut ArrayBase<S, Ix1>) -> Result<&'a mut ArrayBase<S, Ix1>>
+    where
+        S: DataMut<Elem = A>,
+    {
+        self.solve_with(Transpose::Transpose, rhs)
+    }
+    fn solve_h_inplace<'a, S>(&self, rhs: &'a mut ArrayBase<S, Ix1>) -> Result<&'a mut ArrayBase<S, Ix1>>
+    where
+        S: DataMut<Elem = A>,
+    {
+        self.solve_with(Transpose::Hermite, rhs)
+    }
+}
+
+impl<A: Scalar + Lapack> Solve<A> for Tridiagonal<A> {
+    fn solve_inplace<'a, S>(&self, rhs: &'a mut ArrayBase<S, Ix1>) -> Result<&'a mut ArrayBase<S, Ix1>>
+    where
+        S: DataMut<Elem = A>,
+    {
+        self.factorize()?.solve_inplace(rhs)
+    }
+    fn solve_t_inplace<'a, S>(&self, rhs: &'a mut ArrayBase<S, Ix1>) -> Result<&'a mut ArrayBase<S, Ix1>>
+    where
+        S: DataMut<Elem = A>,
+    {
+        self.factorize()?.solve_t_inplace(rhs)
+    }
+    fn solve_h_inplace<'a, S>(&self, rhs: &'a mut ArrayBase<S, Ix1>) -> Result<&'a mut ArrayBase<S, Ix1>>
+    where
+        S: DataMut<Elem = A>,
+    {
+        self.factorize()?.solve_h_inplace(rhs)
+    }
+}
+
+impl<A: Scalar> LinearOperator for Tridiagonal<A> {
+    type Elem = A;
+
+    fn apply<S>(&self, a: &ArrayBase<S, Ix1>) -> Array1<S::Elem>
+    where
+        S: Data<Elem = A>,
+    {
+        self.dot(a)
+    }
+}
+
+impl<A: Scalar> AdjointOperator for Tridiagonal<A> {
+    fn shape(&self) -> (usize, usize) {
+        (self.d.len(), self.d.len())
+    }
+
+    fn apply_adjoint<S>(&self, a: &ArrayBase<S, Ix1>) -> Array1<A>
+    where
+        S: Data<Elem = A>,
+    {
+        // `A^H` is tridiagonal with the conjugated sub- and super-diagonals exchanged
+        let ah = Tridiagonal {
+            dl: self.du.mapv(|x| x.conj()),
+            d: self.d.mapv(|x| x.conj()),
+            du: self.dl.mapv(|x| x.conj()),
+        };
+        ah.dot(a)
+    }
+}
+
+/// Hermitian (or real symmetric) tridiagonal matrix
+#[derive(Debug, Clone)]
+pub struct HermitianTridiagonal<A: Scalar> {
+    /// Real diagonal of length `n`
+    pub d: Array1<A::Real>,
+    /// Sub-diagonal of length `n - 1`, whose conjugate is the super-diagonal
+    pub e: Array1<A>,
+}
+
+impl<A: Scalar> HermitianTridiagonal<A> {
+    /// Create from the diagonal and the sub-diagonal
+    pub fn new(d: Array1<A::Real>, e: Array1<A>) -> Result<Self> {
+        let t = HermitianTridiagonal { d, e };
+        t.check_shape()?;
+        Ok(t)
+    }
+
+    /// Convert to a dense matrix
+    pub fn to_dense(&self) -> Array2<A> {
+        let n = self.d.len();
+        let mut a = Array2::zeros((n, n));
+        for i in 0..n {
+            a[(i, i)] = A::from_real(self.d[i]);
+            if i > 0 {
+                a[(i, i - 1)] = self.e[i - 1];
+                a[(i - 1, i)] = self.e[i - 1].conj();
+            }
+        }
+        a
+    }
+
+    /// Size `n` of the matrix
+    pub fn size(&self) -> usize {
+        self.d.len()
+    }
+
+    /// Matrix-vector product `A x`
+    pub fn dot<S>(&self, x: &ArrayBase<S, Ix1>) -> Array1<A>
+    where
+        S: Data<Elem = A>,
+    {
+        let n = self.d.len();
+        assert_eq!(x.len(), n);
+        Array1::from_shape_fn(n, |i| {
+            let mut y = x[i].mul_real(self.d[i]);
+            if i > 0 {
+                y = y + self.e[i - 1] * x[i - 1];
+            }
+            if i + 1 < n {
+                y = y + self.e[i].conj() * x[i + 1];
+            }
+            y
+        })
+    }
+
+    fn check_shape(&self) -> Result<()> {
+        let m = if self.d.is_empty() { 0 } else { self.d.len() - 1 };
+        if self.e.len() != m {
+            return Err(ShapeError::from_kind(ErrorKind::IncompatibleShape).into());
+        }
+        Ok(())
+    }
+}
+
+impl<A: Scalar + Lapack> HermitianTridiagonal<A> {
+    /// Compute the factorization `A = L D L^H` of a positive definite matrix,
+    /// where `L` is unit lower bidiagonal and `D` is diagonal
+    ///
+    /// This fails if the matrix is not positive definite.
+    pub fn factorizec(&self) -> Result<HermitianTridiagonalFactorized<A>> {
+        self.clone().factorizec_into()
+    }
+
+    /// Compute the factorization `A = L D L^H` of a positive definite matrix, consuming the matrix
+    pub fn factorizec_into(mut self) -> Result<HermitianTridiagonalFactorized<A>> {
+        self.check_shape()?;
+        unsafe { A::ldl_tridiagonal(self.d.as_slice_mut().unwrap(), self.e.as_slice_mut().unwrap())? };
+        Ok(HermitianTridiagonalFactorized { ldl: self })
+    }
+}
+
+/// `L D L^H` factorization of a Hermitian positive definite tridiagonal matrix
+#[derive(Debug, Clone)]
+pub struct HermitianTridiagonalFactorized<A: Scalar> {
+    /// `D` in the diagonal and the sub-diagonal of `L` in the sub-diagonal
+    ldl: HermitianTridiagonal<A>,
+}
+
+impl<A: Scalar + Lapack> SolveC<A> for HermitianTridiagonalFactorized<A> {
+    fn solvec_inplace<'a, S>(&self, b: &'a mut ArrayBase<S, Ix1>) -> Result<&'a mut ArrayBase<S, Ix1>>
+    where
+        S: DataMut<Elem = A>,
+    {
+        if b.len() != self.ldl.d.len() {
+            return Err(ShapeError::from_kind(ErrorKind::IncompatibleShape).into());
+        }
+        unsafe {
+            A::solve_ldl_tridiagonal(
+                self.ldl.d.as_slice().unwrap(),
+                self.ldl.e.as_slice().unwrap(),
+                b.as_slice_mut().unwrap(),
+            )?
+        };
+        Ok(b)
+    }
+}
+
+impl<A: Scalar + Lapack> SolveC<A> for HermitianTridiagonal<A> {
+    fn solvec_inplace<'a, S>(&self, b: &'a mut ArrayBase<S, Ix1>) -> Result<&'a mut ArrayBase<S, Ix1>>
+    where
+        S: DataMut<Elem = A>,
+    {
+        self.factorizec()?.solvec_inplace(b)
+    }
+}
+
+impl<A: Scalar> LinearOperator for HermitianTridiagonal<A> {
+    type Elem = A;
+
+    fn apply<S>(&self, a: &ArrayBase<S, Ix1>) -> Array1<S::Elem>
+    where
+        S: Data<Elem = A>,
+    {
+        self.dot(a)
+    }
+}
+
+impl<A: Scalar> AdjointOperator for HermitianTridiagonal<A> {
+    fn shape(&self) -> (usize, usize) {
+        (self.d.len(), self.d.len())
+    }
+
+    fn apply_adjoint<S>(&self, a: &ArrayBase<S, Ix1>) -> Array1<A>
+    where
+        S: Data<Elem = A>,
+    {
+        self.dot(a)
+    }
+}
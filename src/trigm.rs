@@ -0,0 +1,110 @@
+//! Matrix sine and cosine
+//!
+//! [Trigm](trait.Trigm.html) computes `cos(A)` and `sin(A)` of a general square matrix by the double angle method:
+//! `A` is scaled as `X = A / 2^s` so that its 1-norm is at most one,
+//! the Taylor series of `cos(X)` and `sin(X)` are summed until they converge,
+//! and `cos(2X) = 2 cos(X)^2 - I` and `sin(2X) = 2 sin(X) cos(X)` are applied `s` times.
+//! Unlike `exp(iA)`, this keeps real matrices real.
+//!
+//! They appear in the propagator of second order systems `x'' = -K x`, for which
+//! `x(t) = cos(t √K) x(0) + (√K)^{-1} sin(t √K) x'(0)`.
+//!
+//! - N. J. Higham, Functions of Matrices: Theory and Computation, SIAM (2008), Chapter 12
+//!
+//! # Example
+//!
+//! ```
+//! #[macro_use]
+//! extern crate ndarray;
+//! extern crate ndarray_linalg;
+//!
+//! use ndarray::prelude::*;
+//! use ndarray_linalg::*;
+//! # fn main() {
+//!
+//! let a: Array2<f64> = array![[0., 1.], [0., 0.]];
+//! let (c, s) = a.sincosm().unwrap();
+//! // A^2 = 0, so that cos(A) = I and sin(A) = A
+//! assert!(c.all_close(&Array2::eye(2), 1e-15));
+//! assert!(s.all_close(&a, 1e-15));
+//! # }
+//! ```
+
+use ndarray::*;
+use num_traits::{Float, ToPrimitive};
+
+use crate::error::*;
+use crate::layout::*;
+use crate::norm::Norm;
+use crate::opnorm::*;
+use crate::types::*;
+
+/// Maximal number of the terms of the Taylor series
+const MAX_TAYLOR_TERMS: usize = 30;
+
+/// Matrix cosine and sine
+pub trait Trigm {
+    type Output;
+    /// Compute `cos(A)`
+    fn cosm(&self) -> Result<Self::Output>;
+    /// Compute `sin(A)`
+    fn sinm(&self) -> Result<Self::Output>;
+    /// Compute `(cos(A), sin(A))` at the cost of about one of them
+    fn sincosm(&self) -> Result<(Self::Output, Self::Output)>;
+}
+
+impl<A, S> Trigm for ArrayBase<S, Ix2>
+where
+    A: Scalar + Lapack,
+    S: Data<Elem = A>,
+{
+    type Output = Array2<A>;
+
+    fn cosm(&self) -> Result<Array2<A>> {
+        Ok(self.sincosm()?.0)
+    }
+
+    fn sinm(&self) -> Result<Array2<A>> {
+        Ok(self.sincosm()?.1)
+    }
+
+    fn sincosm(&self) -> Result<(Array2<A>, Array2<A>)> {
+        let (n, _) = self.square_layout()?.size();
+        let norm = self.opnorm_one()?;
+        let s = if norm > A::Real::one() {
+            norm.log2().ceil().to_i32().unwrap()
+        } else {
+            0
+        };
+        let factor = A::real(2.0).powi(s);
+        let x = self.mapv(|x| x.div_real(factor));
+        let (mut c, mut sn) = sincos_taylor(&x, n as usize);
+        for _ in 0..s {
+            sn = sn.dot(&c).mapv(|x| x.mul_real(A::real(2.0)));
+            c = c.dot(&c).mapv(|x| x.mul_real(A::real(2.0)));
+            for d in c.diag_mut() {
+                *d = *d - A::one();
+            }
+        }
+        Ok((c, sn))
+    }
+}
+
+/// Taylor series of `(cos(X), sin(X))` for `|X|_1 <= 1`
+fn sincos_taylor<A: Scalar>(x: &Array2<A>, n: usize) -> (Array2<A>, Array2<A>) {
+    let x2 = x.dot(x);
+    // `p` holds `(-1)^k X^{2k} / (2k)!`
+    let mut p: Array2<A> = Array2::eye(n);
+    let mut c = p.clone();
+    let mut s = p.clone();
+    for k in 1..MAX_TAYLOR_TERMS {
+        let d = A::real((2 * k - 1) * (2 * k));
+        p = p.dot(&x2).mapv(|x| x.div_real(-d));
+        c.scaled_add(A::one(), &p);
+        s.scaled_add(A::from_real(A::real(2 * k + 1).recip()), &p);
+        if p.norm_l2() <= A::Real::epsilon() * c.norm_l2() {
+            break;
+        }
+    }
+    (c, x.dot(&s))
+}
@@ -0,0 +1,262 @@
+//! Diagonal-plus-low-rank matrices and the Woodbury identity
+//!
+//! [DiagPlusLowRank](struct.DiagPlusLowRank.html) represents `A = D + U V^H` by the diagonal `D`
+//! and the `n x k` matrices `U` and `V` without forming the dense `n x n` matrix.
+//! Linear systems are solved by the Woodbury identity
+//!
+//! ```text
+//! A^{-1} = D^{-1} - D^{-1} U K^{-1} V^H D^{-1},  K = I + V^H D^{-1} U
+//! ```
+//!
+//! where only the small `k x k` capacitance matrix `K` is LU-factorized, i.e. in `O(n k^2 + k^3)` time,
+//! and `det(A) = det(K) det(D)` by the matrix determinant lemma.
+//! The identity may lose accuracy if `K` is ill-conditioned even when `A` is not.
+//!
+//! # Example
+//!
+//! ```
+//! #[macro_use]
+//! extern crate ndarray;
+//! extern crate ndarray_linalg;
+//!
+//! use ndarray::prelude::*;
+//! use ndarray_linalg::*;
+//! # fn main() {
+//!
+//! let d = array![1., 2., 3., 4.];
+//! let u = array![[1.], [0.], [1.], [0.]];
+//! let a = DiagPlusLowRank::new(d, u.clone(), u).unwrap();
+//! let x = array![1., 2., 3., 4.];
+//! let b = a.dot(&x);
+//! assert!(b.all_close(&array![5., 4., 13., 16.], 1e-12));
+//! assert!(a.solve(&b).unwrap().all_close(&x, 1e-12));
+//! # }
+//! ```
+
+use ndarray::*;
+use num_traits::{Float, Zero};
+
+use crate::error::*;
+use crate::generate::conjugate;
+use crate::operator::{AdjointOperator, LinearOperator};
+use crate::solve::*;
+use crate::types::*;
+
+/// Matrix `A = D + U V^H` of a diagonal matrix and a low-rank correction
+#[derive(Debug, Clone)]
+pub struct DiagPlusLowRank<A> {
+    d: Array1<A>,
+    u: Array2<A>,
+    v: Array2<A>,
+}
+
+impl<A: Scalar> DiagPlusLowRank<A> {
+    /// Create from the diagonal `d` of length `n` and the `n x k` matrices `u` and `v`
+    pub fn new(d: Array1<A>, u: Array2<A>, v: Array2<A>) -> Result<Self> {
+        if u.rows() != d.len() || u.dim() != v.dim() {
+            return Err(ShapeError::from_kind(ErrorKind::IncompatibleShape).into());
+        }
+        Ok(DiagPlusLowRank { d, u, v })
+    }
+
+    /// Size `n` of the matrix
+    pub fn size(&self) -> usize {
+        self.d.len()
+    }
+
+    /// Number `k` of the columns of `U` and `V`
+    pub fn rank(&self) -> usize {
+        self.u.cols()
+    }
+
+    /// Diagonal `D`
+    pub fn diag(&self) -> ArrayView1<A> {
+        self.d.view()
+    }
+
+    /// Convert to a dense matrix
+    pub fn to_dense(&self) -> Array2<A> {
+        let vh: Array2<A> = conjugate(&self.v);
+        let mut a = self.u.dot(&vh);
+        for (a, &d) in a.diag_mut().iter_mut().zip(self.d.iter()) {
+            *a = *a + d;
+        }
+        a
+    }
+
+    /// Matrix-vector product `A x` in `O(n k)` time
+    pub fn dot<S>(&self, x: &ArrayBase<S, Ix1>) -> Array1<A>
+    where
+        S: Data<Elem = A>,
+    {
+        assert_eq!(x.len(), self.size());
+        let w = Array1::from_shape_fn(self.rank(), |j| {
+            self.v
+                .column(j)
+                .iter()
+                .zip(x.iter())
+                .fold(A::zero(), |acc, (v, &x)| acc + v.conj() * x)
+        });
+        &self.d * x + self.u.dot(&w)
+    }
+}
+
+impl<A: Scalar + Lapack> DiagPlusLowRank<A> {
+    /// Factorize the capacitance matrix `K = I + V^H D^{-1} U`
+    ///
+    /// Fails with `LinalgError::Lapack { return_code: i }` if the `i`-th diagonal element is zero.
+    pub fn factorize(&self) -> Result<DiagPlusLowRankFactorized<A>> {
+        if let Some(i) = self.d.iter().position(|d| d.is_zero()) {
+            return Err(LinalgError::Lapack {
+                return_code: i as i32 + 1,
+            });
+        }
+        let dinv_u = Array2::from_shape_fn(self.u.dim(), |(i, j)| self.u[(i, j)] / self.d[i]);
+        let vh: Array2<A> = conjugate(&self.v);
+        let mut k = vh.dot(&dinv_u);
+        for x in k.diag_mut() {
+            *x = *x + A::one();
+        }
+        Ok(DiagPlusLowRankFactorized {
+            a: self.clone(),
+            dinv_u,
+            vh,
+            k: k.factorize_into()?,
+        })
+    }
+}
+
+/// Woodbury factorization of a diagonal-plus-low-rank matrix
+pub struct DiagPlusLowRankFactorized<A> {
+    a: DiagPlusLowRank<A>,
+    /// `D^{-1} U`
+    dinv_u: Array2<A>,
+    /// `V^H`
+    vh: Array2<A>,
+    /// LU factorization of the capacitance matrix `K`
+    k: LUFactorized<OwnedRepr<A>>,
+}
+
+impl<A: Scalar + Lapack> DiagPlusLowRankFactorized<A> {
+    fn check_shape<S: Data>(&self, rhs: &ArrayBase<S, Ix1>) -> Result<()> {
+        if rhs.len() != self.a.size() {
+            return Err(ShapeError::from_kind(ErrorKind::IncompatibleShape).into());
+        }
+        Ok(())
+    }
+}
+
+impl<A: Scalar + Lapack> Solve<A> for DiagPlusLowRankFactorized<A> {
+    fn solve_inplace<'a, S>(&self, rhs: &'a mut ArrayBase<S, Ix1>) -> Result<&'a mut ArrayBase<S, Ix1>>
+    where
+        S: DataMut<Elem = A>,
+    {
+        self.check_shape(rhs)?;
+        let y = &*rhs / &self.a.d;
+        let z = self.k.solve(&self.vh.dot(&y))?;
+        rhs.assign(&(y - self.dinv_u.dot(&z)));
+        Ok(rhs)
+    }
+    fn solve_t_inplace<'a, S>(&self, rhs: &'a mut ArrayBase<S, Ix1>) -> Result<&'a mut ArrayBase<S, Ix1>>
+    where
+        S: DataMut<Elem = A>,
+    {
+        // `A^T = D + conj(V) U^T` with the capacitance matrix `K^T`
+        self.check_shape(rhs)?;
+        let y = &*rhs / &self.a.d;
+        let z = self.k.solve_t(&self.a.u.t().dot(&y))?;
+        rhs.assign(&(&y - &(self.vh.t().dot(&z) / &self.a.d)));
+        Ok(rhs)
+    }
+    fn solve_h_inplace<'a, S>(&self, rhs: &'a mut ArrayBase<S, Ix1>) -> Result<&'a mut ArrayBase<S, Ix1>>
+    where
+        S: DataMut<Elem = A>,
+    {
+        // `A^H = D^H + V U^H` with the capacitance matrix `K^H`
+        self.check_shape(rhs)?;
+        let dh = self.a.d.mapv(|d| d.conj());
+        let y = &*rhs / &dh;
+        let uh: Array2<A> = conjugate(&self.a.u);
+        let z = self.k.solve_h(&uh.dot(&y))?;
+        rhs.assign(&(&y - &(self.a.v.dot(&z) / &dh)));
+        Ok(rhs)
+    }
+}
+
+impl<A: Scalar + Lapack> Solve<A> for DiagPlusLowRank<A> {
+    fn solve_inplace<'a, S>(&self, rhs: &'a mut ArrayBase<S, Ix1>) -> Result<&'a mut ArrayBase<S, Ix1>>
+    where
+        S: DataMut<Elem = A>,
+    {
+        self.factorize()?.solve_inplace(rhs)
+    }
+    fn solve_t_inplace<'a, S>(&self, rhs: &'a mut ArrayBase<S, Ix1>) -> Result<&'a mut ArrayBase<S, Ix1>>
+    where
+        S: DataMut<Elem = A>,
+    {
+        self.factorize()?.solve_t_inplace(rhs)
+    }
+    fn solve_h_inplace<'a, S>(&self, rhs: &'a mut ArrayBase<S, Ix1>) -> Result<&'a mut ArrayBase<S, Ix1>>
+    where
+        S: DataMut<Elem = A>,
+    {
+        self.factorize()?.solve_h_inplace(rhs)
+    }
+}
+
+impl<A: Scalar + Lapack> Determinant<A> for DiagPlusLowRankFactorized<A> {
+    fn sln_det(&self) -> Result<(A, A::Real)> {
+        let (mut sign, mut ln_det) = self.k.sln_det()?;
+        for &d in self.a.d.iter() {
+            let abs = d.abs();
+            sign = sign * d.div_real(abs);
+            ln_det = ln_det + abs.ln();
+        }
+        Ok((sign, ln_det))
+    }
+}
+
+impl<A: Scalar + Lapack> Determinant<A> for DiagPlusLowRank<A> {
+    fn sln_det(&self) -> Result<(A, A::Real)> {
+        match self.factorize() {
+            Ok(f) => f.sln_det(),
+            // A zero in the diagonal does not make `A` singular, e.g. `[[0, 1], [1, 0]]`,
+            // and the dense determinant also handles the singular capacitance matrix
+            Err(LinalgError::Lapack { .. }) => self.to_dense().sln_det(),
+            Err(err) => Err(err),
+        }
+    }
+}
+
+impl<A: Scalar> LinearOperator for DiagPlusLowRank<A> {
+    type Elem = A;
+
+    fn apply<S>(&self, a: &ArrayBase<S, Ix1>) -> Array1<S::Elem>
+    where
+        S: Data<Elem = A>,
+    {
+        self.dot(a)
+    }
+}
+
+impl<A: Scalar> AdjointOperator for DiagPlusLowRank<A> {
+    fn shape(&self) -> (usize, usize) {
+        (self.size(), self.size())
+    }
+
+    fn apply_adjoint<S>(&self, a: &ArrayBase<S, Ix1>) -> Array1<A>
+    where
+        S: Data<Elem = A>,
+    {
+        // `A^H x = D^H x + V (U^H x)`
+        assert_eq!(a.len(), self.size());
+        let w = Array1::from_shape_fn(self.rank(), |j| {
+            self.u
+                .column(j)
+                .iter()
+                .zip(a.iter())
+                .fold(A::zero(), |acc, (u, &x)| acc + u.conj() * x)
+        });
+        self.d.mapv(|d| d.conj()) * a + self.v.dot(&w)
+    }
+}
@@ -0,0 +1,266 @@
+//! Reusable workspaces of LAPACK routines
+//!
+//! The LAPACK drivers of [Eig](../eig/index.html), [SVD](../svd/index.html) and [QR](../qr/index.html)
+//! need the work arrays whose optimal sizes are queried by `lwork = -1`,
+//! and the ordinary traits query and allocate them (and copy the input matrix) in every call.
+//! A workspace does it once for the matrices of a fixed shape,
+//! and the `*_with_workspace` methods reuse it, e.g. for the small matrices decomposed in a tight loop:
+//!
+//! ```
+//! use ndarray::*;
+//! use ndarray_linalg::*;
+//!
+//! let mut ws = SVDWorkspace::new((3, 3), true, true).unwrap();
+//! for _ in 0..10 {
+//!     let a: Array2<f64> = random((3, 3));
+//!     let (u, s, vt) = a.svd_with_workspace(&mut ws).unwrap();
+//!     let sigma = Array2::from_diag(&s);
+//!     assert_close_l2!(&u.unwrap().dot(&sigma).dot(&vt.unwrap()), &a, 1e-7);
+//! }
+//! ```
+//!
+//! The returned arrays and the small buffers of the drivers, e.g. for the eigenvalues,
+//! are still allocated in each call.
+//! A matrix of a shape different from that of the workspace is rejected by `IncompatibleShape`.
+
+use ndarray::*;
+use num_traits::Zero;
+use std::cmp::min;
+
+use crate::convert::*;
+use crate::eig::Balance;
+use crate::error::*;
+use crate::layout::*;
+use crate::qr::{take_slice, take_slice_upper};
+use crate::types::*;
+
+pub use crate::lapack::WorkSize;
+
+fn check_shape(expected: (usize, usize), actual: (usize, usize)) -> Result<()> {
+    if expected == actual {
+        Ok(())
+    } else {
+        Err(ShapeError::from_kind(ErrorKind::IncompatibleShape).into())
+    }
+}
+
+/// Workspace of [EigWithWorkspace](trait.EigWithWorkspace.html) for `n x n` matrices
+pub struct EigWorkspace<A: Scalar> {
+    balance: Balance,
+    calc_vl: bool,
+    calc_vr: bool,
+    a: Array2<A>,
+    work: Vec<A>,
+    rwork: Vec<A::Real>,
+}
+
+impl<A: Scalar + Lapack> EigWorkspace<A> {
+    /// Workspace computing the left eigenvectors (if `calc_vl`) and the right eigenvectors (if `calc_vr`)
+    /// with the default balancing
+    pub fn new(n: usize, calc_vl: bool, calc_vr: bool) -> Result<Self> {
+        Self::with_balance(n, Balance::default(), calc_vl, calc_vr)
+    }
+
+    /// Workspace with a specified balancing as [EigWith](../eig/trait.EigWith.html)
+    pub fn with_balance(n: usize, balance: Balance, calc_vl: bool, calc_vr: bool) -> Result<Self> {
+        let a = Array2::zeros((n, n).f());
+        let size = unsafe { A::eig_work_size(a.square_layout()?, balance, calc_vl, calc_vr)? };
+        Ok(EigWorkspace {
+            balance,
+            calc_vl,
+            calc_vr,
+            a,
+            work: vec![A::zero(); size.work],
+            rwork: vec![A::Real::zero(); size.rwork],
+        })
+    }
+
+    /// Sizes of the allocated work arrays
+    pub fn work_size(&self) -> WorkSize {
+        WorkSize {
+            work: self.work.len(),
+            rwork: self.rwork.len(),
+        }
+    }
+}
+
+/// Workspace of [SVDWithWorkspace](trait.SVDWithWorkspace.html) for `m x n` matrices
+pub struct SVDWorkspace<A: Scalar> {
+    calc_u: bool,
+    calc_vt: bool,
+    a: Array2<A>,
+    work: Vec<A>,
+    rwork: Vec<A::Real>,
+}
+
+impl<A: Scalar + Lapack> SVDWorkspace<A> {
+    /// Workspace computing `U` (if `calc_u`) and `V^T` (if `calc_vt`)
+    pub fn new(shape: (usize, usize), calc_u: bool, calc_vt: bool) -> Result<Self> {
+        let a = Array2::zeros(shape.f());
+        let size = unsafe { A::svd_work_size(a.layout()?, calc_u, calc_vt)? };
+        Ok(SVDWorkspace {
+            calc_u,
+            calc_vt,
+            a,
+            work: vec![A::zero(); size.work],
+            rwork: vec![A::Real::zero(); size.rwork],
+        })
+    }
+
+    /// Sizes of the allocated work arrays
+    pub fn work_size(&self) -> WorkSize {
+        WorkSize {
+            work: self.work.len(),
+            rwork: self.rwork.len(),
+        }
+    }
+}
+
+/// Workspace of [QRWithWorkspace](trait.QRWithWorkspace.html) for `m x n` matrices
+pub struct QRWorkspace<A: Scalar> {
+    a: Array2<A>,
+    work: Vec<A>,
+}
+
+impl<A: Scalar + Lapack> QRWorkspace<A> {
+    /// Workspace of `*geqrf` and `*orgqr` (`*ungqr`), which share the work array
+    pub fn new(shape: (usize, usize)) -> Result<Self> {
+        let a = Array2::zeros(shape.f());
+        let size = unsafe { A::qr_work_size(a.layout()?)? };
+        Ok(QRWorkspace {
+            a,
+            work: vec![A::zero(); size.work],
+        })
+    }
+
+    /// Sizes of the allocated work arrays
+    pub fn work_size(&self) -> WorkSize {
+        WorkSize {
+            work: self.work.len(),
+            rwork: 0,
+        }
+    }
+}
+
+/// Eigenvalue decomposition of general matrix reference using a workspace
+pub trait EigWithWorkspace<A: Scalar> {
+    type EigVal;
+    type EigVec;
+    /// Same as [EigFull::eig_full](../eig/trait.EigFull.html#tymethod.eig_full)
+    /// with the options of the workspace
+    fn eig_with_workspace(
+        &self,
+        ws: &mut EigWorkspace<A>,
+    ) -> Result<(Self::EigVal, Option<Self::EigVec>, Option<Self::EigVec>)>;
+}
+
+/// Singular-value decomposition of matrix reference using a workspace
+pub trait SVDWithWorkspace<A: Scalar> {
+    type U;
+    type VT;
+    type Sigma;
+    /// Same as [SVD::svd](../svd/trait.SVD.html#tymethod.svd) with the options of the workspace
+    fn svd_with_workspace(&self, ws: &mut SVDWorkspace<A>) -> Result<(Option<Self::U>, Self::Sigma, Option<Self::VT>)>;
+}
+
+/// QR decomposition of matrix reference using a workspace
+pub trait QRWithWorkspace<A: Scalar> {
+    type Q;
+    type R;
+    /// Same as [QR::qr](../qr/trait.QR.html#tymethod.qr)
+    fn qr_with_workspace(&self, ws: &mut QRWorkspace<A>) -> Result<(Self::Q, Self::R)>;
+}
+
+impl<A, S> EigWithWorkspace<A> for ArrayBase<S, Ix2>
+where
+    A: Scalar + Lapack,
+    S: Data<Elem = A>,
+{
+    type EigVal = Array1<A::Complex>;
+    type EigVec = Array2<A::Complex>;
+
+    fn eig_with_workspace(
+        &self,
+        ws: &mut EigWorkspace<A>,
+    ) -> Result<(Self::EigVal, Option<Self::EigVec>, Option<Self::EigVec>)> {
+        check_shape(ws.a.dim(), self.dim())?;
+        ws.a.assign(self);
+        let l = ws.a.square_layout()?;
+        let out = unsafe {
+            A::eig_with_work(
+                l,
+                ws.balance,
+                ws.calc_vl,
+                ws.calc_vr,
+                ws.a.as_allocated_mut()?,
+                &mut ws.work,
+                &mut ws.rwork,
+            )?
+        };
+        let vl = match out.vl {
+            Some(vl) => Some(into_matrix(l, vl)?),
+            None => None,
+        };
+        let vr = match out.vr {
+            Some(vr) => Some(into_matrix(l, vr)?),
+            None => None,
+        };
+        Ok((ArrayBase::from_vec(out.eigs), vl, vr))
+    }
+}
+
+impl<A, S> SVDWithWorkspace<A> for ArrayBase<S, Ix2>
+where
+    A: Scalar + Lapack,
+    S: Data<Elem = A>,
+{
+    type U = Array2<A>;
+    type VT = Array2<A>;
+    type Sigma = Array1<A::Real>;
+
+    fn svd_with_workspace(&self, ws: &mut SVDWorkspace<A>) -> Result<(Option<Self::U>, Self::Sigma, Option<Self::VT>)> {
+        check_shape(ws.a.dim(), self.dim())?;
+        ws.a.assign(self);
+        let l = ws.a.layout()?;
+        let svd_res = unsafe {
+            A::svd_with_work(
+                l,
+                ws.calc_u,
+                ws.calc_vt,
+                ws.a.as_allocated_mut()?,
+                &mut ws.work,
+                &mut ws.rwork,
+            )?
+        };
+        let (n, m) = l.size();
+        let u = match svd_res.u {
+            Some(u) => Some(into_matrix(l.resized(n, n), u)?),
+            None => None,
+        };
+        let vt = match svd_res.vt {
+            Some(vt) => Some(into_matrix(l.resized(m, m), vt)?),
+            None => None,
+        };
+        Ok((u, ArrayBase::from_vec(svd_res.s), vt))
+    }
+}
+
+impl<A, S> QRWithWorkspace<A> for ArrayBase<S, Ix2>
+where
+    A: Scalar + Lapack,
+    S: Data<Elem = A>,
+{
+    type Q = Array2<A>;
+    type R = Array2<A>;
+
+    fn qr_with_workspace(&self, ws: &mut QRWorkspace<A>) -> Result<(Self::Q, Self::R)> {
+        check_shape(ws.a.dim(), self.dim())?;
+        ws.a.assign(self);
+        let (n, m) = self.dim();
+        let k = min(n, m);
+        let l = ws.a.layout()?;
+        let r = unsafe { A::qr_with_work(l, ws.a.as_allocated_mut()?, &mut ws.work)? };
+        let r: Array2<_> = into_matrix(l, r)?;
+        Ok((take_slice(&ws.a, n, k), take_slice_upper(&r, k, m)))
+    }
+}
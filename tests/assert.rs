@@ -1,6 +1,37 @@
-use ndarray_linalg::assert_rclose;
+use ndarray::*;
+use ndarray_linalg::{assert_aclose_max, assert_rclose, assert_rclose_l2};
 
 #[test]
 fn assert() {
     assert_rclose!(1.0, 1.0, 1e-7);
 }
+
+#[test]
+fn assert_rclose_l2() {
+    let a = arr2(&[[1.0, 2.0], [3.0, 4.0]]);
+    let b = arr2(&[[1.0, 2.0], [3.0, 4.0 + 1e-9]]);
+    assert_rclose_l2!(&b, &a, 1e-7);
+}
+
+#[test]
+#[should_panic]
+fn assert_rclose_l2_fail() {
+    let a = arr2(&[[1.0, 2.0], [3.0, 4.0]]);
+    let b = arr2(&[[1.0, 2.5], [3.0, 4.0]]);
+    assert_rclose_l2!(&b, &a, 1e-7);
+}
+
+#[test]
+fn assert_aclose_max() {
+    let a = arr1(&[1.0, 2.0, 3.0]);
+    let b = arr1(&[1.0, 2.0 - 1e-9, 3.0]);
+    assert_aclose_max!(&b, &a, 1e-7);
+}
+
+#[test]
+#[should_panic]
+fn assert_aclose_max_nan() {
+    let a = arr1(&[1.0, 2.0, 3.0]);
+    let b = arr1(&[::std::f64::NAN, 2.0, 3.0]);
+    assert_aclose_max!(&b, &a, 1e-7);
+}
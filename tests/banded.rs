@@ -0,0 +1,133 @@
+use ndarray::*;
+use ndarray_linalg::*;
+
+/// Random dense matrix with `kl` sub-diagonals and `ku` super-diagonals
+fn random_band<A: Scalar>(n: usize, kl: usize, ku: usize) -> Array2<A> {
+    let mut a: Array2<A> = random((n, n));
+    for ((i, j), x) in a.indexed_iter_mut() {
+        if i > j + kl || j > i + ku {
+            *x = A::zero();
+        }
+    }
+    a
+}
+
+#[test]
+fn banded_dense_roundtrip() {
+    let a: Array2<f64> = random_band(6, 2, 1);
+    let b = BandedMatrix::from_dense(&a, 2, 1).unwrap();
+    assert_eq!(b.to_dense(), a);
+    assert_eq!(b.get(3, 1), Some(&a[(3, 1)]));
+    assert_eq!(b.get(0, 3), None);
+}
+
+#[test]
+fn banded_dot() {
+    let a: Array2<c64> = random_band(7, 1, 3);
+    let b = BandedMatrix::from_dense(&a, 1, 3).unwrap();
+    let x: Array1<c64> = random(7);
+    assert_close_l2!(&b.dot(&x), &a.dot(&x), 1e-12);
+}
+
+#[test]
+fn banded_solve() {
+    let n = 10;
+    let a: Array2<f64> = random_band(n, 2, 3);
+    let b = BandedMatrix::from_dense(&a, 2, 3).unwrap();
+    let x: Array1<f64> = random(n);
+    let y = a.dot(&x);
+    assert_close_l2!(&b.solve(&y).unwrap(), &x, 1e-9);
+    let f = b.factorize().unwrap();
+    assert_close_l2!(&f.solve(&y).unwrap(), &x, 1e-9);
+    assert_close_l2!(&f.solve_t(&a.t().dot(&x)).unwrap(), &x, 1e-9);
+}
+
+#[test]
+fn banded_solve_complex() {
+    let n = 8;
+    let a: Array2<c64> = random_band(n, 1, 2);
+    let f = BandedMatrix::from_dense(&a, 1, 2).unwrap().factorize_into().unwrap();
+    let x: Array1<c64> = random(n);
+    let ah: Array2<c64> = conjugate(&a);
+    assert_close_l2!(&f.solve(&a.dot(&x)).unwrap(), &x, 1e-9);
+    assert_close_l2!(&f.solve_h(&ah.dot(&x)).unwrap(), &x, 1e-9);
+}
+
+#[test]
+fn banded_singular() {
+    let b = BandedMatrix::<f64>::zeros(4, 1, 1);
+    assert!(b.factorize().is_err());
+}
+
+#[test]
+fn banded_shape_mismatch() {
+    let a: Array2<f64> = random_band(5, 1, 1);
+    let b = BandedMatrix::from_dense(&a, 1, 1).unwrap();
+    assert!(b.solve(&Array1::zeros(4)).is_err());
+}
+
+/// Random Hermitian positive definite matrix with `kd` off-diagonals, made diagonally dominant
+fn random_hpd_band<A: Scalar>(n: usize, kd: usize) -> Array2<A> {
+    let a: Array2<A> = random_band(n, kd, kd);
+    let ah: Array2<A> = conjugate(&a);
+    let mut a = a + ah;
+    for d in a.diag_mut() {
+        *d = A::from_real(d.re()) + A::from_real(A::real(4 * kd + 1));
+    }
+    a
+}
+
+#[test]
+fn banded_cholesky() {
+    let n = 10;
+    let a: Array2<f64> = random_hpd_band(n, 2);
+    let x: Array1<f64> = random(n);
+    let y = a.dot(&x);
+    for &uplo in &[UPLO::Upper, UPLO::Lower] {
+        let f = BandedMatrix::from_dense(&a, 2, 2).unwrap().factorizec(uplo).unwrap();
+        assert_close_l2!(&f.solvec(&y).unwrap(), &x, 1e-9);
+        // The estimate of |A^{-1}|_1 is a lower bound
+        let rcond = 1.0 / (a.opnorm_one().unwrap() * a.inv().unwrap().opnorm_one().unwrap());
+        let est = f.rcond().unwrap();
+        assert!(est >= rcond * (1.0 - 1e-10) && est <= 10.0 * rcond);
+    }
+    let b = BandedMatrix::from_dense(&a, 2, 2).unwrap();
+    assert_close_l2!(&b.solvec(&y).unwrap(), &x, 1e-9);
+}
+
+#[test]
+fn banded_cholesky_complex() {
+    let n = 8;
+    let a: Array2<c64> = random_hpd_band(n, 3);
+    let x: Array1<c64> = random(n);
+    // Only the band of one side is needed
+    let upper = BandedMatrix::from_dense(&a, 0, 3).unwrap();
+    let lower = BandedMatrix::from_dense(&a, 3, 0).unwrap();
+    assert_close_l2!(
+        &upper.factorizec(UPLO::Upper).unwrap().solvec(&a.dot(&x)).unwrap(),
+        &x,
+        1e-9
+    );
+    assert_close_l2!(
+        &lower.factorizec(UPLO::Lower).unwrap().solvec(&a.dot(&x)).unwrap(),
+        &x,
+        1e-9
+    );
+}
+
+#[test]
+fn banded_cholesky_not_positive_definite() {
+    let a: Array2<f64> = from_diag(&[1.0, -1.0, 1.0]);
+    let b = BandedMatrix::from_dense(&a, 0, 0).unwrap();
+    assert!(b.factorizec(UPLO::Upper).is_err());
+}
+
+#[test]
+fn banded_opnorm() {
+    let a: Array2<c64> = random_band(8, 2, 3);
+    let b = BandedMatrix::from_dense(&a, 2, 3).unwrap();
+    assert_rclose!(b.opnorm_one().unwrap(), a.opnorm_one().unwrap(), 1e-12);
+    assert_rclose!(b.opnorm_inf().unwrap(), a.opnorm_inf().unwrap(), 1e-12);
+    assert_rclose!(b.opnorm_fro().unwrap(), a.opnorm_fro().unwrap(), 1e-12);
+    assert_rclose!(b.opnorm_two().unwrap(), a.opnorm_two().unwrap(), 1e-12);
+}
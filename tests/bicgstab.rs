@@ -0,0 +1,92 @@
+use ndarray::*;
+use ndarray_linalg::{krylov::*, *};
+
+/// Random matrix whose eigenvalues are clustered around 3
+fn random_shifted<A: Scalar>(n: usize) -> Array2<A> {
+    let scale = A::real(n as f64).sqrt();
+    let mut a: Array2<A> = random((n, n));
+    a.mapv_inplace(|x| x.div_real(scale));
+    for d in a.diag_mut() {
+        *d = *d + A::from_real(A::real(3.0));
+    }
+    a
+}
+
+fn test<A: Scalar + Lapack>() {
+    let n = 30;
+    let a: Array2<A> = random_shifted(n);
+    let x: Array1<A> = random(n);
+    let b = a.dot(&x);
+    let sol = bicgstab(
+        &a,
+        &IdentityOperator::new(),
+        &b,
+        &Array1::zeros(n),
+        &SolverOptions::new().rtol(A::real(1e-12)).max_iter(10 * n),
+    )
+    .unwrap();
+    assert!(sol.report.converged);
+    assert_close_l2!(&sol.x, &x, A::real(1e-9));
+}
+
+#[test]
+fn bicgstab_real() {
+    test::<f64>();
+}
+
+#[test]
+fn bicgstab_complex() {
+    test::<c64>();
+}
+
+#[test]
+fn bicgstab_preconditioned() {
+    let n = 50;
+    let a: Array2<f64> = random_shifted(n);
+    let b: Array1<f64> = random(n);
+    // The exact inverse as the preconditioner converges in one iteration
+    let m = a.factorize().unwrap();
+    let sol = bicgstab(
+        &a,
+        &m,
+        &b,
+        &Array1::zeros(n),
+        &SolverOptions::new().rtol(1e-10).max_iter(10),
+    )
+    .unwrap();
+    assert!(sol.report.converged);
+    assert_eq!(sol.report.iterations, 1);
+    assert_close_l2!(&a.dot(&sol.x), &b, 1e-10);
+}
+
+#[test]
+fn bicgstab_max_iter() {
+    let n = 50;
+    let a: Array2<f64> = random_regular(n);
+    let b: Array1<f64> = random(n);
+    let sol = bicgstab(
+        &a,
+        &IdentityOperator::new(),
+        &b,
+        &Array1::zeros(n),
+        &SolverOptions::new().rtol(1e-20).max_iter(3),
+    )
+    .unwrap();
+    assert!(!sol.report.converged);
+    assert_eq!(sol.report.iterations, 3);
+}
+
+#[test]
+fn bicgstab_left_preconditioned() {
+    let n = 50;
+    let a: Array2<c64> = random_shifted(n);
+    let b: Array1<c64> = random(n);
+    let m = jacobi(&a).unwrap();
+    let options = SolverOptions::new().rtol(1e-10).side(PreconditionerSide::Left);
+    let sol = bicgstab(&a, &m, &b, &Array1::zeros(n), &options).unwrap();
+    // The criterion is checked by the true residual at the end
+    let r = &b - &a.dot(&sol.x);
+    assert_eq!(sol.report.converged, r.norm_l2() <= 1e-10 * b.norm_l2());
+    assert!(r.norm_l2() <= 1e-8 * b.norm_l2());
+    assert!((sol.report.residual_norm - r.norm_l2()).abs() < 1e-12);
+}
@@ -0,0 +1,84 @@
+use ndarray::*;
+use ndarray_linalg::*;
+
+fn random_block_diag<A: Scalar>(sizes: &[usize]) -> BlockDiag<A> {
+    let mut a = BlockDiag::new();
+    for &n in sizes {
+        a.push(random((n, n))).unwrap();
+    }
+    a
+}
+
+#[test]
+fn block_diag_dense() {
+    let a: BlockDiag<f64> = random_block_diag(&[2, 3, 1]);
+    assert_eq!(a.size(), 6);
+    let d = a.to_dense();
+    assert_eq!(d.slice(s![2..5, 2..5]), a.blocks()[1]);
+    assert_eq!(d.slice(s![0..2, 2..6]), Array2::<f64>::zeros((2, 4)));
+    let x: Array1<f64> = random(6);
+    assert_close_l2!(&a.dot(&x), &d.dot(&x), 1e-12);
+}
+
+#[test]
+fn block_diag_not_square() {
+    assert!(BlockDiag::from_blocks(vec![Array2::<f64>::zeros((2, 2)), Array2::zeros((2, 3))]).is_err());
+    let mut a = BlockDiag::new();
+    assert!(a.push(Array2::<f64>::zeros((1, 2))).is_err());
+}
+
+#[test]
+fn block_diag_solve() {
+    let a: BlockDiag<c64> = random_block_diag(&[3, 1, 4]);
+    let d = a.to_dense();
+    let dh: Array2<c64> = conjugate(&d);
+    let x: Array1<c64> = random(8);
+    let f = a.factorize().unwrap();
+    assert_close_l2!(&f.solve(&d.dot(&x)).unwrap(), &x, 1e-7);
+    assert_close_l2!(&f.solve_t(&d.t().dot(&x)).unwrap(), &x, 1e-7);
+    assert_close_l2!(&a.solve_h(&dh.dot(&x)).unwrap(), &x, 1e-7);
+    assert!(a.solve(&Array1::zeros(7)).is_err());
+}
+
+#[test]
+fn block_diag_det() {
+    let a: BlockDiag<f64> = random_block_diag(&[2, 3]);
+    let det = a.to_dense().det().unwrap();
+    assert_rclose!(a.det().unwrap(), det, 1e-9);
+    assert_rclose!(a.factorize().unwrap().det().unwrap(), det, 1e-9);
+    let mut singular = a.clone();
+    singular.push(Array2::zeros((2, 2))).unwrap();
+    assert_eq!(singular.det().unwrap(), 0.0);
+}
+
+#[test]
+fn block_diag_inv() {
+    let a: BlockDiag<f64> = random_block_diag(&[2, 1, 3]);
+    let inv = a.inv().unwrap();
+    assert_close_l2!(&inv.to_dense(), &a.to_dense().inv().unwrap(), 1e-7);
+}
+
+#[test]
+fn block_diag_eig() {
+    let a: BlockDiag<f64> = random_block_diag(&[3, 2]);
+    let (e, v) = a.eig().unwrap();
+    let d: Array2<c64> = a.to_dense().mapv(|x| c64::new(x, 0.0));
+    let v = v.to_dense();
+    for (i, &l) in e.iter().enumerate() {
+        let vi = v.column(i);
+        assert_close_l2!(&d.dot(&vi), &vi.mapv(|x| x * l), 1e-9);
+    }
+}
+
+#[test]
+fn block_diag_eigh() {
+    let mut a = BlockDiag::new();
+    for &n in &[2, 4] {
+        let b: Array2<f64> = random_hpd(n);
+        a.push(b).unwrap();
+    }
+    let (e, v) = a.eigh(UPLO::Upper).unwrap();
+    let d = a.to_dense();
+    let v = v.to_dense();
+    assert_close_l2!(&v.t().dot(&d).dot(&v), &from_diag(e.as_slice().unwrap()), 1e-9);
+}
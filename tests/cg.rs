@@ -0,0 +1,178 @@
+use ndarray::*;
+use ndarray_linalg::{krylov::*, *};
+
+fn test<A: Scalar + Lapack>() {
+    let n = 30;
+    let a: Array2<A> = random_hpd(n);
+    let x: Array1<A> = random(n);
+    let b = a.dot(&x);
+    let sol = cg(
+        &a,
+        &b,
+        &Array1::zeros(n),
+        &SolverOptions::new().rtol(A::real(1e-12)).max_iter(10 * n),
+    )
+    .unwrap();
+    assert!(sol.report.converged);
+    assert_close_l2!(&sol.x, &x, A::real(1e-7));
+}
+
+#[test]
+fn cg_real() {
+    test::<f64>();
+}
+
+#[test]
+fn cg_complex() {
+    test::<c64>();
+}
+
+#[test]
+fn cg_matrix_free() {
+    // 1D Laplacian with Dirichlet boundary
+    let n = 100;
+    let a: HermitianTridiagonal<f64> =
+        HermitianTridiagonal::new(Array1::from_elem(n, 2.0), Array1::from_elem(n - 1, -1.0)).unwrap();
+    let b = Array1::ones(n);
+    let sol = cg(
+        &a,
+        &b,
+        &Array1::zeros(n),
+        &SolverOptions::new().rtol(1e-10).max_iter(2 * n),
+    )
+    .unwrap();
+    assert!(sol.report.converged);
+    // `x_i = i (n + 1 - i) / 2` for the unit load
+    let truth = Array1::from_shape_fn(n, |i| ((i + 1) * (n - i)) as f64 / 2.0);
+    assert_close_l2!(&sol.x, &truth, 1e-6);
+}
+
+#[test]
+fn cg_initial_guess() {
+    let a: Array2<f64> = random_hpd(5);
+    let x: Array1<f64> = random(5);
+    let sol = cg(&a, &a.dot(&x), &x, &SolverOptions::new().rtol(1e-12).max_iter(10)).unwrap();
+    assert!(sol.report.converged);
+    assert_eq!(sol.report.iterations, 0);
+    assert!(cg(&a, &a.dot(&x), &Array1::zeros(4), &SolverOptions::new().max_iter(10)).is_err());
+}
+
+#[test]
+fn cg_max_iter() {
+    let n = 50;
+    let a: Array2<f64> = random_hpd(n);
+    let b: Array1<f64> = random(n);
+    let sol = cg(&a, &b, &Array1::zeros(n), &SolverOptions::new().rtol(1e-14).max_iter(3)).unwrap();
+    assert!(!sol.report.converged);
+    assert_eq!(sol.report.iterations, 3);
+}
+
+#[test]
+fn cg_indefinite() {
+    let a = arr2(&[[1.0, 0.0], [0.0, -1.0]]);
+    let b = arr1(&[1.0, 1.0]);
+    let sol = cg(&a, &b, &Array1::zeros(2), &SolverOptions::new().max_iter(10)).unwrap();
+    assert!(!sol.report.converged);
+}
+
+/// 5-point Laplacian on the `m x m` grid
+fn laplacian_2d(m: usize) -> Array2<f64> {
+    let n = m * m;
+    let mut a = Array2::zeros((n, n));
+    for i in 0..m {
+        for j in 0..m {
+            let k = i * m + j;
+            a[(k, k)] = 4.0;
+            if i > 0 {
+                a[(k, k - m)] = -1.0;
+                a[(k - m, k)] = -1.0;
+            }
+            if j > 0 {
+                a[(k, k - 1)] = -1.0;
+                a[(k - 1, k)] = -1.0;
+            }
+        }
+    }
+    a
+}
+
+#[test]
+fn pcg_incomplete_cholesky() {
+    let a = laplacian_2d(10);
+    let b: Array1<f64> = random(100);
+    let options = SolverOptions::new().rtol(1e-10).max_iter(500);
+    let plain = cg(&a, &b, &Array1::zeros(100), &options).unwrap();
+    let m = IncompleteCholesky::new(&a).unwrap();
+    let sol = pcg(&a, &m, &b, &Array1::zeros(100), &options).unwrap();
+    assert!(plain.report.converged);
+    assert!(sol.report.converged);
+    assert!(sol.report.iterations < plain.report.iterations);
+    assert_close_l2!(&a.dot(&sol.x), &b, 1e-9);
+}
+
+#[test]
+fn pcg_jacobi() {
+    // Badly scaled HPD matrix `D A D`
+    let n = 30;
+    let a: Array2<c64> = random_hpd(n);
+    let d = Array1::from_shape_fn(n, |i| c64::new(10f64.powi(i as i32 % 3), 0.0));
+    let a = Array2::from_shape_fn((n, n), |(i, j)| d[i] * a[(i, j)] * d[j]);
+    let b: Array1<c64> = random(n);
+    let m = jacobi(&a).unwrap();
+    let sol = pcg(
+        &a,
+        &m,
+        &b,
+        &Array1::zeros(n),
+        &SolverOptions::new().rtol(1e-12).max_iter(10 * n),
+    )
+    .unwrap();
+    assert!(sol.report.converged);
+    assert_close_l2!(&a.dot(&sol.x), &b, 1e-10);
+}
+
+#[test]
+fn pcg_exact_preconditioner() {
+    // IC(0) of a dense matrix is the Cholesky factorization
+    let a: Array2<f64> = random_hpd(10);
+    let b: Array1<f64> = random(10);
+    let m = IncompleteCholesky::new(&a).unwrap();
+    let sol = pcg(
+        &a,
+        &m,
+        &b,
+        &Array1::zeros(10),
+        &SolverOptions::new().rtol(1e-10).max_iter(10),
+    )
+    .unwrap();
+    assert!(sol.report.converged);
+    assert_eq!(sol.report.iterations, 1);
+}
+
+#[test]
+fn cg_callback() {
+    let n = 30;
+    let a: Array2<f64> = random_hpd(n);
+    let b: Array1<f64> = random(n);
+    let options = SolverOptions::new().rtol(1e-10).max_iter(100);
+    let mut infos = Vec::new();
+    let sol = cg_with_callback(&a, &b, &Array1::zeros(n), &options, |info| {
+        infos.push(*info);
+        true
+    })
+    .unwrap();
+    assert!(sol.report.converged);
+    assert_eq!(infos.len(), sol.report.iterations + 1);
+    for (k, info) in infos.iter().enumerate() {
+        assert_eq!(info.iteration, k);
+        assert_eq!(info.error, sol.report.history[k]);
+    }
+    assert!((infos[0].residual_norm - b.norm_l2()).abs() < 1e-12);
+    let r = &b - &a.dot(&sol.x);
+    assert!((sol.report.residual_norm - r.norm_l2()).abs() < 1e-8);
+
+    // Stop by the callback
+    let sol = cg_with_callback(&a, &b, &Array1::zeros(n), &options, |info| info.iteration < 3).unwrap();
+    assert!(!sol.report.converged);
+    assert_eq!(sol.report.iterations, 3);
+}
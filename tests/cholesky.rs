@@ -180,3 +180,36 @@ fn cholesky_solve() {
     cholesky_solve!(c64, 1e-9);
     cholesky_solve!(c32, 1e-3);
 }
+
+#[test]
+fn cholesky_stack() {
+    let mut a: Array3<f64> = Array3::zeros((4, 3, 3));
+    for mut a_i in a.outer_iter_mut() {
+        a_i.assign(&random_hpd::<f64, OwnedRepr<f64>>(3));
+    }
+    let l = a.cholesky(UPLO::Lower).unwrap();
+    assert_eq!(l.dim(), (4, 3, 3));
+    for (a_i, l_i) in a.outer_iter().zip(l.outer_iter()) {
+        assert_close_l2!(&l_i, &a_i.cholesky(UPLO::Lower).unwrap(), 1e-7);
+    }
+}
+
+#[test]
+fn cholesky_layouts() {
+    let a: Array2<c64> = random_hpd(4);
+    let b: Array1<c64> = random(4);
+    for a in &[a.clone(), a.reversed_axes()] {
+        for &uplo in &[UPLO::Lower, UPLO::Upper] {
+            let f = a.cholesky(uplo).unwrap();
+            let fh = f.t().mapv(|x| x.conj());
+            let ans = match uplo {
+                UPLO::Lower => f.dot(&fh),
+                UPLO::Upper => fh.dot(&f),
+            };
+            assert_close_l2!(&ans, a, 1e-7);
+            let x = a.factorizec(uplo).unwrap().solvec(&b).unwrap();
+            assert_close_l2!(&a.dot(&x), &b, 1e-7);
+            assert_close_l2!(&a.invc().unwrap().dot(a), &Array2::eye(4), 1e-7);
+        }
+    }
+}
@@ -0,0 +1,73 @@
+use ndarray::*;
+use ndarray_linalg::*;
+
+#[test]
+fn circulant_dense() {
+    let c: Circulant<f64> = Circulant::new(random(5));
+    let a = c.to_dense();
+    for i in 0..5 {
+        for j in 0..5 {
+            assert_eq!(a[(i, j)], c.column()[(i + 5 - j) % 5]);
+        }
+    }
+    for &n in &[5, 8] {
+        let c: Circulant<f64> = Circulant::new(random(n));
+        let x: Array1<f64> = random(n);
+        assert_close_l2!(&c.dot(&x), &c.to_dense().dot(&x), 1e-12);
+    }
+}
+
+#[test]
+fn circulant_solve() {
+    let n = 9;
+    let mut col: Array1<f64> = random(n);
+    col[0] += n as f64;
+    let c = Circulant::new(col);
+    let a = c.to_dense();
+    let x: Array1<f64> = random(n);
+    assert_close_l2!(&c.solve(&a.dot(&x)).unwrap(), &x, 1e-10);
+    assert_close_l2!(&c.solve_t(&a.t().dot(&x)).unwrap(), &x, 1e-10);
+}
+
+#[test]
+fn circulant_solve_complex() {
+    let n = 16;
+    let c: Circulant<c64> = Circulant::new(random(n));
+    let a = c.to_dense();
+    let ah: Array2<c64> = conjugate(&a);
+    let x: Array1<c64> = random(n);
+    assert_close_l2!(&c.solve(&a.dot(&x)).unwrap(), &x, 1e-8);
+    assert_close_l2!(&c.solve_t(&a.t().dot(&x)).unwrap(), &x, 1e-8);
+    assert_close_l2!(&c.solve_h(&ah.dot(&x)).unwrap(), &x, 1e-8);
+}
+
+#[test]
+fn circulant_eigenvalues() {
+    let n = 6;
+    let c: Circulant<c64> = Circulant::new(random(n));
+    let a = c.to_dense();
+    let det = a.det().unwrap();
+    assert!((c.det() - det).norm() < 1e-10 * det.norm());
+    for (k, &l) in c.eigenvalues().iter().enumerate() {
+        let v = Array1::from_shape_fn(n, |j| {
+            c64::from_polar(&1.0, &(2.0 * std::f64::consts::PI * (j * k) as f64 / n as f64))
+        });
+        assert_close_l2!(&a.dot(&v), &v.mapv(|x| x * l), 1e-10);
+    }
+}
+
+#[test]
+fn circulant_det_real() {
+    let c = Circulant::new(arr1(&[3.0, 1.0, 0.0, 1.0]));
+    let det = c.det();
+    assert_aclose!(det.re, c.to_dense().det().unwrap(), 1e-12);
+    assert_aclose!(det.im, 0.0, 1e-12);
+}
+
+#[test]
+fn circulant_singular() {
+    // The eigenvalue for `k = 0` is the sum of the column
+    let c = Circulant::new(arr1(&[1.0, -1.0, 0.0, 0.0]));
+    assert!(c.solve(&arr1(&[1.0, 0.0, -1.0, 0.0])).is_err());
+    assert!(c.solve(&Array1::zeros(2)).is_err());
+}
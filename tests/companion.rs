@@ -0,0 +1,73 @@
+use ndarray::*;
+use ndarray_linalg::*;
+
+/// Sort by the real parts and then by the imaginary parts
+fn sorted(roots: Array1<c64>) -> Vec<c64> {
+    let mut roots = roots.to_vec();
+    roots.sort_by(|a, b| (a.re, a.im).partial_cmp(&(b.re, b.im)).unwrap());
+    roots
+}
+
+fn assert_roots(roots: Array1<c64>, expected: &[c64], tol: f64) {
+    let roots = sorted(roots);
+    assert_eq!(roots.len(), expected.len());
+    for (r, e) in roots.iter().zip(expected.iter()) {
+        assert!((r - e).norm() < tol, "{} != {}", r, e);
+    }
+}
+
+#[test]
+fn companion_eigvals() {
+    let mut p: Array1<f64> = random(6);
+    p[0] = 1.0;
+    let c = companion(&p).unwrap();
+    assert_eq!(c.dim(), (5, 5));
+    // Each eigenvalue is a root of p
+    for z in c.eigvals().unwrap().iter() {
+        let value = p.iter().fold(c64::new(0.0, 0.0), |acc, &a| acc * z + a);
+        let scale = p.iter().fold(0.0, |acc, &a| acc * z.norm() + a.abs());
+        assert!(value.norm() < 1e-10 * scale);
+    }
+}
+
+#[test]
+fn poly_roots_real() {
+    // (x - 1)(x - 2)(x - 3)
+    let roots = poly_roots(&arr1(&[1.0, -6.0, 11.0, -6.0])).unwrap();
+    let expected = [c64::new(1.0, 0.0), c64::new(2.0, 0.0), c64::new(3.0, 0.0)];
+    assert_roots(roots, &expected, 1e-10);
+}
+
+#[test]
+fn poly_roots_conjugate_pair() {
+    // 2 (x^2 + 1)
+    let roots = poly_roots(&arr1(&[2.0, 0.0, 2.0])).unwrap();
+    assert_roots(roots, &[c64::new(0.0, -1.0), c64::new(0.0, 1.0)], 1e-12);
+}
+
+#[test]
+fn poly_roots_complex() {
+    // (x - i)(x - 2) = x^2 - (2 + i) x + 2i
+    let p = arr1(&[c64::new(1.0, 0.0), c64::new(-2.0, -1.0), c64::new(0.0, 2.0)]);
+    assert_roots(
+        poly_roots(&p).unwrap(),
+        &[c64::new(0.0, 1.0), c64::new(2.0, 0.0)],
+        1e-12,
+    );
+}
+
+#[test]
+fn poly_roots_zero_coefficients() {
+    // Leading zeros are dropped, and trailing zeros are exact roots at zero: x^2 (x - 1)
+    let roots = poly_roots(&arr1(&[0.0, 1.0, -1.0, 0.0, 0.0])).unwrap();
+    let roots = sorted(roots);
+    assert_eq!(&roots[..2], &[c64::new(0.0, 0.0), c64::new(0.0, 0.0)]);
+    assert!((roots[2] - c64::new(1.0, 0.0)).norm() < 1e-12);
+    assert_eq!(poly_roots(&arr1(&[3.0])).unwrap().len(), 0);
+}
+
+#[test]
+fn poly_roots_zero_polynomial() {
+    assert!(poly_roots(&arr1(&[0.0, 0.0])).is_err());
+    assert!(companion(&arr1(&[0.0, 1.0])).is_err());
+}
@@ -0,0 +1,65 @@
+use ndarray::*;
+use ndarray_linalg::*;
+
+fn exact(a: &Array2<f64>, norm: CondNorm) -> f64 {
+    let inv = a.inv().unwrap();
+    match norm {
+        CondNorm::One => a.opnorm_one().unwrap() * inv.opnorm_one().unwrap(),
+        CondNorm::Infinity => a.opnorm_inf().unwrap() * inv.opnorm_inf().unwrap(),
+        CondNorm::Two => a.opnorm_two().unwrap() * inv.opnorm_two().unwrap(),
+        CondNorm::Frobenius => a.opnorm_fro().unwrap() * inv.opnorm_fro().unwrap(),
+    }
+}
+
+#[test]
+fn cond_diag() {
+    let a = Array2::from_diag(&arr1(&[4.0, -2.0, 0.5]));
+    for &norm in &[CondNorm::One, CondNorm::Two, CondNorm::Infinity] {
+        assert_rclose!(a.cond(norm).unwrap(), 8.0, 1e-9);
+    }
+    let fro = (16.0f64 + 4.0 + 0.25).sqrt() * (1.0f64 / 16.0 + 0.25 + 4.0).sqrt();
+    assert_rclose!(a.cond(CondNorm::Frobenius).unwrap(), fro, 1e-9);
+}
+
+#[test]
+fn cond_random() {
+    let a: Array2<f64> = random((6, 6));
+    for &norm in &[CondNorm::Two, CondNorm::Frobenius] {
+        assert_rclose!(a.cond(norm).unwrap(), exact(&a, norm), 1e-7);
+    }
+    // `*gecon` underestimates the exact value, but rarely by more than a factor of 3
+    for &norm in &[CondNorm::One, CondNorm::Infinity] {
+        let est = a.cond(norm).unwrap();
+        let ex = exact(&a, norm);
+        assert!(
+            est <= ex * (1.0 + 1e-7) && est >= ex / 3.0,
+            "{:?}: {} vs {}",
+            norm,
+            est,
+            ex
+        );
+    }
+}
+
+#[test]
+fn cond_unitary() {
+    let q: Array2<c64> = random_unitary(5);
+    assert_rclose!(q.cond(CondNorm::Two).unwrap(), 1.0, 1e-9);
+    assert_rclose!(q.cond(CondNorm::Frobenius).unwrap(), 5.0, 1e-9);
+}
+
+#[test]
+fn cond_rectangular() {
+    let a = arr2(&[[3.0, 0.0], [0.0, 1.0], [0.0, 0.0]]);
+    assert_rclose!(a.cond(CondNorm::Two).unwrap(), 3.0, 1e-9);
+    assert!(a.cond(CondNorm::One).is_err());
+}
+
+#[test]
+fn cond_singular() {
+    let a = arr2(&[[1.0, 2.0], [2.0, 4.0]]);
+    for &norm in &[CondNorm::One, CondNorm::Infinity, CondNorm::Frobenius] {
+        assert!(a.cond(norm).unwrap().is_infinite());
+    }
+    assert!(a.cond(CondNorm::Two).unwrap() > 1e15);
+}
@@ -0,0 +1,59 @@
+use ndarray::*;
+use ndarray_linalg::*;
+
+#[test]
+fn criteria() {
+    let b = arr1(&[2.0, 0.0]);
+    let x = arr1(&[1.0, 1.0]);
+    let r = arr1(&[0.2, 0.0]);
+    let mut res = Residual::new(r.view(), b.view(), x.view());
+    res.a_norm = Some(3.0);
+    let abs_ax = arr1(&[1.0, 1.0]);
+    res.abs_ax = Some(abs_ax.view());
+
+    assert_rclose!(StoppingCriterion::Absolute(1e-3).error(&res), 0.2, 1e-12);
+    assert_rclose!(StoppingCriterion::RelativeResidual(1e-3).error(&res), 0.1, 1e-12);
+    let be = 0.2 / (3.0 * 2.0_f64.sqrt() + 2.0);
+    assert_rclose!(StoppingCriterion::BackwardError(1e-3).error(&res), be, 1e-12);
+    assert_rclose!(StoppingCriterion::ComponentWise(1e-3).error(&res), 0.2 / 3.0, 1e-12);
+}
+
+#[test]
+fn relative_residual_zero_rhs() {
+    let b = arr1(&[0.0, 0.0]);
+    let x = arr1(&[0.0, 0.0]);
+    let r = arr1(&[1e-3, 0.0]);
+    let res = Residual::new(r.view(), b.view(), x.view());
+    assert_rclose!(StoppingCriterion::RelativeResidual(1e-3).error(&res), 1e-3, 1e-12);
+}
+
+#[test]
+fn report() {
+    let mut report = IterationReport::new(StoppingCriterion::Absolute(1e-2));
+    assert!(!report.record(1.0));
+    assert!(!report.record(0.1));
+    assert!(report.record(1e-3));
+    assert!(report.converged);
+    assert_eq!(report.iterations(), 3);
+    assert_eq!(report.last_error(), Some(1e-3));
+}
+
+#[test]
+fn mixed_tolerances() {
+    let b = arr1(&[2.0, 0.0]);
+    let x = arr1(&[1.0, 1.0]);
+    let r = arr1(&[0.2, 0.0]);
+    let res = Residual::new(r.view(), b.view(), x.view());
+    // `|r| <= max(rtol |b|, atol)`
+    let relative = StoppingCriterion::Mixed { rtol: 0.2, atol: 0.1 };
+    assert_rclose!(relative.error(&res), 0.1, 1e-12);
+    assert!(relative.is_converged(relative.error(&res)));
+    let floor = StoppingCriterion::Mixed { rtol: 0.01, atol: 0.3 };
+    assert_rclose!(floor.error(&res), 0.2 / 30.0, 1e-12);
+    assert!(floor.is_converged(floor.error(&res)));
+    let strict = StoppingCriterion::Mixed { rtol: 0.01, atol: 0.1 };
+    assert!(!strict.is_converged(strict.error(&res)));
+    let absolute = StoppingCriterion::Mixed { rtol: 0.0, atol: 0.3 };
+    assert_rclose!(absolute.error(&res), 0.2, 1e-12);
+    assert_eq!(absolute.tolerance(), 0.3);
+}
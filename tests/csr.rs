@@ -0,0 +1,64 @@
+use ndarray::*;
+use ndarray_linalg::*;
+
+fn sparse_random(m: usize, n: usize) -> Array2<c64> {
+    let a: Array2<c64> = random((m, n));
+    // Drop about a half of the elements
+    a.mapv(|x| if x.re > 0.0 { x } else { c64::new(0.0, 0.0) })
+}
+
+#[test]
+fn csr_dense_roundtrip() {
+    let a = sparse_random(5, 7);
+    let csr = CsrMatrix::from_dense(&a);
+    assert_eq!(csr.shape(), (5, 7));
+    assert_eq!(csr.nnz(), a.iter().filter(|x| x.re > 0.0).count());
+    assert_eq!(csr.to_dense(), a);
+    for ((i, j), x) in a.indexed_iter() {
+        match csr.get(i, j) {
+            Some(y) => assert_eq!(x, y),
+            None => assert_eq!(*x, c64::new(0.0, 0.0)),
+        }
+    }
+}
+
+#[test]
+fn csr_operator() {
+    let a = sparse_random(4, 6);
+    let csr = CsrMatrix::from_dense(&a);
+    let x: Array1<c64> = random(6);
+    let y: Array1<c64> = random(4);
+    let ah: Array2<c64> = conjugate(&a);
+    assert_close_l2!(&csr.apply(&x), &a.dot(&x), 1e-12);
+    assert_close_l2!(&csr.apply_adjoint(&y), &ah.dot(&y), 1e-12);
+}
+
+#[test]
+fn csr_from_banded() {
+    let a: Array2<f64> = random((6, 6));
+    let mut banded = BandedMatrix::from_dense(&a, 2, 1).unwrap();
+    *banded.get_mut(3, 2).unwrap() = 0.0;
+    let csr = CsrMatrix::from_banded(&banded);
+    assert_eq!(csr.to_dense(), banded.to_dense());
+    assert_eq!(csr.get(3, 2), None);
+    assert_eq!(csr.nnz(), 6 + 5 + 5 + 4 - 1);
+}
+
+#[test]
+fn csr_invalid() {
+    // Inconsistent lengths
+    assert!(CsrMatrix::new((2, 2), vec![0, 1], vec![0], vec![1.0]).is_err());
+    assert!(CsrMatrix::new((2, 2), vec![0, 1, 2], vec![0, 1], vec![1.0]).is_err());
+    // Unsorted or out-of-bounds column indices
+    assert!(CsrMatrix::new((1, 2), vec![0, 2], vec![1, 0], vec![1.0, 2.0]).is_err());
+    assert!(CsrMatrix::new((1, 2), vec![0, 1], vec![2], vec![1.0]).is_err());
+    assert!(CsrMatrix::new((2, 2), vec![0, 2, 1], vec![0, 1], vec![1.0, 2.0]).is_err());
+}
+
+#[test]
+fn csr_malformed_indptr() {
+    // The first row would end beyond `indices`
+    assert!(CsrMatrix::new((2, 3), vec![0, 5, 3], vec![0, 1, 2], vec![1.0, 2.0, 3.0]).is_err());
+    // The first row does not start at zero
+    assert!(CsrMatrix::new((2, 3), vec![1, 2, 3], vec![0, 1, 2], vec![1.0, 2.0, 3.0]).is_err());
+}
@@ -0,0 +1,49 @@
+use ndarray::*;
+use ndarray_linalg::*;
+
+fn test_low_rank<A: Scalar + Lapack>() {
+    let x: Array2<A> = random((25, 3));
+    let y: Array2<A> = random((3, 18));
+    let a = x.dot(&y);
+    let cur = a.cur(3, 5, 4).unwrap();
+    assert_eq!(cur.columns.len(), 5);
+    assert_eq!(cur.rows.len(), 4);
+    assert_eq!(cur.u.dim(), (5, 4));
+    assert_close_l2!(&cur.to_dense(), &a, A::real(1e-8));
+}
+
+#[test]
+fn low_rank() {
+    test_low_rank::<f64>();
+    test_low_rank::<c64>();
+}
+
+#[test]
+fn leverage_scores() {
+    let a: Array2<f64> = random((10, 6));
+    let (columns, rows) = a.leverage_scores(2).unwrap();
+    assert!((columns.sum() - 1.0).abs() < 1e-12);
+    assert!((rows.sum() - 1.0).abs() < 1e-12);
+    // All the columns are needed for the full rank
+    let (columns, _) = a.leverage_scores(6).unwrap();
+    assert_close_l2!(&columns, &Array1::from_elem(6, 1.0 / 6.0), 1e-12);
+}
+
+#[test]
+fn dominant_column() {
+    // The last column is far from the span of the others
+    let mut a: Array2<f64> = random((10, 5));
+    a *= 1e-3;
+    a.column_mut(4).assign(&random(10));
+    let cur = a.cur(1, 1, 1).unwrap();
+    assert_eq!(cur.columns, vec![4]);
+}
+
+#[test]
+fn invalid_sizes() {
+    let a: Array2<f64> = random((6, 4));
+    assert!(a.cur(0, 1, 1).is_err());
+    assert!(a.cur(2, 1, 2).is_err());
+    assert!(a.cur(2, 5, 2).is_err());
+    assert!(a.cur(2, 2, 7).is_err());
+}
@@ -0,0 +1,66 @@
+use ndarray::*;
+use ndarray_linalg::error::LinalgError;
+use ndarray_linalg::*;
+
+#[test]
+fn dd_arithmetic() {
+    let one = DoubleDouble::one();
+    let x = (one + DoubleDouble::from(1e-20)) - one;
+    assert_rclose!(x.hi(), 1e-20, 1e-15);
+    let two = DoubleDouble::from(2.0);
+    let r = two.sqrt();
+    let e = r * r - two;
+    assert!(e.abs().hi() < 1e-30);
+    let t = one / DoubleDouble::from(3.0);
+    assert!((t * DoubleDouble::from(3.0) - one).abs().hi() < 1e-31);
+}
+
+#[test]
+fn dd_solve_pascal() {
+    let n = 18;
+    let a: Array2<f64> = gallery::pascal(n);
+    let b = a.dot(&Array1::ones(n));
+    let x = a.solve_dd(&b).unwrap();
+    assert_close_max!(&x, &Array1::ones(n), 1e-12);
+}
+
+#[test]
+fn dd_solve_singular() {
+    let a = arr2(&[[1.0, 2.0], [2.0, 4.0]]);
+    match a.solve_dd(&arr1(&[1.0, 1.0])) {
+        Err(LinalgError::Lapack { return_code }) => assert_eq!(return_code, 2),
+        _ => panic!("must fail"),
+    }
+}
+
+#[test]
+fn dd_cholesky_pascal() {
+    let n = 12;
+    let a: Array2<f64> = gallery::pascal(n);
+    let binom = Array2::from_shape_fn((n, n), |(i, j)| {
+        if j <= i {
+            (0..j).fold(1.0, |b, k| b * (i - k) as f64 / (k + 1) as f64)
+        } else {
+            0.0
+        }
+    });
+    let l = a.cholesky_dd(UPLO::Lower).unwrap();
+    assert_eq!(l, binom);
+    let u = a.cholesky_dd(UPLO::Upper).unwrap();
+    assert_eq!(u, binom.t());
+}
+
+#[test]
+fn dd_qr() {
+    let a: Array2<f64> = random((5, 3));
+    let (q, r) = a.qr_dd().unwrap();
+    assert_eq!(q.dim(), (5, 3));
+    assert_eq!(r.dim(), (3, 3));
+    assert_close_max!(&q.t().dot(&q), &Array2::eye(3), 1e-14);
+    assert_close_max!(&q.dot(&r), &a, 1e-14);
+    for i in 0..3 {
+        for j in 0..i {
+            assert_eq!(r[(i, j)], 0.0);
+        }
+    }
+}
@@ -0,0 +1,207 @@
+use ndarray::*;
+use ndarray_linalg::*;
+
+fn test_right(a: &Array2<c64>, e: &Array1<c64>, vr: &Array2<c64>) {
+    for (i, v) in vr.axis_iter(Axis(1)).enumerate() {
+        let av = a.dot(&v);
+        let ev = v.mapv(|x| e[i] * x);
+        assert_close_l2!(&av, &ev, 1e-7);
+    }
+}
+
+fn test_left(a: &Array2<c64>, e: &Array1<c64>, vl: &Array2<c64>) {
+    // u^H A = λ u^H  <=>  A^H u = conj(λ) u
+    let ah: Array2<c64> = conjugate(a);
+    for (i, u) in vl.axis_iter(Axis(1)).enumerate() {
+        let ahu = ah.dot(&u);
+        let eu = u.mapv(|x| e[i].conj() * x);
+        assert_close_l2!(&ahu, &eu, 1e-7);
+    }
+}
+
+fn to_complex(a: &Array2<f64>) -> Array2<c64> {
+    a.mapv(|x| c64::new(x, 0.0))
+}
+
+#[test]
+fn eig_rotation() {
+    let a: Array2<f64> = arr2(&[[0.0, -1.0], [1.0, 0.0]]);
+    let mut e: Vec<c64> = a.eigvals().unwrap().to_vec();
+    e.sort_by(|x, y| x.im.partial_cmp(&y.im).unwrap());
+    assert_close_l2!(&arr1(&e), &arr1(&[c64::new(0.0, -1.0), c64::new(0.0, 1.0)]), 1e-9);
+}
+
+#[test]
+fn eig_real() {
+    let a: Array2<f64> = random((4, 4));
+    let (e, vr) = a.eig().unwrap();
+    test_right(&to_complex(&a), &e, &vr);
+}
+
+#[test]
+fn eig_real_t() {
+    let a: Array2<f64> = random((4, 4).f());
+    let (e, vr) = a.eig().unwrap();
+    test_right(&to_complex(&a), &e, &vr);
+}
+
+#[test]
+fn eig_full_real() {
+    let a: Array2<f64> = random((5, 5));
+    let (e, vl, vr) = a.eig_full(true, true).unwrap();
+    let ac = to_complex(&a);
+    test_left(&ac, &e, &vl.unwrap());
+    test_right(&ac, &e, &vr.unwrap());
+}
+
+#[test]
+fn eig_full_real_t() {
+    let a: Array2<f64> = random((5, 5).f());
+    let (e, vl, vr) = a.eig_full(true, true).unwrap();
+    let ac = to_complex(&a);
+    test_left(&ac, &e, &vl.unwrap());
+    test_right(&ac, &e, &vr.unwrap());
+}
+
+#[test]
+fn eig_full_left_only() {
+    let a: Array2<f64> = random((4, 4));
+    let (e, vl, vr) = a.eig_full(true, false).unwrap();
+    assert!(vr.is_none());
+    test_left(&to_complex(&a), &e, &vl.unwrap());
+}
+
+#[test]
+fn eig_full_complex() {
+    let a: Array2<c64> = random((4, 4));
+    let (e, vl, vr) = a.eig_full(true, true).unwrap();
+    test_left(&a, &e, &vl.unwrap());
+    test_right(&a, &e, &vr.unwrap());
+}
+
+fn test_generalized_right(a: &Array2<c64>, b: &Array2<c64>, alpha: &Array1<c64>, beta: &Array1<c64>, vr: &Array2<c64>) {
+    // β A v = α B v
+    for (i, v) in vr.axis_iter(Axis(1)).enumerate() {
+        let av = a.dot(&v).mapv(|x| beta[i] * x);
+        let bv = b.dot(&v).mapv(|x| alpha[i] * x);
+        assert_close_l2!(&av, &bv, 1e-7);
+    }
+}
+
+fn test_generalized_left(a: &Array2<c64>, b: &Array2<c64>, alpha: &Array1<c64>, beta: &Array1<c64>, vl: &Array2<c64>) {
+    // β u^H A = α u^H B  <=>  conj(β) A^H u = conj(α) B^H u
+    let ah: Array2<c64> = conjugate(a);
+    let bh: Array2<c64> = conjugate(b);
+    for (i, u) in vl.axis_iter(Axis(1)).enumerate() {
+        let au = ah.dot(&u).mapv(|x| beta[i].conj() * x);
+        let bu = bh.dot(&u).mapv(|x| alpha[i].conj() * x);
+        assert_close_l2!(&au, &bu, 1e-7);
+    }
+}
+
+#[test]
+fn eig_generalized_real() {
+    let a: Array2<f64> = random((4, 4));
+    let b: Array2<f64> = random((4, 4).f());
+    let (alpha, beta, vl, vr) = a.eig_generalized(&b, true, true).unwrap();
+    let (ac, bc) = (to_complex(&a), to_complex(&b));
+    test_generalized_left(&ac, &bc, &alpha, &beta, &vl.unwrap());
+    test_generalized_right(&ac, &bc, &alpha, &beta, &vr.unwrap());
+}
+
+#[test]
+fn eig_generalized_complex() {
+    let a: Array2<c64> = random((4, 4));
+    let b: Array2<c64> = random((4, 4));
+    let (alpha, beta, vl, vr) = a.eig_generalized(&b, true, true).unwrap();
+    test_generalized_left(&a, &b, &alpha, &beta, &vl.unwrap());
+    test_generalized_right(&a, &b, &alpha, &beta, &vr.unwrap());
+}
+
+#[test]
+fn eig_generalized_identity() {
+    let a: Array2<f64> = arr2(&[[2.0, 1.0], [0.0, 3.0]]);
+    let b: Array2<f64> = Array::eye(2);
+    let (alpha, beta, _, _) = a.eig_generalized(&b, false, false).unwrap();
+    let mut e: Vec<f64> = alpha.iter().zip(beta.iter()).map(|(a, b)| (a / b).re).collect();
+    e.sort_by(|x, y| x.partial_cmp(y).unwrap());
+    assert_close_l2!(&arr1(&e), &arr1(&[2.0, 3.0]), 1e-9);
+}
+
+#[test]
+fn eig_generalized_infinite() {
+    // B is singular, and the pencil has an infinite eigenvalue
+    let a: Array2<f64> = Array::eye(2);
+    let b: Array2<f64> = arr2(&[[1.0, 0.0], [0.0, 0.0]]);
+    let (alpha, beta, _, _) = a.eig_generalized(&b, false, false).unwrap();
+    let infinite = alpha
+        .iter()
+        .zip(beta.iter())
+        .filter(|(a, b)| b.norm() < 1e-9 * a.norm())
+        .count();
+    assert_eq!(infinite, 1);
+}
+
+#[test]
+fn eig_with_balance() {
+    let a: Array2<f64> = random((4, 4));
+    let ac = to_complex(&a);
+    for &balance in &[Balance::None, Balance::Permute, Balance::Scale, Balance::Both] {
+        let (e, vl, vr) = a.eig_with(balance, true, true).unwrap();
+        test_left(&ac, &e, &vl.unwrap());
+        test_right(&ac, &e, &vr.unwrap());
+    }
+}
+
+#[test]
+fn balance_scaling() {
+    // badly scaled similarity transform of a well-conditioned matrix
+    let d = from_diag(&[1.0, 1e4, 1e-4]);
+    let d_inv = from_diag(&[1.0, 1e-4, 1e4]);
+    let m: Array2<f64> = arr2(&[[1.0, 2.0, 0.5], [0.3, 2.0, 1.0], [0.7, 0.1, 3.0]]);
+    let a = d.dot(&m).dot(&d_inv);
+    let b = a.balance(Balance::Both).unwrap();
+    assert!(b.matrix.opnorm_one().unwrap() < 1e-2 * a.opnorm_one().unwrap());
+
+    let mut e: Vec<f64> = a.eigvals().unwrap().iter().map(|e| e.re).collect();
+    let mut eb: Vec<f64> = b.matrix.eigvals().unwrap().iter().map(|e| e.re).collect();
+    e.sort_by(|x, y| x.partial_cmp(y).unwrap());
+    eb.sort_by(|x, y| x.partial_cmp(y).unwrap());
+    assert_close_l2!(&arr1(&eb), &arr1(&e), 1e-7);
+}
+
+#[test]
+fn balance_back_transform() {
+    let a: Array2<c64> = random((4, 4));
+    let b = a.balance(Balance::Both).unwrap();
+    let (e, vl, vr) = b.matrix.eig_full(true, true).unwrap();
+    let (mut vl, mut vr) = (vl.unwrap(), vr.unwrap());
+    b.back_transform(&mut vl, true).unwrap();
+    b.back_transform(&mut vr, false).unwrap();
+    test_left(&a, &e, &vl);
+    test_right(&a, &e, &vr);
+}
+
+#[test]
+fn eig_sorted() {
+    let a: Array2<f64> = random((6, 6));
+    let a_c = to_complex(&a);
+    for &order in &[
+        EigOrder::Ascending,
+        EigOrder::Descending,
+        EigOrder::MagnitudeAscending,
+        EigOrder::MagnitudeDescending,
+    ] {
+        let (e, vecs) = a.eig_sorted(order).unwrap();
+        test_right(&a_c, &e, &vecs);
+        for i in 1..e.len() {
+            let (x, y) = (e[i - 1], e[i]);
+            match order {
+                EigOrder::Ascending => assert!(x.re <= y.re),
+                EigOrder::Descending => assert!(x.re >= y.re),
+                EigOrder::MagnitudeAscending => assert!(x.norm() <= y.norm() + 1e-12),
+                EigOrder::MagnitudeDescending => assert!(x.norm() + 1e-12 >= y.norm()),
+            }
+        }
+    }
+}
@@ -120,3 +120,330 @@ fn ssqrt_t_lower() {
     println!("ss = {:?}", &ss);
     assert_close_l2!(&ss, &ans, 1e-7);
 }
+
+#[test]
+fn ssqrt_complex() {
+    let a: Array2<c64> = random_hpd(3);
+    let s = a.ssqrt(UPLO::Upper).unwrap();
+    let sh: Array2<c64> = conjugate(&s);
+    assert_close_l2!(&sh, &s, 1e-7);
+    assert_close_l2!(&s.dot(&s), &a, 1e-7);
+    assert_close_l2!(&s, &a.sqrt_h(UPLO::Upper, None).unwrap(), 1e-7);
+}
+
+fn test_generalized(itype: EighGeneralizedType, uplo: UPLO) {
+    let a: Array2<f64> = random_hpd(4);
+    let b: Array2<f64> = random_hpd(4);
+    let (e, vecs) = a.eigh_generalized(&b, itype, uplo).unwrap();
+    for (i, v) in vecs.axis_iter(Axis(1)).enumerate() {
+        let (lhs, rhs) = match itype {
+            EighGeneralizedType::AxLambdaBx => (a.dot(&v), b.dot(&v).mapv(|x| e[i] * x)),
+            EighGeneralizedType::ABxLambdaX => (a.dot(&b.dot(&v)), v.mapv(|x| e[i] * x)),
+            EighGeneralizedType::BAxLambdaX => (b.dot(&a.dot(&v)), v.mapv(|x| e[i] * x)),
+        };
+        assert_close_l2!(&lhs, &rhs, 1.0e-7);
+    }
+    // Eigenvectors are B-orthonormal for type 1 and 2
+    if itype != EighGeneralizedType::BAxLambdaX {
+        let s = vecs.t().dot(&b).dot(&vecs);
+        assert_close_l2!(&s, &Array::eye(4), 1.0e-7);
+    }
+}
+
+#[test]
+fn eigh_generalized_type1() {
+    test_generalized(EighGeneralizedType::AxLambdaBx, UPLO::Upper);
+    test_generalized(EighGeneralizedType::AxLambdaBx, UPLO::Lower);
+}
+
+#[test]
+fn eigh_generalized_type2() {
+    test_generalized(EighGeneralizedType::ABxLambdaX, UPLO::Upper);
+    test_generalized(EighGeneralizedType::ABxLambdaX, UPLO::Lower);
+}
+
+#[test]
+fn eigh_generalized_type3() {
+    test_generalized(EighGeneralizedType::BAxLambdaX, UPLO::Upper);
+    test_generalized(EighGeneralizedType::BAxLambdaX, UPLO::Lower);
+}
+
+#[test]
+fn eigh_generalized_vibration() {
+    // Two masses connected by springs: K x = ω^2 M x
+    let k = arr2(&[[2.0, -1.0], [-1.0, 1.0]]);
+    let m = arr2(&[[2.0, 0.0], [0.0, 1.0]]);
+    let (e, _) = k
+        .eigh_generalized(&m, EighGeneralizedType::AxLambdaBx, UPLO::Upper)
+        .unwrap();
+    let s = 0.5_f64.sqrt();
+    assert_close_l2!(&e, &arr1(&[1.0 - s, 1.0 + s]), 1.0e-7);
+}
+
+#[test]
+fn eigh_range_index() {
+    let a: Array2<f64> = random_hermite(6);
+    let (e_all, _) = a.eigh(UPLO::Upper).unwrap();
+    let (e, vecs) = a.eigh_range(UPLO::Upper, EigRange::Index(1, 4)).unwrap();
+    assert_close_l2!(&e, &e_all.slice(s![1..4]), 1.0e-7);
+    assert_eq!(vecs.dim(), (6, 3));
+    for (i, v) in vecs.axis_iter(Axis(1)).enumerate() {
+        let av = a.dot(&v);
+        let ev = v.mapv(|x| e[i] * x);
+        assert_close_l2!(&av, &ev, 1.0e-7);
+    }
+}
+
+#[test]
+fn eigh_range_value() {
+    let a: Array2<c64> = random_hermite(6);
+    let e_all = a.eigvalsh(UPLO::Lower).unwrap();
+    // (e_all[1], e_all[4]] contains the 2nd to 4th eigenvalues
+    let range = EigRange::Value(e_all[1], e_all[4]);
+    let (e, vecs) = a.eigh_range(UPLO::Lower, range).unwrap();
+    assert_close_l2!(&e, &e_all.slice(s![2..5]), 1.0e-7);
+    for (i, v) in vecs.axis_iter(Axis(1)).enumerate() {
+        let av = a.dot(&v);
+        let ev = v.mapv(|x| x.mul_real(e[i]));
+        assert_close_l2!(&av, &ev, 1.0e-7);
+    }
+}
+
+#[test]
+fn eigvalsh_range() {
+    let a: Array2<f64> = random_hpd(5);
+    let e_all = a.eigvalsh(UPLO::Upper).unwrap();
+    let e = a.eigvalsh_range(UPLO::Upper, EigRange::Index(0, 2)).unwrap();
+    assert_close_l2!(&e, &e_all.slice(s![0..2]), 1.0e-7);
+    let e = a.eigvalsh_range(UPLO::Upper, EigRange::All).unwrap();
+    assert_close_l2!(&e, &e_all, 1.0e-7);
+    let e = a.eigvalsh_range(UPLO::Upper, EigRange::Index(3, 3)).unwrap();
+    assert_eq!(e.len(), 0);
+    assert!(a.eigvalsh_range(UPLO::Upper, EigRange::Index(3, 6)).is_err());
+}
+
+fn test_driver<A: Scalar + Lapack>(driver: EighDriver, uplo: UPLO) {
+    let a: Array2<A> = random_hermite(5);
+    let e_ev = a.eigvalsh(uplo).unwrap().mapv(A::from_real);
+    let (e, vecs) = a.eigh_with(uplo, driver).unwrap();
+    assert_close_l2!(&e.mapv(A::from_real), &e_ev, A::real(1.0e-7));
+    for (i, v) in vecs.axis_iter(Axis(1)).enumerate() {
+        let av = a.dot(&v);
+        let ev = v.mapv(|x| x.mul_real(e[i]));
+        assert_close_l2!(&av, &ev, A::real(1.0e-7));
+    }
+    let e = a.eigvalsh_with(uplo, driver).unwrap();
+    assert_close_l2!(&e.mapv(A::from_real), &e_ev, A::real(1.0e-7));
+}
+
+#[test]
+fn eigh_driver_real() {
+    for &driver in &[EighDriver::Ev, EighDriver::Evd, EighDriver::Evr, EighDriver::Evx] {
+        test_driver::<f64>(driver, UPLO::Upper);
+        test_driver::<f64>(driver, UPLO::Lower);
+    }
+}
+
+#[test]
+fn eigh_driver_complex() {
+    for &driver in &[EighDriver::Ev, EighDriver::Evd, EighDriver::Evr, EighDriver::Evx] {
+        test_driver::<c64>(driver, UPLO::Upper);
+        test_driver::<c64>(driver, UPLO::Lower);
+    }
+}
+
+#[test]
+fn eigvalsh() {
+    let a: Array2<f64> = random_hermite(5);
+    let (e, _) = a.eigh(UPLO::Upper).unwrap();
+    assert_close_l2!(&a.eigvalsh(UPLO::Upper).unwrap(), &e, 1.0e-7);
+    assert_close_l2!(&a.eigvalsh(UPLO::Lower).unwrap(), &e, 1.0e-7);
+}
+
+#[test]
+fn eigvalsh_t() {
+    let a: Array2<f64> = random_hermite(5).reversed_axes();
+    let (e, _) = a.eigh(UPLO::Upper).unwrap();
+    assert_close_l2!(&a.eigvalsh(UPLO::Upper).unwrap(), &e, 1.0e-7);
+    assert_close_l2!(&a.eigvalsh(UPLO::Lower).unwrap(), &e, 1.0e-7);
+}
+
+#[test]
+fn eigvalsh_complex() {
+    let a: Array2<c64> = random_hermite(5);
+    let (e, _) = a.eigh(UPLO::Upper).unwrap();
+    assert_close_l2!(&a.eigvalsh(UPLO::Upper).unwrap(), &e, 1.0e-7);
+}
+
+fn tridiagonal(d: &Array1<f64>, e: &Array1<f64>) -> Array2<f64> {
+    let n = d.len();
+    let mut a = from_diag(d.as_slice().unwrap());
+    for i in 0..n - 1 {
+        a[(i, i + 1)] = e[i];
+        a[(i + 1, i)] = e[i];
+    }
+    a
+}
+
+#[test]
+fn eigh_tridiagonal() {
+    let d: Array1<f64> = random(6);
+    let e: Array1<f64> = random(5);
+    let a = tridiagonal(&d, &e);
+    let (e_dense, _) = a.eigh(UPLO::Upper).unwrap();
+    let (w, vecs) = ndarray_linalg::eigh_tridiagonal(&d, &e).unwrap();
+    assert_close_l2!(&w, &e_dense, 1.0e-7);
+    for (i, v) in vecs.axis_iter(Axis(1)).enumerate() {
+        assert_close_l2!(&a.dot(&v), &(w[i] * &v), 1.0e-7);
+    }
+    let w = eigvalsh_tridiagonal(&d, &e).unwrap();
+    assert_close_l2!(&w, &e_dense, 1.0e-7);
+}
+
+#[test]
+fn eigh_tridiagonal_range() {
+    let d: Array1<f64> = random(6);
+    let e: Array1<f64> = random(5);
+    let a = tridiagonal(&d, &e);
+    let (e_dense, _) = a.eigh(UPLO::Upper).unwrap();
+    let (w, vecs) = ndarray_linalg::eigh_tridiagonal_range(&d, &e, EigRange::Index(1, 4)).unwrap();
+    assert_close_l2!(&w, &e_dense.slice(s![1..4]), 1.0e-7);
+    assert_eq!(vecs.dim(), (6, 3));
+    for (i, v) in vecs.axis_iter(Axis(1)).enumerate() {
+        assert_close_l2!(&a.dot(&v), &(w[i] * &v), 1.0e-7);
+    }
+    let (w, _) = ndarray_linalg::eigh_tridiagonal_range(&d, &e, EigRange::All).unwrap();
+    assert_close_l2!(&w, &e_dense, 1.0e-7);
+    assert!(ndarray_linalg::eigh_tridiagonal_range(&d, &e, EigRange::Index(3, 7)).is_err());
+}
+
+#[test]
+fn eigh_tridiagonal_shape() {
+    let d: Array1<f64> = random(4);
+    let e: Array1<f64> = random(4);
+    assert!(ndarray_linalg::eigh_tridiagonal(&d, &e).is_err());
+}
+
+#[test]
+fn eigh_sorted() {
+    let a = arr2(&[[3.0, 1.0, 1.0], [1.0, 3.0, 1.0], [1.0, 1.0, 3.0]]) - 3.5 * Array2::<f64>::eye(3);
+    // eigenvalues -1.5, -1.5, 1.5
+    let (e, vecs) = a.eigh_sorted(UPLO::Upper, EigOrder::Descending).unwrap();
+    assert_close_l2!(&e, &arr1(&[1.5, -1.5, -1.5]), 1e-9);
+    for (i, v) in vecs.axis_iter(Axis(1)).enumerate() {
+        assert_close_l2!(&a.dot(&v), &(e[i] * &v), 1e-9);
+    }
+    let (e, _) = a.eigh_sorted(UPLO::Upper, EigOrder::MagnitudeAscending).unwrap();
+    assert_close_l2!(&e, &arr1(&[-1.5, -1.5, 1.5]), 1e-9);
+    let (e, _) = a.eigh_sorted(UPLO::Upper, EigOrder::Ascending).unwrap();
+    assert_close_l2!(&e, &arr1(&[-1.5, -1.5, 1.5]), 1e-9);
+}
+
+#[test]
+fn eigh_stack() {
+    let mut a: Array3<c64> = Array3::zeros((4, 3, 3));
+    for mut a_i in a.outer_iter_mut() {
+        a_i.assign(&random_hermite::<c64, OwnedRepr<c64>>(3));
+    }
+    let (e, v) = a.eigh(UPLO::Upper).unwrap();
+    assert_eq!(e.dim(), (4, 3));
+    assert_eq!(v.dim(), (4, 3, 3));
+    for ((a_i, e_i), v_i) in a.outer_iter().zip(e.outer_iter()).zip(v.outer_iter()) {
+        let (e_ans, _) = a_i.eigh(UPLO::Upper).unwrap();
+        assert_close_l2!(&e_i, &e_ans, 1e-7);
+        for (j, v) in v_i.axis_iter(Axis(1)).enumerate() {
+            let ev = v.mapv(|x| x * e_i[j]);
+            assert_close_l2!(&a_i.dot(&v), &ev, 1e-7);
+        }
+    }
+    assert_close_l2!(&a.eigvalsh(UPLO::Upper).unwrap(), &e, 1e-7);
+    let (e_into, v_into) = a.eigh_into(UPLO::Upper).unwrap();
+    assert_close_l2!(&e_into, &e, 1e-7);
+    assert_eq!(v_into.dim(), (4, 3, 3));
+}
+
+#[test]
+fn eigh_map() {
+    let a: Array2<f64> = random_hpd(4);
+    let a2 = a.eigh_map(UPLO::Upper, |x| x * x).unwrap();
+    assert_close_l2!(&a2, &a.dot(&a), 1e-7);
+    let inv = a.eigh_map(UPLO::Lower, |x| 1.0 / x).unwrap();
+    assert_close_l2!(&inv.dot(&a), &Array2::eye(4), 1e-7);
+    // exp(log(A)) = A
+    let log = a.eigh_map(UPLO::Upper, f64::ln).unwrap();
+    let exp = log.eigh_map_into(UPLO::Upper, f64::exp).unwrap();
+    assert_close_l2!(&exp, &a, 1e-7);
+}
+
+#[test]
+fn eigh_map_complex() {
+    let a: Array2<c64> = random_hpd(4);
+    let a2 = a.eigh_map(UPLO::Upper, |x| x * x).unwrap();
+    assert_close_l2!(&a2, &a.dot(&a), 1e-7);
+}
+
+#[test]
+fn sqrt_h() {
+    let a: Array2<f64> = random_hpd(5);
+    let s = a.sqrt_h(UPLO::Upper, None).unwrap();
+    assert_close_l2!(&s.dot(&s), &a, 1e-7);
+    let s_inv = a.inv_sqrt_h(UPLO::Upper, None).unwrap();
+    assert_close_l2!(&s_inv.dot(&a).dot(&s_inv), &Array2::eye(5), 1e-7);
+}
+
+#[test]
+fn sqrt_h_complex() {
+    let a: Array2<c64> = random_hpd(5);
+    let s = a.sqrt_h(UPLO::Lower, None).unwrap();
+    assert_close_l2!(&s.dot(&s), &a, 1e-7);
+    let s_inv = a.inv_sqrt_h(UPLO::Lower, None).unwrap();
+    assert_close_l2!(&s_inv.dot(&s), &Array2::eye(5), 1e-7);
+}
+
+#[test]
+fn sqrt_h_semidefinite() {
+    // rank 2 projection-like matrix
+    let v: Array2<f64> = random((5, 2));
+    let a = v.dot(&v.t());
+    let s = a.sqrt_h(UPLO::Upper, None).unwrap();
+    assert_close_l2!(&s.dot(&s), &a, 1e-7);
+    // pseudo-inverse square root: A^{-1/2} A A^{-1/2} is the projection onto the range of A
+    let s_inv = a.inv_sqrt_h(UPLO::Upper, Some(1e-9)).unwrap();
+    let p = s_inv.dot(&a).dot(&s_inv);
+    assert_close_l2!(&p.dot(&p), &p, 1e-7);
+    assert_close_l2!(&p.dot(&v), &v, 1e-7);
+}
+
+fn test_inplace<A: Scalar + Lapack>(a: Array2<A>, uplo: UPLO) {
+    let n = a.rows();
+    // Only the `uplo` triangle is read
+    let mut b = a.clone();
+    for i in 0..n {
+        for j in 0..n {
+            let garbage = match uplo {
+                UPLO::Upper => i > j,
+                UPLO::Lower => i < j,
+            };
+            if garbage {
+                b[(i, j)] = A::from_real(A::real(100.0));
+            }
+        }
+    }
+    let (e, v) = b.eigh_inplace(uplo).unwrap();
+    let e_ev = a.eigvalsh(uplo).unwrap().mapv(A::from_real);
+    assert_close_l2!(&e.mapv(A::from_real), &e_ev, A::real(1e-7));
+    for (i, v) in v.axis_iter(Axis(1)).enumerate() {
+        let ev = v.mapv(|x| x.mul_real(e[i]));
+        assert_close_l2!(&a.dot(&v), &ev, A::real(1e-7));
+    }
+}
+
+#[test]
+fn eigh_inplace() {
+    for &uplo in &[UPLO::Upper, UPLO::Lower] {
+        test_inplace::<f64>(random_hermite(4), uplo);
+        test_inplace::<f64>(random_hermite::<f64, OwnedRepr<f64>>(4).reversed_axes(), uplo);
+        test_inplace::<c64>(random_hermite(4), uplo);
+        test_inplace::<c64>(random_hermite::<c64, OwnedRepr<c64>>(4).reversed_axes(), uplo);
+    }
+}
@@ -0,0 +1,157 @@
+use ndarray::*;
+use ndarray_linalg::*;
+
+/// exp(A) = V exp(D) V^H for a Hermitian matrix A = V D V^H
+fn expm_hermite<A: Scalar + Lapack>(a: &Array2<A>) -> Array2<A> {
+    let (e, v) = a.eigh(UPLO::Upper).unwrap();
+    let ve = Array2::from_shape_fn(v.dim(), |(i, j)| v[(i, j)].mul_real(e[j].exp()));
+    let vh: Array2<A> = conjugate(&v);
+    ve.dot(&vh)
+}
+
+#[test]
+fn expm_zero() {
+    let a: Array2<f64> = Array2::zeros((3, 3));
+    assert_close_l2!(&a.expm().unwrap(), &Array2::eye(3), 1e-15);
+}
+
+#[test]
+fn expm_nilpotent() {
+    let a: Array2<f64> = array![[0., 1., 2.], [0., 0., 3.], [0., 0., 0.]];
+    // I + A + A^2 / 2
+    let ans = array![[1., 1., 3.5], [0., 1., 3.], [0., 0., 1.]];
+    assert_close_l2!(&a.expm().unwrap(), &ans, 1e-12);
+}
+
+#[test]
+fn expm_symmetric() {
+    // Cover all degrees of the Padé approximants and the scaling
+    for &scale in &[1e-3, 0.1, 0.5, 1.0, 2.0, 10.0, 50.0] {
+        let a: Array2<f64> = random_hermite(5);
+        let a = a.mapv(|x| x * scale);
+        assert_close_l2!(&a.expm().unwrap(), &expm_hermite(&a), 1e-10);
+    }
+}
+
+#[test]
+fn expm_complex() {
+    let h: Array2<c64> = random_hermite(4);
+    assert_close_l2!(&h.expm().unwrap(), &expm_hermite(&h), 1e-10);
+    // exp(iH) is unitary
+    let u = h.mapv(|x| x * c64::new(0., 3.)).expm().unwrap();
+    let uh: Array2<c64> = conjugate(&u);
+    assert_close_l2!(&uh.dot(&u), &Array2::eye(4), 1e-10);
+}
+
+#[test]
+fn expm_inverse() {
+    let a: Array2<f64> = random((6, 6));
+    let a = a.mapv(|x| x * 5.0);
+    let ea = a.expm().unwrap();
+    let ea_inv = a.mapv(|x| -x).expm().unwrap();
+    assert_close_l2!(&ea.dot(&ea_inv), &Array2::eye(6), 1e-8);
+}
+
+#[test]
+fn expm_f32() {
+    let a: Array2<f32> = random_hermite(4);
+    let a = a.mapv(|x| x * 3.0);
+    assert_close_l2!(&a.expm().unwrap(), &expm_hermite(&a), 1e-4);
+}
+
+#[test]
+fn expm_not_square() {
+    let a: Array2<f64> = random((3, 4));
+    assert!(a.expm().is_err());
+}
+
+#[test]
+fn expm_infinite() {
+    let a: Array2<f64> = array![[0., ::std::f64::INFINITY], [0., 0.]];
+    assert!(a.expm().unwrap().iter().all(|x| x.is_nan()));
+}
+
+#[test]
+fn expm_multiply_vector() {
+    for &t in &[0.0, 0.1, 1.0, 10.0] {
+        let a: Array2<f64> = random((8, 8));
+        let b: Array1<f64> = random(8);
+        let ans = a.mapv(|x| x * t).expm().unwrap().dot(&b);
+        assert_close_l2!(&expm_multiply(&a, &b, t).unwrap(), &ans, 1e-10);
+    }
+}
+
+#[test]
+fn expm_multiply_matrix() {
+    let a: Array2<c64> = random((6, 6));
+    let b: Array2<c64> = random((6, 3).f());
+    let ans = a.mapv(|x| x * 2.0).expm().unwrap().dot(&b);
+    assert_close_l2!(&expm_multiply(&a, &b, 2.0).unwrap(), &ans, 1e-10);
+}
+
+#[test]
+fn expm_multiply_shifted() {
+    // The trace is removed before the propagation
+    let a: Array2<f64> = Array2::eye(4) * 30.0 + random_hermite::<f64, OwnedRepr<f64>>(4);
+    let b: Array1<f64> = random(4);
+    let ans = expm_hermite(&a).dot(&b);
+    assert_close_l2!(&expm_multiply(&a, &b, 1.0).unwrap(), &ans, 1e-10);
+}
+
+#[test]
+fn expm_multiply_shape_mismatch() {
+    let a: Array2<f64> = random((4, 4));
+    let b: Array1<f64> = random(3);
+    assert!(expm_multiply(&a, &b, 1.0).is_err());
+}
+
+#[test]
+fn expm_multiply_infinite() {
+    let a: Array2<f64> = array![[0., ::std::f64::INFINITY], [0., 0.]];
+    let b: Array1<f64> = random(2);
+    assert!(expm_multiply(&a, &b, 1.0).unwrap().iter().all(|x| x.is_nan()));
+}
+
+#[test]
+fn expm_frechet_commuting() {
+    // L(A, A) = A exp(A)
+    let a: Array2<f64> = random((5, 5));
+    let (ea, l) = expm_frechet(&a, &a).unwrap();
+    assert_close_l2!(&ea, &a.expm().unwrap(), 1e-12);
+    assert_close_l2!(&l, &a.dot(&ea), 1e-10);
+}
+
+#[test]
+fn expm_frechet_finite_difference() {
+    let a: Array2<c64> = random((4, 4));
+    let e: Array2<c64> = random((4, 4));
+    let h = 1e-5;
+    let (_, l) = expm_frechet(&a, &e).unwrap();
+    let ap = &a + &e.mapv(|x| x * h);
+    let am = &a - &e.mapv(|x| x * h);
+    let fd = (ap.expm().unwrap() - am.expm().unwrap()).mapv(|x| x / (2.0 * h));
+    assert_close_l2!(&l, &fd, 1e-6);
+}
+
+#[test]
+fn expm_frechet_shape_mismatch() {
+    let a: Array2<f64> = random((4, 4));
+    let e: Array2<f64> = random((3, 3));
+    assert!(expm_frechet(&a, &e).is_err());
+}
+
+#[test]
+fn expm_cond_scalar() {
+    // κ(a) = |a| for 1x1 matrices
+    let a = array![[-3.0_f64]];
+    assert_rclose!(expm_cond(&a).unwrap(), 3.0, 1e-12);
+}
+
+#[test]
+fn expm_cond_diagonal() {
+    // L(A, E)_ij = E_ij (e^a_i - e^a_j) / (a_i - a_j) for diagonal A, whose largest value is e^2 here
+    let a: Array2<f64> = from_diag(&[1.0, 2.0]);
+    let norm_a = 5.0_f64.sqrt();
+    let norm_ea = (1.0_f64.exp().powi(2) + 2.0_f64.exp().powi(2)).sqrt();
+    assert_rclose!(expm_cond(&a).unwrap(), 2.0_f64.exp() * norm_a / norm_ea, 1e-10);
+}
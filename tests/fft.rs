@@ -0,0 +1,44 @@
+use ndarray::*;
+use ndarray_linalg::*;
+use std::f64::consts::PI;
+
+/// DFT by the definition in `O(n^2)`
+fn dft(x: &Array1<c64>) -> Array1<c64> {
+    let n = x.len();
+    Array1::from_shape_fn(n, |k| {
+        x.iter().enumerate().fold(c64::new(0.0, 0.0), |acc, (j, &xj)| {
+            acc + xj * c64::from_polar(&1.0, &(-2.0 * PI * (j * k) as f64 / n as f64))
+        })
+    })
+}
+
+#[test]
+fn fft_power_of_two() {
+    let x: Array1<c64> = random(16);
+    assert_close_l2!(&c64::fft(&x), &dft(&x), 1e-12);
+}
+
+#[test]
+fn fft_bluestein() {
+    for &n in &[3, 7, 12, 25] {
+        let x: Array1<c64> = random(n);
+        assert_close_l2!(&c64::fft(&x), &dft(&x), 1e-12);
+    }
+}
+
+#[test]
+fn fft_roundtrip() {
+    for &n in &[1, 8, 9] {
+        let x: Array1<c64> = random(n);
+        assert_close_l2!(&c64::ifft(&c64::fft(&x)), &x, 1e-12);
+        let y: Array1<f64> = random(n);
+        assert_close_l2!(&f64::ifft(&f64::fft(&y)), &y, 1e-12);
+    }
+}
+
+#[test]
+fn fft_real() {
+    let x: Array1<f64> = random(10);
+    let xc = x.mapv(|x| c64::new(x, 0.0));
+    assert_close_l2!(&f64::fft(&x), &dft(&xc), 1e-12);
+}
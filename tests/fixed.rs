@@ -0,0 +1,69 @@
+use ndarray::*;
+use ndarray_linalg::error::LinalgError;
+use ndarray_linalg::*;
+
+fn test_lu<A: Scalar + Lapack>(rtol: A::Real) {
+    let a: Array2<A> = random_regular(4);
+    let f: [[A; 4]; 4] = to_fixed(&a).unwrap();
+    assert_eq!(from_fixed(&f), a);
+    assert_rclose!(f.det_fixed(), a.det().unwrap(), rtol);
+    assert_close_l2!(&from_fixed(&f.inv_fixed().unwrap()), &a.inv().unwrap(), rtol);
+    let b: Array1<A> = random(4);
+    let x = f.solve_fixed(&[b[0], b[1], b[2], b[3]]).unwrap();
+    assert_close_l2!(&arr1(&x), &a.solve(&b).unwrap(), rtol);
+}
+
+fn test_cholesky<A: Scalar + Lapack>(rtol: A::Real) {
+    let a: Array2<A> = random_hpd(6);
+    let f: [[A; 6]; 6] = to_fixed(&a).unwrap();
+    for &uplo in &[UPLO::Lower, UPLO::Upper] {
+        assert_close_l2!(
+            &from_fixed(&f.cholesky_fixed(uplo).unwrap()),
+            &a.cholesky(uplo).unwrap(),
+            rtol
+        );
+    }
+    let b: Array1<A> = random(6);
+    let mut bf = [A::zero(); 6];
+    bf.copy_from_slice(b.as_slice().unwrap());
+    let x = f.solvec_fixed(&bf).unwrap();
+    assert_close_l2!(&arr1(&x), &a.solvec(&b).unwrap(), rtol);
+}
+
+#[test]
+fn fixed_lu_f64() {
+    test_lu::<f64>(1e-9);
+}
+
+#[test]
+fn fixed_lu_c64() {
+    test_lu::<c64>(1e-9);
+}
+
+#[test]
+fn fixed_cholesky_f64() {
+    test_cholesky::<f64>(1e-9);
+}
+
+#[test]
+fn fixed_cholesky_c64() {
+    test_cholesky::<c64>(1e-9);
+}
+
+#[test]
+fn fixed_singular() {
+    let a = [[1.0, 2.0], [2.0, 4.0]];
+    assert_eq!(a.det_fixed(), 0.0);
+    match a.inv_fixed() {
+        Err(LinalgError::Lapack { return_code: 2 }) => {}
+        res => panic!("unexpected: {:?}", res),
+    }
+    // not positive definite
+    assert!([[1.0, 2.0], [2.0, 1.0]].cholesky_fixed(UPLO::Lower).is_err());
+}
+
+#[test]
+fn fixed_shape() {
+    let a = Array2::<f64>::eye(3);
+    assert!(to_fixed::<_, _, 4>(&a).is_err());
+}
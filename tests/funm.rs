@@ -0,0 +1,55 @@
+use ndarray::*;
+use ndarray_linalg::*;
+
+#[test]
+fn funm_exp() {
+    let a: Array2<f64> = random((6, 6));
+    let f = a.funm(|z: c64, _| z.exp()).unwrap();
+    assert_close_l2!(&f, &a.expm().unwrap().mapv(|x| x.as_c()), 1e-9);
+}
+
+#[test]
+fn funm_sqrt() {
+    // sqrt'(z) = 1 / (2 sqrt(z)), only the first derivative is used for the Jordan block of size 2
+    let a: Array2<f64> = array![[4., 1., 0.], [0., 4., 0.], [0., 0., 9.]];
+    let f = a
+        .funm(|z: c64, k| match k {
+            0 => z.sqrt(),
+            1 => 0.5 / z.sqrt(),
+            2 => -0.25 / (z * z.sqrt()),
+            _ => c64::new(0., 0.),
+        })
+        .unwrap();
+    assert_close_l2!(&f, &a.sqrtm().unwrap(), 1e-12);
+}
+
+#[test]
+fn funm_clustered() {
+    // Eigenvalues 1, 1.01, 1.02 form a single cluster evaluated by the Taylor series
+    let a: Array2<c64> = array![[1., 2., 3.], [0., 1.01, 4.], [0., 0., 1.02]].mapv(|x: f64| x.as_c());
+    let f = a.funm(|z: c64, _| z.exp()).unwrap();
+    assert_close_l2!(&f, &a.expm().unwrap(), 1e-10);
+}
+
+#[test]
+fn funm_polynomial() {
+    // f(z) = z^3 - 2z
+    let a: Array2<c64> = random((5, 5));
+    let f = a
+        .funm(|z: c64, k| match k {
+            0 => z * z * z - 2. * z,
+            1 => 3. * z * z - 2.,
+            2 => 6. * z,
+            3 => c64::new(6., 0.),
+            _ => c64::new(0., 0.),
+        })
+        .unwrap();
+    let ans = a.dot(&a).dot(&a) - a.mapv(|x| 2. * x);
+    assert_close_l2!(&f, &ans, 1e-10);
+}
+
+#[test]
+fn funm_not_square() {
+    let a: Array2<f64> = random((3, 4));
+    assert!(a.funm(|z: c64, _| z.exp()).is_err());
+}
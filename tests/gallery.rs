@@ -0,0 +1,66 @@
+use ndarray::*;
+use ndarray_linalg::*;
+
+#[test]
+fn hilbert_ill_conditioned() {
+    let h: Array2<f64> = gallery::hilbert(6);
+    assert_eq!(h, h.t());
+    // cond_2(H_6) = 1.495e7
+    assert_rclose!(h.cond(CondNorm::Two).unwrap(), 1.495e7, 1e-3);
+}
+
+#[test]
+fn pascal_cholesky() {
+    let p: Array2<f64> = gallery::pascal(6);
+    // The Cholesky factor is the lower triangular matrix of the binomial coefficients
+    let l = p.cholesky(UPLO::Lower).unwrap();
+    for ((i, j), &x) in l.indexed_iter() {
+        let binom = if j <= i {
+            (0..j).fold(1.0, |b, k| b * (i - k) as f64 / (k + 1) as f64)
+        } else {
+            0.0
+        };
+        assert_aclose!(x, binom, 1e-10);
+    }
+    assert_rclose!(p.det().unwrap(), 1.0, 1e-8);
+}
+
+#[test]
+fn wilkinson_close_eigenvalues() {
+    let w: Array2<f64> = gallery::wilkinson(21);
+    let e = w.eigvalsh(UPLO::Lower).unwrap();
+    assert_rclose!(e[20], 10.746194182903393, 1e-12);
+    assert!(e[20] - e[19] < 1e-12);
+}
+
+#[test]
+fn frank_det() {
+    let f: Array2<f64> = gallery::frank(5);
+    assert_eq!(f[(0, 0)], 5.0);
+    assert_eq!(f[(3, 1)], 0.0);
+    assert_eq!(f[(2, 1)], 3.0);
+    assert_rclose!(f.det().unwrap(), 1.0, 1e-10);
+}
+
+#[test]
+fn lehmer_tridiagonal_inverse() {
+    let l: Array2<f64> = gallery::lehmer(5);
+    let inv = l.inv().unwrap();
+    for ((i, j), &x) in inv.indexed_iter() {
+        if i > j + 1 || j > i + 1 {
+            assert_aclose!(x, 0.0, 1e-10);
+        }
+    }
+}
+
+#[test]
+fn kahan_triangular() {
+    let k: Array2<f64> = gallery::kahan(20, 1.2);
+    let s = 1.2f64.sin();
+    assert_aclose!(k[(19, 19)], s.powi(19), 1e-15);
+    assert_aclose!(k[(3, 1)], 0.0, 0.0);
+    assert_aclose!(k[(1, 3)], -1.2f64.cos() * s, 1e-15);
+    // The last diagonal element is much larger than the smallest singular value
+    let (_, sv, _) = k.svd(false, false).unwrap();
+    assert!(sv[19] < k[(19, 19)] * 1e-2);
+}
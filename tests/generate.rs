@@ -0,0 +1,183 @@
+use ndarray::*;
+use ndarray_linalg::*;
+
+#[test]
+fn random_seeded() {
+    let a: Array2<c64> = random_using((4, 3), &mut seeded_rng(1));
+    let b: Array2<c64> = random_using((4, 3), &mut seeded_rng(1));
+    let c: Array2<c64> = random_using((4, 3), &mut seeded_rng(2));
+    assert_eq!(a, b);
+    assert_ne!(a, c);
+}
+
+#[test]
+fn random_hpd_seeded() {
+    let mut rng = seeded_rng(3);
+    let a: Array2<f64> = random_hpd_using(4, &mut rng);
+    let b: Array2<f64> = random_hpd_using(4, &mut rng);
+    assert_ne!(a, b);
+    let mut rng = seeded_rng(3);
+    let c: Array2<f64> = random_hpd_using(4, &mut rng);
+    assert_eq!(a, c);
+}
+
+#[test]
+fn random_unitary_seeded() {
+    let q: Array2<c64> = random_unitary_using(4, &mut seeded_rng(4));
+    let qh: Array2<c64> = conjugate(&q);
+    assert_close_l2!(&qh.dot(&q), &Array2::eye(4), 1e-7);
+    assert_eq!(q, random_unitary_using(4, &mut seeded_rng(4)));
+}
+
+#[test]
+fn random_orthogonal_haar() {
+    let mut rng = seeded_rng(5);
+    let n_samples = 2000;
+    let mut mean = 0.0;
+    for _ in 0..n_samples {
+        let q: Array2<f64> = random_orthogonal_using(2, &mut rng);
+        assert_close_l2!(&q.t().dot(&q), &Array2::eye(2), 1e-12);
+        mean += q[(0, 0)] / n_samples as f64;
+    }
+    // Haar-distributed elements are symmetric around zero
+    assert!(mean.abs() < 0.1, "mean = {}", mean);
+}
+
+#[test]
+fn random_unitary_haar() {
+    let mut rng = seeded_rng(6);
+    let n_samples = 2000;
+    let mut mean = c64::new(0.0, 0.0);
+    for _ in 0..n_samples {
+        let q: Array2<c64> = random_unitary_using(3, &mut rng);
+        mean += q[(1, 1)] / n_samples as f64;
+    }
+    assert!(mean.norm() < 0.1, "mean = {}", mean);
+}
+
+#[test]
+fn random_with_singular_values_exact() {
+    let s = [5.0, 2.0, 0.5, 0.1];
+    let a: Array2<c64> = random_with_singular_values(&s);
+    let (_, sv, _) = a.svd(false, false).unwrap();
+    assert_close_l2!(&sv, &arr1(&s), 1e-12);
+}
+
+#[test]
+fn random_with_cond_rect() {
+    for &(m, n) in &[(6, 4), (3, 5), (4, 4)] {
+        let a: Array2<f64> = random_with_cond_using(m, n, 1e6, &mut seeded_rng(7));
+        assert_eq!(a.dim(), (m, n));
+        assert_rclose!(a.opnorm_two().unwrap(), 1.0, 1e-10);
+        assert_rclose!(a.cond(CondNorm::Two).unwrap(), 1e6, 1e-6);
+    }
+}
+
+#[test]
+fn random_correlation_valid() {
+    for n in 0..6 {
+        let c: Array2<f64> = random_correlation(n);
+        assert_eq!(c.dim(), (n, n));
+        assert_eq!(c.diag(), Array1::<f64>::ones(n));
+        assert_close_max!(&c, &c.t(), 1e-15);
+        if n > 0 {
+            assert!(c.eigvalsh(UPLO::Lower).unwrap()[0] > -1e-12);
+        }
+    }
+}
+
+#[test]
+fn random_correlation_marginal() {
+    // Off-diagonal elements of the uniform correlation matrices follow `2 Beta(n/2, n/2) - 1`
+    let n = 4;
+    let n_samples = 2000;
+    let mut rng = seeded_rng(8);
+    let (mut mean, mut var) = (0.0, 0.0);
+    for _ in 0..n_samples {
+        let c: Array2<f64> = random_correlation_using(n, &mut rng);
+        mean += c[(3, 0)] / n_samples as f64;
+        var += c[(3, 0)] * c[(3, 0)] / n_samples as f64;
+    }
+    assert!(mean.abs() < 0.05, "mean = {}", mean);
+    assert!((var - 1.0 / (n + 1) as f64).abs() < 0.03, "var = {}", var);
+}
+
+#[test]
+fn random_banded_structure() {
+    let a: BandedMatrix<f64> = random_banded(6, 2, 1);
+    let d = a.to_dense();
+    for ((i, j), &x) in d.indexed_iter() {
+        assert_eq!(x != 0.0, i <= j + 2 && j <= i + 1);
+    }
+}
+
+#[test]
+fn random_tridiagonal_structure() {
+    let a: Tridiagonal<c64> = random_tridiagonal_using(5, &mut seeded_rng(9));
+    assert_eq!(a.size(), 5);
+    let d = a.to_dense();
+    assert_eq!(Tridiagonal::from_dense(&d).unwrap().to_dense(), d);
+    let a: Tridiagonal<f64> = random_tridiagonal(1);
+    assert_eq!(a.size(), 1);
+}
+
+#[test]
+fn random_triangular_structure() {
+    let a: Array2<f64> = random_triangular(5, UPLO::Lower);
+    for ((i, j), &x) in a.indexed_iter() {
+        if i < j {
+            assert_eq!(x, 0.0);
+        }
+        if i == j {
+            assert!(x >= 1.0);
+        }
+    }
+}
+
+#[test]
+fn random_diagonally_dominant_rows() {
+    let a: Array2<c64> = random_diagonally_dominant(5);
+    for (i, row) in a.outer_iter().enumerate() {
+        let off: f64 = row.iter().map(|x| x.norm()).sum::<f64>() - row[i].norm();
+        assert!(row[i].norm() > off);
+    }
+}
+
+#[test]
+fn random_hermitian_with_eigs_exact() {
+    let eigs = [-3.0, 0.5, 1.0, 1.0 + 1e-8, 7.0];
+    let a: Array2<c64> = random_hermitian_with_eigs(&eigs);
+    let ah: Array2<c64> = conjugate(&a);
+    assert_eq!(a, ah);
+    let e = a.eigvalsh(UPLO::Upper).unwrap();
+    assert_close_max!(&e, &arr1(&eigs), 1e-12);
+}
+
+#[test]
+fn random_with_normal() {
+    let mut rng = seeded_rng(10);
+    let a: Array1<c64> = random_with_using(10000, &Normal::new(c64::new(1.0, -2.0), 3.0), &mut rng);
+    let mean = a.sum() / 10000.0;
+    let var = a.iter().map(|x| (x - mean).norm_sqr()).sum::<f64>() / 10000.0;
+    assert!((mean - c64::new(1.0, -2.0)).norm() < 0.1, "mean = {}", mean);
+    assert!((var - 9.0).abs() < 0.5, "var = {}", var);
+    let re_var = a.iter().map(|x| (x.re - mean.re).powi(2)).sum::<f64>() / 10000.0;
+    assert!((re_var - 4.5).abs() < 0.3, "var of real part = {}", re_var);
+}
+
+#[test]
+fn random_with_uniform() {
+    let a: Array2<c32> = random_with((20, 20), &Uniform::new(-2.0, -1.0));
+    assert!(a
+        .iter()
+        .all(|x| x.re >= -2.0 && x.re < -1.0 && x.im >= -2.0 && x.im < -1.0));
+    let b: Array1<f64> = random_with_using(10000, &Uniform::new(0.0, 4.0), &mut seeded_rng(11));
+    assert!((b.sum() / 10000.0 - 2.0).abs() < 0.05);
+}
+
+#[test]
+fn random_hpd_with_normal() {
+    let a: Array2<f64> = random_hpd_with(5, &Normal::new(0.0, 10.0));
+    assert_close_max!(&a, &a.t(), 1e-10);
+    assert!(a.eigvalsh(UPLO::Lower).unwrap()[0] > 1.0 - 1e-8);
+}
@@ -0,0 +1,53 @@
+use ndarray::*;
+use ndarray_linalg::*;
+
+#[test]
+fn geometric_mean_riccati() {
+    // G A^{-1} G = B
+    let a: Array2<f64> = random_hpd(5);
+    let b: Array2<f64> = random_hpd(5);
+    let g = spd_geometric_mean(&a, &b).unwrap();
+    let ainv_g = a.inv().unwrap().dot(&g);
+    assert_close_l2!(&g.dot(&ainv_g), &b, 1e-9);
+    assert_close_l2!(&g, &spd_geometric_mean(&b, &a).unwrap(), 1e-9);
+}
+
+#[test]
+fn geometric_mean_complex() {
+    let a: Array2<c64> = random_hpd(4);
+    let b: Array2<c64> = random_hpd(4);
+    let g = spd_geometric_mean(&a, &b).unwrap();
+    let gh: Array2<c64> = conjugate(&g);
+    assert_close_l2!(&g, &gh, 1e-10);
+    let ainv_g = a.inv().unwrap().dot(&g);
+    assert_close_l2!(&g.dot(&ainv_g), &b, 1e-9);
+}
+
+#[test]
+fn geodesic_end_points() {
+    let a: Array2<f64> = random_hpd(4);
+    let b: Array2<f64> = random_hpd(4);
+    assert_close_l2!(&spd_geodesic(&a, &b, 0.0).unwrap(), &a, 1e-10);
+    assert_close_l2!(&spd_geodesic(&a, &b, 1.0).unwrap(), &b, 1e-10);
+}
+
+#[test]
+fn geodesic_commuting() {
+    // A #_t B = A^{1-t} B^t for commuting A and B
+    let a: Array2<f64> = from_diag(&[1.0, 2.0, 3.0]);
+    let b: Array2<f64> = from_diag(&[4.0, 1.0, 0.5]);
+    let t = 0.3_f64;
+    let ans: Array2<f64> = from_diag(&[
+        4.0_f64.powf(t),
+        2.0_f64.powf(1.0 - t),
+        3.0_f64.powf(1.0 - t) * 0.5_f64.powf(t),
+    ]);
+    assert_close_l2!(&spd_geodesic(&a, &b, t).unwrap(), &ans, 1e-12);
+}
+
+#[test]
+fn geodesic_shape_mismatch() {
+    let a: Array2<f64> = random_hpd(3);
+    let b: Array2<f64> = random_hpd(4);
+    assert!(spd_geodesic(&a, &b, 0.5).is_err());
+}
@@ -0,0 +1,146 @@
+use ndarray::*;
+use ndarray_linalg::{krylov::*, *};
+
+/// Random matrix whose eigenvalues are clustered around 3
+fn random_shifted<A: Scalar>(n: usize) -> Array2<A> {
+    let scale = A::real(n as f64).sqrt();
+    let mut a: Array2<A> = random((n, n));
+    a.mapv_inplace(|x| x.div_real(scale));
+    for d in a.diag_mut() {
+        *d = *d + A::from_real(A::real(3.0));
+    }
+    a
+}
+
+fn test<A: Scalar + Lapack>(restart: usize) {
+    let n = 30;
+    let a: Array2<A> = random_shifted(n);
+    let x: Array1<A> = random(n);
+    let b = a.dot(&x);
+    let sol = gmres(
+        &a,
+        &IdentityOperator::new(),
+        &b,
+        &Array1::zeros(n),
+        &SolverOptions::new()
+            .rtol(A::real(1e-12))
+            .max_iter(100 * n)
+            .restart(restart),
+    )
+    .unwrap();
+    assert!(sol.report.converged);
+    assert_close_l2!(&a.dot(&sol.x), &b, A::real(1e-10));
+}
+
+#[test]
+fn gmres_full() {
+    test::<f64>(30);
+    test::<c64>(30);
+}
+
+#[test]
+fn gmres_restarted() {
+    test::<f64>(10);
+    test::<c64>(10);
+}
+
+/// Convection-diffusion `-u'' + c u'` by the upwind difference, which is non-symmetric
+fn convection_diffusion(n: usize, c: f64) -> Tridiagonal<f64> {
+    let h = 1.0 / (n + 1) as f64;
+    let dl = Array1::from_elem(n - 1, -1.0 / (h * h) - c / h);
+    let d = Array1::from_elem(n, 2.0 / (h * h) + c / h);
+    let du = Array1::from_elem(n - 1, -1.0 / (h * h));
+    Tridiagonal::new(dl, d, du).unwrap()
+}
+
+#[test]
+fn gmres_preconditioned() {
+    let n = 100;
+    let a = convection_diffusion(n, 50.0);
+    let b = Array1::ones(n);
+    let options = SolverOptions::new().rtol(1e-10).max_iter(100).restart(20);
+    // The exact inverse as the preconditioner converges in one iteration
+    let m = a.to_dense().factorize_into().unwrap();
+    let sol = gmres(&a, &m, &b, &Array1::zeros(n), &options).unwrap();
+    assert!(sol.report.converged);
+    assert_eq!(sol.report.iterations, 1);
+    assert_close_l2!(&sol.x, &a.solve(&b).unwrap(), 1e-8);
+}
+
+#[test]
+fn gmres_history() {
+    let n = 30;
+    let a: Array2<f64> = random_regular(n);
+    let b: Array1<f64> = random(n);
+    let sol = gmres(
+        &a,
+        &IdentityOperator::new(),
+        &b,
+        &Array1::zeros(n),
+        &SolverOptions::new().rtol(1e-20).max_iter(5).restart(n),
+    )
+    .unwrap();
+    assert!(!sol.report.converged);
+    assert_eq!(sol.report.iterations, 5);
+    assert_eq!(sol.report.history[0], 1.0);
+    // The residual norm is non-increasing within a cycle
+    for w in sol.report.history.windows(2) {
+        assert!(w[1] <= w[0] * (1.0 + 1e-12));
+    }
+    assert!(gmres(
+        &a,
+        &IdentityOperator::new(),
+        &b,
+        &Array1::zeros(n),
+        &SolverOptions::new().restart(0)
+    )
+    .is_err());
+}
+
+#[test]
+fn gmres_callback() {
+    let n = 50;
+    let a: Array2<c64> = random_shifted(n);
+    let b: Array1<c64> = random(n);
+    let options = SolverOptions::new()
+        .criterion(StoppingCriterion::Absolute(1e-3))
+        .restart(10)
+        .max_iter(100);
+    let mut last = None;
+    // Stop as soon as the estimated residual norm drops below a half of the initial one
+    let sol = gmres_with_callback(&a, &IdentityOperator::new(), &b, &Array1::zeros(n), &options, |info| {
+        last = Some(*info);
+        info.residual_norm > 0.5 * b.norm_l2()
+    })
+    .unwrap();
+    let last = last.unwrap();
+    assert_eq!(last.iteration, sol.report.iterations);
+    assert!(last.residual_norm <= 0.5 * b.norm_l2());
+    // The estimate is replaced by the true residual norm
+    let r = &b - &a.dot(&sol.x);
+    assert!((sol.report.residual_norm - r.norm_l2()).abs() < 1e-10);
+    assert!((*sol.report.history.last().unwrap() - r.norm_l2()).abs() < 1e-10);
+}
+
+#[test]
+fn gmres_left_preconditioned() {
+    let n = 100;
+    let a = convection_diffusion(n, 50.0);
+    let b = Array1::ones(n);
+    let m = a.to_dense().factorize_into().unwrap();
+    let options = SolverOptions::new().rtol(1e-10).side(PreconditionerSide::Left);
+    let sol = gmres(&a, &m, &b, &Array1::zeros(n), &options).unwrap();
+    assert!(sol.report.converged);
+    assert_eq!(sol.report.iterations, 1);
+    assert_close_l2!(&sol.x, &a.solve(&b).unwrap(), 1e-8);
+
+    // The true residual is checked at the end of each cycle
+    let a: Array2<c64> = random_shifted(n);
+    let b: Array1<c64> = random(n);
+    let m = jacobi(&a).unwrap();
+    let sol = gmres(&a, &m, &b, &Array1::zeros(n), &options.restart(10)).unwrap();
+    assert!(sol.report.converged);
+    let r = &b - &a.dot(&sol.x);
+    assert!(r.norm_l2() <= 1e-10 * b.norm_l2());
+    assert!((sol.report.residual_norm - r.norm_l2()).abs() < 1e-12);
+}
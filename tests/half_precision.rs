@@ -0,0 +1,38 @@
+#![cfg(feature = "half")]
+
+use half::{bf16, f16};
+use ndarray::*;
+use ndarray_linalg::*;
+
+#[test]
+fn half_inv() {
+    let a: Array2<f32> = random_hpd(3);
+    let h = demote::<f16, _, _>(&a);
+    let inv = promote(&h.inv().unwrap());
+    assert_close_l2!(&inv.dot(&promote(&h)), &Array2::eye(3), 1e-2);
+}
+
+#[test]
+fn half_eigh_svd() {
+    let a: Array2<f32> = random_hpd(4);
+    let h = demote::<bf16, _, _>(&a);
+    let (e, _) = h.eigh(UPLO::Lower).unwrap();
+    let (_, s, _) = h.svd(false, false).unwrap();
+    let mut s = promote(&s).to_vec();
+    s.reverse();
+    assert_close_l2!(&promote(&e), &arr1(&s), 1e-2);
+}
+
+#[test]
+fn half_cholesky_norm() {
+    let a: Array2<f32> = random_hpd(3);
+    let h = demote::<f16, _, _>(&a);
+    let l = promote(&h.cholesky(UPLO::Lower).unwrap());
+    assert_close_l2!(&l.dot(&l.t()), &promote(&h), 1e-2);
+    assert_rclose!(h.norm_l2().to_f32(), promote(&h).norm_l2(), 1e-3);
+    assert_rclose!(
+        h.opnorm_one().unwrap().to_f32(),
+        promote(&h).opnorm_one().unwrap(),
+        1e-3
+    );
+}
@@ -0,0 +1,52 @@
+use ndarray::*;
+use ndarray_linalg::*;
+
+#[test]
+fn hankel_dense() {
+    let h = Hankel::new(arr1(&[1.0, 2.0, 3.0]), arr1(&[9.0, 4.0, 5.0, 6.0])).unwrap();
+    assert_eq!(h.dim(), (3, 4));
+    assert_eq!(h.sequence(), arr1(&[1.0, 2.0, 3.0, 4.0, 5.0, 6.0]));
+    assert_eq!(
+        h.to_dense(),
+        arr2(&[[1.0, 2.0, 3.0, 4.0], [2.0, 3.0, 4.0, 5.0], [3.0, 4.0, 5.0, 6.0]])
+    );
+    assert_eq!(h.t().to_dense(), h.to_dense().t());
+    assert!(Hankel::from_sequence(arr1(&[1.0, 2.0]), 3).is_err());
+}
+
+#[test]
+fn hankel_dot() {
+    for &(m, n) in &[(4, 4), (3, 7), (8, 2)] {
+        let h: Hankel<c64> = Hankel::from_sequence(random(m + n - 1), m).unwrap();
+        let x: Array1<c64> = random(n);
+        assert_close_l2!(&h.dot(&x), &h.to_dense().dot(&x), 1e-12);
+        let y: Array1<c64> = random(m);
+        assert_close_l2!(&h.t().dot(&y), &h.to_dense().t().dot(&y), 1e-12);
+    }
+}
+
+#[test]
+fn hankel_toeplitz_flip() {
+    let h: Hankel<f64> = Hankel::from_sequence(random(9), 5).unwrap();
+    let t = h.flip().unwrap();
+    assert_eq!(t.to_dense(), h.to_dense().slice(s![..;-1, ..]));
+    assert_eq!(Hankel::from_toeplitz(&t).to_dense(), h.to_dense());
+    let rect: Hankel<f64> = Hankel::from_sequence(random(6), 2).unwrap();
+    assert!(rect.flip().is_err());
+}
+
+#[test]
+fn hankel_solve() {
+    let n = 6;
+    // Hankel matrix whose flip is diagonally dominant
+    let mut h: Array1<c64> = random(2 * n - 1);
+    h[n - 1] += c64::new(2.0 * n as f64, 0.0);
+    let h = Hankel::from_sequence(h, n).unwrap();
+    let a = h.to_dense();
+    let ah: Array2<c64> = conjugate(&a);
+    let x: Array1<c64> = random(n);
+    assert_close_l2!(&h.solve(&a.dot(&x)).unwrap(), &x, 1e-9);
+    assert_close_l2!(&h.solve_t(&a.t().dot(&x)).unwrap(), &x, 1e-9);
+    assert_close_l2!(&h.solve_h(&ah.dot(&x)).unwrap(), &x, 1e-9);
+    assert!(h.solve(&Array1::zeros(n + 1)).is_err());
+}
@@ -0,0 +1,41 @@
+use ndarray::*;
+use ndarray_linalg::*;
+
+#[test]
+fn hermitize_complex() {
+    let a: Array2<c64> = random((4, 4));
+    let mut h = a.clone();
+    h.hermitize().unwrap();
+    let ah: Array2<c64> = conjugate(&a);
+    assert_close_max!(&h, &((&a + &ah) / c64::new(2.0, 0.0)), 1e-15);
+    let hh: Array2<c64> = conjugate(&h);
+    assert_eq!(h, hh);
+}
+
+#[test]
+fn symmetrize_complex() {
+    let a: Array2<c64> = random((4, 4));
+    let mut s = a.clone();
+    s.symmetrize().unwrap();
+    assert_close_max!(&s, &((&a + &a.t()) / c64::new(2.0, 0.0)), 1e-15);
+    assert_eq!(s, s.t());
+}
+
+#[test]
+fn hermitize_view() {
+    let mut a: Array2<f64> = random((5, 5));
+    a.slice_mut(s![1..4, 1..4]).hermitize().unwrap();
+    let b = a.slice(s![1..4, 1..4]);
+    assert_eq!(b, b.t());
+    assert!(a.slice_mut(s![.., 1..4]).hermitize().is_err());
+}
+
+#[test]
+fn hermitize_stack() {
+    let mut a: Array3<c64> = random((3, 4, 4));
+    a.hermitize().unwrap();
+    for m in a.outer_iter() {
+        let mh: Array2<c64> = conjugate(&m);
+        assert_eq!(m, mh);
+    }
+}
@@ -0,0 +1,39 @@
+use ndarray::*;
+use ndarray_linalg::*;
+
+fn test<A: Scalar + Lapack>(a: &Array2<A>) {
+    let n = a.rows();
+    let (q, h) = a.hessenberg().unwrap();
+    let qh: Array2<A> = conjugate(&q);
+    assert_close_l2!(&qh.dot(&q), &Array::eye(n), A::real(1e-7));
+    assert_close_l2!(&q.dot(&h).dot(&qh), a, A::real(1e-7));
+    for i in 0..n {
+        for j in 0..i.saturating_sub(1) {
+            assert_eq!(h[(i, j)], A::zero());
+        }
+    }
+}
+
+#[test]
+fn hessenberg() {
+    let a: Array2<f64> = random((5, 5));
+    test(&a);
+}
+
+#[test]
+fn hessenberg_t() {
+    let a: Array2<f64> = random((5, 5).f());
+    test(&a);
+}
+
+#[test]
+fn hessenberg_complex() {
+    let a: Array2<c64> = random((5, 5));
+    test(&a);
+}
+
+#[test]
+fn hessenberg_complex_t() {
+    let a: Array2<c64> = random((5, 5).f());
+    test(&a);
+}
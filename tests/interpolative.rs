@@ -0,0 +1,47 @@
+use ndarray::*;
+use ndarray_linalg::*;
+
+fn test_low_rank<A: Scalar + Lapack>() {
+    let u: Array2<A> = random((30, 4));
+    let v: Array2<A> = random((4, 20));
+    let a = u.dot(&v);
+    let id = a.interpolative_decomposition(4).unwrap();
+    assert_eq!(id.rank(), 4);
+    assert_eq!(id.coefficients.dim(), (4, 20));
+    let x_j = id.coefficients.select(Axis(1), &id.columns);
+    assert_close_l2!(&x_j, &Array2::eye(4), A::real(1e-12));
+    assert_close_l2!(&id.skeleton(&a).dot(&id.coefficients), &a, A::real(1e-10));
+}
+
+#[test]
+fn low_rank() {
+    test_low_rank::<f64>();
+    test_low_rank::<c64>();
+}
+
+#[test]
+fn bounded_coefficients() {
+    // Singular values `2^{-i}`
+    let n = 30;
+    let u: Array2<f64> = random_unitary(40);
+    let v: Array2<f64> = random_unitary(n);
+    let s = Array1::from_shape_fn(n, |i| 0.5_f64.powi(i as i32));
+    let a = (u.slice(s![.., ..n]).to_owned() * &s).dot(&v.t());
+    let k = 8;
+    let id = a.interpolative_decomposition(k).unwrap();
+    assert!(id.coefficients.iter().all(|x| x.abs() <= 2.0 + 1e-10));
+    let error = (&id.skeleton(&a).dot(&id.coefficients) - &a).opnorm_fro().unwrap();
+    // Bound of the spectral norm multiplied by `sqrt(n - k)` for the Frobenius norm
+    let bound = (1.0 + 4.0 * (k * (n - k)) as f64).sqrt() * s[k] * ((n - k) as f64).sqrt();
+    assert!(error <= bound);
+}
+
+#[test]
+fn full_rank() {
+    let a: Array2<f64> = random((5, 3));
+    let id = a.interpolative_decomposition(3).unwrap();
+    assert_eq!(id.columns, vec![0, 1, 2]);
+    assert_close_l2!(&id.skeleton(&a).dot(&id.coefficients), &a, 1e-12);
+    assert!(a.interpolative_decomposition(0).is_err());
+    assert!(a.interpolative_decomposition(4).is_err());
+}
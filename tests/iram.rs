@@ -0,0 +1,124 @@
+use ndarray::*;
+use ndarray_linalg::{krylov::*, *};
+
+/// Check `A x = λ x` for the computed pairs
+fn check_pairs<A: Scalar + Lapack>(a: &Array2<A>, result: &IramResult<A>) {
+    let a = a.mapv(|x| x.as_c());
+    for (i, v) in result.vectors.axis_iter(Axis(1)).enumerate() {
+        let av = a.dot(&v);
+        let ev = v.mapv(|x| x * result.values[i]);
+        assert_close_l2!(&av, &ev, A::real(1e-6));
+    }
+}
+
+fn test<A: Scalar + Lapack>(which: Which) {
+    let n = 60;
+    let k = 3;
+    let a: Array2<A> = random((n, n));
+    let (e, _) = a.eig().unwrap();
+    let x0: Array1<A> = random(n);
+    let result = iram(
+        &a,
+        &x0,
+        k,
+        which,
+        StoppingCriterion::RelativeResidual(A::real(1e-10)),
+        200,
+    )
+    .unwrap();
+    assert!(result.report.converged);
+    let truth: Vec<_> = {
+        let mut e = e.to_vec();
+        match which {
+            Which::LargestMagnitude => e.sort_by(|a, b| b.abs().partial_cmp(&a.abs()).unwrap()),
+            Which::Largest => e.sort_by(|a, b| b.re().partial_cmp(&a.re()).unwrap()),
+            _ => unreachable!(),
+        }
+        e
+    };
+    for (value, truth) in result.values.iter().zip(truth.iter()) {
+        match which {
+            Which::LargestMagnitude => assert_rclose!(value.abs(), truth.abs(), A::real(1e-7)),
+            Which::Largest => assert_rclose!(value.re(), truth.re(), A::real(1e-7)),
+            _ => unreachable!(),
+        }
+    }
+    check_pairs(&a, &result);
+}
+
+#[test]
+fn iram_largest_magnitude() {
+    test::<f64>(Which::LargestMagnitude);
+    test::<c64>(Which::LargestMagnitude);
+}
+
+#[test]
+fn iram_largest_real() {
+    test::<f64>(Which::Largest);
+    test::<c64>(Which::Largest);
+}
+
+#[test]
+fn iram_shift_invert_real() {
+    let n = 40;
+    let a: Array2<f64> = random((n, n));
+    let (e, _) = a.eig().unwrap();
+    let shift = 0.3;
+    let nearest = e
+        .iter()
+        .min_by(|a, b| (*a - shift).norm().partial_cmp(&(*b - shift).norm()).unwrap())
+        .unwrap();
+    let x0: Array1<f64> = random(n);
+    let result = iram_shift_invert(&a, shift, &x0, 1, StoppingCriterion::RelativeResidual(1e-12), 100).unwrap();
+    assert!(result.report.converged);
+    assert_rclose!(result.values[0].re, nearest.re, 1e-8);
+    assert!((result.values[0].im.abs() - nearest.im.abs()).abs() < 1e-8);
+    check_pairs(&a, &result);
+}
+
+#[test]
+fn iram_symmetric() {
+    // Real spectrum, where no complex conjugate pair appears
+    let a: Array2<f64> = random_hermite(30);
+    let (e, _) = a.eigh(UPLO::Upper).unwrap();
+    let x0: Array1<f64> = random(30);
+    let result = iram(
+        &a,
+        &x0,
+        2,
+        Which::Smallest,
+        StoppingCriterion::RelativeResidual(1e-10),
+        200,
+    )
+    .unwrap();
+    assert!(result.report.converged);
+    assert_rclose!(result.values[0].re, e[0], 1e-8);
+    assert_rclose!(result.values[1].re, e[1], 1e-8);
+    check_pairs(&a, &result);
+}
+
+#[test]
+fn iram_callback() {
+    let n = 100;
+    let a: Array2<f64> = random((n, n));
+    let x0: Array1<f64> = random(n);
+    let criterion = StoppingCriterion::RelativeResidual(1e-10);
+    let mut infos = Vec::new();
+    let result = iram_with_callback(&a, &x0, 3, Which::LargestMagnitude, criterion, 200, |info| {
+        infos.push(*info);
+        true
+    })
+    .unwrap();
+    assert!(result.report.converged);
+    assert_eq!(infos.len(), result.report.iterations + 1);
+    for (k, info) in infos.iter().enumerate() {
+        assert_eq!(info.iteration, k);
+        assert_eq!(info.error, result.report.history[k]);
+    }
+    assert_eq!(infos.last().unwrap().residual_norm, result.report.residual_norm);
+
+    // Stop by the callback
+    let result = iram_with_callback(&a, &x0, 3, Which::LargestMagnitude, criterion, 200, |_| false).unwrap();
+    assert!(!result.report.converged);
+    assert_eq!(result.report.iterations, 0);
+}
@@ -0,0 +1,54 @@
+use ndarray::*;
+use ndarray_linalg::*;
+
+#[test]
+fn kron_blocks() {
+    let a: Array2<f64> = random((2, 3));
+    let b: Array2<f64> = random((4, 2));
+    let k = kron(&a, &b);
+    assert_eq!(k.dim(), (8, 6));
+    for i in 0..2 {
+        for j in 0..3 {
+            let block = k.slice(s![i * 4..(i + 1) * 4, j * 2..(j + 1) * 2]);
+            assert_close_l2!(&block.to_owned(), &b.mapv(|x| a[(i, j)] * x), 1e-15);
+        }
+    }
+}
+
+#[test]
+fn kron_mixed_product() {
+    // (A ⊗ B)(C ⊗ D) = AC ⊗ BD
+    let a: Array2<f64> = random((3, 3));
+    let b: Array2<f64> = random((2, 2));
+    let c: Array2<f64> = random((3, 3));
+    let d: Array2<f64> = random((2, 2));
+    let lhs = kron(&a, &b).dot(&kron(&c, &d));
+    assert_close_l2!(&lhs, &kron(&a.dot(&c), &b.dot(&d)), 1e-12);
+}
+
+#[test]
+fn solve_kron_dense() {
+    let a: Array2<f64> = random((4, 4));
+    let b: Array2<f64> = random((3, 3));
+    let x: Array1<f64> = random(12);
+    let c = kron(&a, &b).dot(&x);
+    assert_close_l2!(&solve_kron((&a, &b), &c).unwrap(), &x, 1e-7);
+}
+
+#[test]
+fn solve_kron_complex() {
+    let a: Array2<c64> = random((3, 3));
+    let b: Array2<c64> = random((5, 5));
+    let x: Array1<c64> = random(15);
+    let c = kron(&a, &b).dot(&x);
+    assert_close_l2!(&solve_kron((&a, &b), &c).unwrap(), &x, 1e-7);
+}
+
+#[test]
+fn solve_kron_shape_mismatch() {
+    let a: Array2<f64> = random((3, 3));
+    let b: Array2<f64> = random((2, 2));
+    assert!(solve_kron((&a, &b), &Array1::zeros(5)).is_err());
+    let b: Array2<f64> = random((2, 3));
+    assert!(solve_kron((&a, &b), &Array1::zeros(6)).is_err());
+}
@@ -0,0 +1,127 @@
+use ndarray::*;
+use ndarray_linalg::{krylov::*, *};
+
+fn test<A: Scalar + Lapack>(which: Which) {
+    let n = 50;
+    let k = 3;
+    let a: Array2<A> = random_hermite(n);
+    let (e, _) = a.eigh(UPLO::Upper).unwrap();
+    let x0: Array1<A> = random(n);
+    let result = lanczos(
+        &a,
+        &x0,
+        k,
+        which,
+        StoppingCriterion::RelativeResidual(A::real(1e-9)),
+        100,
+    )
+    .unwrap();
+    assert!(result.report.converged);
+    let truth = match which {
+        Which::Largest => e.slice(s![..;-1]).slice(s![..k]).to_owned(),
+        Which::Smallest => e.slice(s![..k]).to_owned(),
+        _ => unreachable!(),
+    };
+    assert_close_l2!(
+        &result.values.mapv(A::from_real),
+        &truth.mapv(A::from_real),
+        A::real(1e-7)
+    );
+    for (i, v) in result.vectors.axis_iter(Axis(1)).enumerate() {
+        let av = a.dot(&v);
+        let ev = v.mapv(|x| x.mul_real(result.values[i]));
+        assert_close_l2!(&av, &ev, A::real(1e-6));
+    }
+}
+
+#[test]
+fn lanczos_largest() {
+    test::<f64>(Which::Largest);
+}
+
+#[test]
+fn lanczos_smallest() {
+    test::<f64>(Which::Smallest);
+}
+
+#[test]
+fn lanczos_complex() {
+    test::<c64>(Which::Largest);
+    test::<c64>(Which::Smallest);
+}
+
+/// 1D Laplacian with Dirichlet boundary, which is never stored as a dense matrix
+struct Laplacian;
+
+impl LinearOperator for Laplacian {
+    type Elem = f64;
+
+    fn apply<S>(&self, a: &ArrayBase<S, Ix1>) -> Array1<f64>
+    where
+        S: Data<Elem = f64>,
+    {
+        let n = a.len();
+        Array1::from_shape_fn(n, |i| {
+            let left = if i > 0 { a[i - 1] } else { 0.0 };
+            let right = if i + 1 < n { a[i + 1] } else { 0.0 };
+            2.0 * a[i] - left - right
+        })
+    }
+}
+
+#[test]
+fn lanczos_matrix_free() {
+    let n = 100;
+    let x0: Array1<f64> = random(n);
+    let result = lanczos(
+        &Laplacian,
+        &x0,
+        2,
+        Which::Largest,
+        StoppingCriterion::RelativeResidual(1e-8),
+        1000,
+    )
+    .unwrap();
+    assert!(result.report.converged);
+    let h = ::std::f64::consts::PI / (n + 1) as f64;
+    let truth = arr1(&[2.0 - 2.0 * (n as f64 * h).cos(), 2.0 - 2.0 * ((n - 1) as f64 * h).cos()]);
+    assert_close_l2!(&result.values, &truth, 1e-7);
+}
+
+#[test]
+fn lanczos_small() {
+    // The Krylov subspace spans the whole space
+    let a: Array2<f64> = random_hermite(5);
+    let (e, _) = a.eigh(UPLO::Upper).unwrap();
+    let x0: Array1<f64> = random(5);
+    let result = lanczos(&a, &x0, 5, Which::Smallest, StoppingCriterion::default(), 10).unwrap();
+    assert!(result.report.converged);
+    assert_close_l2!(&result.values, &e, 1e-9);
+    assert!(lanczos(&a, &x0, 6, Which::Smallest, StoppingCriterion::default(), 10).is_err());
+}
+
+#[test]
+fn lanczos_callback() {
+    let n = 100;
+    let a: Array2<f64> = random_hermite(n);
+    let x0: Array1<f64> = random(n);
+    let criterion = StoppingCriterion::RelativeResidual(1e-10);
+    let mut infos = Vec::new();
+    let result = lanczos_with_callback(&a, &x0, 3, Which::Largest, criterion, 100, |info| {
+        infos.push(*info);
+        true
+    })
+    .unwrap();
+    assert!(result.report.converged);
+    assert_eq!(infos.len(), result.report.iterations + 1);
+    for (k, info) in infos.iter().enumerate() {
+        assert_eq!(info.iteration, k);
+        assert_eq!(info.error, result.report.history[k]);
+    }
+    assert_eq!(infos.last().unwrap().residual_norm, result.report.residual_norm);
+
+    // Stop by the callback
+    let result = lanczos_with_callback(&a, &x0, 3, Which::Largest, criterion, 100, |_| false).unwrap();
+    assert!(!result.report.converged);
+    assert_eq!(result.report.iterations, 0);
+}
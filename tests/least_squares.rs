@@ -0,0 +1,401 @@
+use ndarray::*;
+use ndarray_linalg::*;
+
+fn test_exact(a: Array2<f64>) {
+    let (_, n) = a.dim();
+    let x: Array1<f64> = random(n);
+    let b = a.dot(&x);
+    let result = a.least_squares(&b, None).unwrap();
+    assert_eq!(result.rank, n as i32);
+    assert_eq!(result.singular_values.len(), n);
+    assert_close_l2!(&result.solution, &x, 1e-7);
+}
+
+#[test]
+fn least_squares_4x3() {
+    test_exact(random((4, 3)));
+}
+
+#[test]
+fn least_squares_4x3_t() {
+    test_exact(random((4, 3).f()));
+}
+
+#[test]
+fn least_squares_3x3() {
+    test_exact(random((3, 3)));
+}
+
+fn test_underdetermined(a: Array2<f64>) {
+    let (m, _) = a.dim();
+    let b: Array1<f64> = random(m);
+    let result = a.least_squares(&b, None).unwrap();
+    assert_eq!(result.rank, m as i32);
+    assert_close_l2!(&a.dot(&result.solution), &b, 1e-7);
+}
+
+#[test]
+fn least_squares_3x4() {
+    test_underdetermined(random((3, 4)));
+}
+
+#[test]
+fn least_squares_3x4_t() {
+    test_underdetermined(random((3, 4).f()));
+}
+
+#[test]
+fn least_squares_rank_deficient() {
+    let a: Array2<f64> = array![[1., 2.], [2., 4.], [3., 6.]];
+    let b: Array1<f64> = array![1., 2., 3.];
+    let result = a.least_squares(&b, None).unwrap();
+    assert_eq!(result.rank, 1);
+    assert_close_l2!(&result.singular_values, &array![70.0_f64.sqrt(), 0.0], 1e-7);
+    assert_close_l2!(&result.solution, &array![0.2, 0.4], 1e-7);
+    assert!(result.is_rank_deficient());
+    assert_rclose!(result.effective_cond(), 1.0, 1e-7);
+    assert!(result.cond() > 1e10);
+}
+
+#[test]
+fn least_squares_cutoff() {
+    let a: Array2<f64> = array![[1., 0.], [0., 1e-8], [0., 0.]];
+    let b: Array1<f64> = array![1., 1., 1.];
+    let full = a.least_squares(&b, None).unwrap();
+    assert_eq!(full.rank, 2);
+    assert!(!full.is_rank_deficient());
+    assert_rclose!(full.cond(), 1e8, 1e-7);
+    let truncated = a.least_squares(&b, Some(1e-6)).unwrap();
+    assert_eq!(truncated.rank, 1);
+    assert!(truncated.is_rank_deficient());
+    assert_rclose!(truncated.effective_cond(), 1.0, 1e-7);
+    assert_close_l2!(&truncated.solution, &array![1., 0.], 1e-7);
+}
+
+#[test]
+fn least_squares_complex() {
+    let a: Array2<c64> = random((5, 3));
+    let x: Array1<c64> = random(3);
+    let b = a.dot(&x);
+    let result = a.least_squares(&b, None).unwrap();
+    assert_eq!(result.rank, 3);
+    assert_close_l2!(&result.solution, &x, 1e-7);
+}
+
+#[test]
+fn least_squares_shape_mismatch() {
+    let a: Array2<f64> = random((4, 3));
+    let b: Array1<f64> = random(3);
+    assert!(a.least_squares(&b, None).is_err());
+}
+
+#[test]
+fn lstsq_overdetermined() {
+    for a in vec![random((5, 3)), random((5, 3).f())] {
+        let a: Array2<f64> = a;
+        let b: Array1<f64> = random(5);
+        let x = a.lstsq(&b).unwrap();
+        // normal equation A^T (b - A x) = 0
+        let r = &b - &a.dot(&x);
+        assert_close_l2!(&a.t().dot(&r), &Array1::zeros(3), 1e-7);
+        let svd = a.least_squares(&b, None).unwrap();
+        assert_close_l2!(&x, &svd.solution, 1e-7);
+        assert_close_l2!(&a.clone().lstsq_into(b.clone()).unwrap(), &x, 1e-7);
+        assert_close_l2!(&a.clone().lstsq_mut(&b).unwrap(), &x, 1e-7);
+    }
+}
+
+#[test]
+fn lstsq_underdetermined() {
+    for a in vec![random((3, 5)), random((3, 5).f())] {
+        let a: Array2<c64> = a;
+        let b: Array1<c64> = random(3);
+        let x = a.lstsq(&b).unwrap();
+        assert_close_l2!(&a.dot(&x), &b, 1e-7);
+        // minimum-norm solution
+        let svd = a.least_squares(&b, None).unwrap();
+        assert_close_l2!(&x, &svd.solution, 1e-7);
+    }
+}
+
+#[test]
+fn lstsq_rank_deficient() {
+    let a: Array2<f64> = array![[1., 0.], [2., 0.], [3., 0.]];
+    let b: Array1<f64> = array![1., 2., 3.];
+    assert!(a.lstsq(&b).is_err());
+}
+
+#[test]
+fn least_squares_with_drivers() {
+    // The third column is the sum of the first two
+    let mut a: Array2<f64> = random((6, 3));
+    let c = &a.column(0) + &a.column(1);
+    a.column_mut(2).assign(&c);
+    let b: Array1<f64> = random(6);
+    let svddc = a.least_squares(&b, Some(1e-10)).unwrap();
+    assert_eq!(svddc.rank, 2);
+    for &driver in &[
+        LeastSquaresDriver::Svd,
+        LeastSquaresDriver::SvdDc,
+        LeastSquaresDriver::Pivoted,
+    ] {
+        let result = a.least_squares_with(&b, Some(1e-10), driver).unwrap();
+        assert_eq!(result.rank, 2);
+        // Every least-squares solution attains the same residual
+        let r = &b - &a.dot(&result.solution);
+        assert_close_l2!(&a.t().dot(&r), &Array1::zeros(3), 1e-7);
+    }
+    let svd = a.least_squares_with(&b, Some(1e-10), LeastSquaresDriver::Svd).unwrap();
+    assert_close_l2!(&svd.solution, &svddc.solution, 1e-7);
+    assert_close_l2!(&svd.singular_values, &svddc.singular_values, 1e-7);
+    let pivoted = a.least_squares_with(&b, None, LeastSquaresDriver::Pivoted).unwrap();
+    assert!(pivoted.singular_values.is_empty());
+}
+
+#[test]
+fn least_squares_with_pivoted_complex() {
+    let a: Array2<c64> = random((5, 3).f());
+    let x: Array1<c64> = random(3);
+    let b = a.dot(&x);
+    let result = a.least_squares_with(&b, None, LeastSquaresDriver::Pivoted).unwrap();
+    assert_eq!(result.rank, 3);
+    assert_close_l2!(&result.solution, &x, 1e-7);
+}
+
+fn ridge_normal_equation(a: &Array2<f64>, b: &Array1<f64>, lambda: f64) -> Array1<f64> {
+    let n = a.cols();
+    let ata = a.t().dot(a) + Array2::<f64>::eye(n) * lambda;
+    ata.solve_into(a.t().dot(b)).unwrap()
+}
+
+#[test]
+fn ridge() {
+    let a: Array2<f64> = random((6, 4));
+    let b: Array1<f64> = random(6);
+    let x = solve_ridge(&a, &b, 0.5).unwrap();
+    assert_close_l2!(&x, &ridge_normal_equation(&a, &b, 0.5), 1e-7);
+    // λ = 0 is the ordinary least squares
+    let x0 = solve_ridge(&a, &b, 0.0).unwrap();
+    assert_close_l2!(&x0, &a.lstsq(&b).unwrap(), 1e-7);
+}
+
+#[test]
+fn ridge_rank_deficient() {
+    let a: Array2<f64> = array![[1., 2.], [2., 4.], [3., 6.]];
+    let b: Array1<f64> = array![1., 2., 3.];
+    let x = solve_ridge(&a, &b, 1e-3).unwrap();
+    assert_close_l2!(&x, &ridge_normal_equation(&a, &b, 1e-3), 1e-7);
+}
+
+#[test]
+fn ridge_factorized() {
+    let a: Array2<f64> = random((6, 4));
+    let b: Array1<f64> = random(6);
+    let f = RidgeFactorized::new(&a).unwrap();
+    assert_eq!(f.singular_values().len(), 4);
+    for &lambda in &[0.0, 1e-2, 1.0, 100.0] {
+        let x = f.solve(&b, lambda).unwrap();
+        assert_close_l2!(&x, &solve_ridge(&a, &b, lambda).unwrap(), 1e-7);
+    }
+}
+
+#[test]
+fn ridge_factorized_complex() {
+    let a: Array2<c64> = random((3, 5));
+    let b: Array1<c64> = random(3);
+    let f = RidgeFactorized::new(&a).unwrap();
+    let x = f.solve(&b, 0.3).unwrap();
+    assert_close_l2!(&x, &solve_ridge(&a, &b, 0.3).unwrap(), 1e-7);
+    // λ = 0 gives the minimum-norm solution
+    let x0 = f.solve(&b, 0.0).unwrap();
+    assert_close_l2!(&x0, &a.lstsq(&b).unwrap(), 1e-7);
+}
+
+#[test]
+fn glm_identity_covariance() {
+    // B = I reduces to the ordinary least squares, and y is the residual
+    let a: Array2<f64> = random((6, 3));
+    let d: Array1<f64> = random(6);
+    let (x, y) = solve_glm(&a, &Array2::<f64>::eye(6), &d).unwrap();
+    assert_close_l2!(&x, &a.lstsq(&d).unwrap(), 1e-7);
+    assert_close_l2!(&y, &(&d - &a.dot(&x)), 1e-7);
+}
+
+#[test]
+fn glm_weighted() {
+    // B = diag(w) gives the weighted least squares with weights 1 / w^2
+    let a: Array2<f64> = random((5, 2));
+    let d: Array1<f64> = random(5);
+    let w = array![1.0, 2.0, 0.5, 4.0, 1.5];
+    let (x, y) = solve_glm(&a, &from_diag(&w.to_vec()), &d).unwrap();
+    let mut aw = a.clone();
+    for (mut row, &w) in aw.axis_iter_mut(Axis(0)).zip(w.iter()) {
+        row /= w;
+    }
+    let dw = &d / &w;
+    assert_close_l2!(&x, &aw.lstsq(&dw).unwrap(), 1e-7);
+    assert_close_l2!(&(a.dot(&x) + &w * &y), &d, 1e-7);
+}
+
+#[test]
+fn glm_complex() {
+    let a: Array2<c64> = random((4, 2));
+    let b: Array2<c64> = random((4, 3));
+    let d: Array1<c64> = random(4);
+    let (x, y) = solve_glm(&a, &b, &d).unwrap();
+    assert_close_l2!(&(a.dot(&x) + b.dot(&y)), &d, 1e-7);
+}
+
+#[test]
+fn glm_shape_mismatch() {
+    let a: Array2<f64> = random((4, 3));
+    let b: Array2<f64> = random((4, 0));
+    let d: Array1<f64> = random(4);
+    assert!(solve_glm(&a, &b, &d).is_err());
+}
+
+#[test]
+fn total_least_squares_exact() {
+    let a: Array2<f64> = random((6, 3));
+    let x: Array1<f64> = random(3);
+    let b = a.dot(&x);
+    let result = total_least_squares(&a, &b).unwrap();
+    assert_close_l2!(&result.solution, &x, 1e-7);
+    assert!(result.correction_norm.into_scalar() < 1e-10);
+}
+
+#[test]
+fn total_least_squares_noisy() {
+    let a: Array2<f64> = random((8, 3));
+    let b: Array1<f64> = random(8);
+    let result = total_least_squares(&a, &b).unwrap();
+    let x = &result.solution;
+    // The TLS solution satisfies (A^T A - σ^2 I) x = A^T b for the smallest singular value σ of [A, b]
+    let sigma2 = result.correction_norm.into_scalar().powi(2);
+    let lhs = a.t().dot(&a.dot(x)) - x * sigma2;
+    assert_close_l2!(&lhs, &a.t().dot(&b), 1e-7);
+    // and minimizes |A x - b|^2 / (1 + |x|^2)
+    let r = &a.dot(x) - &b;
+    assert_rclose!(r.dot(&r) / (1.0 + x.dot(x)), sigma2, 1e-7);
+}
+
+#[test]
+fn total_least_squares_complex() {
+    let a: Array2<c64> = random((5, 2));
+    let x: Array1<c64> = random(2);
+    let b = a.dot(&x);
+    let result = total_least_squares(&a, &b).unwrap();
+    assert_close_l2!(&result.solution, &x, 1e-7);
+}
+
+fn residual_norms(a: &Array2<f64>, x: &Array2<f64>, b: &Array2<f64>) -> Array1<f64> {
+    (b - &a.dot(x)).map_axis(Axis(0), |r| r.dot(&r).sqrt())
+}
+
+#[test]
+fn lstsq_multiple_rhs() {
+    for a in vec![random((6, 3)), random((6, 3).f())] {
+        let a: Array2<f64> = a;
+        let b: Array2<f64> = random((6, 4));
+        let x = a.lstsq(&b).unwrap();
+        assert_eq!(x.dim(), (3, 4));
+        for (x, b) in x.axis_iter(Axis(1)).zip(b.axis_iter(Axis(1))) {
+            assert_close_l2!(&x, &a.lstsq(&b).unwrap(), 1e-7);
+        }
+        let (x_res, norms) = a.lstsq_residual(&b).unwrap();
+        assert_close_l2!(&x_res, &x, 1e-7);
+        assert_close_l2!(&norms, &residual_norms(&a, &x, &b), 1e-7);
+    }
+}
+
+#[test]
+fn lstsq_residual_underdetermined() {
+    let a: Array2<f64> = random((3, 5));
+    let b: Array1<f64> = random(3);
+    let (x, norm) = a.lstsq_residual(&b).unwrap();
+    assert_close_l2!(&a.dot(&x), &b, 1e-7);
+    assert_eq!(norm.into_scalar(), 0.0);
+}
+
+#[test]
+fn least_squares_multiple_rhs() {
+    let a: Array2<f64> = random((7, 3));
+    let b: Array2<f64> = random((7, 2).f());
+    for &driver in &[
+        LeastSquaresDriver::Svd,
+        LeastSquaresDriver::SvdDc,
+        LeastSquaresDriver::Pivoted,
+    ] {
+        let result = a.least_squares_with(&b, None, driver).unwrap();
+        assert_eq!(result.rank, 3);
+        assert_close_l2!(&result.solution, &a.lstsq(&b).unwrap(), 1e-7);
+        match driver {
+            LeastSquaresDriver::Pivoted => assert!(result.residual_norms.is_none()),
+            _ => {
+                let norms = result.residual_norms.unwrap();
+                assert_close_l2!(&norms, &residual_norms(&a, &result.solution, &b), 1e-7);
+            }
+        }
+    }
+    let result = a.least_squares(&b, None).unwrap();
+    assert_eq!(result.solution.dim(), (3, 2));
+}
+
+#[test]
+fn least_squares_residual_vector() {
+    let a: Array2<c64> = random((5, 2));
+    let b: Array1<c64> = random(5);
+    let result = a.least_squares(&b, None).unwrap();
+    let r = &b - &a.dot(&result.solution);
+    let norm = result.residual_norms.unwrap().into_scalar();
+    assert_rclose!(norm, r.norm_l2(), 1e-7);
+    // The residual is not available for rank-deficient matrices
+    let a: Array2<f64> = array![[1., 2.], [2., 4.], [3., 6.]];
+    let b: Array1<f64> = array![1., 2., 4.];
+    assert!(a.least_squares(&b, None).unwrap().residual_norms.is_none());
+}
+
+#[test]
+fn ridge_multiple_rhs() {
+    let a: Array2<f64> = random((6, 4));
+    let b: Array2<f64> = random((6, 3));
+    let f = RidgeFactorized::new(&a).unwrap();
+    let (x, norms) = solve_ridge_residual(&a, &b, 0.5).unwrap();
+    assert_close_l2!(&norms, &residual_norms(&a, &x, &b), 1e-7);
+    let (xf, norms_f) = f.solve_residual(&b, 0.5).unwrap();
+    assert_close_l2!(&xf, &x, 1e-7);
+    assert_close_l2!(&norms_f, &norms, 1e-7);
+    for (j, bj) in b.axis_iter(Axis(1)).enumerate() {
+        let bj = bj.to_owned();
+        assert_close_l2!(&x.column(j), &ridge_normal_equation(&a, &bj, 0.5), 1e-7);
+        assert_close_l2!(&f.solve(&bj, 0.5).unwrap(), &x.column(j), 1e-7);
+    }
+    assert_close_l2!(&solve_ridge(&a, &b, 0.5).unwrap(), &x, 1e-7);
+}
+
+#[test]
+fn glm_multiple_rhs() {
+    let a: Array2<f64> = random((5, 2));
+    let b: Array2<f64> = random((5, 4));
+    let d: Array2<f64> = random((5, 3));
+    let (x, y) = solve_glm(&a, &b, &d).unwrap();
+    assert_eq!(x.dim(), (2, 3));
+    assert_eq!(y.dim(), (4, 3));
+    assert_close_l2!(&(a.dot(&x) + b.dot(&y)), &d, 1e-7);
+    let (x0, y0) = solve_glm(&a, &b, &d.column(0)).unwrap();
+    assert_close_l2!(&x0, &x.column(0), 1e-7);
+    assert_close_l2!(&y0, &y.column(0), 1e-7);
+}
+
+#[test]
+fn total_least_squares_multiple_rhs() {
+    let a: Array2<f64> = random((8, 3));
+    let b: Array2<f64> = random((8, 2));
+    let result = total_least_squares(&a, &b).unwrap();
+    assert_eq!(result.solution.dim(), (3, 2));
+    for (j, bj) in b.axis_iter(Axis(1)).enumerate() {
+        let single = total_least_squares(&a, &bj).unwrap();
+        assert_close_l2!(&result.solution.column(j), &single.solution, 1e-7);
+        assert_rclose!(result.correction_norm[j], single.correction_norm.into_scalar(), 1e-7);
+    }
+}
@@ -0,0 +1,92 @@
+use ndarray::*;
+use ndarray_linalg::*;
+
+fn test<A: Scalar + Lapack>(order: Order) {
+    let n = 30;
+    let k = 3;
+    let a: Array2<A> = random_hermite(n);
+    let (e, _) = a.eigh(UPLO::Upper).unwrap();
+    let x0: Array2<A> = random((n, k));
+    let result = lobpcg(&a, &x0, order, StoppingCriterion::RelativeResidual(A::real(1e-9)), 1000).unwrap();
+    assert!(result.report.converged);
+    let truth = match order {
+        Order::Smallest => e.slice(s![..k]).to_owned(),
+        Order::Largest => e.slice(s![n - k..;-1]).to_owned(),
+    };
+    assert_close_l2!(
+        &result.values.mapv(A::from_real),
+        &truth.mapv(A::from_real),
+        A::real(1e-7)
+    );
+    for (i, v) in result.vectors.axis_iter(Axis(1)).enumerate() {
+        let av = a.dot(&v);
+        let ev = v.mapv(|x| x.mul_real(result.values[i]));
+        assert_close_l2!(&av, &ev, A::real(1e-6));
+    }
+}
+
+#[test]
+fn lobpcg_smallest() {
+    test::<f64>(Order::Smallest);
+    test::<c64>(Order::Smallest);
+}
+
+#[test]
+fn lobpcg_largest() {
+    test::<f64>(Order::Largest);
+    test::<c64>(Order::Largest);
+}
+
+#[test]
+fn lobpcg_generalized_precond() {
+    let n = 40;
+    let k = 2;
+    let a: Array2<f64> = random_hpd(n);
+    let b: Array2<f64> = random_hpd(n);
+    let (e, _) = a
+        .eigh_generalized(&b, EighGeneralizedType::AxLambdaBx, UPLO::Upper)
+        .unwrap();
+    // Jacobi preconditioner
+    let precond: Array2<f64> = from_diag(&a.diag().mapv(|x| 1.0 / x).to_vec());
+    let x0: Array2<f64> = random((n, k));
+    let criterion = StoppingCriterion::RelativeResidual(1e-10);
+    let result = lobpcg_generalized(&a, &b, &precond, &x0, Order::Smallest, criterion, 1000).unwrap();
+    assert!(result.report.converged);
+    assert_close_l2!(&result.values, &e.slice(s![..k]), 1e-7);
+    // B-orthonormal
+    let x = &result.vectors;
+    assert_close_l2!(&x.t().dot(&b.dot(x)), &Array2::eye(k), 1e-9);
+}
+
+#[test]
+fn lobpcg_too_many() {
+    let a: Array2<f64> = random_hermite(5);
+    let x0: Array2<f64> = random((5, 2));
+    assert!(lobpcg(&a, &x0, Order::Smallest, StoppingCriterion::default(), 10).is_err());
+}
+
+#[test]
+fn lobpcg_callback() {
+    let n = 30;
+    let a: Array2<f64> = random_hermite(n);
+    let x0: Array2<f64> = random((n, 3));
+    let criterion = StoppingCriterion::RelativeResidual(1e-9);
+    let mut infos = Vec::new();
+    let result = lobpcg_with_callback(&a, &x0, Order::Smallest, criterion, 1000, |info| {
+        infos.push(*info);
+        true
+    })
+    .unwrap();
+    assert!(result.report.converged);
+    assert_eq!(infos.len(), result.report.iterations + 1);
+    for (k, info) in infos.iter().enumerate() {
+        assert_eq!(info.iteration, k);
+        assert_eq!(info.error, result.report.history[k]);
+    }
+    assert_eq!(infos.last().unwrap().residual_norm, result.report.residual_norm);
+
+    // Stop by the callback
+    let result = lobpcg_with_callback(&a, &x0, Order::Smallest, criterion, 1000, |info| info.iteration < 3).unwrap();
+    assert!(!result.report.converged);
+    assert_eq!(result.report.iterations, 3);
+}
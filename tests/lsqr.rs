@@ -0,0 +1,132 @@
+use ndarray::*;
+use ndarray_linalg::{error::Result, krylov::*, *};
+
+type Solver<A> =
+    fn(&Array2<A>, &Array1<A>, <A as Scalar>::Real, &SolverOptions<<A as Scalar>::Real>) -> Result<LinearSolution<A>>;
+
+fn overdetermined<A: Scalar + Lapack>(solver: Solver<A>) {
+    let a: Array2<A> = random((40, 10));
+    let b: Array1<A> = random(40);
+    let sol = solver(
+        &a,
+        &b,
+        A::real(0.0),
+        &SolverOptions::new().rtol(A::real(1e-12)).max_iter(100),
+    )
+    .unwrap();
+    assert!(sol.report.converged);
+    let truth = a.least_squares(&b, None).unwrap().solution;
+    assert_close_l2!(&sol.x, &truth, A::real(1e-9));
+}
+
+#[test]
+fn lsqr_overdetermined() {
+    overdetermined::<f64>(lsqr);
+    overdetermined::<c64>(lsqr);
+}
+
+#[test]
+fn lsmr_overdetermined() {
+    overdetermined::<f64>(lsmr);
+    overdetermined::<c64>(lsmr);
+}
+
+fn damped(solver: Solver<f64>) {
+    let a: Array2<f64> = random((20, 30));
+    let b: Array1<f64> = random(20);
+    let damp = 0.5;
+    let sol = solver(&a, &b, damp, &SolverOptions::new().rtol(1e-12).max_iter(200)).unwrap();
+    assert!(sol.report.converged);
+    // `(A^T A + damp^2 I) x = A^T b`
+    let ata = a.t().dot(&a) + Array2::<f64>::eye(30) * (damp * damp);
+    assert_close_l2!(&sol.x, &ata.solve(&a.t().dot(&b)).unwrap(), 1e-9);
+}
+
+#[test]
+fn lsqr_damped() {
+    damped(lsqr);
+}
+
+#[test]
+fn lsmr_damped() {
+    damped(lsmr);
+}
+
+#[test]
+fn minimum_norm() {
+    // The iterates lie in the range of `A^T`, which gives the minimum norm solution
+    let a: Array2<f64> = random((10, 30));
+    let b: Array1<f64> = random(10);
+    let truth = a.t().dot(&a.dot(&a.t()).solve(&b).unwrap());
+    for &solver in &[lsqr as Solver<f64>, lsmr] {
+        let sol = solver(&a, &b, 0.0, &SolverOptions::new().rtol(1e-12).max_iter(100)).unwrap();
+        assert!(sol.report.converged);
+        assert_close_l2!(&sol.x, &truth, 1e-9);
+    }
+}
+
+#[test]
+fn lsmr_monotone() {
+    let a: Array2<f64> = random((50, 20));
+    let b: Array1<f64> = random(50);
+    let sol = lsmr(&a, &b, 0.0, &SolverOptions::new().rtol(1e-20).max_iter(10)).unwrap();
+    assert!(!sol.report.converged);
+    assert_eq!(sol.report.iterations, 10);
+    for w in sol.report.history.windows(2) {
+        assert!(w[1] <= w[0] * (1.0 + 1e-10));
+    }
+}
+
+#[test]
+fn lsqr_matrix_free() {
+    // Smoothing `min |x - b|^2 + λ^2 |D x|^2` with the forward difference `D`, as `[I; λ D] x = [b; 0]`
+    let n = 50;
+    let lambda = 3.0;
+    let op = FnOperator::new((2 * n - 1, n), |x: ArrayView1<f64>| {
+        let mut y = Array1::zeros(2 * n - 1);
+        y.slice_mut(s![..n]).assign(&x);
+        y.slice_mut(s![n..])
+            .assign(&((&x.slice(s![1..]) - &x.slice(s![..-1])) * lambda));
+        y
+    })
+    .with_adjoint(|y: ArrayView1<f64>| {
+        let mut x = y.slice(s![..n]).to_owned();
+        let d = y.slice(s![n..]);
+        x.slice_mut(s![1..]).scaled_add(lambda, &d);
+        x.slice_mut(s![..-1]).scaled_add(-lambda, &d);
+        x
+    });
+    let data: Array1<f64> = random(n);
+    let mut b = Array1::zeros(2 * n - 1);
+    b.slice_mut(s![..n]).assign(&data);
+    let sol = lsqr(&op, &b, 0.0, &SolverOptions::new().rtol(1e-10).max_iter(500)).unwrap();
+    assert!(sol.report.converged);
+    // `(I + λ^2 D^T D) x = b` is tridiagonal
+    let t = Tridiagonal::new(
+        Array1::from_elem(n - 1, -lambda * lambda),
+        Array1::from_shape_fn(n, |i| {
+            1.0 + lambda * lambda * if i == 0 || i == n - 1 { 1.0 } else { 2.0 }
+        }),
+        Array1::from_elem(n - 1, -lambda * lambda),
+    )
+    .unwrap();
+    assert_close_l2!(&sol.x, &t.solve(&data).unwrap(), 1e-8);
+}
+
+#[test]
+fn lsqr_callback() {
+    let a: Array2<f64> = random((30, 10));
+    let b: Array1<f64> = random(30);
+    let options = SolverOptions::new().rtol(1e-10).max_iter(100);
+    let mut count = 0;
+    let sol = lsqr_with_callback(&a, &b, 0.0, &options, |_| {
+        count += 1;
+        true
+    })
+    .unwrap();
+    assert!(sol.report.converged);
+    assert_eq!(count, sol.report.iterations + 1);
+    // Residual of the normal equation
+    let r = a.t().dot(&(&b - &a.dot(&sol.x)));
+    assert!((sol.report.residual_norm - r.norm_l2()).abs() < 1e-12);
+}
@@ -0,0 +1,93 @@
+use ndarray::*;
+use ndarray_linalg::{krylov::*, *};
+
+fn test<A: Scalar + Lapack>() {
+    let n = 30;
+    // Random Hermitian matrices are indefinite
+    let a: Array2<A> = random_hermite(n);
+    let x: Array1<A> = random(n);
+    let b = a.dot(&x);
+    let sol = minres(
+        &a,
+        &IdentityOperator::new(),
+        &b,
+        &Array1::zeros(n),
+        &SolverOptions::new().rtol(A::real(1e-10)).max_iter(10 * n),
+    )
+    .unwrap();
+    assert!(sol.report.converged);
+    assert_close_l2!(&a.dot(&sol.x), &b, A::real(1e-10));
+}
+
+#[test]
+fn minres_real() {
+    test::<f64>();
+}
+
+#[test]
+fn minres_complex() {
+    test::<c64>();
+}
+
+/// Saddle-point matrix `[[H, B^T], [B, 0]]` with a positive definite `H`
+fn kkt(n: usize, m: usize) -> Array2<f64> {
+    let h: Array2<f64> = random_hpd(n);
+    let b: Array2<f64> = random((m, n));
+    let mut k = Array2::zeros((n + m, n + m));
+    k.slice_mut(s![..n, ..n]).assign(&h);
+    k.slice_mut(s![n.., ..n]).assign(&b);
+    k.slice_mut(s![..n, n..]).assign(&b.t());
+    k
+}
+
+#[test]
+fn minres_saddle_point() {
+    let k = kkt(20, 5);
+    let b: Array1<f64> = random(25);
+    let options = SolverOptions::new().rtol(1e-10).max_iter(500);
+    let sol = minres(&k, &IdentityOperator::new(), &b, &Array1::zeros(25), &options).unwrap();
+    assert!(sol.report.converged);
+    assert_close_l2!(&sol.x, &k.solve(&b).unwrap(), 1e-7);
+    // CG stops on the indefinite matrix
+    assert!(!cg(&k, &b, &Array1::zeros(25), &options).unwrap().report.converged);
+}
+
+#[test]
+fn minres_preconditioned() {
+    let n = 30;
+    let a: Array2<f64> = random_hermite(n);
+    let b: Array1<f64> = random(n);
+    // Positive definite preconditioner from the absolute values of the diagonal
+    let m = a.diag().mapv(|d| 1.0 / (d.abs() + 1.0)).into_diagonal();
+    let sol = minres(
+        &a,
+        &m,
+        &b,
+        &Array1::zeros(n),
+        &SolverOptions::new().rtol(1e-10).max_iter(10 * n),
+    )
+    .unwrap();
+    assert!(sol.report.converged);
+    assert_close_l2!(&a.dot(&sol.x), &b, 1e-9);
+}
+
+#[test]
+fn minres_max_iter() {
+    let n = 30;
+    let a: Array2<f64> = random_hermite(n);
+    let b: Array1<f64> = random(n);
+    let sol = minres(
+        &a,
+        &IdentityOperator::new(),
+        &b,
+        &Array1::zeros(n),
+        &SolverOptions::new().rtol(1e-20).max_iter(5),
+    )
+    .unwrap();
+    assert!(!sol.report.converged);
+    assert_eq!(sol.report.iterations, 5);
+    // The residual norm is non-increasing
+    for w in sol.report.history.windows(2) {
+        assert!(w[1] <= w[0] * (1.0 + 1e-10));
+    }
+}
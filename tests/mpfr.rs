@@ -0,0 +1,70 @@
+#![cfg(feature = "rug")]
+
+use ndarray::*;
+use ndarray_linalg::*;
+use rug::Float;
+
+const PREC: u32 = 256;
+
+fn hilbert(n: usize) -> Array2<Float> {
+    Array2::from_shape_fn((n, n), |(i, j)| Float::with_val(PREC, 1) / (i + j + 1) as u32)
+}
+
+fn matmul(a: &Array2<Float>, b: &Array2<Float>) -> Array2<Float> {
+    Array2::from_shape_fn((a.rows(), b.cols()), |(i, j)| {
+        let mut s = Float::new(PREC);
+        for k in 0..a.cols() {
+            s += Float::with_val(PREC, &a[(i, k)] * &b[(k, j)]);
+        }
+        s
+    })
+}
+
+fn assert_eye(a: &Array2<Float>, tol: f64) {
+    for ((i, j), x) in a.indexed_iter() {
+        let e = if i == j { 1 } else { 0 };
+        assert!((x.clone() - e).abs() < tol, "({}, {}) = {}", i, j, x);
+    }
+}
+
+#[test]
+fn mpfr_inv_hilbert() {
+    let n = 12;
+    let h = hilbert(n);
+    let inv = h.inv().unwrap();
+    // The inverse of the Hilbert matrix has the integer elements, e.g. `n^2` at the corner
+    assert!((inv[(0, 0)].clone() - (n * n) as u32).abs() < 1e-50);
+    assert_eye(&matmul(&h, &inv), 1e-50);
+    let x = h
+        .factorize()
+        .unwrap()
+        .solve(&Array1::from_elem(n, Float::with_val(PREC, 1)))
+        .unwrap();
+    assert!((x[0].clone() + n as u32).abs() < 1e-50);
+}
+
+#[test]
+fn mpfr_cholesky() {
+    let h = hilbert(8);
+    let l = h.cholesky(UPLO::Lower).unwrap();
+    let u = h.view().cholesky(UPLO::Upper).unwrap();
+    assert_eq!(l.t(), u);
+    let llt = matmul(&l, &u);
+    for (x, y) in llt.iter().zip(h.iter()) {
+        assert!((x.clone() - y).abs() < 1e-60);
+    }
+}
+
+#[test]
+fn mpfr_qr() {
+    let a: Array2<f64> = random((5, 3));
+    let a = a.mapv(|x| Float::with_val(PREC, x));
+    let (q, r) = a.qr().unwrap();
+    assert_eq!(q.dim(), (5, 3));
+    assert_eq!(r.dim(), (3, 3));
+    assert_eye(&matmul(&q.t().to_owned(), &q), 1e-60);
+    let qr = matmul(&q, &r);
+    for (x, y) in qr.iter().zip(a.iter()) {
+        assert!((x.clone() - y).abs() < 1e-60);
+    }
+}
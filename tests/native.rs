@@ -0,0 +1,127 @@
+use ndarray::*;
+use ndarray_linalg::error::LinalgError;
+use ndarray_linalg::lapack::native;
+use ndarray_linalg::layout::*;
+use ndarray_linalg::*;
+
+fn test_lu<A: Scalar + Lapack + native::NativeScalar>(a: Array2<A>) {
+    let n = a.rows();
+    let b: Array1<A> = random(n);
+    let mut f = a.clone();
+    let l = f.layout().unwrap();
+    let ipiv = native::lu(l, f.as_allocated_mut().unwrap()).unwrap();
+    for &t in &[Transpose::No, Transpose::Transpose, Transpose::Hermite] {
+        let mut x = b.clone();
+        native::solve(l, t, f.as_allocated().unwrap(), &ipiv, x.as_slice_mut().unwrap()).unwrap();
+        let ans = match t {
+            Transpose::No => a.solve(&b),
+            Transpose::Transpose => a.solve_t(&b),
+            Transpose::Hermite => a.solve_h(&b),
+        };
+        assert_close_l2!(&x, &ans.unwrap(), A::real(1e-7));
+    }
+    let mut inv = f.clone();
+    native::inv(l, inv.as_allocated_mut().unwrap(), &ipiv).unwrap();
+    assert_close_l2!(&inv, &a.inv().unwrap(), A::real(1e-7));
+    let anorm = a.opnorm_one().unwrap();
+    let rcond = native::rcond(l, f.as_allocated().unwrap(), anorm).unwrap();
+    assert_rclose!(rcond, A::real(1.0) / (anorm * inv.opnorm_one().unwrap()), A::real(1e-7));
+}
+
+fn test_cholesky<A: Scalar + Lapack + native::NativeScalar>(a: Array2<A>) {
+    let n = a.rows();
+    let b: Array1<A> = random(n);
+    for &uplo in &[UPLO::Lower, UPLO::Upper] {
+        let mut f = a.clone();
+        let l = f.layout().unwrap();
+        native::cholesky(l, uplo, f.as_allocated_mut().unwrap()).unwrap();
+        let f = f.into_triangular(uplo);
+        assert_close_l2!(&f, &a.cholesky(uplo).unwrap(), A::real(1e-7));
+        let mut x = b.clone();
+        native::solve_cholesky(l, uplo, f.as_allocated().unwrap(), x.as_slice_mut().unwrap()).unwrap();
+        assert_close_l2!(&x, &a.solvec(&b).unwrap(), A::real(1e-7));
+        let mut inv = f.clone();
+        native::inv_cholesky(l, uplo, inv.as_allocated_mut().unwrap()).unwrap();
+        let ans = a.invc().unwrap().into_triangular(uplo);
+        assert_close_l2!(&inv.into_triangular(uplo), &ans, A::real(1e-7));
+    }
+}
+
+#[test]
+fn native_lu() {
+    test_lu::<f64>(random((5, 5)));
+    test_lu::<f64>(random((5, 5).f()));
+    test_lu::<c64>(random((5, 5)));
+    test_lu::<c64>(random((5, 5).f()));
+}
+
+#[test]
+fn native_cholesky() {
+    test_cholesky::<f64>(random_hpd(5));
+    test_cholesky::<f64>(random_hpd(5).reversed_axes());
+    test_cholesky::<c64>(random_hpd(5));
+    test_cholesky::<c64>(random_hpd(5).reversed_axes());
+}
+
+#[test]
+fn native_lu_blocked() {
+    let n = 2 * native::BLOCK_SIZE + 22;
+    test_lu::<f64>(random((n, n)));
+    test_lu::<f64>(random((n, n).f()));
+    test_lu::<c64>(random((n, n)));
+    test_lu::<c64>(random((n, n).f()));
+}
+
+#[test]
+fn native_lu_rectangular() {
+    let k = native::BLOCK_SIZE + 36;
+    for &(m, n) in &[(k + 50, k), (k, k + 50)] {
+        for a in vec![random((m, n)), random((m, n).f())] {
+            let a: Array2<f64> = a;
+            let l = a.layout().unwrap();
+            let mut f = a.clone();
+            let ipiv = native::lu(l, f.as_allocated_mut().unwrap()).unwrap();
+            assert_eq!(ipiv.len(), m.min(n));
+            // `A = P L U`, where `L` is unit lower trapezoidal and `U` is upper trapezoidal
+            let r = m.min(n);
+            let lower = Array2::from_shape_fn((m, r), |(i, j)| match i.cmp(&j) {
+                std::cmp::Ordering::Greater => f[(i, j)],
+                std::cmp::Ordering::Equal => 1.0,
+                std::cmp::Ordering::Less => 0.0,
+            });
+            let upper = Array2::from_shape_fn((r, n), |(i, j)| if i <= j { f[(i, j)] } else { 0.0 });
+            let mut plu = lower.dot(&upper);
+            for (i, &p) in ipiv.iter().enumerate().rev() {
+                let p = p as usize - 1;
+                for j in 0..n {
+                    plu.swap((i, j), (p, j));
+                }
+            }
+            assert_close_l2!(&plu, &a, 1e-9);
+        }
+    }
+}
+
+#[test]
+fn native_cholesky_blocked() {
+    let n = 2 * native::BLOCK_SIZE + 22;
+    test_cholesky::<f64>(random_hpd(n));
+    test_cholesky::<f64>(random_hpd(n).reversed_axes());
+    test_cholesky::<c64>(random_hpd(n));
+    test_cholesky::<c64>(random_hpd(n).reversed_axes());
+}
+
+#[test]
+fn native_singular() {
+    let mut a = arr2(&[[1.0, 2.0], [2.0, 4.0]]);
+    let l = a.layout().unwrap();
+    match native::lu(l, a.as_allocated_mut().unwrap()) {
+        Err(LinalgError::Lapack { return_code }) => assert_eq!(return_code, 2),
+        _ => panic!("must fail"),
+    }
+    let mut a = arr2(&[[1.0, 2.0], [2.0, 1.0]]);
+    match native::cholesky(l, UPLO::Lower, a.as_allocated_mut().unwrap()) {
+        Err(LinalgError::Lapack { return_code }) => assert_eq!(return_code, 2),
+        _ => panic!("must fail"),
+    }
+}
@@ -0,0 +1,59 @@
+use ndarray::*;
+use ndarray_linalg::*;
+
+#[test]
+fn norm_l2_random() {
+    let a: Array2<f64> = random((4, 3));
+    let naive = a.iter().map(|x| x * x).sum::<f64>().sqrt();
+    assert_rclose!(a.norm_l2(), naive, 1e-12);
+    let (scale, sumsq) = a.norm_l2_scaled();
+    assert_rclose!(scale, a.norm_max(), 1e-15);
+    assert!(sumsq >= 1.0 && sumsq <= 12.0);
+}
+
+#[test]
+fn norm_l2_overflow() {
+    let a = arr1(&[3e300, 4e300]);
+    assert_rclose!(a.norm_l2(), 5e300, 1e-12);
+    let c = arr1(&[c64::new(3e300, 4e300), c64::new(0.0, 0.0)]);
+    assert_rclose!(c.norm_l2(), 5e300, 1e-12);
+}
+
+#[test]
+fn norm_l2_underflow() {
+    let a = arr1(&[3e-300, 4e-300]);
+    assert_rclose!(a.norm_l2(), 5e-300, 1e-12);
+    let c = arr1(&[c32::new(3e-30, 0.0), c32::new(0.0, 4e-30)]);
+    assert_rclose!(c.norm_l2(), 5e-30, 1e-5);
+}
+
+#[test]
+fn norm_l2_zero() {
+    let a: Array1<f64> = Array1::zeros(3);
+    assert_eq!(a.norm_l2(), 0.0);
+    assert_eq!(a.norm_l2_scaled(), (0.0, 1.0));
+}
+
+#[test]
+fn norm_axis() {
+    let a: Array2<c64> = random((4, 3));
+    for &(axis, ord) in &[(0, NormOrd::L1), (0, NormOrd::L2), (1, NormOrd::Max), (1, NormOrd::L2)] {
+        let norms = a.norm_axis(Axis(axis), ord);
+        assert_eq!(norms.len(), a.len_of(Axis(1 - axis)));
+        for (lane, &n) in a.lanes(Axis(axis)).into_iter().zip(norms.iter()) {
+            let truth = match ord {
+                NormOrd::L1 => lane.norm_l1(),
+                NormOrd::L2 => lane.norm_l2(),
+                NormOrd::Max => lane.norm_max(),
+            };
+            assert_rclose!(n, truth, 1e-12);
+        }
+    }
+}
+
+#[test]
+fn norm_axis_empty() {
+    let a: Array2<f64> = Array2::zeros((0, 3));
+    assert_eq!(a.norm_axis(Axis(0), NormOrd::L2), Array1::zeros(3));
+    assert_eq!(a.norm_axis(Axis(1), NormOrd::L2).len(), 0);
+}
@@ -0,0 +1,117 @@
+use ndarray::*;
+use ndarray_linalg::*;
+
+/// Compare the action and the adjoint action with those of the dense matrix
+fn test_adjoint<Op>(op: &Op, dense: &Array2<c64>)
+where
+    Op: AdjointOperator<Elem = c64>,
+{
+    let (m, n) = op.shape();
+    assert_eq!(dense.dim(), (m, n));
+    let x: Array1<c64> = random(n);
+    let y: Array1<c64> = random(m);
+    let ah: Array2<c64> = conjugate(dense);
+    assert_close_l2!(&op.apply(&x), &dense.dot(&x), 1e-9);
+    assert_close_l2!(&op.apply_adjoint(&y), &ah.dot(&y), 1e-9);
+    let y2: Array2<c64> = random((m, 2));
+    assert_close_l2!(&op.apply2_adjoint(&y2), &ah.dot(&y2), 1e-9);
+}
+
+#[test]
+fn adjoint_dense() {
+    let a: Array2<c64> = random((4, 6));
+    test_adjoint(&a, &a);
+}
+
+#[test]
+fn adjoint_diagonal() {
+    let d: Array1<c64> = random(5);
+    test_adjoint(&d.as_diagonal(), &from_diag(d.as_slice().unwrap()));
+}
+
+#[test]
+fn adjoint_banded() {
+    let a: Array2<c64> = random((6, 6));
+    let b = BandedMatrix::from_dense(&a, 2, 1).unwrap();
+    test_adjoint(&b, &b.to_dense());
+}
+
+#[test]
+fn adjoint_tridiagonal() {
+    let t = Tridiagonal::new(random(4), random(5), random(4)).unwrap();
+    test_adjoint(&t, &t.to_dense());
+    let h: HermitianTridiagonal<c64> = HermitianTridiagonal::new(random(5), random(4)).unwrap();
+    test_adjoint(&h, &h.to_dense());
+}
+
+#[test]
+fn adjoint_toeplitz_hankel() {
+    let t = Toeplitz::new(random(5), random(5)).unwrap();
+    test_adjoint(&t, &t.to_dense());
+    let h = Hankel::new(random(4), random(6)).unwrap();
+    test_adjoint(&h, &h.to_dense());
+}
+
+#[test]
+fn adjoint_circulant() {
+    let c = Circulant::new(random(6));
+    test_adjoint(&c, &c.to_dense());
+}
+
+#[test]
+fn adjoint_block_diag() {
+    let a = BlockDiag::from_blocks(vec![random((2, 2)), random((3, 3))]).unwrap();
+    test_adjoint(&a, &a.to_dense());
+}
+
+#[test]
+fn adjoint_woodbury() {
+    let a = DiagPlusLowRank::new(random(6), random((6, 2)), random((6, 2))).unwrap();
+    test_adjoint(&a, &a.to_dense());
+}
+
+#[test]
+fn factorized_as_inverse() {
+    let a: Array2<c64> = random_regular(5);
+    let f = a.factorize().unwrap();
+    test_adjoint(&f, &a.inv().unwrap());
+
+    let h: Array2<c64> = random_hpd(5);
+    let c = h.factorizec(UPLO::Lower).unwrap();
+    test_adjoint(&c, &h.inv().unwrap());
+}
+
+#[test]
+fn structured_factorized_as_inverse() {
+    let a: Array2<c64> = random_regular(6);
+    let b = BandedMatrix::from_dense(&a, 5, 5).unwrap();
+    let f = b.factorize().unwrap();
+    test_adjoint(&f, &a.inv().unwrap());
+
+    let h: Array2<c64> = random_hpd(6);
+    let hb = BandedMatrix::from_dense(&h, 5, 5).unwrap();
+    for &uplo in &[UPLO::Upper, UPLO::Lower] {
+        let c = hb.factorizec(uplo).unwrap();
+        test_adjoint(&c, &h.inv().unwrap());
+    }
+
+    let p = PackedHermitian::from_dense(&h, UPLO::Upper).unwrap();
+    let bk = p.factorizeh().unwrap();
+    test_adjoint(&bk, &h.inv().unwrap());
+}
+
+#[test]
+fn identity_operator() {
+    let i = IdentityOperator::with_size(5);
+    test_adjoint(&i, &Array::eye(5));
+}
+
+#[test]
+fn fn_operator() {
+    let a: Array2<c64> = random((3, 4));
+    let ah: Array2<c64> = conjugate(&a);
+    let op = FnOperator::new((3, 4), |x: ArrayView1<c64>| a.dot(&x)).with_adjoint(|y: ArrayView1<c64>| ah.dot(&y));
+    test_adjoint(&op, &a);
+    let x: Array2<c64> = random((4, 2));
+    assert_close_l2!(&op.apply2(&x), &a.dot(&x), 1e-9);
+}
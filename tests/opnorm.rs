@@ -49,3 +49,93 @@ fn opnorm_4x3() {
 fn opnorm_4x3_t() {
     test(gen(4, 3, true), 42.0, 24.0, 650.0.sqrt());
 }
+
+#[test]
+fn opnorm_two_diag() {
+    let a = arr2(&[[1.0, 0.0, 0.0], [0.0, -4.0, 0.0], [0.0, 0.0, 2.0]]);
+    assert_rclose!(a.opnorm_two().unwrap(), 4.0, 1e-12);
+    assert_rclose!(a.t().opnorm_two().unwrap(), 4.0, 1e-12);
+}
+
+#[test]
+fn opnorm_two_unitary() {
+    let q: Array2<c64> = random_unitary(5);
+    assert_rclose!(q.opnorm_two().unwrap(), 1.0, 1e-12);
+}
+
+#[test]
+fn opnorm_two_rank_one() {
+    // `|u v^T|_2 = |u| |v|`, which is equal to the Frobenius norm
+    let u = arr1(&[1.0, 2.0, 3.0]);
+    let v = arr1(&[4.0, 5.0]);
+    let a = u
+        .clone()
+        .into_shape((3, 1))
+        .unwrap()
+        .dot(&v.clone().into_shape((1, 2)).unwrap());
+    assert_rclose!(a.opnorm_two().unwrap(), 14.0.sqrt() * 41.0.sqrt(), 1e-12);
+    assert_rclose!(a.opnorm_two().unwrap(), a.opnorm_fro().unwrap(), 1e-12);
+}
+
+#[test]
+fn opnorm_two_bounds() {
+    let a = gen(3, 4, false);
+    let two = a.opnorm_two().unwrap();
+    let fro = a.opnorm_fro().unwrap();
+    assert!(two <= fro && fro <= 3.0.sqrt() * two);
+    assert!(two <= (a.opnorm_one().unwrap() * a.opnorm_inf().unwrap()).sqrt());
+}
+
+fn test_onenorm_estimate<A: Scalar + Lapack>() {
+    for &(m, n) in &[(1, 1), (8, 8), (10, 6), (6, 10)] {
+        let a: Array2<A> = random((m, n));
+        let exact = a.opnorm_one().unwrap();
+        let est = onenorm_estimate(&a);
+        assert!(est <= exact * A::real(1.0 + 1e-10));
+        assert!(est >= exact / A::real(3.0));
+    }
+}
+
+#[test]
+fn onenorm_estimate_random() {
+    test_onenorm_estimate::<f64>();
+    test_onenorm_estimate::<c64>();
+}
+
+#[test]
+fn onenorm_estimate_exact() {
+    // The maximal column is found at the first step
+    let a = arr2(&[[1.0, -7.0, 2.0], [0.0, 3.0, 1.0], [-2.0, 1.0, 1.0]]);
+    assert_rclose!(onenorm_estimate(&a), 11.0, 1e-12);
+    let d = arr1(&[1.0, -5.0, 2.0]);
+    assert_rclose!(onenorm_estimate(&from_diag(d.as_slice().unwrap())), 5.0, 1e-12);
+}
+
+#[test]
+fn onenorm_estimate_inverse() {
+    let a: Array2<f64> = random_regular(20);
+    let exact = a.inv().unwrap().opnorm_one().unwrap();
+    let est = onenorm_estimate(&a.factorize().unwrap());
+    assert!(est <= exact * (1.0 + 1e-10));
+    assert!(est >= exact / 3.0);
+}
+
+#[test]
+fn opnorm_stack() {
+    let a: Array3<f64> = random((3, 4, 2));
+    let b: Array3<c64> = random((3, 2, 5).f());
+    for &t in &[NormType::One, NormType::Infinity, NormType::Frobenius] {
+        let norms = a.opnorm(t).unwrap();
+        for (m, &n) in a.outer_iter().zip(norms.iter()) {
+            assert_rclose!(n, m.to_owned().opnorm(t).unwrap(), 1e-12);
+        }
+        let norms = b.opnorm(t).unwrap();
+        for (m, &n) in b.outer_iter().zip(norms.iter()) {
+            assert_rclose!(n, m.to_owned().opnorm(t).unwrap(), 1e-12);
+        }
+    }
+    let norms = a.opnorm_two().unwrap();
+    for (m, &n) in a.outer_iter().zip(norms.iter()) {
+        assert_rclose!(n, m.opnorm_two().unwrap(), 1e-12);
+    }
+}
@@ -0,0 +1,47 @@
+use ndarray_linalg::{krylov::*, *};
+
+#[test]
+fn default_options() {
+    let options: SolverOptions<f64> = SolverOptions::new();
+    assert_eq!(options, SolverOptions::default());
+    assert_eq!(options.criterion, StoppingCriterion::default());
+    assert_eq!(options.side, PreconditionerSide::Right);
+}
+
+#[test]
+fn tolerances() {
+    let options = SolverOptions::new().rtol(1e-8).atol(1e-12);
+    assert_eq!(
+        options.criterion,
+        StoppingCriterion::Mixed {
+            rtol: 1e-8,
+            atol: 1e-12
+        }
+    );
+    // The order does not matter
+    assert_eq!(SolverOptions::new().atol(1e-12).rtol(1e-8), options);
+    // The tolerance of the default criterion is kept
+    let options: SolverOptions<f64> = SolverOptions::new().atol(1e-12);
+    assert_eq!(
+        options.criterion,
+        StoppingCriterion::Mixed {
+            rtol: f64::EPSILON.sqrt(),
+            atol: 1e-12
+        }
+    );
+    let options = SolverOptions::new()
+        .criterion(StoppingCriterion::Absolute(1e-6))
+        .rtol(1e-3);
+    assert_eq!(options.criterion, StoppingCriterion::Mixed { rtol: 1e-3, atol: 1e-6 });
+}
+
+#[test]
+fn builder() {
+    let options = SolverOptions::<f64>::new()
+        .max_iter(10)
+        .restart(5)
+        .side(PreconditionerSide::Left);
+    assert_eq!(options.max_iter, 10);
+    assert_eq!(options.restart, 5);
+    assert_eq!(options.side, PreconditionerSide::Left);
+}
@@ -0,0 +1,53 @@
+use ndarray::*;
+use ndarray_linalg::*;
+
+#[test]
+fn packed_dense_roundtrip() {
+    let a: Array2<c64> = random_hermite(5);
+    for &uplo in &[UPLO::Upper, UPLO::Lower] {
+        let p = PackedHermitian::from_dense(&a, uplo).unwrap();
+        assert_eq!(p.as_slice().len(), 15);
+        assert_close_l2!(&p.to_dense(), &a, 1e-15);
+        let q = PackedHermitian::new(5, uplo, p.as_slice().to_vec()).unwrap();
+        assert_close_l2!(&q.to_dense(), &a, 1e-15);
+    }
+    assert!(PackedHermitian::new(5, UPLO::Upper, vec![c64::new(0., 0.); 14]).is_err());
+}
+
+#[test]
+fn packed_solveh() {
+    let n = 8;
+    let a: Array2<f64> = random_hermite(n);
+    let x: Array1<f64> = random(n);
+    let b = a.dot(&x);
+    for &uplo in &[UPLO::Upper, UPLO::Lower] {
+        let p = PackedHermitian::from_dense(&a, uplo).unwrap();
+        assert_close_l2!(&p.dot(&x), &b, 1e-12);
+        assert_close_l2!(&p.solveh(&b).unwrap(), &x, 1e-7);
+        let f = p.factorizeh().unwrap();
+        assert_close_l2!(&f.solveh(&b).unwrap(), &x, 1e-7);
+    }
+}
+
+#[test]
+fn packed_solveh_complex() {
+    let n = 6;
+    let a: Array2<c64> = random_hermite(n);
+    let x: Array1<c64> = random(n);
+    for &uplo in &[UPLO::Upper, UPLO::Lower] {
+        let f = PackedHermitian::from_dense(&a, uplo)
+            .unwrap()
+            .factorizeh_into()
+            .unwrap();
+        assert_close_l2!(&f.solveh(&a.dot(&x)).unwrap(), &x, 1e-7);
+    }
+}
+
+#[test]
+fn packed_invh() {
+    let a: Array2<f64> = random_hermite(6);
+    for &uplo in &[UPLO::Upper, UPLO::Lower] {
+        let inv = PackedHermitian::from_dense(&a, uplo).unwrap().invh().unwrap();
+        assert_close_l2!(&inv.to_dense().dot(&a), &Array2::eye(6), 1e-7);
+    }
+}
@@ -0,0 +1,67 @@
+use ndarray::*;
+use ndarray_linalg::*;
+
+#[test]
+fn pencil_regular() {
+    let a: Array2<f64> = random((3, 3));
+    let b: Array2<f64> = Array::eye(3);
+    let st = pencil_structure(&a, &b, None).unwrap();
+    assert!(st.is_regular());
+    assert!(st.infinite_blocks.is_empty());
+    assert_eq!(st.regular_a.dim(), (3, 3));
+    assert_eq!(st.regular_b.dim(), (3, 3));
+}
+
+#[test]
+fn pencil_infinite() {
+    let a: Array2<f64> = Array::eye(3);
+    let b: Array2<f64> = array![[1., 0., 0.], [0., 1., 0.], [0., 0., 0.]];
+    let st = pencil_structure(&a, &b, None).unwrap();
+    assert!(st.is_regular());
+    assert_eq!(st.infinite_blocks, vec![1]);
+    assert_eq!(st.regular_a.dim(), (2, 2));
+}
+
+#[test]
+fn pencil_infinite_jordan() {
+    let a: Array2<f64> = Array::eye(2);
+    let b: Array2<f64> = array![[0., 1.], [0., 0.]];
+    let st = pencil_structure(&a, &b, None).unwrap();
+    assert!(st.is_regular());
+    assert_eq!(st.infinite_blocks, vec![2]);
+    assert_eq!(st.regular_a.dim(), (0, 0));
+}
+
+#[test]
+fn pencil_right_singular() {
+    let a: Array2<f64> = array![[1., 0.]];
+    let b: Array2<f64> = array![[0., 1.]];
+    let st = pencil_structure(&a, &b, None).unwrap();
+    assert!(!st.is_regular());
+    assert_eq!(st.right_minimal_indices, vec![1]);
+    assert!(st.left_minimal_indices.is_empty());
+    assert!(st.infinite_blocks.is_empty());
+}
+
+#[test]
+fn pencil_left_singular() {
+    let a: Array2<f64> = array![[1.], [0.]];
+    let b: Array2<f64> = array![[0.], [1.]];
+    let st = pencil_structure(&a, &b, None).unwrap();
+    assert!(!st.is_regular());
+    assert!(st.right_minimal_indices.is_empty());
+    assert_eq!(st.left_minimal_indices, vec![1]);
+}
+
+#[test]
+fn pencil_square_singular() {
+    // det(A - λB) = 0 for all λ although the pencil is square
+    let a: Array2<f64> = array![[1., 0., 0.], [0., 0., 0.], [0., 0., 2.]];
+    let b: Array2<f64> = array![[0., 1., 0.], [0., 0., 0.], [0., 0., 1.]];
+    let st = pencil_structure(&a, &b, None).unwrap();
+    assert!(!st.is_regular());
+    assert_eq!(st.right_minimal_indices, vec![1]);
+    assert_eq!(st.left_minimal_indices, vec![0]);
+    assert_eq!(st.regular_a.dim(), (1, 1));
+    assert_rclose!(st.regular_a[(0, 0)].abs() / st.regular_b[(0, 0)].abs(), 2.0, 1e-9);
+}
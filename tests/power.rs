@@ -0,0 +1,64 @@
+use ndarray::*;
+use ndarray_linalg::*;
+
+#[test]
+fn dominant() {
+    let a: Array2<f64> = random_hpd(5);
+    let (e, _) = a.eigh(UPLO::Upper).unwrap();
+    let x0: Array1<f64> = random(5);
+    let pair = dominant_eigenpair(&a, &x0, StoppingCriterion::RelativeResidual(1e-9), 10000);
+    assert!(pair.report.converged);
+    assert_rclose!(pair.value, e[4], 1e-7);
+    assert_close_l2!(&a.dot(&pair.vector), &(pair.value * &pair.vector), 1e-7);
+}
+
+#[test]
+fn dominant_complex() {
+    let a: Array2<c64> = random_hpd(5);
+    let (e, _) = a.eigh(UPLO::Upper).unwrap();
+    let x0: Array1<c64> = random(5);
+    let pair = dominant_eigenpair(&a, &x0, StoppingCriterion::RelativeResidual(1e-9), 10000);
+    assert!(pair.report.converged);
+    assert_rclose!(pair.value.re, e[4], 1e-7);
+}
+
+#[test]
+fn dominant_max_iter() {
+    // eigenvalues 1 and -1 have the same modulus
+    let a = arr2(&[[0.0, 1.0], [1.0, 0.0]]);
+    let x0 = arr1(&[1.0, 0.0]);
+    let pair = dominant_eigenpair(&a, &x0, StoppingCriterion::RelativeResidual(1e-9), 10);
+    assert!(!pair.report.converged);
+    assert_eq!(pair.report.iterations(), 11);
+}
+
+#[test]
+fn inverse() {
+    let a = arr2(&[[4.0, 1.0, 0.0], [1.0, 3.0, 1.0], [0.0, 1.0, 2.0]]);
+    let (e, _) = a.eigh(UPLO::Upper).unwrap();
+    let x0 = arr1(&[1.0, 1.0, 1.0]);
+    let pair = inverse_iteration(&a, e[1] + 0.1, &x0, StoppingCriterion::RelativeResidual(1e-10), 100).unwrap();
+    assert!(pair.report.converged);
+    assert_rclose!(pair.value, e[1], 1e-8);
+    assert_close_l2!(&a.dot(&pair.vector), &(pair.value * &pair.vector), 1e-8);
+}
+
+#[test]
+fn inverse_h() {
+    let a: Array2<f64> = random_hpd(5);
+    let (e, _) = a.eigh(UPLO::Upper).unwrap();
+    let x0: Array1<f64> = random(5);
+    let criterion = StoppingCriterion::RelativeResidual(1e-10);
+    let pair = inverse_iteration_h(&a, 0.0, UPLO::Upper, &x0, criterion, 10000).unwrap();
+    assert!(pair.report.converged);
+    assert_rclose!(pair.value, e[0], 1e-8);
+    // A - σI is not positive definite
+    assert!(inverse_iteration_h(&a, e[4] + 1.0, UPLO::Upper, &x0, criterion, 10).is_err());
+}
+
+#[test]
+fn inverse_not_square() {
+    let a: Array2<f64> = random((3, 4));
+    let x0: Array1<f64> = random(3);
+    assert!(inverse_iteration(&a, 0.0, &x0, StoppingCriterion::default(), 10).is_err());
+}
@@ -0,0 +1,41 @@
+use ndarray::*;
+use ndarray_linalg::*;
+
+#[test]
+fn powi_zero() {
+    let a: Array2<f64> = random((4, 4));
+    assert_close_l2!(&a.powi(0).unwrap(), &Array2::eye(4), 1e-15);
+}
+
+#[test]
+fn powi_positive() {
+    let a: Array2<f64> = random((5, 5));
+    let mut ans = Array2::eye(5);
+    for n in 1..12 {
+        ans = ans.dot(&a);
+        assert_close_l2!(&a.powi(n).unwrap(), &ans, 1e-10);
+    }
+}
+
+#[test]
+fn powi_negative() {
+    let a: Array2<c64> = random((5, 5));
+    let p = a.powi(7).unwrap();
+    let q = a.powi(-7).unwrap();
+    assert_close_l2!(&p.dot(&q), &Array2::eye(5), 1e-7);
+    assert_close_l2!(&a.powi(-1).unwrap(), &a.inv().unwrap(), 1e-10);
+}
+
+#[test]
+fn powi_singular() {
+    let a: Array2<f64> = array![[1., 2.], [2., 4.]];
+    // A^2 = 5 A
+    assert_close_l2!(&a.powi(2).unwrap(), &a.mapv(|x| 5. * x), 1e-12);
+    assert!(a.powi(-1).is_err());
+}
+
+#[test]
+fn powi_not_square() {
+    let a: Array2<f64> = random((3, 4));
+    assert!(a.powi(2).is_err());
+}
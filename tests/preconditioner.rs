@@ -0,0 +1,155 @@
+use ndarray::*;
+use ndarray_linalg::{error::LinalgError, krylov::*, *};
+
+#[test]
+fn incomplete_cholesky_pattern() {
+    // Arrow matrix, whose Cholesky factor fills in
+    let n = 6;
+    let mut a: Array2<c64> = Array2::eye(n) * c64::new(4.0, 0.0);
+    for i in 1..n {
+        a[(i, 0)] = c64::new(0.5, 0.5);
+        a[(0, i)] = c64::new(0.5, -0.5);
+    }
+    for i in 2..n {
+        a[(i, i - 1)] = c64::new(-1.0, 0.0);
+        a[(i - 1, i)] = c64::new(-1.0, 0.0);
+    }
+    let ic = IncompleteCholesky::new(&a).unwrap();
+    let l = ic.factor();
+    let lh: Array2<c64> = conjugate(&l);
+    let llh = l.dot(&lh);
+    for ((i, j), &x) in a.indexed_iter() {
+        if x != c64::new(0.0, 0.0) {
+            assert!((llh[(i, j)] - x).norm() < 1e-12);
+        }
+        if i < j || x == c64::new(0.0, 0.0) {
+            assert_eq!(l[(i, j)], c64::new(0.0, 0.0));
+        }
+    }
+    // Applies `(L L^H)^{-1}`
+    let x: Array1<c64> = random(n);
+    assert_close_l2!(&ic.apply(&llh.dot(&x)), &x, 1e-12);
+}
+
+#[test]
+fn incomplete_cholesky_breakdown() {
+    let a = arr2(&[[1.0, 2.0], [2.0, 1.0]]);
+    assert!(IncompleteCholesky::new(&a).is_err());
+}
+
+#[test]
+fn jacobi_diagonal() {
+    let a = arr2(&[[2.0, 1.0], [1.0, 4.0]]);
+    let m = jacobi(&a).unwrap();
+    assert_eq!(m.apply(&arr1(&[1.0, 1.0])), arr1(&[0.5, 0.25]));
+    assert!(jacobi(&arr2(&[[0.0, 1.0], [1.0, 1.0]])).is_err());
+}
+
+/// 2-D convection-diffusion operator on the `m x m` grid with the upwind difference
+fn convection_diffusion_2d(m: usize, peclet: f64) -> CsrMatrix<f64> {
+    let n = m * m;
+    let mut a = Array2::zeros((n, n));
+    for i in 0..m {
+        for j in 0..m {
+            let k = i * m + j;
+            a[(k, k)] = 4.0 + peclet;
+            if i > 0 {
+                a[(k, k - m)] = -1.0 - peclet;
+            }
+            if i + 1 < m {
+                a[(k, k + m)] = -1.0;
+            }
+            if j > 0 {
+                a[(k, k - 1)] = -1.0;
+            }
+            if j + 1 < m {
+                a[(k, k + 1)] = -1.0;
+            }
+        }
+    }
+    CsrMatrix::from_dense(&a)
+}
+
+#[test]
+fn incomplete_lu_pattern() {
+    let a = convection_diffusion_2d(5, 2.0);
+    let ilu = IncompleteLU::new(&a).unwrap();
+    let l = ilu.lower().to_dense();
+    let u = ilu.upper().to_dense();
+    let lu = l.dot(&u);
+    for ((i, j), &x) in a.to_dense().indexed_iter() {
+        if x != 0.0 {
+            assert!((lu[(i, j)] - x).abs() < 1e-12);
+        } else {
+            assert_eq!(l[(i, j)], 0.0);
+            assert_eq!(u[(i, j)], 0.0);
+        }
+    }
+    assert_eq!(ilu.pivots(), u.diag());
+    // Applies `(L U)^{-1}`
+    let x: Array1<f64> = random(25);
+    assert_close_l2!(&ilu.apply(&lu.dot(&x)), &x, 1e-12);
+}
+
+#[test]
+fn incomplete_lu_hermitian() {
+    // ILU(0) of a Hermitian matrix is IC(0) in the `L D L^H` form
+    let laplacian = convection_diffusion_2d(4, 0.0).to_dense();
+    let a = Array2::from_shape_fn((16, 16), |(i, j)| {
+        let x = laplacian[(i, j)];
+        if i == j {
+            c64::new(x + 2.0, 0.0)
+        } else if i + 1 == j {
+            c64::new(x, 0.5)
+        } else if i == j + 1 {
+            c64::new(x, -0.5)
+        } else {
+            c64::new(x, 0.0)
+        }
+    });
+    let ilu = IncompleteLU::new(&CsrMatrix::from_dense(&a)).unwrap();
+    let ic = IncompleteCholesky::new(&a).unwrap();
+    assert!(ilu.pivots().iter().all(|d| d.re > 0.0 && d.im.abs() < 1e-12));
+    let x: Array1<c64> = random(16);
+    assert_close_l2!(&ilu.apply(&x), &ic.apply(&x), 1e-10);
+}
+
+#[test]
+fn incomplete_lu_banded() {
+    let a: Array2<f64> = random((8, 8)) + Array2::<f64>::eye(8) * 4.0;
+    let banded = BandedMatrix::from_dense(&a, 1, 2).unwrap();
+    // No fill-in occurs in the band, so that ILU(0) is exact
+    let ilu = IncompleteLU::from_banded(&banded).unwrap();
+    let b: Array1<f64> = random(8);
+    assert_close_l2!(&banded.dot(&ilu.apply(&b)), &b, 1e-10);
+}
+
+#[test]
+fn incomplete_lu_zero_pivot() {
+    let a = arr2(&[[1.0, 1.0, 0.0], [1.0, 1.0, 1.0], [0.0, 1.0, 1.0]]);
+    match IncompleteLU::new(&CsrMatrix::from_dense(&a)) {
+        Err(LinalgError::Lapack { return_code }) => assert_eq!(return_code, 2),
+        _ => panic!("must fail at the second pivot"),
+    }
+    // Missing diagonal element
+    let a = arr2(&[[1.0, 1.0], [1.0, 0.0]]);
+    assert!(IncompleteLU::new(&CsrMatrix::from_dense(&a)).is_err());
+}
+
+#[test]
+fn gmres_incomplete_lu() {
+    let a = convection_diffusion_2d(12, 10.0);
+    let n = 144;
+    let b: Array1<f64> = random(n);
+    let options = SolverOptions::new().rtol(1e-10).max_iter(1000).restart(20);
+    let plain = gmres(&a, &IdentityOperator::new(), &b, &Array1::zeros(n), &options).unwrap();
+    let m = IncompleteLU::new(&a).unwrap();
+    let sol = gmres(&a, &m, &b, &Array1::zeros(n), &options).unwrap();
+    assert!(sol.report.converged);
+    assert!(sol.report.iterations < plain.report.iterations);
+    assert_close_l2!(&a.dot(&sol.x), &b, 1e-9);
+
+    let sol = bicgstab(&a, &m, &b, &Array1::zeros(n), &options).unwrap();
+    assert!(sol.report.converged);
+    assert_close_l2!(&a.dot(&sol.x), &b, 1e-9);
+}
@@ -71,3 +71,28 @@ fn qr_4x3_t() {
     let a = random((4, 3).f());
     test(&a, 4, 3);
 }
+
+#[test]
+fn qr_stack() {
+    let a: Array3<f64> = random((4, 5, 3));
+    let (q, r) = a.qr().unwrap();
+    assert_eq!(q.dim(), (4, 5, 3));
+    assert_eq!(r.dim(), (4, 3, 3));
+    for ((a_i, q_i), r_i) in a.outer_iter().zip(q.outer_iter()).zip(r.outer_iter()) {
+        assert_close_l2!(&q_i.dot(&r_i), &a_i, 1e-7);
+    }
+}
+
+#[test]
+fn qr_complex_layouts() {
+    for &(n, m) in &[(3, 5), (5, 3)] {
+        let mats: [Array2<c64>; 2] = [random((n, m)), random((n, m).f())];
+        for a in &mats {
+            let (q, r) = a.qr().unwrap();
+            let qh = q.t().mapv(|x| x.conj());
+            assert_close_l2!(&qh.dot(&q), &Array2::eye(min(n, m)), 1e-7);
+            assert_close_l2!(&q.dot(&r), a, 1e-7);
+            assert_close_l2!(&r.clone().into_triangular(UPLO::Upper), &r, 1e-7);
+        }
+    }
+}
@@ -0,0 +1,184 @@
+use ndarray::*;
+use ndarray_linalg::*;
+
+/// `m x n` matrix of rank `r`
+fn low_rank<A: Scalar + Lapack>(m: usize, n: usize, r: usize) -> Array2<A> {
+    let u: Array2<A> = random((m, r));
+    let v: Array2<A> = random((r, n));
+    u.dot(&v)
+}
+
+fn range_finder<A: Scalar + Lapack>() {
+    let a: Array2<A> = low_rank(40, 30, 4);
+    for &q in &[0, 2] {
+        let basis = randomized_range_finder(&a, 4, 3, q).unwrap();
+        assert_eq!(basis.dim(), (40, 7));
+        let qh: Array2<A> = conjugate(&basis);
+        assert_close_l2!(&qh.dot(&basis), &Array2::eye(7), A::real(1e-10));
+        assert_close_l2!(&basis.dot(&qh.dot(&a)), &a, A::real(1e-10));
+    }
+}
+
+#[test]
+fn range_finder_low_rank() {
+    range_finder::<f64>();
+    range_finder::<c64>();
+}
+
+#[test]
+fn range_finder_operator() {
+    let a: Array2<f64> = low_rank(30, 20, 3);
+    let csr = CsrMatrix::from_dense(&a);
+    let basis = randomized_range_finder(&csr, 3, 2, 1).unwrap();
+    assert_close_l2!(&basis.dot(&basis.t().dot(&a)), &a, 1e-10);
+}
+
+#[test]
+fn range_finder_clamped() {
+    let a: Array2<f64> = random((10, 4));
+    let basis = randomized_range_finder(&a, 3, 10, 0).unwrap();
+    assert_eq!(basis.dim(), (10, 4));
+    assert_close_l2!(&basis.dot(&basis.t().dot(&a)), &a, 1e-10);
+    assert!(randomized_range_finder(&a, 0, 5, 0).is_err());
+    assert!(randomized_range_finder(&a, 5, 5, 0).is_err());
+}
+
+fn nystrom_low_rank<A: Scalar + Lapack>() {
+    let x: Array2<A> = random((60, 4));
+    let xh: Array2<A> = conjugate(&x);
+    let a = x.dot(&xh);
+    let (e, v) = nystrom(&a, 4, 4, 0).unwrap();
+    let (e_truth, _) = a.eigh_sorted(UPLO::Upper, EigOrder::Descending).unwrap();
+    assert_close_l2!(&e, &e_truth.slice(s![..4]).to_owned(), A::real(1e-8));
+    let ev = v.clone() * &e.mapv(A::from_real);
+    assert_close_l2!(&a.dot(&v), &ev, A::real(1e-8));
+}
+
+#[test]
+fn nystrom_gram() {
+    nystrom_low_rank::<f64>();
+    nystrom_low_rank::<c64>();
+}
+
+#[test]
+fn nystrom_decaying_spectrum() {
+    // Eigenvalues `2^{-i}` in a random basis
+    let n = 50;
+    let u: Array2<f64> = random_unitary(n);
+    let d = Array1::from_shape_fn(n, |i| 0.5_f64.powi(i as i32));
+    let a = (u.clone() * &d).dot(&u.t());
+    let (e, _) = nystrom(&a, 5, 10, 1).unwrap();
+    assert_close_l2!(&e, &d.slice(s![..5]).to_owned(), 1e-6);
+}
+
+#[test]
+fn nystrom_not_square() {
+    let a: Array2<f64> = random((5, 4));
+    assert!(nystrom(&a, 2, 1, 0).is_err());
+}
+
+/// Ratios `|P x| / |x|` for random vectors
+fn distortions<P: LinearOperator<Elem = f64>>(p: &P, d: usize) -> Vec<f64> {
+    (0..10)
+        .map(|_| {
+            let x: Array1<f64> = random(d);
+            p.apply(&x).norm_l2() / x.norm_l2()
+        })
+        .collect()
+}
+
+#[test]
+fn projection_gaussian() {
+    let p: Array2<f64> = gaussian_projection(500, 2000);
+    assert_eq!(p.dim(), (500, 2000));
+    assert!(distortions(&p, 2000).iter().all(|r| (r - 1.0).abs() < 0.3));
+}
+
+#[test]
+fn projection_sparse() {
+    for &density in &[1.0 / 3.0, 1.0 / 2000_f64.sqrt()] {
+        let p: CsrMatrix<f64> = sparse_projection(500, 2000, density);
+        assert_eq!(p.shape(), (500, 2000));
+        let fraction = p.nnz() as f64 / 1e6;
+        assert!((fraction - density).abs() < 0.1 * density);
+        assert!(distortions(&p, 2000).iter().all(|r| (r - 1.0).abs() < 0.3));
+    }
+}
+
+#[test]
+fn projection_min_dim() {
+    assert_eq!(johnson_lindenstrauss_min_dim(1, 0.5), 0);
+    // 4 ln(10^6) / (0.01 / 2 - 0.001 / 3)
+    assert_eq!(johnson_lindenstrauss_min_dim(1_000_000, 0.1), 11842);
+}
+
+#[test]
+fn trace_estimate_rademacher_diagonal() {
+    // Rademacher probes are exact for diagonal matrices
+    let d: Array1<f64> = random(20);
+    let a = from_diag(d.as_slice().unwrap());
+    let est = trace_estimate(&a, 3, ProbeKind::Rademacher).unwrap();
+    assert!((est.value - d.sum()).abs() < 1e-12);
+    assert!(est.standard_error < 1e-12);
+}
+
+fn trace_estimate_hpd<A: Scalar + Lapack>(kind: ProbeKind) {
+    let a: Array2<A> = random_hpd(50);
+    let tr = a.diag().iter().fold(A::zero(), |acc, &x| acc + x);
+    let est = trace_estimate(&a, 2000, kind).unwrap();
+    assert!((est.value - tr).abs() < est.standard_error * A::real(5.0));
+    assert!(est.standard_error < tr.abs() * A::real(0.1));
+}
+
+#[test]
+fn trace_estimate_probes() {
+    trace_estimate_hpd::<f64>(ProbeKind::Rademacher);
+    trace_estimate_hpd::<f64>(ProbeKind::Gaussian);
+    trace_estimate_hpd::<c64>(ProbeKind::Gaussian);
+}
+
+#[test]
+fn trace_estimate_inverse() {
+    // `tr A^{-1}` by the action of the Cholesky factorization
+    let a: Array2<f64> = random_hpd(30);
+    let f = a.factorizec(UPLO::Lower).unwrap();
+    let est = trace_estimate(&f, 2000, ProbeKind::Rademacher).unwrap();
+    let tr = a.invc().unwrap().trace().unwrap();
+    assert!((est.value - tr).abs() < 5.0 * est.standard_error);
+}
+
+#[test]
+fn trace_estimate_invalid() {
+    let a: Array2<f64> = random((3, 4));
+    assert!(trace_estimate(&a, 10, ProbeKind::Gaussian).is_err());
+    let a: Array2<f64> = random((3, 3));
+    assert!(trace_estimate(&a, 0, ProbeKind::Gaussian).is_err());
+}
+
+fn logdet_hpd<A: Scalar + Lapack>() {
+    let a: Array2<A> = random_hpd(60);
+    let (_, logdet) = a.sln_deth().unwrap();
+    let est = logdet_estimate(&a, 200, 40, ProbeKind::Rademacher).unwrap();
+    assert!((est.value - logdet).abs() < est.standard_error * A::real(5.0) + A::real(1e-6));
+}
+
+#[test]
+fn logdet_estimate_hpd() {
+    logdet_hpd::<f64>();
+    logdet_hpd::<c64>();
+}
+
+#[test]
+fn logdet_estimate_diagonal() {
+    // Rademacher probes and full Lanczos steps are exact for diagonal matrices
+    let d = Array1::from_shape_fn(30, |i| 1.0 + i as f64);
+    let a = from_diag(d.as_slice().unwrap());
+    let est = logdet_estimate(&a, 2, 30, ProbeKind::Rademacher).unwrap();
+    assert!((est.value - d.mapv(f64::ln).sum()).abs() < 1e-8);
+}
+
+#[test]
+fn logdet_estimate_indefinite() {
+    let a = from_diag(&[2.0, -1.0, 3.0]);
+    assert!(logdet_estimate(&a, 1, 3, ProbeKind::Gaussian).is_err());
+}
@@ -0,0 +1,25 @@
+use ndarray::*;
+use ndarray_linalg::*;
+
+fn test_real<A: RealScalar>(rtol: A) {
+    let n = 5;
+    let a: Array2<A> = random_hpd(n);
+    let (e, v) = eigh_real(&a, UPLO::Lower).unwrap();
+    assert_close_l2!(&a.dot(&v), &(&v * &e), rtol);
+    assert_close_l2!(&eigvalsh_real(&a, UPLO::Upper).unwrap(), &e, rtol);
+    let l = cholesky_real(&a, UPLO::Lower).unwrap();
+    assert_close_l2!(&l.dot(&l.t()), &a, rtol);
+    let b: Array1<A> = random(n);
+    let x = solveh_real(&a, &b).unwrap();
+    assert_close_l2!(&a.dot(&x), &b, rtol);
+}
+
+#[test]
+fn real_f32() {
+    test_real::<f32>(1e-3);
+}
+
+#[test]
+fn real_f64() {
+    test_real::<f64>(1e-9);
+}
@@ -0,0 +1,82 @@
+use ndarray::*;
+use ndarray_linalg::*;
+
+fn test_care<A: Scalar + Lapack>() {
+    let (n, m) = (6, 2);
+    let a: Array2<A> = random((n, n));
+    let b: Array2<A> = random((n, m));
+    let q: Array2<A> = random_hpd(n);
+    let r: Array2<A> = random_hpd(m);
+    let x = care(&a, &b, &q, &r).unwrap();
+    let ah: Array2<A> = conjugate(&a);
+    let bh: Array2<A> = conjugate(&b);
+    let xh: Array2<A> = conjugate(&x);
+    assert_close_l2!(&xh, &x, A::real(1e-12));
+    // `A^H X + X A + Q = X B R^{-1} B^H X`
+    let k = r.inv().unwrap().dot(&bh).dot(&x);
+    assert_close_l2!(&(ah.dot(&x) + x.dot(&a) + &q), &x.dot(&b).dot(&k), A::real(1e-8));
+    // `X` is positive semidefinite and `A - B K` is stable
+    let (e, _) = x.eigh(UPLO::Upper).unwrap();
+    assert!(e.iter().all(|&e| e >= A::real(-1e-10)));
+    let closed = &a - &b.dot(&k);
+    assert!(closed.eigvals().unwrap().iter().all(|e| e.re() < A::real(0.0)));
+}
+
+#[test]
+fn care_random() {
+    test_care::<f64>();
+    test_care::<c64>();
+}
+
+#[test]
+fn care_scalar() {
+    // `2 x - x^2 + 1 = 0`
+    let x = care(&arr2(&[[1.0]]), &arr2(&[[1.0]]), &arr2(&[[1.0]]), &arr2(&[[1.0]])).unwrap();
+    assert!((x[(0, 0)] - (1.0 + 2.0_f64.sqrt())).abs() < 1e-12);
+}
+
+#[test]
+fn care_shape() {
+    let a: Array2<f64> = random((3, 3));
+    assert!(care(&a, &Array2::zeros((2, 1)), &Array2::eye(3), &Array2::eye(1)).is_err());
+    assert!(care(&a, &Array2::zeros((3, 1)), &Array2::eye(3), &Array2::eye(2)).is_err());
+}
+
+fn test_dare<A: Scalar + Lapack>() {
+    let (n, m) = (6, 2);
+    let a: Array2<A> = random((n, n));
+    let b: Array2<A> = random((n, m));
+    let q: Array2<A> = random_hpd(n);
+    let r: Array2<A> = random_hpd(m);
+    let x = dare(&a, &b, &q, &r).unwrap();
+    let ah: Array2<A> = conjugate(&a);
+    let bh: Array2<A> = conjugate(&b);
+    let xh: Array2<A> = conjugate(&x);
+    assert_close_l2!(&xh, &x, A::real(1e-12));
+    // `A^H X A - X + Q = A^H X B K`
+    let k = (&r + &bh.dot(&x).dot(&b)).inv().unwrap().dot(&bh).dot(&x).dot(&a);
+    let lhs = ah.dot(&x).dot(&a) - &x + &q;
+    assert_close_l2!(&lhs, &ah.dot(&x).dot(&b).dot(&k), A::real(1e-8));
+    // `A - B K` is stable
+    let closed = &a - &b.dot(&k);
+    assert!(closed.eigvals().unwrap().iter().all(|e| e.abs() < A::real(1.0)));
+}
+
+#[test]
+fn dare_random() {
+    test_dare::<f64>();
+    test_dare::<c64>();
+}
+
+#[test]
+fn dare_singular_a() {
+    // Shift register with the input at the end
+    let a = arr2(&[[0.0, 1.0, 0.0], [0.0, 0.0, 1.0], [0.0, 0.0, 0.0]]);
+    let b = arr2(&[[0.0], [0.0], [1.0]]);
+    let q = Array2::eye(3);
+    let r = arr2(&[[1.0]]);
+    let x = dare(&a, &b, &q, &r).unwrap();
+    let k = (&r + &b.t().dot(&x).dot(&b)).inv().unwrap().dot(&b.t()).dot(&x).dot(&a);
+    let lhs = a.t().dot(&x).dot(&a) - &x + &q;
+    assert_close_l2!(&lhs, &a.t().dot(&x).dot(&b).dot(&k), 1e-10);
+}
@@ -0,0 +1,177 @@
+use ndarray::*;
+use ndarray_linalg::*;
+
+fn test_schur<A: Scalar + Lapack>(a: &Array2<A>, q: &Array2<A>, t: &Array2<A>) {
+    let n = a.rows();
+    let qh: Array2<A> = conjugate(q);
+    assert_close_l2!(&qh.dot(q), &Array::eye(n), A::real(1e-7));
+    assert_close_l2!(&q.dot(t).dot(&qh), a, A::real(1e-7));
+}
+
+#[test]
+fn schur_real() {
+    let a: Array2<f64> = random((5, 5));
+    let (q, t) = a.schur().unwrap();
+    test_schur(&a, &q, &t);
+    // quasi-upper triangular
+    for i in 0..5 {
+        for j in 0..i.saturating_sub(1) {
+            assert!(t[(i, j)].abs() < 1e-9);
+        }
+    }
+}
+
+#[test]
+fn schur_real_t() {
+    let a: Array2<f64> = random((5, 5).f());
+    let (q, t) = a.schur().unwrap();
+    test_schur(&a, &q, &t);
+}
+
+#[test]
+fn schur_complex() {
+    let a: Array2<c64> = random((5, 5));
+    let (q, t) = a.schur().unwrap();
+    test_schur(&a, &q, &t);
+    for i in 0..5 {
+        for j in 0..i {
+            assert!(t[(i, j)].abs() < 1e-9);
+        }
+    }
+}
+
+#[test]
+fn reorder_real() {
+    let a: Array2<f64> = arr2(&[[1.0, 2.0, 3.0], [0.0, 2.0, 1.0], [0.0, 0.0, 3.0]]);
+    let (mut q, mut t) = a.schur().unwrap();
+    let select: Vec<bool> = (0..3).map(|i| (t[(i, i)] - 3.0).abs() < 1e-9).collect();
+    let m = reorder_schur(&mut q, &mut t, &select).unwrap();
+    assert_eq!(m, 1);
+    assert_rclose!(t[(0, 0)], 3.0, 1e-9);
+    test_schur(&a, &q, &t);
+}
+
+#[test]
+fn reorder_complex() {
+    let u: Array2<c64> = random_unitary(4);
+    let d = from_diag(&[
+        c64::new(1.0, 0.0),
+        c64::new(2.0, 1.0),
+        c64::new(3.0, 0.0),
+        c64::new(4.0, -1.0),
+    ]);
+    let uh: Array2<c64> = conjugate(&u);
+    let a = u.dot(&d).dot(&uh);
+    let (mut q, mut t) = a.schur().unwrap();
+    let target = [c64::new(2.0, 1.0), c64::new(4.0, -1.0)];
+    let select: Vec<bool> = (0..4)
+        .map(|i| target.iter().any(|e| (t[(i, i)] - e).norm() < 1e-7))
+        .collect();
+    let m = reorder_schur(&mut q, &mut t, &select).unwrap();
+    assert_eq!(m, 2);
+    for i in 0..2 {
+        assert!(target.iter().any(|e| (t[(i, i)] - e).norm() < 1e-7));
+    }
+    test_schur(&a, &q, &t);
+}
+
+fn test_qz<A: Scalar + Lapack>(a: &Array2<A>, b: &Array2<A>, qz: &GeneralizedSchur<A>) {
+    let n = a.rows();
+    let qh: Array2<A> = conjugate(&qz.q);
+    let zh: Array2<A> = conjugate(&qz.z);
+    assert_close_l2!(&qh.dot(&qz.q), &Array::eye(n), A::real(1e-7));
+    assert_close_l2!(&zh.dot(&qz.z), &Array::eye(n), A::real(1e-7));
+    assert_close_l2!(&qz.q.dot(&qz.s).dot(&zh), a, A::real(1e-7));
+    assert_close_l2!(&qz.q.dot(&qz.t).dot(&zh), b, A::real(1e-7));
+}
+
+#[test]
+fn qz_real() {
+    let a: Array2<f64> = random((4, 4));
+    let b: Array2<f64> = random((4, 4).f());
+    let qz = a.qz(&b).unwrap();
+    test_qz(&a, &b, &qz);
+    for i in 0..4 {
+        for j in 0..i {
+            assert!(qz.t[(i, j)].abs() < 1e-9);
+        }
+    }
+}
+
+#[test]
+fn qz_complex() {
+    let a: Array2<c64> = random((4, 4));
+    let b: Array2<c64> = random((4, 4));
+    let qz = a.qz(&b).unwrap();
+    test_qz(&a, &b, &qz);
+    for i in 0..4 {
+        assert_close_l2!(&arr1(&[qz.alpha[i]]), &arr1(&[qz.s[(i, i)]]), 1e-9);
+        assert_close_l2!(&arr1(&[qz.beta[i]]), &arr1(&[qz.t[(i, i)]]), 1e-9);
+    }
+}
+
+#[test]
+fn qz_reorder() {
+    // generalized eigenvalues 1, 2, 3, 4
+    let a: Array2<f64> = from_diag(&[1.0, 2.0, 3.0, 4.0]);
+    let b: Array2<f64> = Array::eye(4);
+    let (u, v): (Array2<f64>, Array2<f64>) = (random_unitary(4), random_unitary(4));
+    let (a, b) = (u.dot(&a).dot(&v), u.dot(&b).dot(&v));
+    let mut qz = a.qz(&b).unwrap();
+    // move the eigenvalues larger than 2.5 to the leading block
+    let select: Vec<bool> = qz
+        .alpha
+        .iter()
+        .zip(qz.beta.iter())
+        .map(|(a, b)| (a / b).re > 2.5)
+        .collect();
+    let m = qz.reorder(&select).unwrap();
+    assert_eq!(m, 2);
+    for i in 0..2 {
+        assert!((qz.alpha[i] / qz.beta[i]).re > 2.5);
+    }
+    test_qz(&a, &b, &qz);
+}
+
+#[test]
+fn condition_normal() {
+    // eigenvalues of normal matrices are perfectly conditioned
+    let a: Array2<f64> = from_diag(&[1.0, 2.0, 4.0]);
+    let (q, t) = a.schur().unwrap();
+    let (s, sep) = schur_condition(&q, &t).unwrap();
+    assert_close_l2!(&s, &arr1(&[1.0, 1.0, 1.0]), 1e-9);
+    // sep is the distance to the nearest other eigenvalue
+    let mut sep = sep.to_vec();
+    sep.sort_by(|x, y| x.partial_cmp(y).unwrap());
+    assert_close_l2!(&arr1(&sep), &arr1(&[1.0, 1.0, 2.0]), 1e-9);
+}
+
+#[test]
+fn condition_non_normal() {
+    let a: Array2<f64> = arr2(&[[1.0, 1e4], [0.0, 1.001]]);
+    let (_, s, _) = a.eig_condition().unwrap();
+    for s in s.iter() {
+        assert!(*s < 1e-2);
+    }
+}
+
+#[test]
+fn condition_complex() {
+    let a: Array2<c64> = random((4, 4));
+    let (e, s, sep) = a.eig_condition().unwrap();
+    assert_eq!(e.len(), 4);
+    for (s, sep) in s.iter().zip(sep.iter()) {
+        assert!(*s > 0.0 && *s <= 1.0 + 1e-9);
+        assert!(*sep > 0.0);
+    }
+}
+
+#[test]
+fn condition_invariant_subspace() {
+    let a: Array2<f64> = from_diag(&[1.0, 2.0, 4.0]);
+    let (q, t) = a.schur().unwrap();
+    let select: Vec<bool> = (0..3).map(|i| t[(i, i)] > 1.5).collect();
+    let (s, sep) = invariant_subspace_condition(&q, &t, &select).unwrap();
+    assert_rclose!(s, 1.0, 1e-9);
+    assert_rclose!(sep, 1.0, 1e-9);
+}
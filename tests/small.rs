@@ -0,0 +1,66 @@
+use ndarray::*;
+use ndarray_linalg::error::LinalgError;
+use ndarray_linalg::*;
+
+fn test_small<A: Scalar + Lapack>(n: usize, rtol: A::Real) {
+    let a: Array2<A> = random_regular(n);
+    assert_rclose!(a.det_small().unwrap(), a.det().unwrap(), rtol);
+    assert_close_l2!(&a.inv_small().unwrap(), &a.inv().unwrap(), rtol);
+    let b: Array1<A> = random(n);
+    assert_close_l2!(&a.solve_small(&b).unwrap(), &a.solve(&b).unwrap(), rtol);
+    let h: Array2<A> = random_hermite(n);
+    let e = h.eigvalsh(UPLO::Upper).unwrap();
+    assert_close_l2!(&h.eigvalsh_small(UPLO::Upper).unwrap(), &e, rtol);
+    assert_close_l2!(&h.eigvalsh_small(UPLO::Lower).unwrap(), &e, rtol);
+}
+
+#[test]
+fn small_f64() {
+    for n in 1..=3 {
+        test_small::<f64>(n, 1e-9);
+    }
+}
+
+#[test]
+fn small_c64() {
+    for n in 1..=3 {
+        test_small::<c64>(n, 1e-9);
+    }
+}
+
+#[test]
+fn small_det_sign() {
+    let a = arr2(&[[0.0, 1.0, 0.0], [1.0, 0.0, 0.0], [0.0, 0.0, 2.0]]);
+    assert_rclose!(a.det_small().unwrap(), -2.0, 1e-12);
+}
+
+#[test]
+fn small_eigvalsh_diagonal() {
+    let a = arr2(&[[3.0, 0.0, 0.0], [0.0, 1.0, 0.0], [0.0, 0.0, 2.0]]);
+    assert_eq!(a.eigvalsh_small(UPLO::Lower).unwrap(), arr1(&[1.0, 2.0, 3.0]));
+}
+
+#[test]
+fn small_eigvalsh_degenerate() {
+    // eigenvalues 0, 0, 3
+    let a = Array2::<f64>::ones((3, 3));
+    assert_close_l2!(&a.eigvalsh_small(UPLO::Upper).unwrap(), &arr1(&[0.0, 0.0, 3.0]), 1e-12);
+}
+
+#[test]
+fn small_singular() {
+    let a = arr2(&[[1.0, 2.0], [2.0, 4.0]]);
+    match a.inv_small() {
+        Err(LinalgError::Lapack { return_code: 2 }) => {}
+        res => panic!("unexpected: {:?}", res),
+    }
+}
+
+#[test]
+fn small_shape() {
+    let a = Array2::<f64>::eye(4);
+    assert!(a.det_small().is_err());
+    assert!(Array2::<f64>::zeros((2, 3)).inv_small().is_err());
+    let b = Array1::<f64>::ones(3);
+    assert!(Array2::<f64>::eye(2).solve_small(&b).is_err());
+}
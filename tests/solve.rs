@@ -66,3 +66,49 @@ fn rcond_identity() {
         rcond_identity!(c32, rows, 1e-3);
     }
 }
+
+#[test]
+fn solve_stack() {
+    let a: Array3<f64> = random((5, 3, 3));
+    let b: Array2<f64> = random((5, 3));
+    for &threads in &[0, 1, 2] {
+        set_batch_threads(threads);
+        let x = a.solve_stack(&b).unwrap();
+        let inv = a.inv().unwrap();
+        for (((a_i, b_i), x_i), inv_i) in a
+            .outer_iter()
+            .zip(b.outer_iter())
+            .zip(x.outer_iter())
+            .zip(inv.outer_iter())
+        {
+            assert_close_l2!(&a_i.dot(&x_i), &b_i, 1e-7);
+            assert_close_l2!(&inv_i, &a_i.inv().unwrap(), 1e-7);
+        }
+    }
+    set_batch_threads(0);
+    assert!(a.solve_stack(&Array2::<f64>::zeros((4, 3))).is_err());
+}
+
+#[test]
+fn solve_layouts() {
+    let x: Array1<c64> = random(4);
+    let mats: [Array2<c64>; 2] = [random((4, 4)), random((4, 4).f())];
+    for a in &mats {
+        let b = a.dot(&x);
+        assert_close_l2!(&a.solve(&b).unwrap(), &x, 1e-7);
+        let b = a.t().dot(&x);
+        assert_close_l2!(&a.solve_t(&b).unwrap(), &x, 1e-7);
+        let b = a.t().mapv(|x| x.conj()).dot(&x);
+        assert_close_l2!(&a.solve_h(&b).unwrap(), &x, 1e-7);
+    }
+}
+
+#[test]
+fn rcond_layouts() {
+    // `|A|_1 |A^{-1}|_1 = 4` differs from `|A|_inf |A^{-1}|_inf = 9`
+    let a = arr2(&[[1.0, 1.0, 1.0], [0.0, 1.0, 0.0], [0.0, 0.0, 1.0]]);
+    assert_aclose!(a.rcond().unwrap(), 0.25, 1e-9);
+    let mut af = Array2::zeros((3, 3).f());
+    af.assign(&a);
+    assert_aclose!(af.rcond().unwrap(), 0.25, 1e-9);
+}
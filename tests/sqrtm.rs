@@ -0,0 +1,59 @@
+use ndarray::*;
+use ndarray_linalg::error::LinalgError;
+use ndarray_linalg::*;
+
+#[test]
+fn sqrtm_triangular() {
+    let a: Array2<f64> = array![[4., 1.], [0., 9.]];
+    let r = a.sqrtm().unwrap();
+    let ans = array![[2., 0.2], [0., 3.]].mapv(|x: f64| x.as_c());
+    assert_close_l2!(&r, &ans, 1e-12);
+}
+
+#[test]
+fn sqrtm_hpd() {
+    let a: Array2<f64> = random_hpd(5);
+    let r = a.sqrtm().unwrap();
+    let ans = a.sqrt_h(UPLO::Upper, None).unwrap().mapv(|x| x.as_c());
+    assert_close_l2!(&r, &ans, 1e-9);
+}
+
+#[test]
+fn sqrtm_negative_eigenvalue() {
+    let a: Array2<f64> = array![[-1., 0.], [0., 4.]];
+    let r = a.sqrtm().unwrap();
+    let ans = array![
+        [c64::new(0., 1.), c64::new(0., 0.)],
+        [c64::new(0., 0.), c64::new(2., 0.)]
+    ];
+    assert_close_l2!(&r, &ans, 1e-12);
+}
+
+#[test]
+fn sqrtm_square() {
+    let a: Array2<f64> = random((6, 6));
+    let r = a.sqrtm().unwrap();
+    assert_close_l2!(&r.dot(&r), &a.mapv(|x| x.as_c()), 1e-9);
+
+    let a: Array2<c64> = random((6, 6));
+    let r = a.sqrtm().unwrap();
+    assert_close_l2!(&r.dot(&r), &a, 1e-9);
+}
+
+#[test]
+fn sqrtm_not_square() {
+    let a: Array2<f64> = random((3, 4));
+    assert!(a.sqrtm().is_err());
+}
+
+#[test]
+fn sqrtm_no_square_root() {
+    let a: Array2<f64> = array![[0., 1.], [0., 0.]];
+    match a.sqrtm() {
+        Err(LinalgError::Lapack { return_code }) => assert_eq!(return_code, 2),
+        _ => panic!("must fail"),
+    }
+    // The zero matrix is its own square root
+    let a: Array2<f64> = Array2::zeros((2, 2));
+    assert_close_l2!(&a.sqrtm().unwrap(), &Array2::<c64>::zeros((2, 2)), 1e-12);
+}
@@ -78,3 +78,53 @@ test_svd_impl!(test, 3, 4);
 test_svd_impl!(test_no_vt, 3, 4);
 test_svd_impl!(test_no_u, 3, 4);
 test_svd_impl!(test_diag_only, 3, 4);
+
+#[test]
+fn svd_stack() {
+    let a: Array3<f64> = random((5, 4, 3));
+    let (u, s, vt) = a.svd(true, true).unwrap();
+    let u = u.unwrap();
+    let vt = vt.unwrap();
+    assert_eq!(u.dim(), (5, 4, 4));
+    assert_eq!(s.dim(), (5, 3));
+    assert_eq!(vt.dim(), (5, 3, 3));
+    for i in 0..5 {
+        let mut sm = Array::zeros((4, 3));
+        for j in 0..3 {
+            sm[(j, j)] = s[(i, j)];
+        }
+        let a_i = a.index_axis(Axis(0), i);
+        let usv = u.index_axis(Axis(0), i).dot(&sm).dot(&vt.index_axis(Axis(0), i));
+        assert_close_l2!(&usv, &a_i, 1e-7);
+    }
+}
+
+#[test]
+fn svd_stack_values_only() {
+    let a: Array3<f64> = random((5, 3, 4).f());
+    let (u, s, vt) = a.svd(false, false).unwrap();
+    assert!(u.is_none());
+    assert!(vt.is_none());
+    for (a_i, s_i) in a.outer_iter().zip(s.outer_iter()) {
+        let (_, s_ans, _) = a_i.svd(false, false).unwrap();
+        assert_close_l2!(&s_i, &s_ans, 1e-7);
+    }
+}
+
+#[test]
+fn svd_complex_layouts() {
+    for &(n, m) in &[(3, 5), (5, 3)] {
+        let mats: [Array2<c64>; 2] = [random((n, m)), random((n, m).f())];
+        for a in &mats {
+            let (u, s, vt) = a.svd(true, true).unwrap();
+            let (u, vt) = (u.unwrap(), vt.unwrap());
+            assert_eq!(u.dim(), (n, n));
+            assert_eq!(vt.dim(), (m, m));
+            let mut sm = Array2::zeros((n, m));
+            for i in 0..min(n, m) {
+                sm[(i, i)] = c64::new(s[i], 0.0);
+            }
+            assert_close_l2!(&u.dot(&sm).dot(&vt), a, 1e-7);
+        }
+    }
+}
@@ -72,3 +72,21 @@ macro_rules! test_svd_impl {
 test_svd_impl!(3, 3);
 test_svd_impl!(4, 3);
 test_svd_impl!(3, 4);
+
+#[test]
+fn svddc_stack_some() {
+    let a: Array3<f64> = random((4, 5, 3));
+    let (u, s, vt) = a.svddc(UVTFlag::Some).unwrap();
+    let u = u.unwrap();
+    let vt = vt.unwrap();
+    assert_eq!(u.dim(), (4, 5, 3));
+    assert_eq!(vt.dim(), (4, 3, 3));
+    for i in 0..4 {
+        let mut sm = Array::zeros((3, 3));
+        for j in 0..3 {
+            sm[(j, j)] = s[(i, j)];
+        }
+        let usv = u.index_axis(Axis(0), i).dot(&sm).dot(&vt.index_axis(Axis(0), i));
+        assert_close_l2!(&usv, &a.index_axis(Axis(0), i), 1e-7);
+    }
+}
@@ -0,0 +1,14 @@
+use ndarray_linalg::*;
+
+#[test]
+fn num_threads() {
+    let saved = get_num_threads();
+    assert!(saved >= 1);
+    set_num_threads(1);
+    assert_eq!(get_num_threads(), 1);
+    // `0` is ignored
+    set_num_threads(0);
+    assert_eq!(get_num_threads(), 1);
+    set_num_threads(saved);
+    assert_eq!(get_num_threads(), saved);
+}
@@ -0,0 +1,114 @@
+use ndarray::*;
+use ndarray_linalg::*;
+
+/// Random diagonally dominant Toeplitz matrix
+fn random_toeplitz<A: Scalar>(n: usize) -> Toeplitz<A> {
+    let mut c: Array1<A> = random(n);
+    c[0] = c[0] + A::real(2 * n);
+    Toeplitz::new(c, random(n)).unwrap()
+}
+
+#[test]
+fn toeplitz_dense() {
+    let t: Toeplitz<f64> = random_toeplitz(5);
+    let a = t.to_dense();
+    for i in 0..5 {
+        for j in 0..5 {
+            let k = i as isize - j as isize;
+            let expected = if k >= 0 {
+                t.column()[k as usize]
+            } else {
+                t.row()[(-k) as usize]
+            };
+            assert_eq!(a[(i, j)], expected);
+        }
+    }
+    assert_eq!(t.t().to_dense(), a.t());
+    let x: Array1<f64> = random(5);
+    assert_close_l2!(&t.dot(&x), &a.dot(&x), 1e-12);
+}
+
+#[test]
+fn toeplitz_solve() {
+    let n = 10;
+    let t: Toeplitz<f64> = random_toeplitz(n);
+    let a = t.to_dense();
+    let x: Array1<f64> = random(n);
+    assert_close_l2!(&t.solve(&a.dot(&x)).unwrap(), &x, 1e-9);
+    assert_close_l2!(&t.solve_t(&a.t().dot(&x)).unwrap(), &x, 1e-9);
+}
+
+#[test]
+fn toeplitz_solve_complex() {
+    let n = 8;
+    let t: Toeplitz<c64> = random_toeplitz(n);
+    let a = t.to_dense();
+    let ah: Array2<c64> = conjugate(&a);
+    let x: Array1<c64> = random(n);
+    assert_close_l2!(&t.solve(&a.dot(&x)).unwrap(), &x, 1e-9);
+    assert_close_l2!(&t.solve_t(&a.t().dot(&x)).unwrap(), &x, 1e-9);
+    assert_close_l2!(&t.solve_h(&ah.dot(&x)).unwrap(), &x, 1e-9);
+}
+
+#[test]
+fn toeplitz_hermitian() {
+    let mut c: Array1<c64> = random(6);
+    c[0] = c64::new(10.0, 0.0);
+    let t = Toeplitz::hermitian(c);
+    let a = t.to_dense();
+    let ah: Array2<c64> = conjugate(&a);
+    assert_close_l2!(&a, &ah, 1e-15);
+    let x: Array1<c64> = random(6);
+    assert_close_l2!(&t.solve(&a.dot(&x)).unwrap(), &x, 1e-9);
+}
+
+#[test]
+fn toeplitz_singular_leading_minor() {
+    let t = Toeplitz::new(arr1(&[0.0, 1.0]), arr1(&[0.0, 1.0])).unwrap();
+    assert!(t.solve(&arr1(&[1.0, 1.0])).is_err());
+}
+
+#[test]
+fn toeplitz_shape_mismatch() {
+    assert!(Toeplitz::new(arr1(&[1.0, 0.5]), arr1(&[1.0])).is_err());
+    let t: Toeplitz<f64> = random_toeplitz(4);
+    assert!(t.solve(&Array1::zeros(3)).is_err());
+}
+
+#[test]
+fn levinson_durbin_ar2() {
+    // Autocorrelation of x_t = 0.5 x_{t-1} - 0.3 x_{t-2} + e_t
+    let r = arr1(&[1.0, 0.5 / 1.3, 0.5 * 0.5 / 1.3 - 0.3]);
+    let ar = levinson_durbin(&r).unwrap();
+    assert_close_l2!(&ar.coefficients, &arr1(&[0.5, -0.3]), 1e-12);
+    assert_rclose!(ar.reflection[1], -0.3, 1e-12);
+    assert_rclose!(ar.error, r[0] - r[1] * 0.5 + r[2] * 0.3, 1e-12);
+}
+
+#[test]
+fn levinson_durbin_complex() {
+    let p = 5;
+    let t: Toeplitz<c64> = {
+        let mut c: Array1<c64> = random(p + 1);
+        c[0] = c64::new(3.0 * p as f64, 0.0);
+        Toeplitz::hermitian(c)
+    };
+    let r = t.column().to_owned();
+    let ar = levinson_durbin(&r).unwrap();
+    // Yule-Walker equations by the dense solver
+    let a = t.to_dense().slice(s![..p, ..p]).to_owned();
+    let expected = a.solve(&r.slice(s![1..]).to_owned()).unwrap();
+    assert_close_l2!(&ar.coefficients, &expected, 1e-9);
+    let error = r
+        .slice(s![1..])
+        .iter()
+        .zip(expected.iter())
+        .fold(r[0], |acc, (&ri, &ai)| acc - ai.conj() * ri);
+    assert_rclose!(ar.error, error.re, 1e-9);
+}
+
+#[test]
+fn levinson_durbin_not_positive_definite() {
+    assert!(levinson_durbin(&arr1(&[1.0, 1.0, 0.5])).is_err());
+    assert!(levinson_durbin(&Array1::<f64>::zeros(0)).is_err());
+}
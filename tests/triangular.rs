@@ -118,3 +118,21 @@ fn triangular_2d_upper_t_bt() {
     let a: Array2<f64> = random((3, 3).f()).into_triangular(UPLO::Upper);
     test2d(UPLO::Upper, &a, &b, 1e-7);
 }
+
+fn test_opnorm<A: Scalar + Lapack>(t: TriangularView<A>) {
+    let a = t.to_dense();
+    assert_rclose!(t.opnorm_one().unwrap(), a.opnorm_one().unwrap(), A::real(1e-5));
+    assert_rclose!(t.opnorm_inf().unwrap(), a.opnorm_inf().unwrap(), A::real(1e-5));
+    assert_rclose!(t.opnorm_fro().unwrap(), a.opnorm_fro().unwrap(), A::real(1e-5));
+    assert_rclose!(t.opnorm_two().unwrap(), a.opnorm_two().unwrap(), A::real(1e-5));
+}
+
+#[test]
+fn triangular_opnorm() {
+    let a: Array2<f64> = random((4, 4));
+    test_opnorm(TriangularView::new(a.view(), UPLO::Upper, Diag::NonUnit));
+    test_opnorm(TriangularView::new(a.view(), UPLO::Lower, Diag::Unit));
+    let a: Array2<c32> = random((5, 3).f());
+    test_opnorm(TriangularView::new(a.view(), UPLO::Lower, Diag::NonUnit));
+    test_opnorm(TriangularView::new(a.t(), UPLO::Upper, Diag::Unit));
+}
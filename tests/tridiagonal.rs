@@ -0,0 +1,99 @@
+use ndarray::*;
+use ndarray_linalg::*;
+
+fn random_tridiagonal<A: Scalar>(n: usize) -> Tridiagonal<A> {
+    Tridiagonal::new(random(n - 1), random(n), random(n - 1)).unwrap()
+}
+
+#[test]
+fn tridiagonal_dense_roundtrip() {
+    let t: Tridiagonal<f64> = random_tridiagonal(6);
+    let a = t.to_dense();
+    let t2 = Tridiagonal::from_dense(&a).unwrap();
+    assert_eq!(t2.to_dense(), a);
+    let x: Array1<f64> = random(6);
+    assert_close_l2!(&t.dot(&x), &a.dot(&x), 1e-12);
+}
+
+#[test]
+fn tridiagonal_solve() {
+    let n = 12;
+    let t: Tridiagonal<f64> = random_tridiagonal(n);
+    let a = t.to_dense();
+    let x: Array1<f64> = random(n);
+    assert_close_l2!(&t.solve(&a.dot(&x)).unwrap(), &x, 1e-8);
+    let f = t.factorize().unwrap();
+    for _ in 0..3 {
+        let x: Array1<f64> = random(n);
+        assert_close_l2!(&f.solve(&a.dot(&x)).unwrap(), &x, 1e-8);
+        assert_close_l2!(&f.solve_t(&a.t().dot(&x)).unwrap(), &x, 1e-8);
+    }
+}
+
+#[test]
+fn tridiagonal_solve_complex() {
+    let n = 8;
+    let t: Tridiagonal<c64> = random_tridiagonal(n);
+    let a = t.to_dense();
+    let ah: Array2<c64> = conjugate(&a);
+    let x: Array1<c64> = random(n);
+    let f = t.factorize_into().unwrap();
+    assert_close_l2!(&f.solve(&a.dot(&x)).unwrap(), &x, 1e-8);
+    assert_close_l2!(&f.solve_h(&ah.dot(&x)).unwrap(), &x, 1e-8);
+}
+
+#[test]
+fn tridiagonal_needs_pivoting() {
+    // The Thomas algorithm without pivoting fails by the zero in the first pivot
+    let t = Tridiagonal::new(arr1(&[1.0, 1.0]), arr1(&[0.0, 1.0, 2.0]), arr1(&[1.0, 1.0])).unwrap();
+    let x = arr1(&[1.0, 2.0, 3.0]);
+    assert_close_l2!(&t.solve(&t.dot(&x)).unwrap(), &x, 1e-12);
+}
+
+#[test]
+fn tridiagonal_shape_mismatch() {
+    assert!(Tridiagonal::new(arr1(&[1.0]), arr1(&[1.0, 1.0, 1.0]), arr1(&[1.0, 1.0])).is_err());
+    let t: Tridiagonal<f64> = random_tridiagonal(4);
+    assert!(t.solve(&Array1::zeros(3)).is_err());
+}
+
+/// Random diagonally dominant Hermitian tridiagonal matrix
+fn random_hpd_tridiagonal<A: Scalar>(n: usize) -> HermitianTridiagonal<A> {
+    let e: Array1<A> = random(n - 1);
+    let d: Array1<A::Real> = Array1::from_elem(n, A::real(3.0));
+    HermitianTridiagonal::new(d, e).unwrap()
+}
+
+#[test]
+fn hermitian_tridiagonal_solve() {
+    let n = 10;
+    let t: HermitianTridiagonal<f64> = random_hpd_tridiagonal(n);
+    let a = t.to_dense();
+    let x: Array1<f64> = random(n);
+    assert_close_l2!(&t.dot(&x), &a.dot(&x), 1e-12);
+    assert_close_l2!(&t.solvec(&a.dot(&x)).unwrap(), &x, 1e-9);
+    let f = t.factorizec().unwrap();
+    for _ in 0..3 {
+        let x: Array1<f64> = random(n);
+        assert_close_l2!(&f.solvec(&a.dot(&x)).unwrap(), &x, 1e-9);
+    }
+}
+
+#[test]
+fn hermitian_tridiagonal_solve_complex() {
+    let n = 8;
+    let t: HermitianTridiagonal<c64> = random_hpd_tridiagonal(n);
+    let a = t.to_dense();
+    let ah: Array2<c64> = conjugate(&a);
+    assert_close_l2!(&a, &ah, 1e-15);
+    let x: Array1<c64> = random(n);
+    assert_close_l2!(&t.dot(&x), &a.dot(&x), 1e-12);
+    let f = t.factorizec_into().unwrap();
+    assert_close_l2!(&f.solvec(&a.dot(&x)).unwrap(), &x, 1e-9);
+}
+
+#[test]
+fn hermitian_tridiagonal_not_positive_definite() {
+    let t = HermitianTridiagonal::new(arr1(&[1.0, -1.0, 1.0]), arr1(&[0.5, 0.5])).unwrap();
+    assert!(t.factorizec().is_err());
+}
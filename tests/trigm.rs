@@ -0,0 +1,55 @@
+use ndarray::*;
+use ndarray_linalg::*;
+
+/// (cos(A), sin(A)) = V (cos(D), sin(D)) V^H for a Hermitian matrix A = V D V^H
+fn sincosm_hermite<A: Scalar + Lapack>(a: &Array2<A>) -> (Array2<A>, Array2<A>) {
+    let (e, v) = a.eigh(UPLO::Upper).unwrap();
+    let vh: Array2<A> = conjugate(&v);
+    let vc = Array2::from_shape_fn(v.dim(), |(i, j)| v[(i, j)].mul_real(e[j].cos()));
+    let vs = Array2::from_shape_fn(v.dim(), |(i, j)| v[(i, j)].mul_real(e[j].sin()));
+    (vc.dot(&vh), vs.dot(&vh))
+}
+
+#[test]
+fn sincosm_symmetric() {
+    for &scale in &[1e-3, 0.5, 2.0, 10.0, 50.0] {
+        let a: Array2<f64> = random_hermite(5);
+        let a = a.mapv(|x| x * scale);
+        let (c, s) = sincosm_hermite(&a);
+        assert_close_l2!(&a.cosm().unwrap(), &c, 1e-9);
+        assert_close_l2!(&a.sinm().unwrap(), &s, 1e-9);
+    }
+}
+
+#[test]
+fn sincosm_complex() {
+    let a: Array2<c64> = random_hermite(4);
+    let a = a.mapv(|x| x * 3.0);
+    let (c, s) = a.sincosm().unwrap();
+    let (c_ans, s_ans) = sincosm_hermite(&a);
+    assert_close_l2!(&c, &c_ans, 1e-10);
+    assert_close_l2!(&s, &s_ans, 1e-10);
+}
+
+#[test]
+fn sincosm_pythagorean() {
+    let a: Array2<f64> = random((6, 6));
+    let a = a.mapv(|x| x * 2.0);
+    let (c, s) = a.sincosm().unwrap();
+    assert_close_l2!(&(c.dot(&c) + s.dot(&s)), &Array2::eye(6), 1e-9);
+}
+
+#[test]
+fn sincosm_euler() {
+    // exp(iA) = cos(A) + i sin(A)
+    let a: Array2<f64> = random((4, 4));
+    let (c, s) = a.sincosm().unwrap();
+    let e = a.mapv(|x| c64::new(0., x)).expm().unwrap();
+    assert_close_l2!(&e, &Array2::from_shape_fn((4, 4), |ij| c64::new(c[ij], s[ij])), 1e-10);
+}
+
+#[test]
+fn sincosm_not_square() {
+    let a: Array2<f64> = random((3, 4));
+    assert!(a.sincosm().is_err());
+}
@@ -0,0 +1,63 @@
+use ndarray::*;
+use ndarray_linalg::*;
+
+fn random_dplr<A: Scalar>(n: usize, k: usize) -> DiagPlusLowRank<A> {
+    let d: Array1<A> = random(n);
+    let d = d.mapv(|x| x + A::from_real(A::real(2.0)));
+    DiagPlusLowRank::new(d, random((n, k)), random((n, k))).unwrap()
+}
+
+#[test]
+fn woodbury_dense() {
+    let a: DiagPlusLowRank<c64> = random_dplr(7, 2);
+    let dense = a.to_dense();
+    let x: Array1<c64> = random(7);
+    assert_close_l2!(&a.dot(&x), &dense.dot(&x), 1e-12);
+    assert_eq!(a.rank(), 2);
+}
+
+#[test]
+fn woodbury_solve() {
+    let n = 20;
+    let a: DiagPlusLowRank<f64> = random_dplr(n, 3);
+    let dense = a.to_dense();
+    let f = a.factorize().unwrap();
+    let x: Array1<f64> = random(n);
+    assert_close_l2!(&f.solve(&dense.dot(&x)).unwrap(), &x, 1e-8);
+    assert_close_l2!(&f.solve_t(&dense.t().dot(&x)).unwrap(), &x, 1e-8);
+    assert_close_l2!(&a.solve(&dense.dot(&x)).unwrap(), &x, 1e-8);
+}
+
+#[test]
+fn woodbury_solve_complex() {
+    let n = 12;
+    let a: DiagPlusLowRank<c64> = random_dplr(n, 4);
+    let dense = a.to_dense();
+    let dh: Array2<c64> = conjugate(&dense);
+    let f = a.factorize().unwrap();
+    let x: Array1<c64> = random(n);
+    assert_close_l2!(&f.solve(&dense.dot(&x)).unwrap(), &x, 1e-8);
+    assert_close_l2!(&f.solve_t(&dense.t().dot(&x)).unwrap(), &x, 1e-8);
+    assert_close_l2!(&f.solve_h(&dh.dot(&x)).unwrap(), &x, 1e-8);
+}
+
+#[test]
+fn woodbury_det() {
+    let a: DiagPlusLowRank<f64> = random_dplr(8, 2);
+    let det = a.to_dense().det().unwrap();
+    assert_rclose!(a.det().unwrap(), det, 1e-9);
+    assert_rclose!(a.factorize().unwrap().det().unwrap(), det, 1e-9);
+    // Zero in the diagonal with a non-singular sum `[[1, 1], [1, 2]]`
+    let u = arr2(&[[1.0], [1.0]]);
+    let b = DiagPlusLowRank::new(arr1(&[0.0, 1.0]), u.clone(), u).unwrap();
+    assert!(b.factorize().is_err());
+    assert_rclose!(b.det().unwrap(), 1.0, 1e-12);
+}
+
+#[test]
+fn woodbury_shape_mismatch() {
+    assert!(DiagPlusLowRank::new(Array1::<f64>::zeros(3), Array2::zeros((3, 2)), Array2::zeros((3, 1))).is_err());
+    assert!(DiagPlusLowRank::new(Array1::<f64>::zeros(3), Array2::zeros((2, 1)), Array2::zeros((2, 1))).is_err());
+    let a: DiagPlusLowRank<f64> = random_dplr(4, 1);
+    assert!(a.solve(&Array1::zeros(3)).is_err());
+}
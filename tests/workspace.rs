@@ -0,0 +1,73 @@
+use ndarray::*;
+use ndarray_linalg::*;
+
+fn test_eig<A: Scalar + Lapack>(n: usize) {
+    let mut ws = EigWorkspace::<A>::new(n, true, true).unwrap();
+    for _ in 0..3 {
+        let a: Array2<A> = random((n, n));
+        let (e, vl, vr) = a.eig_with_workspace(&mut ws).unwrap();
+        let (ans, ans_vl, ans_vr) = a.eig_full(true, true).unwrap();
+        assert_close_l2!(&e, &ans, A::real(1e-7));
+        assert_close_l2!(&vl.unwrap(), &ans_vl.unwrap(), A::real(1e-7));
+        assert_close_l2!(&vr.unwrap(), &ans_vr.unwrap(), A::real(1e-7));
+    }
+}
+
+fn test_svd<A: Scalar + Lapack>(shape: (usize, usize)) {
+    let mut ws = SVDWorkspace::<A>::new(shape, true, true).unwrap();
+    for _ in 0..3 {
+        let a: Array2<A> = random(shape);
+        let (u, s, vt) = a.svd_with_workspace(&mut ws).unwrap();
+        let (u, vt) = (u.unwrap(), vt.unwrap());
+        let (ans_u, ans_s, ans_vt) = a.svd(true, true).unwrap();
+        assert_close_l2!(&s, &ans_s, A::real(1e-7));
+        assert_eq!(u.dim(), ans_u.unwrap().dim());
+        assert_eq!(vt.dim(), ans_vt.unwrap().dim());
+        let mut sm = Array2::zeros(shape);
+        for (i, &x) in s.iter().enumerate() {
+            sm[(i, i)] = A::from_real(x);
+        }
+        assert_close_l2!(&u.dot(&sm).dot(&vt), &a, A::real(1e-7));
+    }
+}
+
+fn test_qr<A: Scalar + Lapack>(shape: (usize, usize)) {
+    let mut ws = QRWorkspace::<A>::new(shape).unwrap();
+    for _ in 0..3 {
+        let a: Array2<A> = random(shape);
+        let (q, r) = a.qr_with_workspace(&mut ws).unwrap();
+        let (ans_q, ans_r) = a.qr().unwrap();
+        assert_close_l2!(&q, &ans_q, A::real(1e-7));
+        assert_close_l2!(&r, &ans_r, A::real(1e-7));
+    }
+}
+
+#[test]
+fn workspace_eig() {
+    test_eig::<f64>(4);
+    test_eig::<c64>(4);
+}
+
+#[test]
+fn workspace_svd() {
+    test_svd::<f64>((3, 5));
+    test_svd::<f64>((5, 3));
+    test_svd::<c64>((4, 4));
+}
+
+#[test]
+fn workspace_qr() {
+    test_qr::<f64>((5, 3));
+    test_qr::<f64>((3, 5));
+    test_qr::<c64>((4, 4));
+}
+
+#[test]
+fn workspace_shape_mismatch() {
+    let mut ws = QRWorkspace::<f64>::new((3, 3)).unwrap();
+    let a: Array2<f64> = random((4, 3));
+    assert!(a.qr_with_workspace(&mut ws).is_err());
+    let a: Array2<f64> = random((3, 3));
+    assert!(a.t().qr_with_workspace(&mut ws).is_ok());
+    assert!(ws.work_size().work >= 3);
+}